@@ -0,0 +1,66 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Strain Gauge Readings from CSV ===\n");
+
+    // Use the file given on the command line, or write a small sample with x/y/z
+    // columns, an integer channel and a text label per gauge
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            let csv = "\
+x,y,z,channel,strain,label
+0.0,0.0,0.0,1,1.2e-4,base
+0.0,0.0,1.5,2,3.4e-4,column mid
+0.0,0.0,3.0,3,2.9e-4,column top
+2.0,0.0,3.0,4,-1.8e-4,girder mid
+";
+            if let Err(e) = std::fs::write("gauges.csv", csv) {
+                eprintln!("✗ {}", e);
+                return;
+            }
+            "gauges.csv".to_string()
+        }
+    };
+
+    let mut table = match Table::read_csv(&path) {
+        Ok(table) => table,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            return;
+        }
+    };
+    println!("✓ Read {} rows from {}", table.get_number_of_rows(), path);
+    for name in table.get_column_names() {
+        println!("  {:<8} {:?}", name, table.get_column_type(&name));
+    }
+
+    if let Some(strain) = table.get_double_column("strain") {
+        let peak = (0..strain.get_number_of_tuples())
+            .map(|row| strain.get_value(row).abs())
+            .fold(0.0, f64::max);
+        println!("  peak |strain| = {:e}", peak);
+    }
+    if let Some(labels) = table.get_string_column("label") {
+        println!("  labels: {:?}", labels);
+    }
+
+    // One point per row; channel, strain and label become point data arrays
+    let poly_data = match table.to_poly_data("x", "y", "z") {
+        Ok(poly_data) => poly_data,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            return;
+        }
+    };
+    println!("\n✓ {} points", poly_data.get_number_of_points());
+    let point_data = poly_data.get_point_data();
+    for index in 0..point_data.get_number_of_arrays() {
+        println!("  point array: {:?}", point_data.get_array_name(index));
+    }
+
+    match poly_data.write_vtp("gauges.vtp") {
+        Ok(()) => println!("✓ Wrote gauges.vtp"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
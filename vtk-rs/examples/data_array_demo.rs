@@ -100,6 +100,28 @@ fn main() {
         println!("  Node {}: {} (flag {})", i, bc_desc, bc);
     }
 
+    // === Typed Data: Single Precision and Masks ===
+    println!("\n=== Typed Data: Single Precision and Masks ===");
+    // f32 halves the memory of large result fields
+    let mut von_mises = DataArray::<f32>::new_scalar("VonMises");
+    von_mises.insert_next_value(182.5);  // Node 0
+    von_mises.insert_next_value(96.0);   // Node 1
+    von_mises.insert_next_value(12.25);  // Node 2
+
+    // u8 is enough for yes/no flags
+    let mut yielded = DataArray::<u8>::new_scalar("Yielded");
+    for i in 0..von_mises.get_number_of_tuples() {
+        yielded.insert_next_value(u8::from(von_mises.get_value(i) > 150.0));
+    }
+
+    println!("Created {} ({:?}) and {} ({:?})",
+        von_mises.get_name().unwrap(), von_mises.get_data_type(),
+        yielded.get_name().unwrap(), yielded.get_data_type());
+    for i in 0..von_mises.get_number_of_tuples() {
+        let (value, flag) = (von_mises.get_value(i), yielded.get_value(i));
+        println!("  Node {}: {:.2} MPa, yielded = {}", i, value, flag);
+    }
+
     // === Modifying Data ===
     println!("\n=== Modifying Data ===");
     println!("Updating Node 1 displacement magnitude from 2.5 to 3.0 mm");
@@ -0,0 +1,56 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Reading Exodus II Results ===\n");
+
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: exodus_results <file.exo>");
+        return;
+    };
+
+    let mut reader = ExodusIIReader::new();
+    reader.set_file_name(&path);
+    if let Err(e) = reader.update_information() {
+        eprintln!("✗ {}", e);
+        return;
+    }
+
+    // List what the file contains
+    let object_types = [
+        ExodusObjectType::ElementBlock,
+        ExodusObjectType::NodeSet,
+        ExodusObjectType::SideSet,
+    ];
+    for object_type in object_types {
+        let names = reader.get_object_names(object_type);
+        println!("{:?}: {:?}", object_type, names);
+    }
+    let nodal = reader.get_object_array_names(ExodusObjectType::Nodal);
+    let element = reader.get_object_array_names(ExodusObjectType::ElementBlock);
+    println!("Nodal variables: {:?}", nodal);
+    println!("Element variables: {:?}", element);
+
+    // Load the element blocks and node sets with all nodal variables
+    reader.set_all_object_status(ExodusObjectType::NodeSet, true);
+    reader.set_all_array_status(ExodusObjectType::Nodal, true);
+    reader.set_generate_global_node_id_array(true);
+
+    let times = reader.get_time_values();
+    println!("\n{} time steps", times.len());
+    for (step, time) in times.iter().enumerate() {
+        reader.set_time_step(step);
+        if let Err(e) = reader.update() {
+            eprintln!("✗ {}", e);
+            return;
+        }
+        println!("t = {}", time);
+        for block in reader.output_blocks() {
+            println!(
+                "  {:?} \"{}\": {} cells",
+                block.object_type,
+                block.name,
+                block.grid.get_number_of_cells()
+            );
+        }
+    }
+}
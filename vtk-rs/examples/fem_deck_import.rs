@@ -0,0 +1,92 @@
+use vtk_rs::*;
+
+// A brick with a beam stiffener, clamped on one face
+const SAMPLE_INP: &str = "*HEADING
+Bracket
+*NODE, NSET=ALLNODES
+1, 0., 0., 0.
+2, 1., 0., 0.
+3, 1., 1., 0.
+4, 0., 1., 0.
+5, 0., 0., 1.
+6, 1., 0., 1.
+7, 1., 1., 1.
+8, 0., 1., 1.
+9, 2., 0., 0.
+*ELEMENT, TYPE=C3D8R, ELSET=SOLID
+1, 1, 2, 3, 4, 5, 6, 7, 8
+*ELEMENT, TYPE=B31, ELSET=STIFFENER
+2, 2, 9
+*NSET, NSET=CLAMPED
+1, 4, 5, 8
+";
+
+// The same model as Nastran bulk data
+const SAMPLE_BDF: &str = "BEGIN BULK
+GRID,1,,0.,0.,0.
+GRID,2,,1.,0.,0.
+GRID,3,,1.,1.,0.
+GRID,4,,0.,1.,0.
+GRID,5,,0.,0.,1.
+GRID,6,,1.,0.,1.
+GRID,7,,1.,1.,1.
+GRID,8,,0.,1.,1.
+GRID,9,,2.,0.,0.
+CHEXA,1,10,1,2,3,4,5,6,+
++,7,8
+CBAR,2,20,2,9,0.,0.,1.
+SET1,1,1,4,5,8
+SET3,2,PROP,20
+ENDDATA
+";
+
+fn main() {
+    println!("=== Importing Solver Input Decks ===\n");
+
+    // Use the .inp or .bdf file given on the command line, or small samples
+    let paths = match std::env::args().nth(1) {
+        Some(path) => vec![path],
+        None => {
+            for (path, content) in [("sample.inp", SAMPLE_INP), ("sample.bdf", SAMPLE_BDF)] {
+                if let Err(e) = std::fs::write(path, content) {
+                    eprintln!("✗ {}", e);
+                    return;
+                }
+            }
+            vec!["sample.inp".to_string(), "sample.bdf".to_string()]
+        }
+    };
+
+    for path in paths {
+        let mesh = if path.to_ascii_lowercase().ends_with(".inp") {
+            FemMesh::read_inp(&path)
+        } else {
+            FemMesh::read_bdf(&path)
+        };
+        let mesh = match mesh {
+            Ok(mesh) => mesh,
+            Err(e) => {
+                eprintln!("✗ {}", e);
+                continue;
+            }
+        };
+        println!("✓ Read {}: {} nodes, {} elements", path, mesh.points.len(), mesh.elements.len());
+        for set in &mesh.node_sets {
+            println!("  node set \"{}\": {} nodes", set.name, set.ids.len());
+        }
+        for set in &mesh.element_sets {
+            println!("  element set \"{}\": {} elements", set.name, set.ids.len());
+        }
+
+        // Sets become 0/1 arrays that can be used for coloring or thresholding
+        let mut grid = mesh.to_unstructured_grid();
+        let mut mapper = DataSetMapper::new();
+        mapper.set_input_data(&mut grid);
+
+        let output = format!("{}.vtu", path);
+        match grid.write_vtu(&output) {
+            Ok(()) => println!("✓ Wrote {} (cell array \"{}\")", output, ELEMENT_ID_ARRAY),
+            Err(e) => eprintln!("✗ {}", e),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use vtk_rs::*;
+
+// Two tetrahedra with their shared base triangle tagged as a boundary group
+const SAMPLE_MESH: &str = "$MeshFormat
+2.2 0 8
+$EndMeshFormat
+$PhysicalNames
+2
+2 1 \"clamped\"
+3 2 \"steel\"
+$EndPhysicalNames
+$Nodes
+5
+1 0 0 0
+2 1 0 0
+3 0 1 0
+4 0 0 1
+5 0 0 -1
+$EndNodes
+$Elements
+3
+1 2 2 1 1 1 2 3
+2 4 2 2 1 1 2 3 4
+3 4 2 2 1 1 3 2 5
+$EndElements
+";
+
+fn main() {
+    println!("=== Importing a Gmsh Mesh ===\n");
+
+    // Use the .msh file given on the command line, or a small sample mesh
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            if let Err(e) = std::fs::write("sample.msh", SAMPLE_MESH) {
+                eprintln!("✗ {}", e);
+                return;
+            }
+            "sample.msh".to_string()
+        }
+    };
+
+    let mesh = match GmshMesh::read(&path) {
+        Ok(mesh) => mesh,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            return;
+        }
+    };
+    println!("✓ Read {}: {} nodes, {} elements", path, mesh.points.len(), mesh.elements.len());
+    for group in &mesh.physical_names {
+        let count = mesh.elements
+            .iter()
+            .filter(|e| e.dimension == group.dimension && e.physical_tag == group.tag)
+            .count();
+        println!("  {}D group {} \"{}\": {} elements", group.dimension, group.tag, group.name, count);
+    }
+
+    // Keep only the volume elements for the analysis model
+    let grid = mesh.to_unstructured_grid_of_dimension(mesh.max_dimension());
+    println!("✓ Volume mesh with {} cells", grid.get_number_of_cells());
+
+    match grid.write_vtu("gmsh_mesh.vtu") {
+        Ok(()) => println!("✓ Wrote gmsh_mesh.vtu (cell array \"{}\")", GMSH_PHYSICAL_ARRAY),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
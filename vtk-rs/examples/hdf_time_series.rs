@@ -0,0 +1,78 @@
+use vtk_rs::*;
+
+fn build_step(load: f64) -> UnstructuredGrid {
+    // A row of tetrahedra along x
+    let mut points = Points::new();
+    for i in 0..=10 {
+        let x = i as f64;
+        points.insert_next_point(x, 0.0, 0.0);
+        points.insert_next_point(x, 1.0, 0.0);
+        points.insert_next_point(x, 0.0, 1.0);
+    }
+
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(10);
+    for i in 0..10 {
+        let base = i * 3;
+        grid.insert_next_cell(VtkCellType::Tetra, &[base, base + 1, base + 2, base + 3]);
+    }
+
+    let mut displacement = DoubleArray::new_vector("Displacement");
+    for i in 0..=10 {
+        let x = i as f64;
+        for _ in 0..3 {
+            displacement.insert_next_tuple3(0.0, 0.0, -load * x * x * 0.001);
+        }
+    }
+    grid.get_point_data().add_array(&displacement);
+    grid
+}
+
+fn main() {
+    println!("=== Storing a Time Series in one VTKHDF File ===\n");
+
+    let mut writer = HDFWriter::new();
+    writer.set_file_name("beam_series.vtkhdf");
+    writer.set_compression_level(4);
+
+    for step in 0..5 {
+        let time = step as f64 * 0.1;
+        let grid = build_step(step as f64);
+        if let Err(e) = writer.add_time_step(time, &grid) {
+            eprintln!("✗ {}", e);
+            return;
+        }
+    }
+
+    match writer.write() {
+        Ok(()) => {
+            let steps = writer.get_number_of_time_steps();
+            println!("✓ Wrote {} time steps to beam_series.vtkhdf", steps)
+        }
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            return;
+        }
+    }
+
+    let mut reader = HDFReader::new();
+    reader.set_file_name("beam_series.vtkhdf");
+    let times = reader.get_time_values();
+    println!("✓ File contains {} steps: {:?}", times.len(), times);
+
+    reader.set_step(times.len().saturating_sub(1));
+    match reader.update() {
+        Ok(()) => {
+            if let Some(grid) = reader.get_output_unstructured_grid() {
+                println!(
+                    "✓ Last step (t = {}) has {} points and {} cells",
+                    reader.get_time_value(),
+                    grid.get_number_of_points(),
+                    grid.get_number_of_cells()
+                );
+            }
+        }
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
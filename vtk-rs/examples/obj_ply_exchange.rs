@@ -0,0 +1,87 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Exchanging Surfaces as OBJ and PLY ===\n");
+
+    // A procedurally generated part to start from
+    let mut sphere = SphereSource::new();
+    sphere.set_radius(1.0);
+    sphere.set_theta_resolution(24);
+    sphere.set_phi_resolution(16);
+
+    let mut ply_writer = PLYWriter::new();
+    ply_writer.set_file_name("sphere.ply");
+    ply_writer.set_input_connection(sphere.get_output_port());
+    ply_writer.set_file_type(FileType::Ascii);
+    ply_writer.set_color_mode(PLYColorMode::UniformPointColor);
+    ply_writer.set_color(200, 120, 40);
+    if let Err(e) = ply_writer.write() {
+        eprintln!("✗ {}", e);
+        return;
+    }
+    println!("✓ Wrote sphere.ply with a uniform vertex color");
+
+    // Reading it back gives the colors as an "RGB" point array
+    let surface = match PLYReader::read("sphere.ply") {
+        Ok(surface) => surface,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            return;
+        }
+    };
+    let mut point_data = surface.get_point_data();
+    if let Some(colors) = point_data.get_colors("RGB") {
+        println!("Read {} vertex colors, first one is {:?}", colors.len(), colors[0]);
+    }
+
+    // Replace them by a gradient and attach texture coordinates
+    let num_points = surface.get_number_of_points() as usize;
+    let colors: Vec<[u8; 4]> = (0..num_points)
+        .map(|i| {
+            let t = (i * 255 / num_points.max(1)) as u8;
+            [t, 64, 255 - t, 255]
+        })
+        .collect();
+    point_data.set_colors("Gradient", &colors);
+
+    let mut tcoords = DoubleArray::new();
+    tcoords.set_name("TCoords");
+    tcoords.set_number_of_components(2);
+    for i in 0..num_points {
+        tcoords.insert_next_tuple2(i as f64 / num_points as f64, 0.5);
+    }
+    point_data.set_tcoords(&tcoords);
+
+    let mut ply_writer = PLYWriter::new();
+    ply_writer.set_file_name("sphere_gradient.ply");
+    ply_writer.set_input_data(&surface);
+    ply_writer.set_color_mode(PLYColorMode::Default);
+    ply_writer.set_array_name("Gradient");
+    ply_writer.set_enable_alpha(true);
+    ply_writer.set_texture_coordinates_name(PLYTextureCoordinatesName::UV);
+    match ply_writer.write() {
+        Ok(()) => println!("✓ Wrote sphere_gradient.ply"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+
+    let mut obj_writer = OBJWriter::new();
+    obj_writer.set_file_name("sphere.obj");
+    obj_writer.set_input_data(&surface);
+    match obj_writer.write() {
+        Ok(()) => println!("✓ Wrote sphere.obj"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+
+    match OBJReader::read("sphere.obj") {
+        Ok(obj) => {
+            let uv = obj.get_point_data().get_tcoords().unwrap_or_default();
+            println!(
+                "✓ Read sphere.obj: {} points, {} cells, {} texture coordinates",
+                obj.get_number_of_points(),
+                obj.get_number_of_cells(),
+                uv.len()
+            );
+        }
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
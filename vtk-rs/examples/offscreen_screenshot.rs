@@ -0,0 +1,56 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Offscreen Rendering of a Beam Structure ===\n");
+
+    // Simple portal frame: two columns and a girder
+    let mut points = Points::new();
+    let n0 = points.insert_next_point(0.0, 0.0, 0.0);
+    let n1 = points.insert_next_point(0.0, 0.0, 3.0);
+    let n2 = points.insert_next_point(4.0, 0.0, 3.0);
+    let n3 = points.insert_next_point(4.0, 0.0, 0.0);
+
+    let mut beams = CellArray::new();
+    beams.insert_next_cell(&[n0, n1]);
+    beams.insert_next_cell(&[n1, n2]);
+    beams.insert_next_cell(&[n2, n3]);
+
+    let mut poly_data = PolyData::from_beam_structure(&points, &beams);
+
+    let mut tube = TubeFilter::new();
+    tube.set_input_connection(poly_data.get_output_port() as *mut std::ffi::c_void);
+    tube.set_radius(0.1);
+    tube.set_number_of_sides(16);
+    tube.set_capping(true);
+
+    let mut mapper = PolyDataMapper::new();
+    mapper.set_input_connection(tube.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_mapper(&mut mapper);
+    actor.get_property().set_color(0.2, 0.5, 0.9);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(1.0, 1.0, 1.0);
+
+    // No interactor and no visible window: everything happens offscreen
+    let mut render_window = RenderWindow::new_offscreen();
+    render_window.set_size(800, 600);
+    render_window.add_renderer(&mut renderer);
+    renderer.reset_camera();
+    render_window.render();
+
+    let image = render_window.capture_rgba();
+    println!("Captured {}x{} RGBA image ({} bytes)", image.width, image.height, image.data.len());
+
+    match render_window.write_png("portal_frame.png") {
+        Ok(()) => println!("✓ Wrote portal_frame.png"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+
+    match render_window.write_jpeg("portal_frame.jpg", 90) {
+        Ok(()) => println!("✓ Wrote portal_frame.jpg"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
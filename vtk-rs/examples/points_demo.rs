@@ -44,6 +44,29 @@ fn main() {
         println!("  Point {}: ({:.2}, {:.2}, {:.2})", id, x, y, z);
     }
 
+    // Bulk construction: build the coordinates in Rust and hand the buffer to VTK
+    println!("\nBuilding a 100 x 100 x 100 grid of nodes in one go...");
+    let mut coordinates = Vec::with_capacity(1_000_000);
+    for k in 0..100 {
+        for j in 0..100 {
+            for i in 0..100 {
+                coordinates.push([i as f64 * 0.1, j as f64 * 0.1, k as f64 * 0.1]);
+            }
+        }
+    }
+    let mut grid = vtk::Points::from_vec(coordinates);
+    println!("  {} points, no per-point FFI calls", grid.get_number_of_points());
+
+    // Zero-copy view: shift the whole grid up by 1.0
+    // (unsafe: no other handle shares these points here)
+    if let Some(nodes) = unsafe { grid.as_mut_slice() } {
+        for node in nodes.iter_mut() {
+            node[2] += 1.0;
+        }
+        grid.modified();
+    }
+    println!("  Last node: {:?}", grid.get_point(999_999));
+
     // Create a PolyData to visualize the points
     // For now, just create the points - we'll add visualization in future examples
     println!("\n═══════════════════════════════════════════════════════");
@@ -59,7 +59,7 @@ fn main() {
     println!("  Total cells: {}", poly_data.get_number_of_cells());
 
     // Get and display bounds
-    let (xmin, xmax, ymin, ymax, zmin, zmax) = poly_data.get_bounds();
+    let [xmin, xmax, ymin, ymax, zmin, zmax] = poly_data.get_bounds();
     println!("\nSpatial bounds:");
     println!("  X: [{:.1}, {:.1}]", xmin, xmax);
     println!("  Y: [{:.1}, {:.1}]", ymin, ymax);
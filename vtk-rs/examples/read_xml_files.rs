@@ -0,0 +1,51 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Reading VTK XML Files ===\n");
+
+    let paths: Vec<String> = std::env::args().skip(1).collect();
+    if paths.is_empty() {
+        println!("Usage: cargo run --example read_xml_files -- <file.vtp|file.vtu|file.vti> ...");
+        return;
+    }
+
+    for path in &paths {
+        let extension = std::path::Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("");
+
+        let result = match extension {
+            "vtp" => {
+                XMLPolyDataReader::read(path).map(|poly_data| {
+                    format!(
+                        "PolyData: {} points, {} cells",
+                        poly_data.get_number_of_points(),
+                        poly_data.get_number_of_cells()
+                    )
+                })
+            }
+            "vtu" => {
+                XMLUnstructuredGridReader::read(path).map(|grid| {
+                    format!(
+                        "UnstructuredGrid: {} points, {} cells",
+                        grid.get_number_of_points(),
+                        grid.get_number_of_cells()
+                    )
+                })
+            }
+            "vti" => {
+                XMLImageDataReader::read(path).map(|image| {
+                    let (nx, ny, nz) = image.get_dimensions();
+                    format!("ImageData: {} x {} x {} points", nx, ny, nz)
+                })
+            }
+            _ => Err(VtkError::InvalidArgument(format!("unknown extension: {}", path))),
+        };
+
+        match result {
+            Ok(summary) => println!("✓ {}: {}", path, summary),
+            Err(e) => eprintln!("✗ {}", e),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Exporting a Scene for External Viewers ===\n");
+
+    // Portal frame as tubes, with a sphere marking the loaded node
+    let mut points = Points::new();
+    let n0 = points.insert_next_point(0.0, 0.0, 0.0);
+    let n1 = points.insert_next_point(0.0, 0.0, 3.0);
+    let n2 = points.insert_next_point(4.0, 0.0, 3.0);
+    let n3 = points.insert_next_point(4.0, 0.0, 0.0);
+
+    let mut beams = CellArray::new();
+    beams.insert_next_cell(&[n0, n1]);
+    beams.insert_next_cell(&[n1, n2]);
+    beams.insert_next_cell(&[n2, n3]);
+
+    let mut poly_data = PolyData::from_beam_structure(&points, &beams);
+
+    let mut tube = TubeFilter::new();
+    tube.set_input_connection(poly_data.get_output_port() as *mut std::ffi::c_void);
+    tube.set_radius(0.1);
+    tube.set_number_of_sides(16);
+    tube.set_capping(true);
+
+    let mut frame_mapper = PolyDataMapper::new();
+    frame_mapper.set_input_connection(tube.get_output_port());
+    let mut frame = Actor::new();
+    frame.set_mapper(&mut frame_mapper);
+    frame.get_property().set_color(0.2, 0.5, 0.9);
+
+    let mut sphere = SphereSource::new();
+    sphere.set_center([2.0, 0.0, 3.0]);
+    sphere.set_radius(0.25);
+    let mut load_mapper = PolyDataMapper::new();
+    load_mapper.set_input_connection(sphere.get_output_port());
+    let mut load = Actor::new();
+    load.set_mapper(&mut load_mapper);
+    load.get_property().set_color(0.9, 0.2, 0.1);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut frame);
+    renderer.add_actor(&mut load);
+    renderer.set_background(1.0, 1.0, 1.0);
+
+    // Exporting needs no display, so batch jobs can use an offscreen window
+    let mut render_window = RenderWindow::new_offscreen();
+    render_window.set_size(800, 600);
+    render_window.add_renderer(&mut renderer);
+    renderer.reset_camera();
+
+    let mut gltf = GLTFExporter::new();
+    gltf.set_file_name("portal_frame.gltf");
+    gltf.set_inline_data(true);
+    gltf.set_render_window(&mut render_window);
+    render_window.render();
+    match gltf.write() {
+        Ok(()) => println!("✓ Wrote portal_frame.gltf"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+
+    match render_window.export_x3d("portal_frame.x3d") {
+        Ok(()) => println!("✓ Wrote portal_frame.x3d"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+
+    match render_window.export_vrml("portal_frame.wrl") {
+        Ok(()) => println!("✓ Wrote portal_frame.wrl"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
@@ -0,0 +1,79 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Placing an STL Part next to a Beam Structure ===\n");
+
+    // Use the STL file given on the command line, or export a cone as stand-in part
+    let part_path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            let mut cone = ConeSource::new();
+            cone.set_resolution(32);
+            let mut writer = STLWriter::new();
+            writer.set_file_name("part.stl");
+            writer.set_file_type(FileType::Binary);
+            writer.set_input_connection(cone.get_output_port());
+            if let Err(e) = writer.write() {
+                eprintln!("✗ {}", e);
+                return;
+            }
+            println!("✓ Wrote part.stl");
+            "part.stl".to_string()
+        }
+    };
+
+    let mut reader = STLReader::new();
+    reader.set_file_name(&part_path);
+    reader.set_merging(true);
+    if let Err(e) = reader.update() {
+        eprintln!("✗ {}", e);
+        return;
+    }
+    let part = reader.get_output();
+    println!(
+        "Read {}: {} points, {} triangles",
+        part_path,
+        part.get_number_of_points(),
+        part.get_number_of_cells()
+    );
+
+    let mut part_mapper = PolyDataMapper::new();
+    part_mapper.set_input_connection(reader.get_output_port());
+    let mut part_actor = Actor::new();
+    part_actor.set_mapper(&mut part_mapper);
+    part_actor.set_position(0.0, 0.0, 2.0);
+    part_actor.get_property().set_color(0.8, 0.3, 0.2);
+
+    // Beam structure
+    let mut points = Points::new();
+    let n0 = points.insert_next_point(-2.0, 0.0, 0.0);
+    let n1 = points.insert_next_point(2.0, 0.0, 0.0);
+    let mut beams = CellArray::new();
+    beams.insert_next_cell(&[n0, n1]);
+    let mut beam = PolyData::from_beam_structure(&points, &beams);
+
+    let mut tube = TubeFilter::new();
+    tube.set_input_connection(beam.get_output_port() as *mut std::ffi::c_void);
+    tube.set_radius(0.1);
+    tube.set_number_of_sides(16);
+    let mut beam_mapper = PolyDataMapper::new();
+    beam_mapper.set_input_connection(tube.get_output_port());
+    let mut beam_actor = Actor::new();
+    beam_actor.set_mapper(&mut beam_mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut part_actor);
+    renderer.add_actor(&mut beam_actor);
+    renderer.set_background(1.0, 1.0, 1.0);
+
+    let mut render_window = RenderWindow::new_offscreen();
+    render_window.set_size(800, 600);
+    render_window.add_renderer(&mut renderer);
+    renderer.reset_camera();
+    render_window.render();
+
+    match render_window.write_png("stl_import.png") {
+        Ok(()) => println!("✓ Wrote stl_import.png"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
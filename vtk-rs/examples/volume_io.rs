@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use vtk_rs::*;
+
+fn read_volume(path: &str) -> Result<ImageData, VtkError> {
+    let lower = path.to_ascii_lowercase();
+    if Path::new(path).is_dir() {
+        DICOMImageReader::read_directory(path)
+    } else if lower.ends_with(".nrrd") || lower.ends_with(".nhdr") {
+        NrrdReader::read(path)
+    } else if lower.ends_with(".dcm") {
+        DICOMImageReader::read(path)
+    } else {
+        MetaImageReader::read(path)
+    }
+}
+
+fn main() {
+    println!("=== Volume Image I/O ===\n");
+
+    // Use the volume given on the command line (MetaImage, NRRD, DICOM file or
+    // DICOM series directory), or write a small synthetic scan first
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            let mut volume = ImageData::new();
+            volume.set_dimensions(16, 16, 8);
+            volume.set_spacing(0.5, 0.5, 1.25);
+            volume.set_origin(-4.0, -4.0, 0.0);
+            volume.allocate_scalars(VtkDataType::Int16, 1);
+            for z in 0..8 {
+                for y in 0..16 {
+                    for x in 0..16 {
+                        // A dense cylinder in air, in Hounsfield-like units
+                        let r = ((x as f64 - 7.5).powi(2) + (y as f64 - 7.5).powi(2)).sqrt();
+                        let value = if r < 5.0 { 1200.0 } else { -1000.0 };
+                        volume.set_scalar_component(x, y, z, 0, value);
+                    }
+                }
+            }
+            if let Err(e) = MetaImageWriter::write_image("specimen.mha", &mut volume) {
+                eprintln!("✗ {}", e);
+                return;
+            }
+            println!("✓ Wrote specimen.mha");
+            "specimen.mha".to_string()
+        }
+    };
+
+    let volume = match read_volume(&path) {
+        Ok(volume) => volume,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            return;
+        }
+    };
+    println!("✓ Read {}", path);
+    println!("  dimensions: {:?}", volume.get_dimensions());
+    println!("  spacing:    {:?}", volume.get_spacing());
+    println!("  origin:     {:?}", volume.get_origin());
+    println!("  bounds:     {:?}", volume.get_bounds());
+}
@@ -0,0 +1,62 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Exporting a Colored Scene for vtk.js ===\n");
+
+    // Portal frame with a bending moment value at each node
+    let mut points = Points::new();
+    let n0 = points.insert_next_point(0.0, 0.0, 0.0);
+    let n1 = points.insert_next_point(0.0, 0.0, 3.0);
+    let n2 = points.insert_next_point(2.0, 0.0, 3.0);
+    let n3 = points.insert_next_point(4.0, 0.0, 3.0);
+    let n4 = points.insert_next_point(4.0, 0.0, 0.0);
+
+    let mut beams = CellArray::new();
+    beams.insert_next_cell(&[n0, n1]);
+    beams.insert_next_cell(&[n1, n2]);
+    beams.insert_next_cell(&[n2, n3]);
+    beams.insert_next_cell(&[n3, n4]);
+
+    let mut poly_data = PolyData::from_beam_structure(&points, &beams);
+    let mut moment = DoubleArray::new();
+    moment.set_name("Moment");
+    for value in [0.0, -12.5, 18.0, -12.5, 0.0] {
+        moment.insert_next_value(value);
+    }
+    poly_data.get_point_data().add_array(&moment);
+
+    let mut tube = TubeFilter::new();
+    tube.set_input_connection(poly_data.get_output_port() as *mut std::ffi::c_void);
+    tube.set_radius(0.1);
+    tube.set_number_of_sides(16);
+
+    // Blue to red, stored with the scene so the web viewer shows the same colors
+    let mut lut = LookupTable::new();
+    lut.set_hue_range(0.66, 0.0);
+    lut.set_range(-20.0, 20.0);
+    lut.build();
+
+    let mut mapper = PolyDataMapper::new();
+    mapper.set_input_connection(tube.get_output_port());
+    mapper.set_lookup_table(&mut lut);
+    mapper.set_scalar_range(-20.0, 20.0);
+    mapper.color_by_point_array("Moment");
+
+    let mut actor = Actor::new();
+    actor.set_mapper(&mut mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(1.0, 1.0, 1.0);
+
+    let mut render_window = RenderWindow::new_offscreen();
+    render_window.set_size(800, 600);
+    render_window.add_renderer(&mut renderer);
+    renderer.reset_camera();
+
+    // A single file that vtk.js viewers load from any static file server
+    match JSONSceneExporter::export_archive("portal_frame.vtkjs", &mut render_window) {
+        Ok(()) => println!("✓ Wrote portal_frame.vtkjs"),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
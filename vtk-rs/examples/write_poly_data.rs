@@ -0,0 +1,66 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Saving a Beam Model for ParaView ===\n");
+
+    // Cantilever with four nodes and three beam elements
+    let mut points = Points::new();
+    let n0 = points.insert_next_point(0.0, 0.0, 0.0);
+    let n1 = points.insert_next_point(1.0, 0.0, 0.0);
+    let n2 = points.insert_next_point(2.0, 0.0, 0.0);
+    let n3 = points.insert_next_point(3.0, 0.0, 0.0);
+
+    let mut beams = CellArray::new();
+    beams.insert_next_cell(&[n0, n1]);
+    beams.insert_next_cell(&[n1, n2]);
+    beams.insert_next_cell(&[n2, n3]);
+
+    let poly_data = PolyData::from_beam_structure(&points, &beams);
+
+    let mut displacement = DoubleArray::new_vector("Displacement");
+    displacement.insert_next_tuple3(0.0, 0.0, 0.0);
+    displacement.insert_next_tuple3(0.0, -2.4, 0.0);
+    displacement.insert_next_tuple3(0.0, -7.2, 0.0);
+    displacement.insert_next_tuple3(0.0, -14.5, 0.0);
+    poly_data.get_point_data().add_array(&displacement);
+
+    let mut material_ids = IntArray::new_id_array("MaterialID");
+    for _ in 0..3 {
+        material_ids.insert_next_value(1);
+    }
+    poly_data.get_cell_data().add_int_array(&material_ids);
+
+    // Legacy format, readable by almost every VTK based tool
+    for (path, file_type) in [("beam_ascii.vtk", FileType::Ascii), ("beam_binary.vtk", FileType::Binary)] {
+        match poly_data.write_vtk(path, file_type) {
+            Ok(()) => println!("✓ Wrote {}", path),
+            Err(e) => eprintln!("✗ {}", e),
+        }
+    }
+
+    // XML format with each of the three data modes
+    let mut writer = XMLPolyDataWriter::new();
+    writer.set_input_data(&poly_data);
+    for (path, mode) in [
+        ("beam_ascii.vtp", XMLDataMode::Ascii),
+        ("beam_binary.vtp", XMLDataMode::Binary),
+        ("beam_appended.vtp", XMLDataMode::Appended),
+    ] {
+        writer.set_file_name(path);
+        writer.set_data_mode(mode);
+        writer.set_compressor_type(XMLCompressorType::ZLib);
+        match writer.write() {
+            Ok(()) => println!("✓ Wrote {}", path),
+            Err(e) => eprintln!("✗ {}", e),
+        }
+    }
+
+    // Writing into memory, e.g. to embed the model into another file
+    let mut writer = PolyDataWriter::new();
+    writer.set_input_data(&poly_data);
+    writer.set_write_to_output_string(true);
+    match writer.write() {
+        Ok(()) => println!("\nLegacy ASCII output:\n{}", writer.get_output_string()),
+        Err(e) => eprintln!("✗ {}", e),
+    }
+}
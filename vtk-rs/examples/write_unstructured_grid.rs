@@ -0,0 +1,84 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Writing an FEM Mesh per Load Step ===\n");
+
+    // Two hexahedral elements side by side
+    let coordinates = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [2.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [2.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+        [1.0, 0.0, 1.0],
+        [2.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0],
+        [1.0, 1.0, 1.0],
+        [2.0, 1.0, 1.0],
+    ];
+    let elements = [[0, 1, 4, 3, 6, 7, 10, 9], [1, 2, 5, 4, 7, 8, 11, 10]];
+
+    let mut writer = XMLUnstructuredGridWriter::new();
+    writer.set_data_mode(XMLDataMode::Appended);
+    writer.set_compressor_type(XMLCompressorType::ZLib);
+    writer.set_compression_level(6);
+    writer.set_encode_appended_data(false);
+
+    // Index of all steps, so ParaView opens them as one time series
+    let mut collection = PVDWriter::new("mesh.pvd");
+
+    for step in 0..3 {
+        let load = step as f64 * 0.5;
+
+        let mut points = Points::new();
+        for [x, y, z] in coordinates {
+            points.insert_next_point(x, y, z);
+        }
+
+        let mut grid = UnstructuredGrid::new();
+        grid.set_points(&mut points);
+        grid.allocate(elements.len() as i32);
+        for element in &elements {
+            grid.insert_next_cell(VtkCellType::Hexahedron, element);
+        }
+
+        // Nodal results: cantilever-like bending growing with the load
+        let mut displacement = DoubleArray::new_vector("Displacement");
+        for [x, _, _] in coordinates {
+            displacement.insert_next_tuple3(0.0, 0.0, -load * x * x * 0.01);
+        }
+        grid.get_point_data().add_array(&displacement);
+
+        // Element results
+        let mut stress = DoubleArray::new_scalar("VonMises");
+        stress.insert_next_value(120.0 * load);
+        stress.insert_next_value(60.0 * load);
+        grid.get_cell_data().add_array(&stress);
+
+        // Dataset metadata, stored in the file so it stays self-describing
+        let mut metadata = grid.get_field_data();
+        metadata.set_string("LoadCase", &format!("Step {} ({} x service load)", step, load));
+        metadata.set_string("Units", "m, N, MPa");
+        metadata.set_string("SolverVersion", "beam-solver 2.3.1");
+
+        let path = format!("mesh_step_{}.vtu", step);
+        writer.set_file_name(&path);
+        writer.set_input_data(&mut grid);
+        match writer.write().and_then(|()| collection.add_dataset(load, &path)) {
+            Ok(()) => println!("✓ Wrote {} (t = {})", path, load),
+            Err(e) => eprintln!("✗ {}", e),
+        }
+    }
+
+    println!("✓ Wrote {} with {} steps", collection.path().display(), collection.entries().len());
+
+    // The metadata comes back with the mesh
+    if let Ok(mut grid) = UnstructuredGrid::read_vtu("mesh_step_2.vtu") {
+        let metadata = grid.get_field_data();
+        for name in ["LoadCase", "Units", "SolverVersion"] {
+            println!("  {}: {}", name, metadata.get_string(name).unwrap_or_default());
+        }
+    }
+}
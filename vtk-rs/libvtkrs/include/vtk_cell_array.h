@@ -17,6 +17,12 @@ extern "C" {
     
     // Access cells
     bool cell_array_get_cell(void* cells, int64_t loc, int64_t* npts, int64_t** pts);
+
+    // Copy the underlying arrays as 64 bit ids (`ids` holds GetNumberOfOffsets()
+    // or GetNumberOfConnectivityIds() values)
+    int64_t cell_array_get_number_of_offsets(void* cells);
+    void cell_array_copy_offsets(void* cells, int64_t* ids);
+    void cell_array_copy_connectivity(void* cells, int64_t* ids);
     
     // Modify
     void cell_array_reset(void* cells);
@@ -4,9 +4,6 @@
 #include <stdint.h>
 
 extern "C" {
-    // Get CellData from PolyData
-    void* poly_data_get_cell_data(void* poly_data);
-
     // Array management
     void cell_data_add_array(void* cell_data, void* array);
     void cell_data_remove_array(void* cell_data, const char* name);
@@ -23,6 +20,11 @@ extern "C" {
     // Active attributes
     void cell_data_set_active_scalars(void* cell_data, const char* name);
     void cell_data_set_active_vectors(void* cell_data, const char* name);
+
+    // Colors (stored as 4 component unsigned char scalars)
+    void cell_data_set_colors(void* cell_data, const char* name, const uint8_t* rgba, int64_t num_tuples);
+    int64_t cell_data_get_number_of_colors(void* cell_data, const char* name);
+    void cell_data_copy_colors(void* cell_data, const char* name, uint8_t* rgba);
 }
 
 #endif // VTK_CELL_DATA_H
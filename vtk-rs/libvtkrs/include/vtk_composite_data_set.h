@@ -0,0 +1,25 @@
+#ifndef VTK_COMPOSITE_DATA_SET_H
+#define VTK_COMPOSITE_DATA_SET_H
+
+#include <stdint.h>
+
+class vtkDataObject;
+
+// Type of a block as a VTK data object type (VTK_POLY_DATA, ...), with image data
+// subclasses reported as VTK_IMAGE_DATA, or -1 for empty and unsupported blocks
+int composite_block_type(vtkDataObject* block);
+
+// New reference to a supported block, or null (block_type receives its type)
+void* composite_block_register(vtkDataObject* block, int* block_type);
+
+extern "C" {
+    // Totals over all leaves (composite is any vtkCompositeDataSet)
+    int64_t composite_data_set_get_number_of_points(void* composite);
+    int64_t composite_data_set_get_number_of_cells(void* composite);
+    void composite_data_set_get_bounds(void* composite, double bounds[6]);
+
+    // Flat index of a direct child, as used by vtkCompositePolyDataMapper
+    unsigned int composite_data_set_get_flat_index(void* composite, unsigned int index);
+}
+
+#endif // VTK_COMPOSITE_DATA_SET_H
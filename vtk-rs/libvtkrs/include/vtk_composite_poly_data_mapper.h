@@ -0,0 +1,52 @@
+#pragma once
+#ifndef VTK_COMPOSITE_POLY_DATA_MAPPER_H
+#define VTK_COMPOSITE_POLY_DATA_MAPPER_H
+
+#include <vtkCompositePolyDataMapper.h>
+#include <vtkDataObject.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkCompositePolyDataMapper* vtk_composite_poly_data_mapper_new();
+void vtk_composite_poly_data_mapper_delete(vtkCompositePolyDataMapper& mapper);
+
+// Input connection
+void composite_poly_data_mapper_set_input_connection(
+    vtkCompositePolyDataMapper& mapper,
+    vtkAlgorithmOutput* output);
+void composite_poly_data_mapper_set_input_data(
+    vtkCompositePolyDataMapper& mapper,
+    vtkDataObject* composite);
+
+// Per-block attributes
+void composite_poly_data_mapper_set_block_color(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index,
+    double r,
+    double g,
+    double b);
+void composite_poly_data_mapper_remove_block_color(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index);
+void composite_poly_data_mapper_remove_block_colors(vtkCompositePolyDataMapper& mapper);
+void composite_poly_data_mapper_set_block_visibility(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index,
+    bool visible);
+bool composite_poly_data_mapper_get_block_visibility(
+    const vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index);
+void composite_poly_data_mapper_set_block_opacity(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index,
+    double opacity);
+void composite_poly_data_mapper_remove_block_attributes(vtkCompositePolyDataMapper& mapper);
+
+// Coloring
+void composite_poly_data_mapper_set_scalar_visibility(
+    vtkCompositePolyDataMapper& mapper,
+    bool visible);
+bool composite_poly_data_mapper_get_scalar_visibility(const vtkCompositePolyDataMapper& mapper);
+
+#endif // VTK_COMPOSITE_POLY_DATA_MAPPER_H
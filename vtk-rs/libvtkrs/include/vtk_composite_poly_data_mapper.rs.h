@@ -0,0 +1,84 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_composite_poly_data_mapper.h"
+#include "vtk_mapper.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkCompositePolyDataMapper = ::vtkCompositePolyDataMapper;
+using vtkMapper = ::vtkMapper;
+using vtkDataObject = ::vtkDataObject;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+
+extern "C" {
+::vtkCompositePolyDataMapper *cxxbridge1$192$vtk_composite_poly_data_mapper_new() noexcept {
+  ::vtkCompositePolyDataMapper *(*vtk_composite_poly_data_mapper_new$)() = ::vtk_composite_poly_data_mapper_new;
+  return vtk_composite_poly_data_mapper_new$();
+}
+
+void cxxbridge1$192$vtk_composite_poly_data_mapper_delete(::vtkCompositePolyDataMapper &mapper) noexcept {
+  void (*vtk_composite_poly_data_mapper_delete$)(::vtkCompositePolyDataMapper &) = ::vtk_composite_poly_data_mapper_delete;
+  vtk_composite_poly_data_mapper_delete$(mapper);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_set_input_connection(::vtkCompositePolyDataMapper &mapper, ::vtkAlgorithmOutput *output) noexcept {
+  void (*composite_poly_data_mapper_set_input_connection$)(::vtkCompositePolyDataMapper &, ::vtkAlgorithmOutput *) = ::composite_poly_data_mapper_set_input_connection;
+  composite_poly_data_mapper_set_input_connection$(mapper, output);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_set_input_data(::vtkCompositePolyDataMapper &mapper, ::vtkDataObject *composite) noexcept {
+  void (*composite_poly_data_mapper_set_input_data$)(::vtkCompositePolyDataMapper &, ::vtkDataObject *) = ::composite_poly_data_mapper_set_input_data;
+  composite_poly_data_mapper_set_input_data$(mapper, composite);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_set_block_color(::vtkCompositePolyDataMapper &mapper, ::std::uint32_t flat_index, double r, double g, double b) noexcept {
+  void (*composite_poly_data_mapper_set_block_color$)(::vtkCompositePolyDataMapper &, ::std::uint32_t, double, double, double) = ::composite_poly_data_mapper_set_block_color;
+  composite_poly_data_mapper_set_block_color$(mapper, flat_index, r, g, b);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_remove_block_color(::vtkCompositePolyDataMapper &mapper, ::std::uint32_t flat_index) noexcept {
+  void (*composite_poly_data_mapper_remove_block_color$)(::vtkCompositePolyDataMapper &, ::std::uint32_t) = ::composite_poly_data_mapper_remove_block_color;
+  composite_poly_data_mapper_remove_block_color$(mapper, flat_index);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_remove_block_colors(::vtkCompositePolyDataMapper &mapper) noexcept {
+  void (*composite_poly_data_mapper_remove_block_colors$)(::vtkCompositePolyDataMapper &) = ::composite_poly_data_mapper_remove_block_colors;
+  composite_poly_data_mapper_remove_block_colors$(mapper);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_set_block_visibility(::vtkCompositePolyDataMapper &mapper, ::std::uint32_t flat_index, bool visible) noexcept {
+  void (*composite_poly_data_mapper_set_block_visibility$)(::vtkCompositePolyDataMapper &, ::std::uint32_t, bool) = ::composite_poly_data_mapper_set_block_visibility;
+  composite_poly_data_mapper_set_block_visibility$(mapper, flat_index, visible);
+}
+
+bool cxxbridge1$192$composite_poly_data_mapper_get_block_visibility(::vtkCompositePolyDataMapper const &mapper, ::std::uint32_t flat_index) noexcept {
+  bool (*composite_poly_data_mapper_get_block_visibility$)(::vtkCompositePolyDataMapper const &, ::std::uint32_t) = ::composite_poly_data_mapper_get_block_visibility;
+  return composite_poly_data_mapper_get_block_visibility$(mapper, flat_index);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_set_block_opacity(::vtkCompositePolyDataMapper &mapper, ::std::uint32_t flat_index, double opacity) noexcept {
+  void (*composite_poly_data_mapper_set_block_opacity$)(::vtkCompositePolyDataMapper &, ::std::uint32_t, double) = ::composite_poly_data_mapper_set_block_opacity;
+  composite_poly_data_mapper_set_block_opacity$(mapper, flat_index, opacity);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_remove_block_attributes(::vtkCompositePolyDataMapper &mapper) noexcept {
+  void (*composite_poly_data_mapper_remove_block_attributes$)(::vtkCompositePolyDataMapper &) = ::composite_poly_data_mapper_remove_block_attributes;
+  composite_poly_data_mapper_remove_block_attributes$(mapper);
+}
+
+void cxxbridge1$192$composite_poly_data_mapper_set_scalar_visibility(::vtkCompositePolyDataMapper &mapper, bool visible) noexcept {
+  void (*composite_poly_data_mapper_set_scalar_visibility$)(::vtkCompositePolyDataMapper &, bool) = ::composite_poly_data_mapper_set_scalar_visibility;
+  composite_poly_data_mapper_set_scalar_visibility$(mapper, visible);
+}
+
+bool cxxbridge1$192$composite_poly_data_mapper_get_scalar_visibility(::vtkCompositePolyDataMapper const &mapper) noexcept {
+  bool (*composite_poly_data_mapper_get_scalar_visibility$)(::vtkCompositePolyDataMapper const &) = ::composite_poly_data_mapper_get_scalar_visibility;
+  return composite_poly_data_mapper_get_scalar_visibility$(mapper);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,45 @@
+#ifndef VTK_DATA_ARRAY_H
+#define VTK_DATA_ARRAY_H
+
+#include <stdint.h>
+
+// Values and tuples are passed as pointers to the array's own value type
+extern "C" {
+    // Create and destroy
+    void* data_array_new(int32_t data_type);
+    void data_array_delete(void* array);
+    int32_t data_array_get_data_type(void* array);
+
+    // Configuration
+    void data_array_set_number_of_components(void* array, int64_t num_components);
+    int64_t data_array_get_number_of_components(void* array);
+    void data_array_set_number_of_tuples(void* array, int64_t num_tuples);
+    int64_t data_array_get_number_of_tuples(void* array);
+    int64_t data_array_get_number_of_values(void* array);
+
+    // Name management
+    void data_array_set_name(void* array, const char* name);
+    const char* data_array_get_name(void* array);
+
+    // Data insertion and modification
+    int64_t data_array_insert_next_value(void* array, const void* value);
+    int64_t data_array_insert_next_tuple(void* array, const void* tuple);
+    void data_array_set_value(void* array, int64_t id, const void* value);
+    void data_array_set_tuple(void* array, int64_t id, const void* tuple);
+
+    // Data retrieval
+    void data_array_get_value(void* array, int64_t id, void* value);
+    void data_array_get_tuple(void* array, int64_t id, void* tuple);
+    void data_array_get_range(void* array, int32_t component, double* range);
+
+    // Memory management
+    void data_array_initialize(void* array);
+    void data_array_squeeze(void* array);
+    void data_array_modified(void* array);
+
+    // Direct buffer access
+    void* data_array_get_void_pointer(void* array);
+    void data_array_adopt(void* array, void* values, int64_t num_values, void (*free_function)(void*));
+}
+
+#endif // VTK_DATA_ARRAY_H
@@ -0,0 +1,30 @@
+#ifndef VTK_DATA_SET_H
+#define VTK_DATA_SET_H
+
+#include <stdint.h>
+
+extern "C" {
+    // Size and geometry (data_set is any vtkDataSet)
+    int64_t data_set_get_number_of_points(void* data_set);
+    int64_t data_set_get_number_of_cells(void* data_set);
+    bool data_set_get_point(void* data_set, int64_t point_id, double point[3]);
+    void data_set_get_bounds(void* data_set, double bounds[6]);
+    int64_t data_set_find_point(void* data_set, double x, double y, double z);
+
+    // Cells (cell type is -1 for an invalid id)
+    int data_set_get_cell_type(void* data_set, int64_t cell_id);
+    int64_t data_set_get_cell_size(void* data_set, int64_t cell_id);
+    int64_t data_set_get_cell_points(
+        void* data_set, int64_t cell_id, int64_t* point_ids, int64_t capacity
+    );
+
+    // Attributes
+    void* data_set_get_point_data(void* data_set);
+    void* data_set_get_cell_data(void* data_set);
+
+    // Copies (target and source are of the same type)
+    void data_set_deep_copy(void* target, void* source);
+    void data_set_shallow_copy(void* target, void* source);
+}
+
+#endif // VTK_DATA_SET_H
@@ -5,6 +5,8 @@
 #include <vtkDataSetMapper.h>
 #include <vtkDataSet.h>
 #include <vtkAlgorithmOutput.h>
+#include <vtkLookupTable.h>
+#include "cxx.h"
 
 // Create/Delete
 vtkDataSetMapper* vtk_data_set_mapper_new();
@@ -14,4 +16,11 @@ void vtk_data_set_mapper_delete(vtkDataSetMapper& mapper);
 void data_set_mapper_set_input_connection(vtkDataSetMapper& mapper, vtkAlgorithmOutput* output);
 void data_set_mapper_set_input_data(vtkDataSetMapper& mapper, vtkDataSet* data_set);
 
+// Coloring
+void data_set_mapper_set_lookup_table(vtkDataSetMapper& mapper, vtkLookupTable& lut);
+void data_set_mapper_set_scalar_range(vtkDataSetMapper& mapper, double min, double max);
+void data_set_mapper_set_scalar_visibility(vtkDataSetMapper& mapper, bool visible);
+bool data_set_mapper_get_scalar_visibility(const vtkDataSetMapper& mapper);
+void data_set_mapper_color_by_array(vtkDataSetMapper& mapper, rust::Str name, bool cell_data);
+
 #endif // VTK_DATA_SET_MAPPER_H
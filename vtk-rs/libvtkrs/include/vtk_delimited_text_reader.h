@@ -0,0 +1,36 @@
+#pragma once
+
+#include <vtkDelimitedTextReader.h>
+#include <vtkTable.h>
+#include <vtkAlgorithmOutput.h>
+#include <stdint.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkDelimitedTextReader* vtk_delimited_text_reader_new();
+void vtk_delimited_text_reader_delete(vtkDelimitedTextReader& reader);
+
+// File name
+void delimited_text_reader_set_file_name(vtkDelimitedTextReader& reader, rust::Str file_name);
+rust::String delimited_text_reader_get_file_name(const vtkDelimitedTextReader& reader);
+
+// Parsing
+void delimited_text_reader_set_field_delimiter_characters(vtkDelimitedTextReader& reader, rust::Str delimiters);
+rust::String delimited_text_reader_get_field_delimiter_characters(const vtkDelimitedTextReader& reader);
+void delimited_text_reader_set_add_tab_field_delimiter(vtkDelimitedTextReader& reader, bool enabled);
+void delimited_text_reader_set_merge_consecutive_delimiters(vtkDelimitedTextReader& reader, bool enabled);
+void delimited_text_reader_set_string_delimiter(vtkDelimitedTextReader& reader, uint8_t delimiter);
+void delimited_text_reader_set_use_string_delimiter(vtkDelimitedTextReader& reader, bool enabled);
+void delimited_text_reader_set_have_headers(vtkDelimitedTextReader& reader, bool enabled);
+bool delimited_text_reader_get_have_headers(const vtkDelimitedTextReader& reader);
+void delimited_text_reader_set_detect_numeric_columns(vtkDelimitedTextReader& reader, bool enabled);
+bool delimited_text_reader_get_detect_numeric_columns(const vtkDelimitedTextReader& reader);
+void delimited_text_reader_set_force_double(vtkDelimitedTextReader& reader, bool enabled);
+void delimited_text_reader_set_max_records(vtkDelimitedTextReader& reader, int64_t max_records);
+
+// Execution
+void delimited_text_reader_update(vtkDelimitedTextReader& reader);
+
+// Output
+vtkTable* delimited_text_reader_get_output(vtkDelimitedTextReader& reader);
+vtkAlgorithmOutput* delimited_text_reader_get_output_port(vtkDelimitedTextReader& reader);
@@ -0,0 +1,34 @@
+#pragma once
+
+#include <vtkDICOMImageReader.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkDICOMImageReader* vtk_dicom_image_reader_new();
+void vtk_dicom_image_reader_delete(vtkDICOMImageReader& reader);
+
+// File name
+void dicom_image_reader_set_file_name(vtkDICOMImageReader& reader, rust::Str file_name);
+rust::String dicom_image_reader_get_file_name(const vtkDICOMImageReader& reader);
+bool dicom_image_reader_can_read_file(vtkDICOMImageReader& reader, rust::Str file_name);
+
+// Series directory
+void dicom_image_reader_set_directory_name(vtkDICOMImageReader& reader, rust::Str directory_name);
+rust::String dicom_image_reader_get_directory_name(const vtkDICOMImageReader& reader);
+
+// Header information
+rust::String dicom_image_reader_get_patient_name(vtkDICOMImageReader& reader);
+rust::String dicom_image_reader_get_study_uid(vtkDICOMImageReader& reader);
+rust::String dicom_image_reader_get_study_id(vtkDICOMImageReader& reader);
+float dicom_image_reader_get_rescale_slope(vtkDICOMImageReader& reader);
+float dicom_image_reader_get_rescale_offset(vtkDICOMImageReader& reader);
+void dicom_image_reader_get_pixel_spacing(vtkDICOMImageReader& reader, double& x, double& y, double& z);
+
+// Execution
+void dicom_image_reader_update(vtkDICOMImageReader& reader);
+
+// Output
+vtkImageData* dicom_image_reader_get_output(vtkDICOMImageReader& reader);
+vtkAlgorithmOutput* dicom_image_reader_get_output_port(vtkDICOMImageReader& reader);
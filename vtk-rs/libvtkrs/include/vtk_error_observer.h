@@ -0,0 +1,31 @@
+#pragma once
+
+#include <vtkCommand.h>
+#include <vtkObject.h>
+#include <string>
+
+// Collects ErrorEvent messages emitted by an algorithm while it executes.
+//
+// VTK readers report most failures (corrupt XML, truncated data, ...) only through
+// vtkErrorMacro, which prints to the console. Attaching this observer allows the
+// bindings to turn those messages into exceptions.
+class vtkRsErrorObserver : public vtkCommand {
+public:
+    static vtkRsErrorObserver* New() { return new vtkRsErrorObserver; }
+
+    void Execute(vtkObject*, unsigned long, void* call_data) override {
+        this->HasError = true;
+        if (call_data && this->Message.empty()) {
+            this->Message = static_cast<const char*>(call_data);
+        }
+    }
+
+    bool GetHasError() const { return this->HasError; }
+    const std::string& GetErrorText() const { return this->Message; }
+
+private:
+    vtkRsErrorObserver() = default;
+
+    bool HasError = false;
+    std::string Message;
+};
@@ -0,0 +1,54 @@
+#pragma once
+
+#include <vtkExodusIIReader.h>
+#include <vtkUnstructuredGrid.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkExodusIIReader* vtk_exodus_ii_reader_new();
+void vtk_exodus_ii_reader_delete(vtkExodusIIReader& reader);
+
+// File name
+void exodus_ii_reader_set_file_name(vtkExodusIIReader& reader, rust::Str file_name);
+rust::String exodus_ii_reader_get_file_name(const vtkExodusIIReader& reader);
+bool exodus_ii_reader_can_read_file(vtkExodusIIReader& reader, rust::Str file_name);
+
+// Execution
+void exodus_ii_reader_update_information(vtkExodusIIReader& reader);
+void exodus_ii_reader_update(vtkExodusIIReader& reader);
+
+// Time steps
+rust::Vec<double> exodus_ii_reader_get_time_values(vtkExodusIIReader& reader);
+void exodus_ii_reader_set_time_step(vtkExodusIIReader& reader, int step);
+int exodus_ii_reader_get_time_step(const vtkExodusIIReader& reader);
+
+// Objects
+int exodus_ii_reader_get_number_of_objects(vtkExodusIIReader& reader, int object_type);
+rust::String exodus_ii_reader_get_object_name(vtkExodusIIReader& reader, int object_type, int index);
+int exodus_ii_reader_get_object_id(vtkExodusIIReader& reader, int object_type, int index);
+bool exodus_ii_reader_get_object_status(vtkExodusIIReader& reader, int object_type, int index);
+void exodus_ii_reader_set_object_status(vtkExodusIIReader& reader, int object_type, int index, bool enabled);
+
+// Result variables
+int exodus_ii_reader_get_number_of_object_arrays(vtkExodusIIReader& reader, int object_type);
+rust::String exodus_ii_reader_get_object_array_name(vtkExodusIIReader& reader, int object_type, int index);
+bool exodus_ii_reader_get_object_array_status(vtkExodusIIReader& reader, int object_type, int index);
+void exodus_ii_reader_set_object_array_status(vtkExodusIIReader& reader, int object_type, rust::Str name, bool enabled);
+void exodus_ii_reader_set_all_array_status(vtkExodusIIReader& reader, int object_type, bool enabled);
+
+// Options
+void exodus_ii_reader_set_apply_displacements(vtkExodusIIReader& reader, bool enabled);
+bool exodus_ii_reader_get_apply_displacements(const vtkExodusIIReader& reader);
+void exodus_ii_reader_set_displacement_magnitude(vtkExodusIIReader& reader, float magnitude);
+float exodus_ii_reader_get_displacement_magnitude(const vtkExodusIIReader& reader);
+void exodus_ii_reader_set_generate_object_id_cell_array(vtkExodusIIReader& reader, bool enabled);
+void exodus_ii_reader_set_generate_global_node_id_array(vtkExodusIIReader& reader, bool enabled);
+void exodus_ii_reader_set_generate_global_element_id_array(vtkExodusIIReader& reader, bool enabled);
+
+// Output
+int exodus_ii_reader_get_number_of_output_blocks(vtkExodusIIReader& reader);
+vtkUnstructuredGrid* exodus_ii_reader_get_output_block(vtkExodusIIReader& reader, int index);
+rust::String exodus_ii_reader_get_output_block_name(vtkExodusIIReader& reader, int index);
+int exodus_ii_reader_get_output_block_type(vtkExodusIIReader& reader, int index);
+vtkAlgorithmOutput* exodus_ii_reader_get_output_port(vtkExodusIIReader& reader);
@@ -0,0 +1,1014 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_exodus_ii_reader.h"
+#include "vtk_algorithm_output.h"
+#include <algorithm>
+#include <array>
+#include <cassert>
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <initializer_list>
+#include <iterator>
+#include <new>
+#include <stdexcept>
+#include <string>
+#include <type_traits>
+#include <utility>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+#if __cplusplus >= 202002L
+#include <ranges>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#pragma GCC diagnostic ignored "-Wshadow"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+#ifndef CXXBRIDGE1_PANIC
+#define CXXBRIDGE1_PANIC
+template <typename Exception>
+void panic [[noreturn]] (const char *msg);
+#endif // CXXBRIDGE1_PANIC
+
+struct unsafe_bitcopy_t;
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class Opaque;
+
+template <typename T>
+::std::size_t size_of();
+template <typename T>
+::std::size_t align_of();
+
+#ifndef CXXBRIDGE1_RUST_STRING
+#define CXXBRIDGE1_RUST_STRING
+class String final {
+public:
+  String() noexcept;
+  String(const String &) noexcept;
+  String(String &&) noexcept;
+  ~String() noexcept;
+
+  String(const std::string &);
+  String(const char *);
+  String(const char *, std::size_t);
+  String(const char16_t *);
+  String(const char16_t *, std::size_t);
+#ifdef __cpp_char8_t
+  String(const char8_t *s);
+  String(const char8_t *s, std::size_t len);
+#endif
+
+  static String lossy(const std::string &) noexcept;
+  static String lossy(const char *) noexcept;
+  static String lossy(const char *, std::size_t) noexcept;
+  static String lossy(const char16_t *) noexcept;
+  static String lossy(const char16_t *, std::size_t) noexcept;
+
+  String &operator=(const String &) & noexcept;
+  String &operator=(String &&) & noexcept;
+
+  explicit operator std::string() const;
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  const char *c_str() noexcept;
+
+  std::size_t capacity() const noexcept;
+  void reserve(size_t new_cap) noexcept;
+
+  using iterator = char *;
+  iterator begin() noexcept;
+  iterator end() noexcept;
+
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const String &) const noexcept;
+  bool operator!=(const String &) const noexcept;
+  bool operator<(const String &) const noexcept;
+  bool operator<=(const String &) const noexcept;
+  bool operator>(const String &) const noexcept;
+  bool operator>=(const String &) const noexcept;
+
+  void swap(String &) noexcept;
+
+  String(unsafe_bitcopy_t, const String &) noexcept;
+
+private:
+  struct lossy_t;
+  String(lossy_t, const char *, std::size_t) noexcept;
+  String(lossy_t, const char16_t *, std::size_t) noexcept;
+  friend void swap(String &lhs, String &rhs) noexcept { lhs.swap(rhs); }
+
+  std::array<std::uintptr_t, 3> repr;
+};
+#endif // CXXBRIDGE1_RUST_STRING
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+
+#ifndef CXXBRIDGE1_RUST_SLICE
+#define CXXBRIDGE1_RUST_SLICE
+namespace detail {
+template <bool>
+struct copy_assignable_if {};
+
+template <>
+struct copy_assignable_if<false> {
+  copy_assignable_if() noexcept = default;
+  copy_assignable_if(const copy_assignable_if &) noexcept = default;
+  copy_assignable_if &operator=(const copy_assignable_if &) & noexcept = delete;
+  copy_assignable_if &operator=(copy_assignable_if &&) & noexcept = default;
+};
+} // namespace detail
+
+template <typename T>
+class Slice final
+    : private detail::copy_assignable_if<std::is_const<T>::value> {
+public:
+  using value_type = T;
+
+  Slice() noexcept;
+  Slice(T *, std::size_t count) noexcept;
+
+  template <typename C>
+  explicit Slice(C &c) : Slice(c.data(), c.size()) {}
+
+  Slice &operator=(const Slice<T> &) & noexcept = default;
+  Slice &operator=(Slice<T> &&) & noexcept = default;
+
+  T *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  T &operator[](std::size_t n) const noexcept;
+  T &at(std::size_t n) const;
+  T &front() const noexcept;
+  T &back() const noexcept;
+
+  Slice(const Slice<T> &) noexcept = default;
+  ~Slice() noexcept = default;
+
+  class iterator;
+  iterator begin() const noexcept;
+  iterator end() const noexcept;
+
+  void swap(Slice &) noexcept;
+
+private:
+  class uninit;
+  Slice(uninit) noexcept;
+  friend impl<Slice>;
+  friend void sliceInit(void *, const void *, std::size_t) noexcept;
+  friend void *slicePtr(const void *) noexcept;
+  friend std::size_t sliceLen(const void *) noexcept;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+
+#ifdef __cpp_deduction_guides
+template <typename C>
+explicit Slice(C &c)
+    -> Slice<std::remove_reference_t<decltype(*std::declval<C>().data())>>;
+#endif // __cpp_deduction_guides
+
+template <typename T>
+class Slice<T>::iterator final {
+public:
+#if __cplusplus >= 202002L
+  using iterator_category = std::contiguous_iterator_tag;
+#else
+  using iterator_category = std::random_access_iterator_tag;
+#endif
+  using value_type = T;
+  using difference_type = std::ptrdiff_t;
+  using pointer = typename std::add_pointer<T>::type;
+  using reference = typename std::add_lvalue_reference<T>::type;
+
+  reference operator*() const noexcept;
+  pointer operator->() const noexcept;
+  reference operator[](difference_type) const noexcept;
+
+  iterator &operator++() noexcept;
+  iterator operator++(int) noexcept;
+  iterator &operator--() noexcept;
+  iterator operator--(int) noexcept;
+
+  iterator &operator+=(difference_type) noexcept;
+  iterator &operator-=(difference_type) noexcept;
+  iterator operator+(difference_type) const noexcept;
+  friend inline iterator operator+(difference_type lhs, iterator rhs) noexcept {
+    return rhs + lhs;
+  }
+  iterator operator-(difference_type) const noexcept;
+  difference_type operator-(const iterator &) const noexcept;
+
+  bool operator==(const iterator &) const noexcept;
+  bool operator!=(const iterator &) const noexcept;
+  bool operator<(const iterator &) const noexcept;
+  bool operator<=(const iterator &) const noexcept;
+  bool operator>(const iterator &) const noexcept;
+  bool operator>=(const iterator &) const noexcept;
+
+private:
+  friend class Slice;
+  void *pos;
+  std::size_t stride;
+};
+
+#if __cplusplus >= 202002L
+static_assert(std::ranges::contiguous_range<rust::Slice<const uint8_t>>);
+static_assert(std::contiguous_iterator<rust::Slice<const uint8_t>::iterator>);
+#endif
+
+template <typename T>
+Slice<T>::Slice() noexcept {
+  sliceInit(this, reinterpret_cast<void *>(align_of<T>()), 0);
+}
+
+template <typename T>
+Slice<T>::Slice(T *s, std::size_t count) noexcept {
+  assert(s != nullptr || count == 0);
+  sliceInit(this,
+            s == nullptr && count == 0
+                ? reinterpret_cast<void *>(align_of<T>())
+                : const_cast<typename std::remove_const<T>::type *>(s),
+            count);
+}
+
+template <typename T>
+T *Slice<T>::data() const noexcept {
+  return reinterpret_cast<T *>(slicePtr(this));
+}
+
+template <typename T>
+std::size_t Slice<T>::size() const noexcept {
+  return sliceLen(this);
+}
+
+template <typename T>
+std::size_t Slice<T>::length() const noexcept {
+  return this->size();
+}
+
+template <typename T>
+bool Slice<T>::empty() const noexcept {
+  return this->size() == 0;
+}
+
+template <typename T>
+T &Slice<T>::operator[](std::size_t n) const noexcept {
+  assert(n < this->size());
+  auto ptr = static_cast<char *>(slicePtr(this)) + size_of<T>() * n;
+  return *reinterpret_cast<T *>(ptr);
+}
+
+template <typename T>
+T &Slice<T>::at(std::size_t n) const {
+  if (n >= this->size()) {
+    panic<std::out_of_range>("rust::Slice index out of range");
+  }
+  return (*this)[n];
+}
+
+template <typename T>
+T &Slice<T>::front() const noexcept {
+  assert(!this->empty());
+  return (*this)[0];
+}
+
+template <typename T>
+T &Slice<T>::back() const noexcept {
+  assert(!this->empty());
+  return (*this)[this->size() - 1];
+}
+
+template <typename T>
+typename Slice<T>::iterator::reference
+Slice<T>::iterator::operator*() const noexcept {
+  return *static_cast<T *>(this->pos);
+}
+
+template <typename T>
+typename Slice<T>::iterator::pointer
+Slice<T>::iterator::operator->() const noexcept {
+  return static_cast<T *>(this->pos);
+}
+
+template <typename T>
+typename Slice<T>::iterator::reference Slice<T>::iterator::operator[](
+    typename Slice<T>::iterator::difference_type n) const noexcept {
+  auto ptr = static_cast<char *>(this->pos) + this->stride * n;
+  return *reinterpret_cast<T *>(ptr);
+}
+
+template <typename T>
+typename Slice<T>::iterator &Slice<T>::iterator::operator++() noexcept {
+  this->pos = static_cast<char *>(this->pos) + this->stride;
+  return *this;
+}
+
+template <typename T>
+typename Slice<T>::iterator Slice<T>::iterator::operator++(int) noexcept {
+  auto ret = iterator(*this);
+  this->pos = static_cast<char *>(this->pos) + this->stride;
+  return ret;
+}
+
+template <typename T>
+typename Slice<T>::iterator &Slice<T>::iterator::operator--() noexcept {
+  this->pos = static_cast<char *>(this->pos) - this->stride;
+  return *this;
+}
+
+template <typename T>
+typename Slice<T>::iterator Slice<T>::iterator::operator--(int) noexcept {
+  auto ret = iterator(*this);
+  this->pos = static_cast<char *>(this->pos) - this->stride;
+  return ret;
+}
+
+template <typename T>
+typename Slice<T>::iterator &Slice<T>::iterator::operator+=(
+    typename Slice<T>::iterator::difference_type n) noexcept {
+  this->pos = static_cast<char *>(this->pos) + this->stride * n;
+  return *this;
+}
+
+template <typename T>
+typename Slice<T>::iterator &Slice<T>::iterator::operator-=(
+    typename Slice<T>::iterator::difference_type n) noexcept {
+  this->pos = static_cast<char *>(this->pos) - this->stride * n;
+  return *this;
+}
+
+template <typename T>
+typename Slice<T>::iterator Slice<T>::iterator::operator+(
+    typename Slice<T>::iterator::difference_type n) const noexcept {
+  auto ret = iterator(*this);
+  ret.pos = static_cast<char *>(this->pos) + this->stride * n;
+  return ret;
+}
+
+template <typename T>
+typename Slice<T>::iterator Slice<T>::iterator::operator-(
+    typename Slice<T>::iterator::difference_type n) const noexcept {
+  auto ret = iterator(*this);
+  ret.pos = static_cast<char *>(this->pos) - this->stride * n;
+  return ret;
+}
+
+template <typename T>
+typename Slice<T>::iterator::difference_type
+Slice<T>::iterator::operator-(const iterator &other) const noexcept {
+  auto diff = std::distance(static_cast<char *>(other.pos),
+                            static_cast<char *>(this->pos));
+  return diff / static_cast<typename Slice<T>::iterator::difference_type>(
+                    this->stride);
+}
+
+template <typename T>
+bool Slice<T>::iterator::operator==(const iterator &other) const noexcept {
+  return this->pos == other.pos;
+}
+
+template <typename T>
+bool Slice<T>::iterator::operator!=(const iterator &other) const noexcept {
+  return this->pos != other.pos;
+}
+
+template <typename T>
+bool Slice<T>::iterator::operator<(const iterator &other) const noexcept {
+  return this->pos < other.pos;
+}
+
+template <typename T>
+bool Slice<T>::iterator::operator<=(const iterator &other) const noexcept {
+  return this->pos <= other.pos;
+}
+
+template <typename T>
+bool Slice<T>::iterator::operator>(const iterator &other) const noexcept {
+  return this->pos > other.pos;
+}
+
+template <typename T>
+bool Slice<T>::iterator::operator>=(const iterator &other) const noexcept {
+  return this->pos >= other.pos;
+}
+
+template <typename T>
+typename Slice<T>::iterator Slice<T>::begin() const noexcept {
+  iterator it;
+  it.pos = slicePtr(this);
+  it.stride = size_of<T>();
+  return it;
+}
+
+template <typename T>
+typename Slice<T>::iterator Slice<T>::end() const noexcept {
+  iterator it = this->begin();
+  it.pos = static_cast<char *>(it.pos) + it.stride * this->size();
+  return it;
+}
+
+template <typename T>
+void Slice<T>::swap(Slice &rhs) noexcept {
+  std::swap(*this, rhs);
+}
+#endif // CXXBRIDGE1_RUST_SLICE
+
+#ifndef CXXBRIDGE1_RUST_BITCOPY_T
+#define CXXBRIDGE1_RUST_BITCOPY_T
+struct unsafe_bitcopy_t final {
+  explicit unsafe_bitcopy_t() = default;
+};
+#endif // CXXBRIDGE1_RUST_BITCOPY_T
+
+#ifndef CXXBRIDGE1_RUST_VEC
+#define CXXBRIDGE1_RUST_VEC
+template <typename T>
+class Vec final {
+public:
+  using value_type = T;
+
+  Vec() noexcept;
+  Vec(std::initializer_list<T>);
+  Vec(const Vec &);
+  Vec(Vec &&) noexcept;
+  ~Vec() noexcept;
+
+  Vec &operator=(Vec &&) & noexcept;
+  Vec &operator=(const Vec &) &;
+
+  std::size_t size() const noexcept;
+  bool empty() const noexcept;
+  const T *data() const noexcept;
+  T *data() noexcept;
+  std::size_t capacity() const noexcept;
+
+  const T &operator[](std::size_t n) const noexcept;
+  const T &at(std::size_t n) const;
+  const T &front() const noexcept;
+  const T &back() const noexcept;
+
+  T &operator[](std::size_t n) noexcept;
+  T &at(std::size_t n);
+  T &front() noexcept;
+  T &back() noexcept;
+
+  void reserve(std::size_t new_cap);
+  void push_back(const T &value);
+  void push_back(T &&value);
+  template <typename... Args>
+  void emplace_back(Args &&...args);
+  void truncate(std::size_t len);
+  void clear();
+
+  using iterator = typename Slice<T>::iterator;
+  iterator begin() noexcept;
+  iterator end() noexcept;
+
+  using const_iterator = typename Slice<const T>::iterator;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  void swap(Vec &) noexcept;
+
+  Vec(unsafe_bitcopy_t, const Vec &) noexcept;
+
+private:
+  void reserve_total(std::size_t new_cap) noexcept;
+  void set_len(std::size_t len) noexcept;
+  void drop() noexcept;
+
+  friend void swap(Vec &lhs, Vec &rhs) noexcept { lhs.swap(rhs); }
+
+  std::array<std::uintptr_t, 3> repr;
+};
+
+template <typename T>
+Vec<T>::Vec(std::initializer_list<T> init) : Vec{} {
+  this->reserve_total(init.size());
+  std::move(init.begin(), init.end(), std::back_inserter(*this));
+}
+
+template <typename T>
+Vec<T>::Vec(const Vec &other) : Vec() {
+  this->reserve_total(other.size());
+  std::copy(other.begin(), other.end(), std::back_inserter(*this));
+}
+
+template <typename T>
+Vec<T>::Vec(Vec &&other) noexcept : repr(other.repr) {
+  new (&other) Vec();
+}
+
+template <typename T>
+Vec<T>::~Vec() noexcept {
+  this->drop();
+}
+
+template <typename T>
+Vec<T> &Vec<T>::operator=(Vec &&other) & noexcept {
+  this->drop();
+  this->repr = other.repr;
+  new (&other) Vec();
+  return *this;
+}
+
+template <typename T>
+Vec<T> &Vec<T>::operator=(const Vec &other) & {
+  if (this != &other) {
+    this->drop();
+    new (this) Vec(other);
+  }
+  return *this;
+}
+
+template <typename T>
+bool Vec<T>::empty() const noexcept {
+  return this->size() == 0;
+}
+
+template <typename T>
+T *Vec<T>::data() noexcept {
+  return const_cast<T *>(const_cast<const Vec<T> *>(this)->data());
+}
+
+template <typename T>
+const T &Vec<T>::operator[](std::size_t n) const noexcept {
+  assert(n < this->size());
+  auto data = reinterpret_cast<const char *>(this->data());
+  return *reinterpret_cast<const T *>(data + n * size_of<T>());
+}
+
+template <typename T>
+const T &Vec<T>::at(std::size_t n) const {
+  if (n >= this->size()) {
+    panic<std::out_of_range>("rust::Vec index out of range");
+  }
+  return (*this)[n];
+}
+
+template <typename T>
+const T &Vec<T>::front() const noexcept {
+  assert(!this->empty());
+  return (*this)[0];
+}
+
+template <typename T>
+const T &Vec<T>::back() const noexcept {
+  assert(!this->empty());
+  return (*this)[this->size() - 1];
+}
+
+template <typename T>
+T &Vec<T>::operator[](std::size_t n) noexcept {
+  assert(n < this->size());
+  auto data = reinterpret_cast<char *>(this->data());
+  return *reinterpret_cast<T *>(data + n * size_of<T>());
+}
+
+template <typename T>
+T &Vec<T>::at(std::size_t n) {
+  if (n >= this->size()) {
+    panic<std::out_of_range>("rust::Vec index out of range");
+  }
+  return (*this)[n];
+}
+
+template <typename T>
+T &Vec<T>::front() noexcept {
+  assert(!this->empty());
+  return (*this)[0];
+}
+
+template <typename T>
+T &Vec<T>::back() noexcept {
+  assert(!this->empty());
+  return (*this)[this->size() - 1];
+}
+
+template <typename T>
+void Vec<T>::reserve(std::size_t new_cap) {
+  this->reserve_total(new_cap);
+}
+
+template <typename T>
+void Vec<T>::push_back(const T &value) {
+  this->emplace_back(value);
+}
+
+template <typename T>
+void Vec<T>::push_back(T &&value) {
+  this->emplace_back(std::move(value));
+}
+
+template <typename T>
+template <typename... Args>
+void Vec<T>::emplace_back(Args &&...args) {
+  auto size = this->size();
+  this->reserve_total(size + 1);
+  ::new (reinterpret_cast<T *>(reinterpret_cast<char *>(this->data()) +
+                               size * size_of<T>()))
+      T(std::forward<Args>(args)...);
+  this->set_len(size + 1);
+}
+
+template <typename T>
+void Vec<T>::clear() {
+  this->truncate(0);
+}
+
+template <typename T>
+typename Vec<T>::iterator Vec<T>::begin() noexcept {
+  return Slice<T>(this->data(), this->size()).begin();
+}
+
+template <typename T>
+typename Vec<T>::iterator Vec<T>::end() noexcept {
+  return Slice<T>(this->data(), this->size()).end();
+}
+
+template <typename T>
+typename Vec<T>::const_iterator Vec<T>::begin() const noexcept {
+  return this->cbegin();
+}
+
+template <typename T>
+typename Vec<T>::const_iterator Vec<T>::end() const noexcept {
+  return this->cend();
+}
+
+template <typename T>
+typename Vec<T>::const_iterator Vec<T>::cbegin() const noexcept {
+  return Slice<const T>(this->data(), this->size()).begin();
+}
+
+template <typename T>
+typename Vec<T>::const_iterator Vec<T>::cend() const noexcept {
+  return Slice<const T>(this->data(), this->size()).end();
+}
+
+template <typename T>
+void Vec<T>::swap(Vec &rhs) noexcept {
+  using std::swap;
+  swap(this->repr, rhs.repr);
+}
+
+template <typename T>
+Vec<T>::Vec(unsafe_bitcopy_t, const Vec &bits) noexcept : repr(bits.repr) {}
+#endif // CXXBRIDGE1_RUST_VEC
+
+#ifndef CXXBRIDGE1_IS_COMPLETE
+#define CXXBRIDGE1_IS_COMPLETE
+namespace detail {
+namespace {
+template <typename T, typename = std::size_t>
+struct is_complete : std::false_type {};
+template <typename T>
+struct is_complete<T, decltype(sizeof(T))> : std::true_type {};
+} // namespace
+} // namespace detail
+#endif // CXXBRIDGE1_IS_COMPLETE
+
+#ifndef CXXBRIDGE1_LAYOUT
+#define CXXBRIDGE1_LAYOUT
+class layout {
+  template <typename T>
+  friend std::size_t size_of();
+  template <typename T>
+  friend std::size_t align_of();
+  template <typename T>
+  static typename std::enable_if<std::is_base_of<Opaque, T>::value,
+                                 std::size_t>::type
+  do_size_of() {
+    return T::layout::size();
+  }
+  template <typename T>
+  static typename std::enable_if<!std::is_base_of<Opaque, T>::value,
+                                 std::size_t>::type
+  do_size_of() {
+    return sizeof(T);
+  }
+  template <typename T>
+  static
+      typename std::enable_if<detail::is_complete<T>::value, std::size_t>::type
+      size_of() {
+    return do_size_of<T>();
+  }
+  template <typename T>
+  static typename std::enable_if<std::is_base_of<Opaque, T>::value,
+                                 std::size_t>::type
+  do_align_of() {
+    return T::layout::align();
+  }
+  template <typename T>
+  static typename std::enable_if<!std::is_base_of<Opaque, T>::value,
+                                 std::size_t>::type
+  do_align_of() {
+    return alignof(T);
+  }
+  template <typename T>
+  static
+      typename std::enable_if<detail::is_complete<T>::value, std::size_t>::type
+      align_of() {
+    return do_align_of<T>();
+  }
+};
+
+template <typename T>
+std::size_t size_of() {
+  return layout::size_of<T>();
+}
+
+template <typename T>
+std::size_t align_of() {
+  return layout::align_of<T>();
+}
+#endif // CXXBRIDGE1_LAYOUT
+
+namespace repr {
+struct PtrLen final {
+  void *ptr;
+  ::std::size_t len;
+};
+} // namespace repr
+
+namespace detail {
+class Fail final {
+  ::rust::repr::PtrLen &throw$;
+public:
+  Fail(::rust::repr::PtrLen &throw$) noexcept : throw$(throw$) {}
+  void operator()(char const *) noexcept;
+  void operator()(std::string const &) noexcept;
+};
+} // namespace detail
+} // namespace cxxbridge1
+
+namespace behavior {
+class missing {};
+missing trycatch(...);
+
+template <typename Try, typename Fail>
+static typename ::std::enable_if<::std::is_same<
+    decltype(trycatch(::std::declval<Try>(), ::std::declval<Fail>())),
+    missing>::value>::type
+trycatch(Try &&func, Fail &&fail) noexcept try {
+  func();
+} catch (::std::exception const &e) {
+  fail(e.what());
+}
+} // namespace behavior
+} // namespace rust
+
+using vtkExodusIIReader = ::vtkExodusIIReader;
+using vtkUnstructuredGrid = ::vtkUnstructuredGrid;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+
+extern "C" {
+::vtkExodusIIReader *cxxbridge1$192$vtk_exodus_ii_reader_new() noexcept {
+  ::vtkExodusIIReader *(*vtk_exodus_ii_reader_new$)() = ::vtk_exodus_ii_reader_new;
+  return vtk_exodus_ii_reader_new$();
+}
+
+void cxxbridge1$192$vtk_exodus_ii_reader_delete(::vtkExodusIIReader &reader) noexcept {
+  void (*vtk_exodus_ii_reader_delete$)(::vtkExodusIIReader &) = ::vtk_exodus_ii_reader_delete;
+  vtk_exodus_ii_reader_delete$(reader);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_file_name(::vtkExodusIIReader &reader, ::rust::Str file_name) noexcept {
+  void (*exodus_ii_reader_set_file_name$)(::vtkExodusIIReader &, ::rust::Str) = ::exodus_ii_reader_set_file_name;
+  exodus_ii_reader_set_file_name$(reader, file_name);
+}
+
+void cxxbridge1$192$exodus_ii_reader_get_file_name(::vtkExodusIIReader const &reader, ::rust::String *return$) noexcept {
+  ::rust::String (*exodus_ii_reader_get_file_name$)(::vtkExodusIIReader const &) = ::exodus_ii_reader_get_file_name;
+  new (return$) ::rust::String(exodus_ii_reader_get_file_name$(reader));
+}
+
+bool cxxbridge1$192$exodus_ii_reader_can_read_file(::vtkExodusIIReader &reader, ::rust::Str file_name) noexcept {
+  bool (*exodus_ii_reader_can_read_file$)(::vtkExodusIIReader &, ::rust::Str) = ::exodus_ii_reader_can_read_file;
+  return exodus_ii_reader_can_read_file$(reader, file_name);
+}
+
+::rust::repr::PtrLen cxxbridge1$192$exodus_ii_reader_update_information(::vtkExodusIIReader &reader) noexcept {
+  void (*exodus_ii_reader_update_information$)(::vtkExodusIIReader &) = ::exodus_ii_reader_update_information;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        exodus_ii_reader_update_information$(reader);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen cxxbridge1$192$exodus_ii_reader_update(::vtkExodusIIReader &reader) noexcept {
+  void (*exodus_ii_reader_update$)(::vtkExodusIIReader &) = ::exodus_ii_reader_update;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        exodus_ii_reader_update$(reader);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+void cxxbridge1$192$exodus_ii_reader_get_time_values(::vtkExodusIIReader &reader, ::rust::Vec<double> *return$) noexcept {
+  ::rust::Vec<double> (*exodus_ii_reader_get_time_values$)(::vtkExodusIIReader &) = ::exodus_ii_reader_get_time_values;
+  new (return$) ::rust::Vec<double>(exodus_ii_reader_get_time_values$(reader));
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_time_step(::vtkExodusIIReader &reader, ::std::int32_t step) noexcept {
+  void (*exodus_ii_reader_set_time_step$)(::vtkExodusIIReader &, ::std::int32_t) = ::exodus_ii_reader_set_time_step;
+  exodus_ii_reader_set_time_step$(reader, step);
+}
+
+::std::int32_t cxxbridge1$192$exodus_ii_reader_get_time_step(::vtkExodusIIReader const &reader) noexcept {
+  ::std::int32_t (*exodus_ii_reader_get_time_step$)(::vtkExodusIIReader const &) = ::exodus_ii_reader_get_time_step;
+  return exodus_ii_reader_get_time_step$(reader);
+}
+
+::std::int32_t cxxbridge1$192$exodus_ii_reader_get_number_of_objects(::vtkExodusIIReader &reader, ::std::int32_t object_type) noexcept {
+  ::std::int32_t (*exodus_ii_reader_get_number_of_objects$)(::vtkExodusIIReader &, ::std::int32_t) = ::exodus_ii_reader_get_number_of_objects;
+  return exodus_ii_reader_get_number_of_objects$(reader, object_type);
+}
+
+void cxxbridge1$192$exodus_ii_reader_get_object_name(::vtkExodusIIReader &reader, ::std::int32_t object_type, ::std::int32_t index, ::rust::String *return$) noexcept {
+  ::rust::String (*exodus_ii_reader_get_object_name$)(::vtkExodusIIReader &, ::std::int32_t, ::std::int32_t) = ::exodus_ii_reader_get_object_name;
+  new (return$) ::rust::String(exodus_ii_reader_get_object_name$(reader, object_type, index));
+}
+
+::std::int32_t cxxbridge1$192$exodus_ii_reader_get_object_id(::vtkExodusIIReader &reader, ::std::int32_t object_type, ::std::int32_t index) noexcept {
+  ::std::int32_t (*exodus_ii_reader_get_object_id$)(::vtkExodusIIReader &, ::std::int32_t, ::std::int32_t) = ::exodus_ii_reader_get_object_id;
+  return exodus_ii_reader_get_object_id$(reader, object_type, index);
+}
+
+bool cxxbridge1$192$exodus_ii_reader_get_object_status(::vtkExodusIIReader &reader, ::std::int32_t object_type, ::std::int32_t index) noexcept {
+  bool (*exodus_ii_reader_get_object_status$)(::vtkExodusIIReader &, ::std::int32_t, ::std::int32_t) = ::exodus_ii_reader_get_object_status;
+  return exodus_ii_reader_get_object_status$(reader, object_type, index);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_object_status(::vtkExodusIIReader &reader, ::std::int32_t object_type, ::std::int32_t index, bool enabled) noexcept {
+  void (*exodus_ii_reader_set_object_status$)(::vtkExodusIIReader &, ::std::int32_t, ::std::int32_t, bool) = ::exodus_ii_reader_set_object_status;
+  exodus_ii_reader_set_object_status$(reader, object_type, index, enabled);
+}
+
+::std::int32_t cxxbridge1$192$exodus_ii_reader_get_number_of_object_arrays(::vtkExodusIIReader &reader, ::std::int32_t object_type) noexcept {
+  ::std::int32_t (*exodus_ii_reader_get_number_of_object_arrays$)(::vtkExodusIIReader &, ::std::int32_t) = ::exodus_ii_reader_get_number_of_object_arrays;
+  return exodus_ii_reader_get_number_of_object_arrays$(reader, object_type);
+}
+
+void cxxbridge1$192$exodus_ii_reader_get_object_array_name(::vtkExodusIIReader &reader, ::std::int32_t object_type, ::std::int32_t index, ::rust::String *return$) noexcept {
+  ::rust::String (*exodus_ii_reader_get_object_array_name$)(::vtkExodusIIReader &, ::std::int32_t, ::std::int32_t) = ::exodus_ii_reader_get_object_array_name;
+  new (return$) ::rust::String(exodus_ii_reader_get_object_array_name$(reader, object_type, index));
+}
+
+bool cxxbridge1$192$exodus_ii_reader_get_object_array_status(::vtkExodusIIReader &reader, ::std::int32_t object_type, ::std::int32_t index) noexcept {
+  bool (*exodus_ii_reader_get_object_array_status$)(::vtkExodusIIReader &, ::std::int32_t, ::std::int32_t) = ::exodus_ii_reader_get_object_array_status;
+  return exodus_ii_reader_get_object_array_status$(reader, object_type, index);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_object_array_status(::vtkExodusIIReader &reader, ::std::int32_t object_type, ::rust::Str name, bool enabled) noexcept {
+  void (*exodus_ii_reader_set_object_array_status$)(::vtkExodusIIReader &, ::std::int32_t, ::rust::Str, bool) = ::exodus_ii_reader_set_object_array_status;
+  exodus_ii_reader_set_object_array_status$(reader, object_type, name, enabled);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_all_array_status(::vtkExodusIIReader &reader, ::std::int32_t object_type, bool enabled) noexcept {
+  void (*exodus_ii_reader_set_all_array_status$)(::vtkExodusIIReader &, ::std::int32_t, bool) = ::exodus_ii_reader_set_all_array_status;
+  exodus_ii_reader_set_all_array_status$(reader, object_type, enabled);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_apply_displacements(::vtkExodusIIReader &reader, bool enabled) noexcept {
+  void (*exodus_ii_reader_set_apply_displacements$)(::vtkExodusIIReader &, bool) = ::exodus_ii_reader_set_apply_displacements;
+  exodus_ii_reader_set_apply_displacements$(reader, enabled);
+}
+
+bool cxxbridge1$192$exodus_ii_reader_get_apply_displacements(::vtkExodusIIReader const &reader) noexcept {
+  bool (*exodus_ii_reader_get_apply_displacements$)(::vtkExodusIIReader const &) = ::exodus_ii_reader_get_apply_displacements;
+  return exodus_ii_reader_get_apply_displacements$(reader);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_displacement_magnitude(::vtkExodusIIReader &reader, float magnitude) noexcept {
+  void (*exodus_ii_reader_set_displacement_magnitude$)(::vtkExodusIIReader &, float) = ::exodus_ii_reader_set_displacement_magnitude;
+  exodus_ii_reader_set_displacement_magnitude$(reader, magnitude);
+}
+
+float cxxbridge1$192$exodus_ii_reader_get_displacement_magnitude(::vtkExodusIIReader const &reader) noexcept {
+  float (*exodus_ii_reader_get_displacement_magnitude$)(::vtkExodusIIReader const &) = ::exodus_ii_reader_get_displacement_magnitude;
+  return exodus_ii_reader_get_displacement_magnitude$(reader);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_generate_object_id_cell_array(::vtkExodusIIReader &reader, bool enabled) noexcept {
+  void (*exodus_ii_reader_set_generate_object_id_cell_array$)(::vtkExodusIIReader &, bool) = ::exodus_ii_reader_set_generate_object_id_cell_array;
+  exodus_ii_reader_set_generate_object_id_cell_array$(reader, enabled);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_generate_global_node_id_array(::vtkExodusIIReader &reader, bool enabled) noexcept {
+  void (*exodus_ii_reader_set_generate_global_node_id_array$)(::vtkExodusIIReader &, bool) = ::exodus_ii_reader_set_generate_global_node_id_array;
+  exodus_ii_reader_set_generate_global_node_id_array$(reader, enabled);
+}
+
+void cxxbridge1$192$exodus_ii_reader_set_generate_global_element_id_array(::vtkExodusIIReader &reader, bool enabled) noexcept {
+  void (*exodus_ii_reader_set_generate_global_element_id_array$)(::vtkExodusIIReader &, bool) = ::exodus_ii_reader_set_generate_global_element_id_array;
+  exodus_ii_reader_set_generate_global_element_id_array$(reader, enabled);
+}
+
+::std::int32_t cxxbridge1$192$exodus_ii_reader_get_number_of_output_blocks(::vtkExodusIIReader &reader) noexcept {
+  ::std::int32_t (*exodus_ii_reader_get_number_of_output_blocks$)(::vtkExodusIIReader &) = ::exodus_ii_reader_get_number_of_output_blocks;
+  return exodus_ii_reader_get_number_of_output_blocks$(reader);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$exodus_ii_reader_get_output_block(::vtkExodusIIReader &reader, ::std::int32_t index) noexcept {
+  ::vtkUnstructuredGrid *(*exodus_ii_reader_get_output_block$)(::vtkExodusIIReader &, ::std::int32_t) = ::exodus_ii_reader_get_output_block;
+  return exodus_ii_reader_get_output_block$(reader, index);
+}
+
+void cxxbridge1$192$exodus_ii_reader_get_output_block_name(::vtkExodusIIReader &reader, ::std::int32_t index, ::rust::String *return$) noexcept {
+  ::rust::String (*exodus_ii_reader_get_output_block_name$)(::vtkExodusIIReader &, ::std::int32_t) = ::exodus_ii_reader_get_output_block_name;
+  new (return$) ::rust::String(exodus_ii_reader_get_output_block_name$(reader, index));
+}
+
+::std::int32_t cxxbridge1$192$exodus_ii_reader_get_output_block_type(::vtkExodusIIReader &reader, ::std::int32_t index) noexcept {
+  ::std::int32_t (*exodus_ii_reader_get_output_block_type$)(::vtkExodusIIReader &, ::std::int32_t) = ::exodus_ii_reader_get_output_block_type;
+  return exodus_ii_reader_get_output_block_type$(reader, index);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$exodus_ii_reader_get_output_port(::vtkExodusIIReader &reader) noexcept {
+  ::vtkAlgorithmOutput *(*exodus_ii_reader_get_output_port$)(::vtkExodusIIReader &) = ::exodus_ii_reader_get_output_port;
+  return exodus_ii_reader_get_output_port$(reader);
+}
+} // extern "C"
\ No newline at end of file
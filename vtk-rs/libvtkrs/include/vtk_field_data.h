@@ -0,0 +1,23 @@
+#ifndef VTK_FIELD_DATA_H
+#define VTK_FIELD_DATA_H
+
+#include <stdint.h>
+
+extern "C" {
+    // Get FieldData from any vtkDataObject
+    void* data_object_get_field_data(void* data_object);
+
+    // Array management
+    void field_data_add_array(void* field_data, void* array);
+    void field_data_remove_array(void* field_data, const char* name);
+    bool field_data_has_array(void* field_data, const char* name);
+    int64_t field_data_get_number_of_arrays(void* field_data);
+    const char* field_data_get_array_name(void* field_data, int64_t index);
+
+    // Typed access (each returns a new reference, or null on a type mismatch)
+    void* field_data_get_double_array(void* field_data, const char* name);
+    void* field_data_get_int_array(void* field_data, const char* name);
+    void* field_data_get_string_array(void* field_data, const char* name);
+}
+
+#endif // VTK_FIELD_DATA_H
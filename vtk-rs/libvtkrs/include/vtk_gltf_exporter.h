@@ -0,0 +1,27 @@
+#pragma once
+
+#include <vtkGLTFExporter.h>
+#include <vtkRenderWindow.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkGLTFExporter* vtk_gltf_exporter_new();
+void vtk_gltf_exporter_delete(vtkGLTFExporter& exporter);
+
+// File name
+void gltf_exporter_set_file_name(vtkGLTFExporter& exporter, rust::Str file_name);
+rust::String gltf_exporter_get_file_name(const vtkGLTFExporter& exporter);
+
+// Input
+void gltf_exporter_set_render_window(vtkGLTFExporter& exporter, vtkRenderWindow* window);
+
+// Options
+void gltf_exporter_set_inline_data(vtkGLTFExporter& exporter, bool enabled);
+bool gltf_exporter_get_inline_data(const vtkGLTFExporter& exporter);
+void gltf_exporter_set_save_normal(vtkGLTFExporter& exporter, bool enabled);
+bool gltf_exporter_get_save_normal(const vtkGLTFExporter& exporter);
+void gltf_exporter_set_save_batch_id(vtkGLTFExporter& exporter, bool enabled);
+bool gltf_exporter_get_save_batch_id(const vtkGLTFExporter& exporter);
+
+// Output
+void gltf_exporter_write(vtkGLTFExporter& exporter);
@@ -0,0 +1,40 @@
+#pragma once
+
+#include <vtkHDFReader.h>
+#include <vtkUnstructuredGrid.h>
+#include <vtkPolyData.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkHDFReader* vtk_hdf_reader_new();
+void vtk_hdf_reader_delete(vtkHDFReader& reader);
+
+// File name
+void hdf_reader_set_file_name(vtkHDFReader& reader, rust::Str file_name);
+rust::String hdf_reader_get_file_name(const vtkHDFReader& reader);
+bool hdf_reader_can_read_file(vtkHDFReader& reader, rust::Str file_name);
+
+// Execution
+void hdf_reader_update(vtkHDFReader& reader);
+
+// Time steps
+rust::Vec<double> hdf_reader_get_time_values(vtkHDFReader& reader);
+void hdf_reader_set_step(vtkHDFReader& reader, int64_t step);
+int64_t hdf_reader_get_step(const vtkHDFReader& reader);
+double hdf_reader_get_time_value(const vtkHDFReader& reader);
+
+// Arrays
+int hdf_reader_get_number_of_point_arrays(vtkHDFReader& reader);
+rust::String hdf_reader_get_point_array_name(vtkHDFReader& reader, int index);
+void hdf_reader_set_point_array_status(vtkHDFReader& reader, rust::Str name, bool enabled);
+int hdf_reader_get_number_of_cell_arrays(vtkHDFReader& reader);
+rust::String hdf_reader_get_cell_array_name(vtkHDFReader& reader, int index);
+void hdf_reader_set_cell_array_status(vtkHDFReader& reader, rust::Str name, bool enabled);
+
+// Output
+vtkUnstructuredGrid* hdf_reader_get_output_unstructured_grid(vtkHDFReader& reader);
+vtkPolyData* hdf_reader_get_output_poly_data(vtkHDFReader& reader);
+vtkImageData* hdf_reader_get_output_image_data(vtkHDFReader& reader);
+vtkAlgorithmOutput* hdf_reader_get_output_port(vtkHDFReader& reader);
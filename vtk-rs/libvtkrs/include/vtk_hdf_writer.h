@@ -0,0 +1,38 @@
+#pragma once
+
+// vtkHDFWriter was added in VTK 9.4
+#ifdef VTK094
+
+#include <vtkHDFWriter.h>
+#include <vtkDataObject.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkHDFWriter* vtk_hdf_writer_new();
+void vtk_hdf_writer_delete(vtkHDFWriter& writer);
+
+// File name
+void hdf_writer_set_file_name(vtkHDFWriter& writer, rust::Str file_name);
+rust::String hdf_writer_get_file_name(const vtkHDFWriter& writer);
+
+// Options
+void hdf_writer_set_overwrite(vtkHDFWriter& writer, bool overwrite);
+bool hdf_writer_get_overwrite(const vtkHDFWriter& writer);
+void hdf_writer_set_write_all_time_steps(vtkHDFWriter& writer, bool enabled);
+bool hdf_writer_get_write_all_time_steps(const vtkHDFWriter& writer);
+void hdf_writer_set_chunk_size(vtkHDFWriter& writer, int size);
+int hdf_writer_get_chunk_size(const vtkHDFWriter& writer);
+void hdf_writer_set_compression_level(vtkHDFWriter& writer, int level);
+int hdf_writer_get_compression_level(const vtkHDFWriter& writer);
+
+// Input
+void hdf_writer_set_input_connection(vtkHDFWriter& writer, vtkAlgorithmOutput* output);
+void hdf_writer_set_input_data(vtkHDFWriter& writer, vtkDataObject* data);
+void hdf_writer_add_time_step(vtkHDFWriter& writer, double time, vtkDataObject* data);
+int hdf_writer_get_number_of_time_steps(const vtkHDFWriter& writer);
+
+// Output
+void hdf_writer_write(vtkHDFWriter& writer);
+
+#endif // VTK094
@@ -0,0 +1,293 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_hdf_writer.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <new>
+#include <string>
+#include <type_traits>
+#include <utility>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#pragma GCC diagnostic ignored "-Wshadow"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+struct unsafe_bitcopy_t;
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+#ifndef CXXBRIDGE1_RUST_STRING
+#define CXXBRIDGE1_RUST_STRING
+class String final {
+public:
+  String() noexcept;
+  String(const String &) noexcept;
+  String(String &&) noexcept;
+  ~String() noexcept;
+
+  String(const std::string &);
+  String(const char *);
+  String(const char *, std::size_t);
+  String(const char16_t *);
+  String(const char16_t *, std::size_t);
+#ifdef __cpp_char8_t
+  String(const char8_t *s);
+  String(const char8_t *s, std::size_t len);
+#endif
+
+  static String lossy(const std::string &) noexcept;
+  static String lossy(const char *) noexcept;
+  static String lossy(const char *, std::size_t) noexcept;
+  static String lossy(const char16_t *) noexcept;
+  static String lossy(const char16_t *, std::size_t) noexcept;
+
+  String &operator=(const String &) & noexcept;
+  String &operator=(String &&) & noexcept;
+
+  explicit operator std::string() const;
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  const char *c_str() noexcept;
+
+  std::size_t capacity() const noexcept;
+  void reserve(size_t new_cap) noexcept;
+
+  using iterator = char *;
+  iterator begin() noexcept;
+  iterator end() noexcept;
+
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const String &) const noexcept;
+  bool operator!=(const String &) const noexcept;
+  bool operator<(const String &) const noexcept;
+  bool operator<=(const String &) const noexcept;
+  bool operator>(const String &) const noexcept;
+  bool operator>=(const String &) const noexcept;
+
+  void swap(String &) noexcept;
+
+  String(unsafe_bitcopy_t, const String &) noexcept;
+
+private:
+  struct lossy_t;
+  String(lossy_t, const char *, std::size_t) noexcept;
+  String(lossy_t, const char16_t *, std::size_t) noexcept;
+  friend void swap(String &lhs, String &rhs) noexcept { lhs.swap(rhs); }
+
+  std::array<std::uintptr_t, 3> repr;
+};
+#endif // CXXBRIDGE1_RUST_STRING
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+
+namespace repr {
+struct PtrLen final {
+  void *ptr;
+  ::std::size_t len;
+};
+} // namespace repr
+
+namespace detail {
+class Fail final {
+  ::rust::repr::PtrLen &throw$;
+public:
+  Fail(::rust::repr::PtrLen &throw$) noexcept : throw$(throw$) {}
+  void operator()(char const *) noexcept;
+  void operator()(std::string const &) noexcept;
+};
+} // namespace detail
+} // namespace cxxbridge1
+
+namespace behavior {
+class missing {};
+missing trycatch(...);
+
+template <typename Try, typename Fail>
+static typename ::std::enable_if<::std::is_same<
+    decltype(trycatch(::std::declval<Try>(), ::std::declval<Fail>())),
+    missing>::value>::type
+trycatch(Try &&func, Fail &&fail) noexcept try {
+  func();
+} catch (::std::exception const &e) {
+  fail(e.what());
+}
+} // namespace behavior
+} // namespace rust
+
+using vtkHDFWriter = ::vtkHDFWriter;
+using vtkDataObject = ::vtkDataObject;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+
+extern "C" {
+::vtkHDFWriter *cxxbridge1$192$vtk_hdf_writer_new() noexcept {
+  ::vtkHDFWriter *(*vtk_hdf_writer_new$)() = ::vtk_hdf_writer_new;
+  return vtk_hdf_writer_new$();
+}
+
+void cxxbridge1$192$vtk_hdf_writer_delete(::vtkHDFWriter &writer) noexcept {
+  void (*vtk_hdf_writer_delete$)(::vtkHDFWriter &) = ::vtk_hdf_writer_delete;
+  vtk_hdf_writer_delete$(writer);
+}
+
+void cxxbridge1$192$hdf_writer_set_file_name(::vtkHDFWriter &writer, ::rust::Str file_name) noexcept {
+  void (*hdf_writer_set_file_name$)(::vtkHDFWriter &, ::rust::Str) = ::hdf_writer_set_file_name;
+  hdf_writer_set_file_name$(writer, file_name);
+}
+
+void cxxbridge1$192$hdf_writer_get_file_name(::vtkHDFWriter const &writer, ::rust::String *return$) noexcept {
+  ::rust::String (*hdf_writer_get_file_name$)(::vtkHDFWriter const &) = ::hdf_writer_get_file_name;
+  new (return$) ::rust::String(hdf_writer_get_file_name$(writer));
+}
+
+void cxxbridge1$192$hdf_writer_set_overwrite(::vtkHDFWriter &writer, bool overwrite) noexcept {
+  void (*hdf_writer_set_overwrite$)(::vtkHDFWriter &, bool) = ::hdf_writer_set_overwrite;
+  hdf_writer_set_overwrite$(writer, overwrite);
+}
+
+bool cxxbridge1$192$hdf_writer_get_overwrite(::vtkHDFWriter const &writer) noexcept {
+  bool (*hdf_writer_get_overwrite$)(::vtkHDFWriter const &) = ::hdf_writer_get_overwrite;
+  return hdf_writer_get_overwrite$(writer);
+}
+
+void cxxbridge1$192$hdf_writer_set_write_all_time_steps(::vtkHDFWriter &writer, bool enabled) noexcept {
+  void (*hdf_writer_set_write_all_time_steps$)(::vtkHDFWriter &, bool) = ::hdf_writer_set_write_all_time_steps;
+  hdf_writer_set_write_all_time_steps$(writer, enabled);
+}
+
+bool cxxbridge1$192$hdf_writer_get_write_all_time_steps(::vtkHDFWriter const &writer) noexcept {
+  bool (*hdf_writer_get_write_all_time_steps$)(::vtkHDFWriter const &) = ::hdf_writer_get_write_all_time_steps;
+  return hdf_writer_get_write_all_time_steps$(writer);
+}
+
+void cxxbridge1$192$hdf_writer_set_chunk_size(::vtkHDFWriter &writer, ::std::int32_t size) noexcept {
+  void (*hdf_writer_set_chunk_size$)(::vtkHDFWriter &, ::std::int32_t) = ::hdf_writer_set_chunk_size;
+  hdf_writer_set_chunk_size$(writer, size);
+}
+
+::std::int32_t cxxbridge1$192$hdf_writer_get_chunk_size(::vtkHDFWriter const &writer) noexcept {
+  ::std::int32_t (*hdf_writer_get_chunk_size$)(::vtkHDFWriter const &) = ::hdf_writer_get_chunk_size;
+  return hdf_writer_get_chunk_size$(writer);
+}
+
+void cxxbridge1$192$hdf_writer_set_compression_level(::vtkHDFWriter &writer, ::std::int32_t level) noexcept {
+  void (*hdf_writer_set_compression_level$)(::vtkHDFWriter &, ::std::int32_t) = ::hdf_writer_set_compression_level;
+  hdf_writer_set_compression_level$(writer, level);
+}
+
+::std::int32_t cxxbridge1$192$hdf_writer_get_compression_level(::vtkHDFWriter const &writer) noexcept {
+  ::std::int32_t (*hdf_writer_get_compression_level$)(::vtkHDFWriter const &) = ::hdf_writer_get_compression_level;
+  return hdf_writer_get_compression_level$(writer);
+}
+
+void cxxbridge1$192$hdf_writer_set_input_connection(::vtkHDFWriter &writer, ::vtkAlgorithmOutput *output) noexcept {
+  void (*hdf_writer_set_input_connection$)(::vtkHDFWriter &, ::vtkAlgorithmOutput *) = ::hdf_writer_set_input_connection;
+  hdf_writer_set_input_connection$(writer, output);
+}
+
+void cxxbridge1$192$hdf_writer_set_input_data(::vtkHDFWriter &writer, ::vtkDataObject *data) noexcept {
+  void (*hdf_writer_set_input_data$)(::vtkHDFWriter &, ::vtkDataObject *) = ::hdf_writer_set_input_data;
+  hdf_writer_set_input_data$(writer, data);
+}
+
+::rust::repr::PtrLen cxxbridge1$192$hdf_writer_add_time_step(::vtkHDFWriter &writer, double time, ::vtkDataObject *data) noexcept {
+  void (*hdf_writer_add_time_step$)(::vtkHDFWriter &, double, ::vtkDataObject *) = ::hdf_writer_add_time_step;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        hdf_writer_add_time_step$(writer, time, data);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::std::int32_t cxxbridge1$192$hdf_writer_get_number_of_time_steps(::vtkHDFWriter const &writer) noexcept {
+  ::std::int32_t (*hdf_writer_get_number_of_time_steps$)(::vtkHDFWriter const &) = ::hdf_writer_get_number_of_time_steps;
+  return hdf_writer_get_number_of_time_steps$(writer);
+}
+
+::rust::repr::PtrLen cxxbridge1$192$hdf_writer_write(::vtkHDFWriter &writer) noexcept {
+  void (*hdf_writer_write$)(::vtkHDFWriter &) = ::hdf_writer_write;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        hdf_writer_write$(writer);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+} // extern "C"
\ No newline at end of file
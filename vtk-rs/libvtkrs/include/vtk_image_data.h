@@ -2,6 +2,9 @@
 
 #include <vtkImageData.h>
 #include <vtkAlgorithmOutput.h>
+#include <vtkDataArray.h>
+#include <vtkPointData.h>
+#include "cxx.h"
 
 // Create/Delete
 vtkImageData* vtk_image_data_new();
@@ -40,12 +43,26 @@ double image_data_get_scalar_component_as_double(
     int component
 );
 
-// Number of points and cells
-int image_data_get_number_of_points(const vtkImageData& image_data);
-int image_data_get_number_of_cells(const vtkImageData& image_data);
-
-// Bounds  
-void image_data_get_bounds(const vtkImageData& image_data, double bounds[6]);
+// RGBA pixel transfer (2D unsigned char images)
+void image_data_import_rgba(
+    vtkImageData& image_data,
+    int width,
+    int height,
+    rust::Slice<const uint8_t> pixels
+);
+rust::Vec<uint8_t> image_data_export_rgba(const vtkImageData& image_data, int& width, int& height);
 
 // Algorithm output port
-vtkAlgorithmOutput* image_data_get_output_port(vtkImageData& image_data);
\ No newline at end of file
+vtkAlgorithmOutput* image_data_get_output_port(vtkImageData& image_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Active point scalars (get returns a new reference, or null if missing or not of vtk_type)
+void image_data_set_scalars(void* image_data_ptr, vtkDataArray* scalars);
+vtkDataArray* image_data_get_scalars(void* image_data_ptr, int vtk_type);
+
+#ifdef __cplusplus
+}
+#endif
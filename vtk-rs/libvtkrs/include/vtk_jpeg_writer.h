@@ -0,0 +1,27 @@
+#pragma once
+
+#include <vtkJPEGWriter.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkJPEGWriter* vtk_jpeg_writer_new();
+void vtk_jpeg_writer_delete(vtkJPEGWriter& writer);
+
+// File name
+void jpeg_writer_set_file_name(vtkJPEGWriter& writer, rust::Str file_name);
+rust::String jpeg_writer_get_file_name(const vtkJPEGWriter& writer);
+
+// Options
+void jpeg_writer_set_quality(vtkJPEGWriter& writer, int quality);
+int jpeg_writer_get_quality(const vtkJPEGWriter& writer);
+void jpeg_writer_set_progressive(vtkJPEGWriter& writer, bool progressive);
+bool jpeg_writer_get_progressive(const vtkJPEGWriter& writer);
+
+// Input
+void jpeg_writer_set_input_connection(vtkJPEGWriter& writer, vtkAlgorithmOutput* output);
+void jpeg_writer_set_input_data(vtkJPEGWriter& writer, vtkImageData* image_data);
+
+// Output
+void jpeg_writer_write(vtkJPEGWriter& writer);
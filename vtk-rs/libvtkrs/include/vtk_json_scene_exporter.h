@@ -0,0 +1,23 @@
+#pragma once
+
+#include <vtkJSONSceneExporter.h>
+#include <vtkRenderWindow.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkJSONSceneExporter* vtk_json_scene_exporter_new();
+void vtk_json_scene_exporter_delete(vtkJSONSceneExporter& exporter);
+
+// File name
+void json_scene_exporter_set_file_name(vtkJSONSceneExporter& exporter, rust::Str file_name);
+rust::String json_scene_exporter_get_file_name(const vtkJSONSceneExporter& exporter);
+
+// Input
+void json_scene_exporter_set_render_window(vtkJSONSceneExporter& exporter, vtkRenderWindow* window);
+
+// Options
+void json_scene_exporter_set_write_textures(vtkJSONSceneExporter& exporter, bool enabled);
+bool json_scene_exporter_get_write_textures(const vtkJSONSceneExporter& exporter);
+
+// Output
+void json_scene_exporter_write(vtkJSONSceneExporter& exporter);
@@ -0,0 +1,22 @@
+#pragma once
+
+#include <vtkMetaImageReader.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkMetaImageReader* vtk_meta_image_reader_new();
+void vtk_meta_image_reader_delete(vtkMetaImageReader& reader);
+
+// File name
+void meta_image_reader_set_file_name(vtkMetaImageReader& reader, rust::Str file_name);
+rust::String meta_image_reader_get_file_name(const vtkMetaImageReader& reader);
+bool meta_image_reader_can_read_file(vtkMetaImageReader& reader, rust::Str file_name);
+
+// Execution
+void meta_image_reader_update(vtkMetaImageReader& reader);
+
+// Output
+vtkImageData* meta_image_reader_get_output(vtkMetaImageReader& reader);
+vtkAlgorithmOutput* meta_image_reader_get_output_port(vtkMetaImageReader& reader);
@@ -0,0 +1,27 @@
+#pragma once
+
+#include <vtkMetaImageWriter.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkMetaImageWriter* vtk_meta_image_writer_new();
+void vtk_meta_image_writer_delete(vtkMetaImageWriter& writer);
+
+// File name
+void meta_image_writer_set_file_name(vtkMetaImageWriter& writer, rust::Str file_name);
+rust::String meta_image_writer_get_file_name(const vtkMetaImageWriter& writer);
+void meta_image_writer_set_raw_file_name(vtkMetaImageWriter& writer, rust::Str file_name);
+rust::String meta_image_writer_get_raw_file_name(const vtkMetaImageWriter& writer);
+
+// Options
+void meta_image_writer_set_compression(vtkMetaImageWriter& writer, bool compression);
+bool meta_image_writer_get_compression(const vtkMetaImageWriter& writer);
+
+// Input
+void meta_image_writer_set_input_connection(vtkMetaImageWriter& writer, vtkAlgorithmOutput* output);
+void meta_image_writer_set_input_data(vtkMetaImageWriter& writer, vtkImageData* image_data);
+
+// Output
+void meta_image_writer_write(vtkMetaImageWriter& writer);
@@ -0,0 +1,23 @@
+#ifndef VTK_MULTI_BLOCK_DATA_SET_H
+#define VTK_MULTI_BLOCK_DATA_SET_H
+
+#include <stdint.h>
+
+extern "C" {
+    // Create/Delete
+    void* multi_block_data_set_new();
+    void multi_block_data_set_delete(void* blocks);
+
+    // Blocks (block is any vtkDataObject, or null to clear)
+    void multi_block_data_set_set_number_of_blocks(void* blocks, unsigned int count);
+    unsigned int multi_block_data_set_get_number_of_blocks(void* blocks);
+    void multi_block_data_set_set_block(void* blocks, unsigned int index, void* block);
+    void* multi_block_data_set_get_block(void* blocks, unsigned int index, int* block_type);
+    void multi_block_data_set_remove_block(void* blocks, unsigned int index);
+
+    // Block metadata
+    void multi_block_data_set_set_block_name(void* blocks, unsigned int index, const char* name);
+    const char* multi_block_data_set_get_block_name(void* blocks, unsigned int index);
+}
+
+#endif // VTK_MULTI_BLOCK_DATA_SET_H
@@ -0,0 +1,22 @@
+#pragma once
+
+#include <vtkNrrdReader.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkNrrdReader* vtk_nrrd_reader_new();
+void vtk_nrrd_reader_delete(vtkNrrdReader& reader);
+
+// File name
+void nrrd_reader_set_file_name(vtkNrrdReader& reader, rust::Str file_name);
+rust::String nrrd_reader_get_file_name(const vtkNrrdReader& reader);
+bool nrrd_reader_can_read_file(vtkNrrdReader& reader, rust::Str file_name);
+
+// Execution
+void nrrd_reader_update(vtkNrrdReader& reader);
+
+// Output
+vtkImageData* nrrd_reader_get_output(vtkNrrdReader& reader);
+vtkAlgorithmOutput* nrrd_reader_get_output_port(vtkNrrdReader& reader);
@@ -0,0 +1,21 @@
+#pragma once
+
+#include <vtkOBJReader.h>
+#include <vtkPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkOBJReader* vtk_obj_reader_new();
+void vtk_obj_reader_delete(vtkOBJReader& reader);
+
+// File name
+void obj_reader_set_file_name(vtkOBJReader& reader, rust::Str file_name);
+rust::String obj_reader_get_file_name(const vtkOBJReader& reader);
+
+// Execution
+void obj_reader_update(vtkOBJReader& reader);
+
+// Output
+vtkPolyData* obj_reader_get_output(vtkOBJReader& reader);
+vtkAlgorithmOutput* obj_reader_get_output_port(vtkOBJReader& reader);
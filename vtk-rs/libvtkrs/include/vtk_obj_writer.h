@@ -0,0 +1,27 @@
+#pragma once
+
+#include <vtkOBJWriter.h>
+#include <vtkPolyData.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkOBJWriter* vtk_obj_writer_new();
+void vtk_obj_writer_delete(vtkOBJWriter& writer);
+
+// File name
+void obj_writer_set_file_name(vtkOBJWriter& writer, rust::Str file_name);
+rust::String obj_writer_get_file_name(const vtkOBJWriter& writer);
+
+// Options
+void obj_writer_set_texture_file_name(vtkOBJWriter& writer, rust::Str texture_file_name);
+rust::String obj_writer_get_texture_file_name(const vtkOBJWriter& writer);
+void obj_writer_set_texture_data(vtkOBJWriter& writer, vtkImageData* texture);
+
+// Input
+void obj_writer_set_input_connection(vtkOBJWriter& writer, vtkAlgorithmOutput* output);
+void obj_writer_set_input_data(vtkOBJWriter& writer, vtkPolyData* poly_data);
+
+// Output
+void obj_writer_write(vtkOBJWriter& writer);
@@ -0,0 +1,49 @@
+#ifndef VTK_PARTITIONED_DATA_SET_COLLECTION_H
+#define VTK_PARTITIONED_DATA_SET_COLLECTION_H
+
+#include <stdint.h>
+
+extern "C" {
+    // Create/Delete
+    void* partitioned_data_set_collection_new();
+    void partitioned_data_set_collection_delete(void* collection);
+
+    // Partitioned datasets (index) and their partitions (partition is any vtkDataSet)
+    void partitioned_data_set_collection_set_number_of_partitioned_data_sets(
+        void* collection,
+        unsigned int count
+    );
+    unsigned int partitioned_data_set_collection_get_number_of_partitioned_data_sets(
+        void* collection
+    );
+    unsigned int partitioned_data_set_collection_get_number_of_partitions(
+        void* collection,
+        unsigned int index
+    );
+    void partitioned_data_set_collection_set_partition(
+        void* collection,
+        unsigned int index,
+        unsigned int partition,
+        void* data_set
+    );
+    void* partitioned_data_set_collection_get_partition(
+        void* collection,
+        unsigned int index,
+        unsigned int partition,
+        int* block_type
+    );
+    void partitioned_data_set_collection_remove_partitioned_data_set(
+        void* collection,
+        unsigned int index
+    );
+
+    // Metadata of each partitioned dataset
+    void partitioned_data_set_collection_set_name(
+        void* collection,
+        unsigned int index,
+        const char* name
+    );
+    const char* partitioned_data_set_collection_get_name(void* collection, unsigned int index);
+}
+
+#endif // VTK_PARTITIONED_DATA_SET_COLLECTION_H
@@ -0,0 +1,26 @@
+#pragma once
+
+#include <vtkPLYReader.h>
+#include <vtkPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkPLYReader* vtk_ply_reader_new();
+void vtk_ply_reader_delete(vtkPLYReader& reader);
+
+// File name
+void ply_reader_set_file_name(vtkPLYReader& reader, rust::Str file_name);
+rust::String ply_reader_get_file_name(const vtkPLYReader& reader);
+bool ply_reader_can_read_file(vtkPLYReader& reader, rust::Str file_name);
+
+// Options
+void ply_reader_set_duplicate_points_for_face_texture(vtkPLYReader& reader, bool duplicate_points_for_face_texture);
+bool ply_reader_get_duplicate_points_for_face_texture(const vtkPLYReader& reader);
+
+// Execution
+void ply_reader_update(vtkPLYReader& reader);
+
+// Output
+vtkPolyData* ply_reader_get_output(vtkPLYReader& reader);
+vtkAlgorithmOutput* ply_reader_get_output_port(vtkPLYReader& reader);
@@ -0,0 +1,34 @@
+#pragma once
+
+#include <vtkPLYWriter.h>
+#include <vtkPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkPLYWriter* vtk_ply_writer_new();
+void vtk_ply_writer_delete(vtkPLYWriter& writer);
+
+// File name
+void ply_writer_set_file_name(vtkPLYWriter& writer, rust::Str file_name);
+rust::String ply_writer_get_file_name(const vtkPLYWriter& writer);
+
+// Options
+void ply_writer_set_array_name(vtkPLYWriter& writer, rust::Str array_name);
+rust::String ply_writer_get_array_name(const vtkPLYWriter& writer);
+void ply_writer_set_enable_alpha(vtkPLYWriter& writer, bool enable_alpha);
+bool ply_writer_get_enable_alpha(const vtkPLYWriter& writer);
+void ply_writer_set_file_type(vtkPLYWriter& writer, int file_type);
+int ply_writer_get_file_type(const vtkPLYWriter& writer);
+void ply_writer_set_color_mode(vtkPLYWriter& writer, int mode);
+int ply_writer_get_color_mode(const vtkPLYWriter& writer);
+void ply_writer_set_color(vtkPLYWriter& writer, uint8_t r, uint8_t g, uint8_t b);
+void ply_writer_set_alpha(vtkPLYWriter& writer, uint8_t alpha);
+void ply_writer_set_texture_coordinates_name(vtkPLYWriter& writer, int name);
+
+// Input
+void ply_writer_set_input_connection(vtkPLYWriter& writer, vtkAlgorithmOutput* output);
+void ply_writer_set_input_data(vtkPLYWriter& writer, vtkPolyData* poly_data);
+
+// Output
+void ply_writer_write(vtkPLYWriter& writer);
@@ -0,0 +1,22 @@
+#pragma once
+
+#include <vtkPNGReader.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkPNGReader* vtk_png_reader_new();
+void vtk_png_reader_delete(vtkPNGReader& reader);
+
+// File name
+void png_reader_set_file_name(vtkPNGReader& reader, rust::Str file_name);
+rust::String png_reader_get_file_name(const vtkPNGReader& reader);
+bool png_reader_can_read_file(vtkPNGReader& reader, rust::Str file_name);
+
+// Execution
+void png_reader_update(vtkPNGReader& reader);
+
+// Output
+vtkImageData* png_reader_get_output(vtkPNGReader& reader);
+vtkAlgorithmOutput* png_reader_get_output_port(vtkPNGReader& reader);
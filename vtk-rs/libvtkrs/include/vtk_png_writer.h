@@ -0,0 +1,25 @@
+#pragma once
+
+#include <vtkPNGWriter.h>
+#include <vtkImageData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkPNGWriter* vtk_png_writer_new();
+void vtk_png_writer_delete(vtkPNGWriter& writer);
+
+// File name
+void png_writer_set_file_name(vtkPNGWriter& writer, rust::Str file_name);
+rust::String png_writer_get_file_name(const vtkPNGWriter& writer);
+
+// Options
+void png_writer_set_compression_level(vtkPNGWriter& writer, int level);
+int png_writer_get_compression_level(const vtkPNGWriter& writer);
+
+// Input
+void png_writer_set_input_connection(vtkPNGWriter& writer, vtkAlgorithmOutput* output);
+void png_writer_set_input_data(vtkPNGWriter& writer, vtkImageData* image_data);
+
+// Output
+void png_writer_write(vtkPNGWriter& writer);
@@ -0,0 +1,246 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_png_writer.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <new>
+#include <string>
+#include <type_traits>
+#include <utility>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#pragma GCC diagnostic ignored "-Wshadow"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+struct unsafe_bitcopy_t;
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+#ifndef CXXBRIDGE1_RUST_STRING
+#define CXXBRIDGE1_RUST_STRING
+class String final {
+public:
+  String() noexcept;
+  String(const String &) noexcept;
+  String(String &&) noexcept;
+  ~String() noexcept;
+
+  String(const std::string &);
+  String(const char *);
+  String(const char *, std::size_t);
+  String(const char16_t *);
+  String(const char16_t *, std::size_t);
+#ifdef __cpp_char8_t
+  String(const char8_t *s);
+  String(const char8_t *s, std::size_t len);
+#endif
+
+  static String lossy(const std::string &) noexcept;
+  static String lossy(const char *) noexcept;
+  static String lossy(const char *, std::size_t) noexcept;
+  static String lossy(const char16_t *) noexcept;
+  static String lossy(const char16_t *, std::size_t) noexcept;
+
+  String &operator=(const String &) & noexcept;
+  String &operator=(String &&) & noexcept;
+
+  explicit operator std::string() const;
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  const char *c_str() noexcept;
+
+  std::size_t capacity() const noexcept;
+  void reserve(size_t new_cap) noexcept;
+
+  using iterator = char *;
+  iterator begin() noexcept;
+  iterator end() noexcept;
+
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const String &) const noexcept;
+  bool operator!=(const String &) const noexcept;
+  bool operator<(const String &) const noexcept;
+  bool operator<=(const String &) const noexcept;
+  bool operator>(const String &) const noexcept;
+  bool operator>=(const String &) const noexcept;
+
+  void swap(String &) noexcept;
+
+  String(unsafe_bitcopy_t, const String &) noexcept;
+
+private:
+  struct lossy_t;
+  String(lossy_t, const char *, std::size_t) noexcept;
+  String(lossy_t, const char16_t *, std::size_t) noexcept;
+  friend void swap(String &lhs, String &rhs) noexcept { lhs.swap(rhs); }
+
+  std::array<std::uintptr_t, 3> repr;
+};
+#endif // CXXBRIDGE1_RUST_STRING
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+
+namespace repr {
+struct PtrLen final {
+  void *ptr;
+  ::std::size_t len;
+};
+} // namespace repr
+
+namespace detail {
+class Fail final {
+  ::rust::repr::PtrLen &throw$;
+public:
+  Fail(::rust::repr::PtrLen &throw$) noexcept : throw$(throw$) {}
+  void operator()(char const *) noexcept;
+  void operator()(std::string const &) noexcept;
+};
+} // namespace detail
+} // namespace cxxbridge1
+
+namespace behavior {
+class missing {};
+missing trycatch(...);
+
+template <typename Try, typename Fail>
+static typename ::std::enable_if<::std::is_same<
+    decltype(trycatch(::std::declval<Try>(), ::std::declval<Fail>())),
+    missing>::value>::type
+trycatch(Try &&func, Fail &&fail) noexcept try {
+  func();
+} catch (::std::exception const &e) {
+  fail(e.what());
+}
+} // namespace behavior
+} // namespace rust
+
+using vtkPNGWriter = ::vtkPNGWriter;
+using vtkImageData = ::vtkImageData;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+
+extern "C" {
+::vtkPNGWriter *cxxbridge1$192$vtk_png_writer_new() noexcept {
+  ::vtkPNGWriter *(*vtk_png_writer_new$)() = ::vtk_png_writer_new;
+  return vtk_png_writer_new$();
+}
+
+void cxxbridge1$192$vtk_png_writer_delete(::vtkPNGWriter &writer) noexcept {
+  void (*vtk_png_writer_delete$)(::vtkPNGWriter &) = ::vtk_png_writer_delete;
+  vtk_png_writer_delete$(writer);
+}
+
+void cxxbridge1$192$png_writer_set_file_name(::vtkPNGWriter &writer, ::rust::Str file_name) noexcept {
+  void (*png_writer_set_file_name$)(::vtkPNGWriter &, ::rust::Str) = ::png_writer_set_file_name;
+  png_writer_set_file_name$(writer, file_name);
+}
+
+void cxxbridge1$192$png_writer_get_file_name(::vtkPNGWriter const &writer, ::rust::String *return$) noexcept {
+  ::rust::String (*png_writer_get_file_name$)(::vtkPNGWriter const &) = ::png_writer_get_file_name;
+  new (return$) ::rust::String(png_writer_get_file_name$(writer));
+}
+
+void cxxbridge1$192$png_writer_set_compression_level(::vtkPNGWriter &writer, ::std::int32_t level) noexcept {
+  void (*png_writer_set_compression_level$)(::vtkPNGWriter &, ::std::int32_t) = ::png_writer_set_compression_level;
+  png_writer_set_compression_level$(writer, level);
+}
+
+::std::int32_t cxxbridge1$192$png_writer_get_compression_level(::vtkPNGWriter const &writer) noexcept {
+  ::std::int32_t (*png_writer_get_compression_level$)(::vtkPNGWriter const &) = ::png_writer_get_compression_level;
+  return png_writer_get_compression_level$(writer);
+}
+
+void cxxbridge1$192$png_writer_set_input_connection(::vtkPNGWriter &writer, ::vtkAlgorithmOutput *output) noexcept {
+  void (*png_writer_set_input_connection$)(::vtkPNGWriter &, ::vtkAlgorithmOutput *) = ::png_writer_set_input_connection;
+  png_writer_set_input_connection$(writer, output);
+}
+
+void cxxbridge1$192$png_writer_set_input_data(::vtkPNGWriter &writer, ::vtkImageData *image_data) noexcept {
+  void (*png_writer_set_input_data$)(::vtkPNGWriter &, ::vtkImageData *) = ::png_writer_set_input_data;
+  png_writer_set_input_data$(writer, image_data);
+}
+
+::rust::repr::PtrLen cxxbridge1$192$png_writer_write(::vtkPNGWriter &writer) noexcept {
+  void (*png_writer_write$)(::vtkPNGWriter &) = ::png_writer_write;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        png_writer_write$(writer);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+} // extern "C"
\ No newline at end of file
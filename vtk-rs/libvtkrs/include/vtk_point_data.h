@@ -4,9 +4,6 @@
 #include <stdint.h>
 
 extern "C" {
-    // Get PointData from PolyData
-    void* poly_data_get_point_data(void* poly_data);
-
     // Array management
     void point_data_add_array(void* point_data, void* array);
     void point_data_remove_array(void* point_data, const char* name);
@@ -23,6 +20,16 @@ extern "C" {
     // Active attributes
     void point_data_set_active_scalars(void* point_data, const char* name);
     void point_data_set_active_vectors(void* point_data, const char* name);
+
+    // Colors (stored as 4 component unsigned char scalars)
+    void point_data_set_colors(void* point_data, const char* name, const uint8_t* rgba, int64_t num_tuples);
+    int64_t point_data_get_number_of_colors(void* point_data, const char* name);
+    void point_data_copy_colors(void* point_data, const char* name, uint8_t* rgba);
+
+    // Texture coordinates
+    void point_data_set_tcoords(void* point_data, void* array);
+    int64_t point_data_get_number_of_tcoords(void* point_data);
+    void point_data_copy_tcoords(void* point_data, double* uv);
 }
 
 #endif // VTK_POINT_DATA_H
@@ -13,6 +13,10 @@ extern "C" {
     void points_reset(vtkPoints* points);
     void points_set_number_of_points(vtkPoints* points, vtkIdType number);
     void points_resize(vtkPoints* points, vtkIdType number);
+    void points_set_data_type_to_double(vtkPoints* points);
+    bool points_is_double(vtkPoints* points);
+    void* points_get_data(vtkPoints* points);
+    void points_modified(vtkPoints* points);
 }
 
 #endif // VTK_POINTS_H
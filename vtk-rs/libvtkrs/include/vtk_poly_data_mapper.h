@@ -3,7 +3,15 @@
 
 #include <vtkPolyDataMapper.h>
 #include <vtkAlgorithmOutput.h>
+#include <vtkLookupTable.h>
 
 vtkPolyDataMapper* poly_data_mapper_new();
 void poly_data_mapper_delete(vtkPolyDataMapper& pdm);
 void poly_data_mapper_set_input_connection(vtkPolyDataMapper& mapper, vtkAlgorithmOutput* output);
+
+// Coloring
+void poly_data_mapper_set_lookup_table(vtkPolyDataMapper& mapper, vtkLookupTable& lut);
+void poly_data_mapper_set_scalar_range(vtkPolyDataMapper& mapper, double min, double max);
+void poly_data_mapper_set_scalar_visibility(vtkPolyDataMapper& mapper, bool visible);
+bool poly_data_mapper_get_scalar_visibility(const vtkPolyDataMapper& mapper);
+void poly_data_mapper_color_by_array(vtkPolyDataMapper& mapper, rust::Str name, bool cell_data);
@@ -2,6 +2,12 @@
 #include "vtk_poly_data_mapper.h"
 #include "vtk_algorithm_output.h"
 #include "vtk_mapper.h"
+#include <array>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
 
 #ifdef __GNUC__
 #pragma GCC diagnostic ignored "-Wmissing-declarations"
@@ -10,6 +16,69 @@
 #endif // __clang__
 #endif // __GNUC__
 
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
 using vtkPolyDataMapper = ::vtkPolyDataMapper;
 using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
 using vtkMapper = ::vtkMapper;
@@ -29,4 +98,29 @@ void cxxbridge1$192$poly_data_mapper_set_input_connection(::vtkPolyDataMapper &m
   void (*poly_data_mapper_set_input_connection$)(::vtkPolyDataMapper &, ::vtkAlgorithmOutput *) = ::poly_data_mapper_set_input_connection;
   poly_data_mapper_set_input_connection$(mapper, output);
 }
+
+void cxxbridge1$192$poly_data_mapper_set_lookup_table(::vtkPolyDataMapper &mapper, ::vtkLookupTable &lut) noexcept {
+  void (*poly_data_mapper_set_lookup_table$)(::vtkPolyDataMapper &, ::vtkLookupTable &) = ::poly_data_mapper_set_lookup_table;
+  poly_data_mapper_set_lookup_table$(mapper, lut);
+}
+
+void cxxbridge1$192$poly_data_mapper_set_scalar_range(::vtkPolyDataMapper &mapper, double min, double max) noexcept {
+  void (*poly_data_mapper_set_scalar_range$)(::vtkPolyDataMapper &, double, double) = ::poly_data_mapper_set_scalar_range;
+  poly_data_mapper_set_scalar_range$(mapper, min, max);
+}
+
+void cxxbridge1$192$poly_data_mapper_set_scalar_visibility(::vtkPolyDataMapper &mapper, bool visible) noexcept {
+  void (*poly_data_mapper_set_scalar_visibility$)(::vtkPolyDataMapper &, bool) = ::poly_data_mapper_set_scalar_visibility;
+  poly_data_mapper_set_scalar_visibility$(mapper, visible);
+}
+
+bool cxxbridge1$192$poly_data_mapper_get_scalar_visibility(::vtkPolyDataMapper const &mapper) noexcept {
+  bool (*poly_data_mapper_get_scalar_visibility$)(::vtkPolyDataMapper const &) = ::poly_data_mapper_get_scalar_visibility;
+  return poly_data_mapper_get_scalar_visibility$(mapper);
+}
+
+void cxxbridge1$192$poly_data_mapper_color_by_array(::vtkPolyDataMapper &mapper, ::rust::Str name, bool cell_data) noexcept {
+  void (*poly_data_mapper_color_by_array$)(::vtkPolyDataMapper &, ::rust::Str, bool) = ::poly_data_mapper_color_by_array;
+  poly_data_mapper_color_by_array$(mapper, name, cell_data);
+}
 } // extern "C"
\ No newline at end of file
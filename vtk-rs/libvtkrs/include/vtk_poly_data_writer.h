@@ -0,0 +1,29 @@
+#pragma once
+
+#include <vtkPolyDataWriter.h>
+#include <vtkPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkPolyDataWriter* vtk_poly_data_writer_new();
+void vtk_poly_data_writer_delete(vtkPolyDataWriter& writer);
+
+// File name
+void poly_data_writer_set_file_name(vtkPolyDataWriter& writer, rust::Str file_name);
+rust::String poly_data_writer_get_file_name(const vtkPolyDataWriter& writer);
+
+// Options
+void poly_data_writer_set_file_type(vtkPolyDataWriter& writer, int file_type);
+int poly_data_writer_get_file_type(const vtkPolyDataWriter& writer);
+void poly_data_writer_set_header(vtkPolyDataWriter& writer, rust::Str header);
+rust::String poly_data_writer_get_header(const vtkPolyDataWriter& writer);
+void poly_data_writer_set_write_to_output_string(vtkPolyDataWriter& writer, bool enabled);
+rust::Vec<uint8_t> poly_data_writer_get_output_string(const vtkPolyDataWriter& writer);
+
+// Input
+void poly_data_writer_set_input_connection(vtkPolyDataWriter& writer, vtkAlgorithmOutput* output);
+void poly_data_writer_set_input_data(vtkPolyDataWriter& writer, vtkPolyData* poly_data);
+
+// Output
+void poly_data_writer_write(vtkPolyDataWriter& writer);
@@ -0,0 +1,21 @@
+#pragma once
+
+#include <vtkRectilinearGrid.h>
+#include <vtkDoubleArray.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkRectilinearGrid* vtk_rectilinear_grid_new();
+void vtk_rectilinear_grid_delete(vtkRectilinearGrid& grid);
+
+// Dimensions
+void rectilinear_grid_set_dimensions(vtkRectilinearGrid& grid, int nx, int ny, int nz);
+void rectilinear_grid_get_dimensions(const vtkRectilinearGrid& grid, int* nx, int* ny, int* nz);
+
+// Coordinates (axis 0 = x, 1 = y, 2 = z)
+void rectilinear_grid_set_coordinates(
+    vtkRectilinearGrid& grid,
+    int axis,
+    vtkDoubleArray* coordinates
+);
+rust::Vec<double> rectilinear_grid_get_coordinates(const vtkRectilinearGrid& grid, int axis);
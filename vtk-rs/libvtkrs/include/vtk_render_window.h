@@ -6,11 +6,14 @@
 #include "cxx.h"
 
 vtkRenderWindow* render_window_new();
+vtkRenderWindow* render_window_new_offscreen();
 void render_window_delete(vtkRenderWindow& window);
 void render_window_add_renderer(vtkRenderWindow& window, vtkRenderer* renderer);
 void render_window_set_size(vtkRenderWindow& window, int width, int height);
 void render_window_set_window_name(vtkRenderWindow& window, rust::Str name);
 void render_window_render(vtkRenderWindow& window);
+void render_window_set_off_screen_rendering(vtkRenderWindow& window, bool on);
+bool render_window_get_off_screen_rendering(const vtkRenderWindow& window);
 void render_window_get_size(vtkRenderWindow& window, int& width, int& height);
 void render_window_get_pixel_data(vtkRenderWindow& window, unsigned char* data, int size);
 void render_window_set_pixel_data(vtkRenderWindow& window, const unsigned char* data, int size);
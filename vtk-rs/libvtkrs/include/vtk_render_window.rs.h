@@ -90,6 +90,11 @@ extern "C" {
   return render_window_new$();
 }
 
+::vtkRenderWindow *cxxbridge1$192$render_window_new_offscreen() noexcept {
+  ::vtkRenderWindow *(*render_window_new_offscreen$)() = ::render_window_new_offscreen;
+  return render_window_new_offscreen$();
+}
+
 void cxxbridge1$192$render_window_delete(::vtkRenderWindow &window) noexcept {
   void (*render_window_delete$)(::vtkRenderWindow &) = ::render_window_delete;
   render_window_delete$(window);
@@ -115,6 +120,16 @@ void cxxbridge1$192$render_window_render(::vtkRenderWindow &window) noexcept {
   render_window_render$(window);
 }
 
+void cxxbridge1$192$render_window_set_off_screen_rendering(::vtkRenderWindow &window, bool on) noexcept {
+  void (*render_window_set_off_screen_rendering$)(::vtkRenderWindow &, bool) = ::render_window_set_off_screen_rendering;
+  render_window_set_off_screen_rendering$(window, on);
+}
+
+bool cxxbridge1$192$render_window_get_off_screen_rendering(::vtkRenderWindow const &window) noexcept {
+  bool (*render_window_get_off_screen_rendering$)(::vtkRenderWindow const &) = ::render_window_get_off_screen_rendering;
+  return render_window_get_off_screen_rendering$(window);
+}
+
 void cxxbridge1$192$render_window_get_size(::vtkRenderWindow &window, ::std::int32_t &width, ::std::int32_t &height) noexcept {
   void (*render_window_get_size$)(::vtkRenderWindow &, ::std::int32_t &, ::std::int32_t &) = ::render_window_get_size;
   render_window_get_size$(window, width, height);
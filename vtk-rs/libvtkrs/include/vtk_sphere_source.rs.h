@@ -15,6 +15,7 @@
 
 using vtkSphereSource = ::vtkSphereSource;
 using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
 
 extern "C" {
 ::vtkSphereSource *cxxbridge1$192$vtk_sphere_source_new() noexcept {
@@ -67,6 +68,11 @@ void cxxbridge1$192$vtk_sphere_source_set_theta_resolution(::vtkSphereSource &sp
   return vtk_sphere_source_get_theta_resolution$(sphere_source);
 }
 
+::vtkPolyData *cxxbridge1$192$sphere_source_get_output(::vtkSphereSource const &sphere_source) noexcept {
+  ::vtkPolyData *(*sphere_source_get_output$)(::vtkSphereSource const &) = ::sphere_source_get_output;
+  return sphere_source_get_output$(sphere_source);
+}
+
 ::vtkAlgorithmOutput *cxxbridge1$192$sphere_source_get_output_port(::vtkSphereSource &sphere_source) noexcept {
   ::vtkAlgorithmOutput *(*sphere_source_get_output_port$)(::vtkSphereSource &) = ::sphere_source_get_output_port;
   return sphere_source_get_output_port$(sphere_source);
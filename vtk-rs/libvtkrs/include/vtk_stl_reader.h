@@ -0,0 +1,27 @@
+#pragma once
+
+#include <vtkSTLReader.h>
+#include <vtkPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkSTLReader* vtk_stl_reader_new();
+void vtk_stl_reader_delete(vtkSTLReader& reader);
+
+// File name
+void stl_reader_set_file_name(vtkSTLReader& reader, rust::Str file_name);
+rust::String stl_reader_get_file_name(const vtkSTLReader& reader);
+
+// Options
+void stl_reader_set_merging(vtkSTLReader& reader, bool merging);
+bool stl_reader_get_merging(const vtkSTLReader& reader);
+void stl_reader_set_scalar_tags(vtkSTLReader& reader, bool scalar_tags);
+bool stl_reader_get_scalar_tags(const vtkSTLReader& reader);
+
+// Execution
+void stl_reader_update(vtkSTLReader& reader);
+
+// Output
+vtkPolyData* stl_reader_get_output(vtkSTLReader& reader);
+vtkAlgorithmOutput* stl_reader_get_output_port(vtkSTLReader& reader);
@@ -0,0 +1,27 @@
+#pragma once
+
+#include <vtkSTLWriter.h>
+#include <vtkPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkSTLWriter* vtk_stl_writer_new();
+void vtk_stl_writer_delete(vtkSTLWriter& writer);
+
+// File name
+void stl_writer_set_file_name(vtkSTLWriter& writer, rust::Str file_name);
+rust::String stl_writer_get_file_name(const vtkSTLWriter& writer);
+
+// Options
+void stl_writer_set_header(vtkSTLWriter& writer, rust::Str header);
+rust::String stl_writer_get_header(const vtkSTLWriter& writer);
+void stl_writer_set_file_type(vtkSTLWriter& writer, int file_type);
+int stl_writer_get_file_type(const vtkSTLWriter& writer);
+
+// Input
+void stl_writer_set_input_connection(vtkSTLWriter& writer, vtkAlgorithmOutput* output);
+void stl_writer_set_input_data(vtkSTLWriter& writer, vtkPolyData* poly_data);
+
+// Output
+void stl_writer_write(vtkSTLWriter& writer);
@@ -0,0 +1,28 @@
+#ifndef VTK_STRING_ARRAY_H
+#define VTK_STRING_ARRAY_H
+
+#include <stdint.h>
+
+extern "C" {
+    // Create and destroy
+    void* string_array_new();
+    void string_array_delete(void* array);
+
+    // Configuration
+    void string_array_set_number_of_values(void* array, int64_t num_values);
+    int64_t string_array_get_number_of_values(void* array);
+
+    // Name management
+    void string_array_set_name(void* array, const char* name);
+    const char* string_array_get_name(void* array);
+
+    // Data access
+    int64_t string_array_insert_next_value(void* array, const char* value);
+    void string_array_set_value(void* array, int64_t id, const char* value);
+    const char* string_array_get_value(void* array, int64_t id);
+
+    // Memory management
+    void string_array_initialize(void* array);
+}
+
+#endif // VTK_STRING_ARRAY_H
@@ -0,0 +1,17 @@
+#pragma once
+
+#include <vtkStructuredGrid.h>
+#include <vtkPoints.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkStructuredGrid* vtk_structured_grid_new();
+void vtk_structured_grid_delete(vtkStructuredGrid& grid);
+
+// Dimensions
+void structured_grid_set_dimensions(vtkStructuredGrid& grid, int nx, int ny, int nz);
+void structured_grid_get_dimensions(const vtkStructuredGrid& grid, int* nx, int* ny, int* nz);
+
+// Points
+void structured_grid_set_points(vtkStructuredGrid& grid, vtkPoints* points);
+vtkPoints* structured_grid_get_points(vtkStructuredGrid& grid);
@@ -0,0 +1,46 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_structured_grid.h"
+#include "vtk_points.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkStructuredGrid = ::vtkStructuredGrid;
+using vtkPoints = ::vtkPoints;
+
+extern "C" {
+::vtkStructuredGrid *cxxbridge1$192$vtk_structured_grid_new() noexcept {
+  ::vtkStructuredGrid *(*vtk_structured_grid_new$)() = ::vtk_structured_grid_new;
+  return vtk_structured_grid_new$();
+}
+
+void cxxbridge1$192$vtk_structured_grid_delete(::vtkStructuredGrid &grid) noexcept {
+  void (*vtk_structured_grid_delete$)(::vtkStructuredGrid &) = ::vtk_structured_grid_delete;
+  vtk_structured_grid_delete$(grid);
+}
+
+void cxxbridge1$192$structured_grid_set_dimensions(::vtkStructuredGrid &grid, ::std::int32_t nx, ::std::int32_t ny, ::std::int32_t nz) noexcept {
+  void (*structured_grid_set_dimensions$)(::vtkStructuredGrid &, ::std::int32_t, ::std::int32_t, ::std::int32_t) = ::structured_grid_set_dimensions;
+  structured_grid_set_dimensions$(grid, nx, ny, nz);
+}
+
+void cxxbridge1$192$structured_grid_get_dimensions(::vtkStructuredGrid const &grid, ::std::int32_t *nx, ::std::int32_t *ny, ::std::int32_t *nz) noexcept {
+  void (*structured_grid_get_dimensions$)(::vtkStructuredGrid const &, ::std::int32_t *, ::std::int32_t *, ::std::int32_t *) = ::structured_grid_get_dimensions;
+  structured_grid_get_dimensions$(grid, nx, ny, nz);
+}
+
+void cxxbridge1$192$structured_grid_set_points(::vtkStructuredGrid &grid, ::vtkPoints *points) noexcept {
+  void (*structured_grid_set_points$)(::vtkStructuredGrid &, ::vtkPoints *) = ::structured_grid_set_points;
+  structured_grid_set_points$(grid, points);
+}
+
+::vtkPoints *cxxbridge1$192$structured_grid_get_points(::vtkStructuredGrid &grid) noexcept {
+  ::vtkPoints *(*structured_grid_get_points$)(::vtkStructuredGrid &) = ::structured_grid_get_points;
+  return structured_grid_get_points$(grid);
+}
+} // extern "C"
\ No newline at end of file
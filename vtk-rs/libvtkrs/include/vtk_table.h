@@ -0,0 +1,30 @@
+#pragma once
+
+#include <vtkTable.h>
+#include <stdint.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkTable* vtk_table_new();
+void vtk_table_delete(vtkTable& table);
+
+// Shape
+int64_t table_get_number_of_rows(const vtkTable& table);
+int64_t table_get_number_of_columns(const vtkTable& table);
+
+// Columns
+rust::String table_get_column_name(const vtkTable& table, int64_t index);
+int table_get_column_type(const vtkTable& table, rust::Str name);
+void table_remove_column(vtkTable& table, rust::Str name);
+
+// String columns
+void table_add_string_column(vtkTable& table, rust::Str name, rust::Vec<rust::String> values);
+rust::Vec<rust::String> table_get_string_column(const vtkTable& table, rust::Str name);
+rust::String table_get_value_as_string(const vtkTable& table, int64_t row, int64_t column);
+
+// Numeric columns (extern "C" for the DoubleArray/IntArray wrappers)
+extern "C" {
+    void table_add_column(void* table, void* array);
+    void* table_get_double_column(void* table, const char* name);
+    void* table_get_int_column(void* table, const char* name);
+}
@@ -0,0 +1,32 @@
+#pragma once
+
+#include <vtkTableToPolyData.h>
+#include <vtkTable.h>
+#include <vtkPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkTableToPolyData* vtk_table_to_poly_data_new();
+void vtk_table_to_poly_data_delete(vtkTableToPolyData& filter);
+
+// Input
+void table_to_poly_data_set_input_connection(vtkTableToPolyData& filter, vtkAlgorithmOutput* port);
+void table_to_poly_data_set_input_data(vtkTableToPolyData& filter, vtkTable* table);
+
+// Coordinate columns
+void table_to_poly_data_set_x_column(vtkTableToPolyData& filter, rust::Str name);
+void table_to_poly_data_set_y_column(vtkTableToPolyData& filter, rust::Str name);
+void table_to_poly_data_set_z_column(vtkTableToPolyData& filter, rust::Str name);
+void table_to_poly_data_set_x_column_index(vtkTableToPolyData& filter, int index);
+void table_to_poly_data_set_y_column_index(vtkTableToPolyData& filter, int index);
+void table_to_poly_data_set_z_column_index(vtkTableToPolyData& filter, int index);
+void table_to_poly_data_set_create_2d_points(vtkTableToPolyData& filter, bool enabled);
+void table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays(vtkTableToPolyData& filter, bool enabled);
+
+// Execution
+void table_to_poly_data_update(vtkTableToPolyData& filter);
+
+// Output
+vtkPolyData* table_to_poly_data_get_output(vtkTableToPolyData& filter);
+vtkAlgorithmOutput* table_to_poly_data_get_output_port(vtkTableToPolyData& filter);
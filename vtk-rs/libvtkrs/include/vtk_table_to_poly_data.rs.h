@@ -0,0 +1,205 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_table_to_poly_data.h"
+#include "vtk_table.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <exception>
+#include <string>
+#include <type_traits>
+#include <utility>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#pragma GCC diagnostic ignored "-Wshadow"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+
+namespace repr {
+struct PtrLen final {
+  void *ptr;
+  ::std::size_t len;
+};
+} // namespace repr
+
+namespace detail {
+class Fail final {
+  ::rust::repr::PtrLen &throw$;
+public:
+  Fail(::rust::repr::PtrLen &throw$) noexcept : throw$(throw$) {}
+  void operator()(char const *) noexcept;
+  void operator()(std::string const &) noexcept;
+};
+} // namespace detail
+} // namespace cxxbridge1
+
+namespace behavior {
+class missing {};
+missing trycatch(...);
+
+template <typename Try, typename Fail>
+static typename ::std::enable_if<::std::is_same<
+    decltype(trycatch(::std::declval<Try>(), ::std::declval<Fail>())),
+    missing>::value>::type
+trycatch(Try &&func, Fail &&fail) noexcept try {
+  func();
+} catch (::std::exception const &e) {
+  fail(e.what());
+}
+} // namespace behavior
+} // namespace rust
+
+using vtkTableToPolyData = ::vtkTableToPolyData;
+using vtkPolyData = ::vtkPolyData;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+
+extern "C" {
+::vtkTableToPolyData *cxxbridge1$192$vtk_table_to_poly_data_new() noexcept {
+  ::vtkTableToPolyData *(*vtk_table_to_poly_data_new$)() = ::vtk_table_to_poly_data_new;
+  return vtk_table_to_poly_data_new$();
+}
+
+void cxxbridge1$192$vtk_table_to_poly_data_delete(::vtkTableToPolyData &filter) noexcept {
+  void (*vtk_table_to_poly_data_delete$)(::vtkTableToPolyData &) = ::vtk_table_to_poly_data_delete;
+  vtk_table_to_poly_data_delete$(filter);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_input_connection(::vtkTableToPolyData &filter, ::vtkAlgorithmOutput *port) noexcept {
+  void (*table_to_poly_data_set_input_connection$)(::vtkTableToPolyData &, ::vtkAlgorithmOutput *) = ::table_to_poly_data_set_input_connection;
+  table_to_poly_data_set_input_connection$(filter, port);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_input_data(::vtkTableToPolyData &filter, ::vtkTable *table) noexcept {
+  void (*table_to_poly_data_set_input_data$)(::vtkTableToPolyData &, ::vtkTable *) = ::table_to_poly_data_set_input_data;
+  table_to_poly_data_set_input_data$(filter, table);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_x_column(::vtkTableToPolyData &filter, ::rust::Str name) noexcept {
+  void (*table_to_poly_data_set_x_column$)(::vtkTableToPolyData &, ::rust::Str) = ::table_to_poly_data_set_x_column;
+  table_to_poly_data_set_x_column$(filter, name);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_y_column(::vtkTableToPolyData &filter, ::rust::Str name) noexcept {
+  void (*table_to_poly_data_set_y_column$)(::vtkTableToPolyData &, ::rust::Str) = ::table_to_poly_data_set_y_column;
+  table_to_poly_data_set_y_column$(filter, name);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_z_column(::vtkTableToPolyData &filter, ::rust::Str name) noexcept {
+  void (*table_to_poly_data_set_z_column$)(::vtkTableToPolyData &, ::rust::Str) = ::table_to_poly_data_set_z_column;
+  table_to_poly_data_set_z_column$(filter, name);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_x_column_index(::vtkTableToPolyData &filter, ::std::int32_t index) noexcept {
+  void (*table_to_poly_data_set_x_column_index$)(::vtkTableToPolyData &, ::std::int32_t) = ::table_to_poly_data_set_x_column_index;
+  table_to_poly_data_set_x_column_index$(filter, index);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_y_column_index(::vtkTableToPolyData &filter, ::std::int32_t index) noexcept {
+  void (*table_to_poly_data_set_y_column_index$)(::vtkTableToPolyData &, ::std::int32_t) = ::table_to_poly_data_set_y_column_index;
+  table_to_poly_data_set_y_column_index$(filter, index);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_z_column_index(::vtkTableToPolyData &filter, ::std::int32_t index) noexcept {
+  void (*table_to_poly_data_set_z_column_index$)(::vtkTableToPolyData &, ::std::int32_t) = ::table_to_poly_data_set_z_column_index;
+  table_to_poly_data_set_z_column_index$(filter, index);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_create_2d_points(::vtkTableToPolyData &filter, bool enabled) noexcept {
+  void (*table_to_poly_data_set_create_2d_points$)(::vtkTableToPolyData &, bool) = ::table_to_poly_data_set_create_2d_points;
+  table_to_poly_data_set_create_2d_points$(filter, enabled);
+}
+
+void cxxbridge1$192$table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays(::vtkTableToPolyData &filter, bool enabled) noexcept {
+  void (*table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays$)(::vtkTableToPolyData &, bool) = ::table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays;
+  table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays$(filter, enabled);
+}
+
+::rust::repr::PtrLen cxxbridge1$192$table_to_poly_data_update(::vtkTableToPolyData &filter) noexcept {
+  void (*table_to_poly_data_update$)(::vtkTableToPolyData &) = ::table_to_poly_data_update;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        table_to_poly_data_update$(filter);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::vtkPolyData *cxxbridge1$192$table_to_poly_data_get_output(::vtkTableToPolyData &filter) noexcept {
+  ::vtkPolyData *(*table_to_poly_data_get_output$)(::vtkTableToPolyData &) = ::table_to_poly_data_get_output;
+  return table_to_poly_data_get_output$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$table_to_poly_data_get_output_port(::vtkTableToPolyData &filter) noexcept {
+  ::vtkAlgorithmOutput *(*table_to_poly_data_get_output_port$)(::vtkTableToPolyData &) = ::table_to_poly_data_get_output_port;
+  return table_to_poly_data_get_output_port$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,113 @@
+#pragma once
+
+#include <vtkDataObject.h>
+#include <vtkDataObjectAlgorithm.h>
+#include <vtkInformation.h>
+#include <vtkInformationVector.h>
+#include <vtkSmartPointer.h>
+#include <vtkStreamingDemandDrivenPipeline.h>
+#include <algorithm>
+#include <cmath>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+// Serves a list of in-memory datasets as the time steps of a temporal pipeline.
+//
+// Writers that store all time steps in one file (such as vtkHDFWriter) pull the
+// steps through the pipeline one after another. Simulation results that were
+// computed step by step in Rust are added here and connected to such a writer.
+// Each step keeps a shallow copy of the dataset it was given. Steps are kept sorted
+// by time, as TIME_STEPS must be increasing; a step at an existing time replaces it.
+// The output type is taken from the steps, so all of them must be of the same class.
+class vtkRsTemporalSource : public vtkDataObjectAlgorithm {
+public:
+    static vtkRsTemporalSource* New() {
+        vtkRsTemporalSource* result = new vtkRsTemporalSource;
+        result->InitializeObjectBase();
+        return result;
+    }
+    vtkTypeMacro(vtkRsTemporalSource, vtkDataObjectAlgorithm);
+
+    void AddStep(double time, vtkDataObject* data) {
+        if (!this->Steps.empty() && !data->IsA(this->Steps.front().second->GetClassName())) {
+            throw std::invalid_argument(std::string("cannot add a ") + data->GetClassName() +
+                " time step to a series of " + this->Steps.front().second->GetClassName());
+        }
+        vtkSmartPointer<vtkDataObject> copy;
+        copy.TakeReference(data->NewInstance());
+        copy->ShallowCopy(data);
+        auto position = std::lower_bound(this->Steps.begin(), this->Steps.end(), time,
+            [](const Step& step, double t) { return step.first < t; });
+        if (position != this->Steps.end() && position->first == time) {
+            position->second = copy;
+        } else {
+            this->Steps.emplace(position, time, copy);
+        }
+        this->Modified();
+    }
+
+    int GetNumberOfSteps() const { return static_cast<int>(this->Steps.size()); }
+
+protected:
+    vtkRsTemporalSource() { this->SetNumberOfInputPorts(0); }
+
+    int RequestDataObject(vtkInformation*, vtkInformationVector**, vtkInformationVector* outputVector) override {
+        if (this->Steps.empty()) {
+            return 0;
+        }
+        vtkInformation* outInfo = outputVector->GetInformationObject(0);
+        vtkDataObject* output = vtkDataObject::GetData(outInfo);
+        if (!output || !output->IsA(this->Steps.front().second->GetClassName())) {
+            vtkSmartPointer<vtkDataObject> instance;
+            instance.TakeReference(this->Steps.front().second->NewInstance());
+            outInfo->Set(vtkDataObject::DATA_OBJECT(), instance);
+        }
+        return 1;
+    }
+
+    int RequestInformation(vtkInformation*, vtkInformationVector**, vtkInformationVector* outputVector) override {
+        vtkInformation* outInfo = outputVector->GetInformationObject(0);
+        if (this->Steps.empty()) {
+            return 1;
+        }
+        std::vector<double> times;
+        for (const auto& step : this->Steps) {
+            times.push_back(step.first);
+        }
+        double range[2] = { times.front(), times.back() };
+        outInfo->Set(vtkStreamingDemandDrivenPipeline::TIME_STEPS(), times.data(), static_cast<int>(times.size()));
+        outInfo->Set(vtkStreamingDemandDrivenPipeline::TIME_RANGE(), range, 2);
+        return 1;
+    }
+
+    int RequestData(vtkInformation*, vtkInformationVector**, vtkInformationVector* outputVector) override {
+        if (this->Steps.empty()) {
+            return 0;
+        }
+        vtkInformation* outInfo = outputVector->GetInformationObject(0);
+        vtkDataObject* output = vtkDataObject::GetData(outInfo);
+
+        // Serve the step closest to the requested time (the first one if none was requested)
+        size_t index = 0;
+        if (outInfo->Has(vtkStreamingDemandDrivenPipeline::UPDATE_TIME_STEP())) {
+            double time = outInfo->Get(vtkStreamingDemandDrivenPipeline::UPDATE_TIME_STEP());
+            for (size_t i = 1; i < this->Steps.size(); ++i) {
+                if (std::abs(this->Steps[i].first - time) < std::abs(this->Steps[index].first - time)) {
+                    index = i;
+                }
+            }
+        }
+        output->ShallowCopy(this->Steps[index].second);
+        output->GetInformation()->Set(vtkDataObject::DATA_TIME_STEP(), this->Steps[index].first);
+        return 1;
+    }
+
+private:
+    vtkRsTemporalSource(const vtkRsTemporalSource&) = delete;
+    void operator=(const vtkRsTemporalSource&) = delete;
+
+    using Step = std::pair<double, vtkSmartPointer<vtkDataObject>>;
+    std::vector<Step> Steps;
+};
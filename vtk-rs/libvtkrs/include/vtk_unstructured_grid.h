@@ -6,6 +6,9 @@
 #include <vtkUnstructuredGrid.h>
 #include <vtkPoints.h>
 #include <vtkPointData.h>
+#include <vtkCellData.h>
+#include <vtkCellArray.h>
+#include "cxx.h"
 
 // Create/Delete
 vtkUnstructuredGrid* vtk_unstructured_grid_new();
@@ -13,7 +16,7 @@ void vtk_unstructured_grid_delete(vtkUnstructuredGrid& grid);
 
 // Points
 void unstructured_grid_set_points(vtkUnstructuredGrid& grid, vtkPoints* points);
-vtkPoints* unstructured_grid_get_points(vtkUnstructuredGrid& grid);
+vtkPoints* unstructured_grid_get_points(const vtkUnstructuredGrid& grid);
 
 // Cell allocation
 void unstructured_grid_allocate(vtkUnstructuredGrid& grid, int num_cells);
@@ -26,20 +29,19 @@ void unstructured_grid_insert_next_cell(
     const int* point_ids
 );
 
-// Metadata
-int unstructured_grid_get_number_of_points(const vtkUnstructuredGrid& grid);
-int unstructured_grid_get_number_of_cells(const vtkUnstructuredGrid& grid);
-void unstructured_grid_get_bounds(const vtkUnstructuredGrid& grid, double* bounds);
-
-#ifdef __cplusplus
-extern "C" {
-#endif
-
-// Point data access (extern "C" for Rust FFI)
-vtkPointData* unstructured_grid_get_point_data(void* grid_ptr);
+int64_t unstructured_grid_insert_next_polyhedron(
+    vtkUnstructuredGrid& grid,
+    rust::Slice<const int64_t> face_stream
+);
 
-#ifdef __cplusplus
-}
-#endif
+// Cell access
+vtkCellArray* unstructured_grid_get_cells(const vtkUnstructuredGrid& grid);
+rust::Vec<int64_t> unstructured_grid_get_connectivity(const vtkUnstructuredGrid& grid);
+rust::Vec<int64_t> unstructured_grid_get_offsets(const vtkUnstructuredGrid& grid);
+rust::Vec<uint8_t> unstructured_grid_get_cell_types(const vtkUnstructuredGrid& grid);
+rust::Vec<int64_t> unstructured_grid_get_face_stream(
+    const vtkUnstructuredGrid& grid,
+    int64_t cell_id
+);
 
 #endif // VTK_UNSTRUCTURED_GRID_H
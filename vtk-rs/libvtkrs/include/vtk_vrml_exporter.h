@@ -0,0 +1,19 @@
+#pragma once
+
+#include <vtkVRMLExporter.h>
+#include <vtkRenderWindow.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkVRMLExporter* vtk_vrml_exporter_new();
+void vtk_vrml_exporter_delete(vtkVRMLExporter& exporter);
+
+// File name
+void vrml_exporter_set_file_name(vtkVRMLExporter& exporter, rust::Str file_name);
+rust::String vrml_exporter_get_file_name(const vtkVRMLExporter& exporter);
+
+// Input
+void vrml_exporter_set_render_window(vtkVRMLExporter& exporter, vtkRenderWindow* window);
+
+// Output
+void vrml_exporter_write(vtkVRMLExporter& exporter);
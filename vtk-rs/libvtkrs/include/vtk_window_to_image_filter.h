@@ -0,0 +1,31 @@
+#pragma once
+
+#include <vtkWindowToImageFilter.h>
+#include <vtkRenderWindow.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkWindowToImageFilter* vtk_window_to_image_filter_new();
+void vtk_window_to_image_filter_delete(vtkWindowToImageFilter& filter);
+
+// Input
+void window_to_image_filter_set_input(vtkWindowToImageFilter& filter, vtkRenderWindow* window);
+void window_to_image_filter_set_input_buffer_type_to_rgb(vtkWindowToImageFilter& filter);
+void window_to_image_filter_set_input_buffer_type_to_rgba(vtkWindowToImageFilter& filter);
+void window_to_image_filter_set_read_front_buffer(vtkWindowToImageFilter& filter, bool front);
+bool window_to_image_filter_get_read_front_buffer(const vtkWindowToImageFilter& filter);
+void window_to_image_filter_set_scale(vtkWindowToImageFilter& filter, int scale);
+int window_to_image_filter_get_scale(const vtkWindowToImageFilter& filter);
+
+// Execution
+void window_to_image_filter_modified(vtkWindowToImageFilter& filter);
+void window_to_image_filter_update(vtkWindowToImageFilter& filter);
+
+// Output
+rust::Vec<uint8_t> window_to_image_filter_get_rgba(
+    vtkWindowToImageFilter& filter,
+    int& width,
+    int& height
+);
+vtkAlgorithmOutput* window_to_image_filter_get_output_port(vtkWindowToImageFilter& filter);
@@ -0,0 +1,25 @@
+#pragma once
+
+#include <vtkX3DExporter.h>
+#include <vtkRenderWindow.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkX3DExporter* vtk_x3d_exporter_new();
+void vtk_x3d_exporter_delete(vtkX3DExporter& exporter);
+
+// File name
+void x3d_exporter_set_file_name(vtkX3DExporter& exporter, rust::Str file_name);
+rust::String x3d_exporter_get_file_name(const vtkX3DExporter& exporter);
+
+// Input
+void x3d_exporter_set_render_window(vtkX3DExporter& exporter, vtkRenderWindow* window);
+
+// Options
+void x3d_exporter_set_binary(vtkX3DExporter& exporter, bool enabled);
+bool x3d_exporter_get_binary(const vtkX3DExporter& exporter);
+void x3d_exporter_set_fastest(vtkX3DExporter& exporter, bool enabled);
+bool x3d_exporter_get_fastest(const vtkX3DExporter& exporter);
+
+// Output
+void x3d_exporter_write(vtkX3DExporter& exporter);
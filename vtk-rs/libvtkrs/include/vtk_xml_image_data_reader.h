@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLImageDataReader.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLImageDataReader* vtk_xml_image_data_reader_new();
+void vtk_xml_image_data_reader_delete(vtkXMLImageDataReader& reader);
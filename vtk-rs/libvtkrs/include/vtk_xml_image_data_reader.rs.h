@@ -0,0 +1,23 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_xml_image_data_reader.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkXMLImageDataReader = ::vtkXMLImageDataReader;
+
+extern "C" {
+::vtkXMLImageDataReader *cxxbridge1$192$vtk_xml_image_data_reader_new() noexcept {
+  ::vtkXMLImageDataReader *(*vtk_xml_image_data_reader_new$)() = ::vtk_xml_image_data_reader_new;
+  return vtk_xml_image_data_reader_new$();
+}
+
+void cxxbridge1$192$vtk_xml_image_data_reader_delete(::vtkXMLImageDataReader &reader) noexcept {
+  void (*vtk_xml_image_data_reader_delete$)(::vtkXMLImageDataReader &) = ::vtk_xml_image_data_reader_delete;
+  vtk_xml_image_data_reader_delete$(reader);
+}
+} // extern "C"
\ No newline at end of file
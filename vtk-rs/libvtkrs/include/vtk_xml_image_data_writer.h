@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLImageDataWriter.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLImageDataWriter* vtk_xml_image_data_writer_new();
+void vtk_xml_image_data_writer_delete(vtkXMLImageDataWriter& writer);
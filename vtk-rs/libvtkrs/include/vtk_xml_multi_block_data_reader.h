@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLMultiBlockDataReader.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLMultiBlockDataReader* vtk_xml_multi_block_data_reader_new();
+void vtk_xml_multi_block_data_reader_delete(vtkXMLMultiBlockDataReader& reader);
@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLMultiBlockDataWriter.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLMultiBlockDataWriter* vtk_xml_multi_block_data_writer_new();
+void vtk_xml_multi_block_data_writer_delete(vtkXMLMultiBlockDataWriter& writer);
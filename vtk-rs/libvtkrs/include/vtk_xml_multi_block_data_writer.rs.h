@@ -0,0 +1,23 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_xml_multi_block_data_writer.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkXMLMultiBlockDataWriter = ::vtkXMLMultiBlockDataWriter;
+
+extern "C" {
+::vtkXMLMultiBlockDataWriter *cxxbridge1$192$vtk_xml_multi_block_data_writer_new() noexcept {
+  ::vtkXMLMultiBlockDataWriter *(*vtk_xml_multi_block_data_writer_new$)() = ::vtk_xml_multi_block_data_writer_new;
+  return vtk_xml_multi_block_data_writer_new$();
+}
+
+void cxxbridge1$192$vtk_xml_multi_block_data_writer_delete(::vtkXMLMultiBlockDataWriter &writer) noexcept {
+  void (*vtk_xml_multi_block_data_writer_delete$)(::vtkXMLMultiBlockDataWriter &) = ::vtk_xml_multi_block_data_writer_delete;
+  vtk_xml_multi_block_data_writer_delete$(writer);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLPartitionedDataSetCollectionReader.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLPartitionedDataSetCollectionReader* vtk_xml_partitioned_data_set_collection_reader_new();
+void vtk_xml_partitioned_data_set_collection_reader_delete(vtkXMLPartitionedDataSetCollectionReader& reader);
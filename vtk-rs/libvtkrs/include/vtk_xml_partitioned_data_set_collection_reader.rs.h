@@ -0,0 +1,23 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_xml_partitioned_data_set_collection_reader.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkXMLPartitionedDataSetCollectionReader = ::vtkXMLPartitionedDataSetCollectionReader;
+
+extern "C" {
+::vtkXMLPartitionedDataSetCollectionReader *cxxbridge1$192$vtk_xml_partitioned_data_set_collection_reader_new() noexcept {
+  ::vtkXMLPartitionedDataSetCollectionReader *(*vtk_xml_partitioned_data_set_collection_reader_new$)() = ::vtk_xml_partitioned_data_set_collection_reader_new;
+  return vtk_xml_partitioned_data_set_collection_reader_new$();
+}
+
+void cxxbridge1$192$vtk_xml_partitioned_data_set_collection_reader_delete(::vtkXMLPartitionedDataSetCollectionReader &reader) noexcept {
+  void (*vtk_xml_partitioned_data_set_collection_reader_delete$)(::vtkXMLPartitionedDataSetCollectionReader &) = ::vtk_xml_partitioned_data_set_collection_reader_delete;
+  vtk_xml_partitioned_data_set_collection_reader_delete$(reader);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLPartitionedDataSetCollectionWriter.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLPartitionedDataSetCollectionWriter* vtk_xml_partitioned_data_set_collection_writer_new();
+void vtk_xml_partitioned_data_set_collection_writer_delete(vtkXMLPartitionedDataSetCollectionWriter& writer);
@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLPolyDataReader.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLPolyDataReader* vtk_xml_poly_data_reader_new();
+void vtk_xml_poly_data_reader_delete(vtkXMLPolyDataReader& reader);
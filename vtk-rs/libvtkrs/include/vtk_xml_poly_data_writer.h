@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLPolyDataWriter.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLPolyDataWriter* vtk_xml_poly_data_writer_new();
+void vtk_xml_poly_data_writer_delete(vtkXMLPolyDataWriter& writer);
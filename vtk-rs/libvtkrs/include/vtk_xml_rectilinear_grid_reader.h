@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLRectilinearGridReader.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLRectilinearGridReader* vtk_xml_rectilinear_grid_reader_new();
+void vtk_xml_rectilinear_grid_reader_delete(vtkXMLRectilinearGridReader& reader);
@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLRectilinearGridWriter.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLRectilinearGridWriter* vtk_xml_rectilinear_grid_writer_new();
+void vtk_xml_rectilinear_grid_writer_delete(vtkXMLRectilinearGridWriter& writer);
@@ -0,0 +1,23 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_xml_rectilinear_grid_writer.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkXMLRectilinearGridWriter = ::vtkXMLRectilinearGridWriter;
+
+extern "C" {
+::vtkXMLRectilinearGridWriter *cxxbridge1$192$vtk_xml_rectilinear_grid_writer_new() noexcept {
+  ::vtkXMLRectilinearGridWriter *(*vtk_xml_rectilinear_grid_writer_new$)() = ::vtk_xml_rectilinear_grid_writer_new;
+  return vtk_xml_rectilinear_grid_writer_new$();
+}
+
+void cxxbridge1$192$vtk_xml_rectilinear_grid_writer_delete(::vtkXMLRectilinearGridWriter &writer) noexcept {
+  void (*vtk_xml_rectilinear_grid_writer_delete$)(::vtkXMLRectilinearGridWriter &) = ::vtk_xml_rectilinear_grid_writer_delete;
+  vtk_xml_rectilinear_grid_writer_delete$(writer);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLStructuredGridReader.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLStructuredGridReader* vtk_xml_structured_grid_reader_new();
+void vtk_xml_structured_grid_reader_delete(vtkXMLStructuredGridReader& reader);
@@ -0,0 +1,23 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_xml_structured_grid_reader.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkXMLStructuredGridReader = ::vtkXMLStructuredGridReader;
+
+extern "C" {
+::vtkXMLStructuredGridReader *cxxbridge1$192$vtk_xml_structured_grid_reader_new() noexcept {
+  ::vtkXMLStructuredGridReader *(*vtk_xml_structured_grid_reader_new$)() = ::vtk_xml_structured_grid_reader_new;
+  return vtk_xml_structured_grid_reader_new$();
+}
+
+void cxxbridge1$192$vtk_xml_structured_grid_reader_delete(::vtkXMLStructuredGridReader &reader) noexcept {
+  void (*vtk_xml_structured_grid_reader_delete$)(::vtkXMLStructuredGridReader &) = ::vtk_xml_structured_grid_reader_delete;
+  vtk_xml_structured_grid_reader_delete$(reader);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLStructuredGridWriter.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLStructuredGridWriter* vtk_xml_structured_grid_writer_new();
+void vtk_xml_structured_grid_writer_delete(vtkXMLStructuredGridWriter& writer);
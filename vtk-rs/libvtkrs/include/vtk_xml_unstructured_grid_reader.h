@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLUnstructuredGridReader.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLUnstructuredGridReader* vtk_xml_unstructured_grid_reader_new();
+void vtk_xml_unstructured_grid_reader_delete(vtkXMLUnstructuredGridReader& reader);
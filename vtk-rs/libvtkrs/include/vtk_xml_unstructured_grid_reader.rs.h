@@ -0,0 +1,23 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_xml_unstructured_grid_reader.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkXMLUnstructuredGridReader = ::vtkXMLUnstructuredGridReader;
+
+extern "C" {
+::vtkXMLUnstructuredGridReader *cxxbridge1$192$vtk_xml_unstructured_grid_reader_new() noexcept {
+  ::vtkXMLUnstructuredGridReader *(*vtk_xml_unstructured_grid_reader_new$)() = ::vtk_xml_unstructured_grid_reader_new;
+  return vtk_xml_unstructured_grid_reader_new$();
+}
+
+void cxxbridge1$192$vtk_xml_unstructured_grid_reader_delete(::vtkXMLUnstructuredGridReader &reader) noexcept {
+  void (*vtk_xml_unstructured_grid_reader_delete$)(::vtkXMLUnstructuredGridReader &) = ::vtk_xml_unstructured_grid_reader_delete;
+  vtk_xml_unstructured_grid_reader_delete$(reader);
+}
+} // extern "C"
\ No newline at end of file
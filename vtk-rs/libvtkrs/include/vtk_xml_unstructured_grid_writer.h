@@ -0,0 +1,8 @@
+#pragma once
+
+#include <vtkXMLUnstructuredGridWriter.h>
+#include "cxx.h"
+
+// Create/Delete
+vtkXMLUnstructuredGridWriter* vtk_xml_unstructured_grid_writer_new();
+void vtk_xml_unstructured_grid_writer_delete(vtkXMLUnstructuredGridWriter& writer);
@@ -0,0 +1,49 @@
+#pragma once
+
+#include <vtkXMLWriter.h>
+#include <vtkXMLReader.h>
+#include <vtkDataObject.h>
+#include <vtkAlgorithmOutput.h>
+#include "cxx.h"
+
+// Writers: file name
+void xml_writer_set_file_name(vtkXMLWriter& writer, rust::Str file_name);
+rust::String xml_writer_get_file_name(const vtkXMLWriter& writer);
+rust::String xml_writer_get_default_file_extension(vtkXMLWriter& writer);
+
+// Writers: encoding
+void xml_writer_set_data_mode(vtkXMLWriter& writer, int mode);
+int xml_writer_get_data_mode(const vtkXMLWriter& writer);
+void xml_writer_set_compressor_type(vtkXMLWriter& writer, int compressor);
+int xml_writer_get_compressor_type(const vtkXMLWriter& writer);
+void xml_writer_set_compression_level(vtkXMLWriter& writer, int level);
+int xml_writer_get_compression_level(const vtkXMLWriter& writer);
+void xml_writer_set_encode_appended_data(vtkXMLWriter& writer, bool encode);
+bool xml_writer_get_encode_appended_data(const vtkXMLWriter& writer);
+
+// Writers: output string
+void xml_writer_set_write_to_output_string(vtkXMLWriter& writer, bool enabled);
+rust::Vec<uint8_t> xml_writer_get_output_string(const vtkXMLWriter& writer);
+
+// Writers: input and output
+void xml_writer_set_input_connection(vtkXMLWriter& writer, vtkAlgorithmOutput* output);
+void xml_writer_set_input_data(vtkXMLWriter& writer, vtkDataObject* data);
+void xml_writer_write(vtkXMLWriter& writer);
+
+// Readers: file name
+void xml_reader_set_file_name(vtkXMLReader& reader, rust::Str file_name);
+rust::String xml_reader_get_file_name(const vtkXMLReader& reader);
+bool xml_reader_can_read_file(vtkXMLReader& reader, rust::Str file_name);
+
+// Readers: execution
+void xml_reader_update(vtkXMLReader& reader);
+
+// Readers: arrays
+int xml_reader_get_number_of_point_arrays(vtkXMLReader& reader);
+rust::String xml_reader_get_point_array_name(vtkXMLReader& reader, int index);
+int xml_reader_get_number_of_cell_arrays(vtkXMLReader& reader);
+rust::String xml_reader_get_cell_array_name(vtkXMLReader& reader, int index);
+
+// Readers: output
+vtkDataObject* xml_reader_get_output(vtkXMLReader& reader);
+vtkAlgorithmOutput* xml_reader_get_output_port(vtkXMLReader& reader);
@@ -70,6 +70,39 @@ bool cell_array_get_cell(void* cells, int64_t loc, int64_t* npts, int64_t** pts)
     return false;
 }
 
+int64_t cell_array_get_number_of_offsets(void* cells) {
+    if (!cells) return 0;
+    return static_cast<vtkCellArray*>(cells)->GetNumberOfOffsets();
+}
+
+// Copy the values of a 32 or 64 bit id array, widening them to 64 bit
+template <typename ArrayT>
+static void copy_ids(ArrayT* array, int64_t* ids) {
+    for (vtkIdType i = 0; i < array->GetNumberOfValues(); ++i) {
+        ids[i] = static_cast<int64_t>(array->GetValue(i));
+    }
+}
+
+void cell_array_copy_offsets(void* cells, int64_t* ids) {
+    if (!cells || !ids) return;
+    vtkCellArray* ca = static_cast<vtkCellArray*>(cells);
+    if (ca->IsStorage64Bit()) {
+        copy_ids(ca->GetOffsetsArray64(), ids);
+    } else {
+        copy_ids(ca->GetOffsetsArray32(), ids);
+    }
+}
+
+void cell_array_copy_connectivity(void* cells, int64_t* ids) {
+    if (!cells || !ids) return;
+    vtkCellArray* ca = static_cast<vtkCellArray*>(cells);
+    if (ca->IsStorage64Bit()) {
+        copy_ids(ca->GetConnectivityArray64(), ids);
+    } else {
+        copy_ids(ca->GetConnectivityArray32(), ids);
+    }
+}
+
 void cell_array_reset(void* cells) {
     if (!cells) return;
     static_cast<vtkCellArray*>(cells)->Reset();
@@ -1,20 +1,15 @@
 #include "vtk_cell_data.h"
-#include <vtkPolyData.h>
 #include <vtkCellData.h>
 #include <vtkDataArray.h>
+#include <vtkUnsignedCharArray.h>
 #include <iostream>
 #include <cstring>
 
 extern "C" {
 
-void* poly_data_get_cell_data(void* poly_data) {
-    if (!poly_data) return nullptr;
-    return static_cast<vtkPolyData*>(poly_data)->GetCellData();
-}
-
 void cell_data_add_array(void* cell_data, void* array) {
     if (!cell_data || !array) return;
-    static_cast<vtkCellData*>(cell_data)->AddArray(static_cast<vtkDataArray*>(array));
+    static_cast<vtkCellData*>(cell_data)->AddArray(static_cast<vtkAbstractArray*>(array));
 }
 
 void cell_data_remove_array(void* cell_data, const char* name) {
@@ -67,4 +62,39 @@ void cell_data_set_active_vectors(void* cell_data, const char* name) {
     static_cast<vtkCellData*>(cell_data)->SetActiveVectors(name);
 }
 
+void cell_data_set_colors(void* cell_data, const char* name, const uint8_t* rgba, int64_t num_tuples) {
+    if (!cell_data || !name || (!rgba && num_tuples > 0)) return;
+    vtkUnsignedCharArray* colors = vtkUnsignedCharArray::New();
+    colors->SetName(name);
+    colors->SetNumberOfComponents(4);
+    colors->SetNumberOfTuples(num_tuples);
+    std::memcpy(colors->GetPointer(0), rgba, static_cast<size_t>(num_tuples) * 4);
+    static_cast<vtkCellData*>(cell_data)->SetScalars(colors);
+    colors->Delete();
+}
+
+int64_t cell_data_get_number_of_colors(void* cell_data, const char* name) {
+    if (!cell_data || !name) return -1;
+    vtkUnsignedCharArray* colors =
+        vtkUnsignedCharArray::SafeDownCast(static_cast<vtkCellData*>(cell_data)->GetArray(name));
+    if (!colors || colors->GetNumberOfComponents() < 3 || colors->GetNumberOfComponents() > 4) {
+        return -1;
+    }
+    return static_cast<int64_t>(colors->GetNumberOfTuples());
+}
+
+void cell_data_copy_colors(void* cell_data, const char* name, uint8_t* rgba) {
+    if (cell_data_get_number_of_colors(cell_data, name) < 0 || !rgba) return;
+    vtkUnsignedCharArray* colors =
+        vtkUnsignedCharArray::SafeDownCast(static_cast<vtkCellData*>(cell_data)->GetArray(name));
+    int components = colors->GetNumberOfComponents();
+    vtkIdType num_tuples = colors->GetNumberOfTuples();
+    for (vtkIdType i = 0; i < num_tuples; ++i) {
+        for (int c = 0; c < 3; ++c) {
+            rgba[i * 4 + c] = colors->GetValue(i * components + c);
+        }
+        rgba[i * 4 + 3] = components == 4 ? colors->GetValue(i * components + 3) : 255;
+    }
+}
+
 } // extern "C"
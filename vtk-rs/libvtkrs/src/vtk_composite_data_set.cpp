@@ -0,0 +1,101 @@
+#include "vtk_composite_data_set.h"
+#include <vtkBoundingBox.h>
+#include <vtkCompositeDataIterator.h>
+#include <vtkCompositeDataSet.h>
+#include <vtkDataSet.h>
+#include <vtkImageData.h>
+#include <vtkMath.h>
+#include <vtkMultiBlockDataSet.h>
+#include <vtkPartitionedDataSet.h>
+#include <vtkPartitionedDataSetCollection.h>
+#include <vtkSmartPointer.h>
+
+int composite_block_type(vtkDataObject* block) {
+    if (!block) return -1;
+    if (vtkImageData::SafeDownCast(block)) return VTK_IMAGE_DATA;
+    switch (block->GetDataObjectType()) {
+        case VTK_POLY_DATA:
+        case VTK_STRUCTURED_GRID:
+        case VTK_RECTILINEAR_GRID:
+        case VTK_UNSTRUCTURED_GRID:
+        case VTK_MULTIBLOCK_DATA_SET:
+            return block->GetDataObjectType();
+        default:
+            return -1;
+    }
+}
+
+void* composite_block_register(vtkDataObject* block, int* block_type) {
+    *block_type = composite_block_type(block);
+    if (*block_type < 0) return nullptr;
+    // The Rust wrapper releases its own reference
+    block->Register(nullptr);
+    return block;
+}
+
+// Number of nodes in the tree below and including `node`, matching the flat indices
+// of vtkDataObjectTreeIterator with empty nodes and inner nodes visited
+static unsigned int subtree_size(vtkDataObject* node) {
+    unsigned int size = 1;
+    if (auto* blocks = vtkMultiBlockDataSet::SafeDownCast(node)) {
+        for (unsigned int i = 0; i < blocks->GetNumberOfBlocks(); ++i) {
+            size += subtree_size(blocks->GetBlock(i));
+        }
+    } else if (auto* collection = vtkPartitionedDataSetCollection::SafeDownCast(node)) {
+        for (unsigned int i = 0; i < collection->GetNumberOfPartitionedDataSets(); ++i) {
+            size += subtree_size(collection->GetPartitionedDataSet(i));
+        }
+    } else if (auto* partitions = vtkPartitionedDataSet::SafeDownCast(node)) {
+        size += partitions->GetNumberOfPartitions();
+    }
+    return size;
+}
+
+extern "C" {
+
+int64_t composite_data_set_get_number_of_points(void* composite) {
+    if (!composite) return 0;
+    return static_cast<int64_t>(static_cast<vtkCompositeDataSet*>(composite)->GetNumberOfPoints());
+}
+
+int64_t composite_data_set_get_number_of_cells(void* composite) {
+    if (!composite) return 0;
+    return static_cast<int64_t>(static_cast<vtkCompositeDataSet*>(composite)->GetNumberOfCells());
+}
+
+void composite_data_set_get_bounds(void* composite, double bounds[6]) {
+    if (!composite) return;
+    vtkBoundingBox box;
+    auto iter = vtkSmartPointer<vtkCompositeDataIterator>::Take(
+        static_cast<vtkCompositeDataSet*>(composite)->NewIterator());
+    for (iter->InitTraversal(); !iter->IsDoneWithTraversal(); iter->GoToNextItem()) {
+        if (auto* data_set = vtkDataSet::SafeDownCast(iter->GetCurrentDataObject())) {
+            if (data_set->GetNumberOfPoints() > 0) {
+                box.AddBounds(data_set->GetBounds());
+            }
+        }
+    }
+    if (box.IsValid()) {
+        box.GetBounds(bounds);
+    } else {
+        vtkMath::UninitializeBounds(bounds);
+    }
+}
+
+unsigned int composite_data_set_get_flat_index(void* composite, unsigned int index) {
+    if (!composite) return 0;
+    vtkDataObject* node = static_cast<vtkDataObject*>(composite);
+    unsigned int flat_index = 1;
+    if (auto* blocks = vtkMultiBlockDataSet::SafeDownCast(node)) {
+        for (unsigned int i = 0; i < index && i < blocks->GetNumberOfBlocks(); ++i) {
+            flat_index += subtree_size(blocks->GetBlock(i));
+        }
+    } else if (auto* collection = vtkPartitionedDataSetCollection::SafeDownCast(node)) {
+        for (unsigned int i = 0; i < index && i < collection->GetNumberOfPartitionedDataSets(); ++i) {
+            flat_index += subtree_size(collection->GetPartitionedDataSet(i));
+        }
+    }
+    return flat_index;
+}
+
+}
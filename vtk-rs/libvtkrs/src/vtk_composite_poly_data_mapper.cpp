@@ -0,0 +1,89 @@
+#include "vtk_composite_poly_data_mapper.h"
+#include "vtk_composite_poly_data_mapper.rs.h"
+
+#include <vtkCompositeDataDisplayAttributes.h>
+
+vtkCompositePolyDataMapper* vtk_composite_poly_data_mapper_new() {
+    vtkCompositePolyDataMapper* mapper = vtkCompositePolyDataMapper::New();
+    // Block attributes are stored in the display attributes, which are not created by default
+    vtkCompositeDataDisplayAttributes* attributes = vtkCompositeDataDisplayAttributes::New();
+    mapper->SetCompositeDataDisplayAttributes(attributes);
+    attributes->Delete();
+    return mapper;
+}
+
+void vtk_composite_poly_data_mapper_delete(vtkCompositePolyDataMapper& mapper) {
+    mapper.Delete();
+}
+
+void composite_poly_data_mapper_set_input_connection(
+    vtkCompositePolyDataMapper& mapper,
+    vtkAlgorithmOutput* output) {
+    if (output) {
+        mapper.SetInputConnection(output);
+    }
+}
+
+void composite_poly_data_mapper_set_input_data(
+    vtkCompositePolyDataMapper& mapper,
+    vtkDataObject* composite) {
+    mapper.SetInputDataObject(composite);
+}
+
+// Per-block attributes
+void composite_poly_data_mapper_set_block_color(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index,
+    double r,
+    double g,
+    double b) {
+    double color[3] = {r, g, b};
+    mapper.SetBlockColor(flat_index, color);
+}
+
+void composite_poly_data_mapper_remove_block_color(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index) {
+    mapper.RemoveBlockColor(flat_index);
+}
+
+void composite_poly_data_mapper_remove_block_colors(vtkCompositePolyDataMapper& mapper) {
+    mapper.RemoveBlockColors();
+}
+
+void composite_poly_data_mapper_set_block_visibility(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index,
+    bool visible) {
+    mapper.SetBlockVisibility(flat_index, visible);
+}
+
+bool composite_poly_data_mapper_get_block_visibility(
+    const vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index) {
+    return const_cast<vtkCompositePolyDataMapper&>(mapper).GetBlockVisibility(flat_index);
+}
+
+void composite_poly_data_mapper_set_block_opacity(
+    vtkCompositePolyDataMapper& mapper,
+    unsigned int flat_index,
+    double opacity) {
+    mapper.SetBlockOpacity(flat_index, opacity);
+}
+
+void composite_poly_data_mapper_remove_block_attributes(vtkCompositePolyDataMapper& mapper) {
+    mapper.RemoveBlockColors();
+    mapper.RemoveBlockVisibilities();
+    mapper.RemoveBlockOpacities();
+}
+
+// Coloring
+void composite_poly_data_mapper_set_scalar_visibility(
+    vtkCompositePolyDataMapper& mapper,
+    bool visible) {
+    mapper.SetScalarVisibility(visible);
+}
+
+bool composite_poly_data_mapper_get_scalar_visibility(const vtkCompositePolyDataMapper& mapper) {
+    return const_cast<vtkCompositePolyDataMapper&>(mapper).GetScalarVisibility() != 0;
+}
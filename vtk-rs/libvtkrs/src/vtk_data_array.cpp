@@ -0,0 +1,152 @@
+#include "vtk_data_array.h"
+#include <vtkAOSDataArrayTemplate.h>
+#include <vtkDataArray.h>
+#include <vtkType.h>
+
+namespace {
+
+// Run `body` with `Array` bound to the typed array class of `array`.
+// DataArray<T> only creates the fixed width types, all of which are vtkAOSDataArrayTemplate.
+#define DATA_ARRAY_DISPATCH(array, body)                                                \
+    switch (static_cast<vtkDataArray*>(array)->GetDataType()) {                         \
+        vtkTemplateMacro({                                                              \
+            using Array = vtkAOSDataArrayTemplate<VTK_TT>;                              \
+            using Value = VTK_TT;                                                       \
+            Array* typed = Array::FastDownCast(static_cast<vtkDataArray*>(array));      \
+            if (typed) { body; }                                                        \
+        });                                                                             \
+    }
+
+vtkDataArray* as_data_array(void* array) {
+    return static_cast<vtkDataArray*>(array);
+}
+
+} // namespace
+
+extern "C" {
+
+void* data_array_new(int32_t data_type) {
+    return vtkDataArray::CreateDataArray(data_type);
+}
+
+void data_array_delete(void* array) {
+    if (array) {
+        as_data_array(array)->Delete();
+    }
+}
+
+int32_t data_array_get_data_type(void* array) {
+    if (!array) return -1;
+    return as_data_array(array)->GetDataType();
+}
+
+void data_array_set_number_of_components(void* array, int64_t num_components) {
+    if (!array) return;
+    as_data_array(array)->SetNumberOfComponents(static_cast<int>(num_components));
+}
+
+int64_t data_array_get_number_of_components(void* array) {
+    if (!array) return 0;
+    return static_cast<int64_t>(as_data_array(array)->GetNumberOfComponents());
+}
+
+void data_array_set_number_of_tuples(void* array, int64_t num_tuples) {
+    if (!array) return;
+    as_data_array(array)->SetNumberOfTuples(static_cast<vtkIdType>(num_tuples));
+}
+
+int64_t data_array_get_number_of_tuples(void* array) {
+    if (!array) return 0;
+    return static_cast<int64_t>(as_data_array(array)->GetNumberOfTuples());
+}
+
+int64_t data_array_get_number_of_values(void* array) {
+    if (!array) return 0;
+    return static_cast<int64_t>(as_data_array(array)->GetNumberOfValues());
+}
+
+void data_array_set_name(void* array, const char* name) {
+    if (!array || !name) return;
+    as_data_array(array)->SetName(name);
+}
+
+const char* data_array_get_name(void* array) {
+    if (!array) return nullptr;
+    return as_data_array(array)->GetName();
+}
+
+int64_t data_array_insert_next_value(void* array, const void* value) {
+    if (!array || !value) return -1;
+    vtkIdType id = -1;
+    DATA_ARRAY_DISPATCH(array, id = typed->InsertNextValue(*static_cast<const Value*>(value)));
+    return static_cast<int64_t>(id);
+}
+
+int64_t data_array_insert_next_tuple(void* array, const void* tuple) {
+    if (!array || !tuple) return -1;
+    vtkIdType id = -1;
+    DATA_ARRAY_DISPATCH(array, id = typed->InsertNextTypedTuple(static_cast<const Value*>(tuple)));
+    return static_cast<int64_t>(id);
+}
+
+void data_array_set_value(void* array, int64_t id, const void* value) {
+    if (!array || !value) return;
+    DATA_ARRAY_DISPATCH(array,
+        typed->SetValue(static_cast<vtkIdType>(id), *static_cast<const Value*>(value)));
+}
+
+void data_array_set_tuple(void* array, int64_t id, const void* tuple) {
+    if (!array || !tuple) return;
+    DATA_ARRAY_DISPATCH(array,
+        typed->SetTypedTuple(static_cast<vtkIdType>(id), static_cast<const Value*>(tuple)));
+}
+
+void data_array_get_value(void* array, int64_t id, void* value) {
+    if (!array || !value) return;
+    DATA_ARRAY_DISPATCH(array,
+        *static_cast<Value*>(value) = typed->GetValue(static_cast<vtkIdType>(id)));
+}
+
+void data_array_get_tuple(void* array, int64_t id, void* tuple) {
+    if (!array || !tuple) return;
+    DATA_ARRAY_DISPATCH(array,
+        typed->GetTypedTuple(static_cast<vtkIdType>(id), static_cast<Value*>(tuple)));
+}
+
+void data_array_get_range(void* array, int32_t component, double* range) {
+    if (!array || !range) return;
+    as_data_array(array)->GetRange(range, component);
+}
+
+void data_array_initialize(void* array) {
+    if (!array) return;
+    as_data_array(array)->Initialize();
+}
+
+void data_array_squeeze(void* array) {
+    if (!array) return;
+    as_data_array(array)->Squeeze();
+}
+
+void data_array_modified(void* array) {
+    if (!array) return;
+    as_data_array(array)->DataChanged();
+    as_data_array(array)->Modified();
+}
+
+void* data_array_get_void_pointer(void* array) {
+    if (!array) return nullptr;
+    return as_data_array(array)->GetVoidPointer(0);
+}
+
+void data_array_adopt(void* array, void* values, int64_t num_values, void (*free_function)(void*)) {
+    if (!array || !values) return;
+    // The buffer belongs to Rust, so VTK must release it with the given function
+    DATA_ARRAY_DISPATCH(array, {
+        typed->SetArray(static_cast<Value*>(values), static_cast<vtkIdType>(num_values), 0,
+            vtkAbstractArray::VTK_DATA_ARRAY_USER_DEFINED);
+        typed->SetArrayFreeFunction(free_function);
+    });
+}
+
+} // extern "C"
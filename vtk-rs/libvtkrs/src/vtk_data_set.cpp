@@ -0,0 +1,91 @@
+#include "vtk_data_set.h"
+#include <vtkDataSet.h>
+#include <vtkPointData.h>
+#include <vtkCellData.h>
+#include <vtkIdList.h>
+#include <vtkNew.h>
+
+#include <algorithm>
+
+extern "C" {
+
+int64_t data_set_get_number_of_points(void* data_set) {
+    if (!data_set) return 0;
+    return static_cast<int64_t>(static_cast<vtkDataSet*>(data_set)->GetNumberOfPoints());
+}
+
+int64_t data_set_get_number_of_cells(void* data_set) {
+    if (!data_set) return 0;
+    return static_cast<int64_t>(static_cast<vtkDataSet*>(data_set)->GetNumberOfCells());
+}
+
+bool data_set_get_point(void* data_set, int64_t point_id, double point[3]) {
+    if (!data_set) return false;
+    vtkDataSet* ds = static_cast<vtkDataSet*>(data_set);
+    if (point_id < 0 || point_id >= ds->GetNumberOfPoints()) return false;
+    ds->GetPoint(static_cast<vtkIdType>(point_id), point);
+    return true;
+}
+
+void data_set_get_bounds(void* data_set, double bounds[6]) {
+    if (!data_set) return;
+    static_cast<vtkDataSet*>(data_set)->GetBounds(bounds);
+}
+
+int64_t data_set_find_point(void* data_set, double x, double y, double z) {
+    if (!data_set) return -1;
+    vtkDataSet* ds = static_cast<vtkDataSet*>(data_set);
+    if (ds->GetNumberOfPoints() == 0) return -1;
+    return static_cast<int64_t>(ds->FindPoint(x, y, z));
+}
+
+int data_set_get_cell_type(void* data_set, int64_t cell_id) {
+    if (!data_set) return -1;
+    vtkDataSet* ds = static_cast<vtkDataSet*>(data_set);
+    if (cell_id < 0 || cell_id >= ds->GetNumberOfCells()) return -1;
+    return ds->GetCellType(static_cast<vtkIdType>(cell_id));
+}
+
+int64_t data_set_get_cell_size(void* data_set, int64_t cell_id) {
+    if (!data_set) return 0;
+    vtkDataSet* ds = static_cast<vtkDataSet*>(data_set);
+    if (cell_id < 0 || cell_id >= ds->GetNumberOfCells()) return 0;
+    return static_cast<int64_t>(ds->GetCellSize(static_cast<vtkIdType>(cell_id)));
+}
+
+int64_t data_set_get_cell_points(
+    void* data_set, int64_t cell_id, int64_t* point_ids, int64_t capacity
+) {
+    if (!data_set || !point_ids || capacity <= 0) return 0;
+    vtkDataSet* ds = static_cast<vtkDataSet*>(data_set);
+    if (cell_id < 0 || cell_id >= ds->GetNumberOfCells()) return 0;
+    vtkNew<vtkIdList> ids;
+    ds->GetCellPoints(static_cast<vtkIdType>(cell_id), ids);
+    vtkIdType count = std::min(ids->GetNumberOfIds(), static_cast<vtkIdType>(capacity));
+    for (vtkIdType i = 0; i < count; ++i) {
+        point_ids[i] = static_cast<int64_t>(ids->GetId(i));
+    }
+    return static_cast<int64_t>(count);
+}
+
+void* data_set_get_point_data(void* data_set) {
+    if (!data_set) return nullptr;
+    return static_cast<vtkDataSet*>(data_set)->GetPointData();
+}
+
+void* data_set_get_cell_data(void* data_set) {
+    if (!data_set) return nullptr;
+    return static_cast<vtkDataSet*>(data_set)->GetCellData();
+}
+
+void data_set_deep_copy(void* target, void* source) {
+    if (!target || !source) return;
+    static_cast<vtkDataSet*>(target)->DeepCopy(static_cast<vtkDataSet*>(source));
+}
+
+void data_set_shallow_copy(void* target, void* source) {
+    if (!target || !source) return;
+    static_cast<vtkDataSet*>(target)->ShallowCopy(static_cast<vtkDataSet*>(source));
+}
+
+} // extern "C"
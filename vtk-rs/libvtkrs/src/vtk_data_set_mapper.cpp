@@ -1,6 +1,8 @@
 #include "vtk_data_set_mapper.h"
 #include "vtk_data_set_mapper.rs.h"
 
+#include <string>
+
 vtkDataSetMapper* vtk_data_set_mapper_new() {
     return vtkDataSetMapper::New();
 }
@@ -18,3 +20,30 @@ void data_set_mapper_set_input_connection(vtkDataSetMapper& mapper, vtkAlgorithm
 void data_set_mapper_set_input_data(vtkDataSetMapper& mapper, vtkDataSet* data_set) {
     mapper.SetInputData(data_set);
 }
+
+// Coloring
+void data_set_mapper_set_lookup_table(vtkDataSetMapper& mapper, vtkLookupTable& lut) {
+    mapper.SetLookupTable(&lut);
+}
+
+void data_set_mapper_set_scalar_range(vtkDataSetMapper& mapper, double min, double max) {
+    mapper.SetScalarRange(min, max);
+}
+
+void data_set_mapper_set_scalar_visibility(vtkDataSetMapper& mapper, bool visible) {
+    mapper.SetScalarVisibility(visible);
+}
+
+bool data_set_mapper_get_scalar_visibility(const vtkDataSetMapper& mapper) {
+    return const_cast<vtkDataSetMapper&>(mapper).GetScalarVisibility() != 0;
+}
+
+void data_set_mapper_color_by_array(vtkDataSetMapper& mapper, rust::Str name, bool cell_data) {
+    if (cell_data) {
+        mapper.SetScalarModeToUseCellFieldData();
+    } else {
+        mapper.SetScalarModeToUsePointFieldData();
+    }
+    mapper.SelectColorArray(std::string(name).c_str());
+    mapper.ScalarVisibilityOn();
+}
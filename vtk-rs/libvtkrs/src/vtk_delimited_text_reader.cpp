@@ -0,0 +1,108 @@
+#include "cxx.h"
+#include "vtk_delimited_text_reader.h"
+#include "vtk_delimited_text_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkDelimitedTextReader.h>
+#include <vtkTable.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkDelimitedTextReader* vtk_delimited_text_reader_new() {
+    vtkDelimitedTextReader* obj = vtkDelimitedTextReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkDelimitedTextReader");
+    }
+    return obj;
+}
+
+void vtk_delimited_text_reader_delete(vtkDelimitedTextReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void delimited_text_reader_set_file_name(vtkDelimitedTextReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String delimited_text_reader_get_file_name(const vtkDelimitedTextReader& reader) {
+    const char* name = const_cast<vtkDelimitedTextReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Parsing
+void delimited_text_reader_set_field_delimiter_characters(vtkDelimitedTextReader& reader, rust::Str delimiters) {
+    reader.SetFieldDelimiterCharacters(std::string(delimiters).c_str());
+}
+
+rust::String delimited_text_reader_get_field_delimiter_characters(const vtkDelimitedTextReader& reader) {
+    const char* delimiters = const_cast<vtkDelimitedTextReader&>(reader).GetFieldDelimiterCharacters();
+    return rust::String(delimiters ? delimiters : "");
+}
+
+void delimited_text_reader_set_add_tab_field_delimiter(vtkDelimitedTextReader& reader, bool enabled) {
+    reader.SetAddTabFieldDelimiter(enabled);
+}
+
+void delimited_text_reader_set_merge_consecutive_delimiters(vtkDelimitedTextReader& reader, bool enabled) {
+    reader.SetMergeConsecutiveDelimiters(enabled);
+}
+
+void delimited_text_reader_set_string_delimiter(vtkDelimitedTextReader& reader, uint8_t delimiter) {
+    reader.SetStringDelimiter(static_cast<char>(delimiter));
+}
+
+void delimited_text_reader_set_use_string_delimiter(vtkDelimitedTextReader& reader, bool enabled) {
+    reader.SetUseStringDelimiter(enabled);
+}
+
+void delimited_text_reader_set_have_headers(vtkDelimitedTextReader& reader, bool enabled) {
+    reader.SetHaveHeaders(enabled);
+}
+
+bool delimited_text_reader_get_have_headers(const vtkDelimitedTextReader& reader) {
+    return const_cast<vtkDelimitedTextReader&>(reader).GetHaveHeaders();
+}
+
+void delimited_text_reader_set_detect_numeric_columns(vtkDelimitedTextReader& reader, bool enabled) {
+    reader.SetDetectNumericColumns(enabled);
+}
+
+bool delimited_text_reader_get_detect_numeric_columns(const vtkDelimitedTextReader& reader) {
+    return const_cast<vtkDelimitedTextReader&>(reader).GetDetectNumericColumns();
+}
+
+void delimited_text_reader_set_force_double(vtkDelimitedTextReader& reader, bool enabled) {
+    reader.SetForceDouble(enabled);
+}
+
+void delimited_text_reader_set_max_records(vtkDelimitedTextReader& reader, int64_t max_records) {
+    reader.SetMaxRecords(static_cast<vtkIdType>(max_records));
+}
+
+// Execution
+void delimited_text_reader_update(vtkDelimitedTextReader& reader) {
+    // The reader has no error code, failures are only reported as error events
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkTable* delimited_text_reader_get_output(vtkDelimitedTextReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkTable* copy = vtkTable::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* delimited_text_reader_get_output_port(vtkDelimitedTextReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,107 @@
+#include "cxx.h"
+#include "vtk_dicom_image_reader.h"
+#include "vtk_dicom_image_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkDICOMImageReader.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkDICOMImageReader* vtk_dicom_image_reader_new() {
+    vtkDICOMImageReader* obj = vtkDICOMImageReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkDICOMImageReader");
+    }
+    return obj;
+}
+
+void vtk_dicom_image_reader_delete(vtkDICOMImageReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void dicom_image_reader_set_file_name(vtkDICOMImageReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String dicom_image_reader_get_file_name(const vtkDICOMImageReader& reader) {
+    const char* name = const_cast<vtkDICOMImageReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool dicom_image_reader_can_read_file(vtkDICOMImageReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Series directory
+void dicom_image_reader_set_directory_name(vtkDICOMImageReader& reader, rust::Str directory_name) {
+    reader.SetDirectoryName(std::string(directory_name).c_str());
+}
+
+rust::String dicom_image_reader_get_directory_name(const vtkDICOMImageReader& reader) {
+    const char* name = const_cast<vtkDICOMImageReader&>(reader).GetDirectoryName();
+    return rust::String(name ? name : "");
+}
+
+// Header information
+static rust::String to_string(const char* value) {
+    return rust::String(value ? value : "");
+}
+
+rust::String dicom_image_reader_get_patient_name(vtkDICOMImageReader& reader) {
+    return to_string(reader.GetPatientName());
+}
+
+rust::String dicom_image_reader_get_study_uid(vtkDICOMImageReader& reader) {
+    return to_string(reader.GetStudyUID());
+}
+
+rust::String dicom_image_reader_get_study_id(vtkDICOMImageReader& reader) {
+    return to_string(reader.GetStudyID());
+}
+
+float dicom_image_reader_get_rescale_slope(vtkDICOMImageReader& reader) {
+    return reader.GetRescaleSlope();
+}
+
+float dicom_image_reader_get_rescale_offset(vtkDICOMImageReader& reader) {
+    return reader.GetRescaleOffset();
+}
+
+void dicom_image_reader_get_pixel_spacing(vtkDICOMImageReader& reader, double& x, double& y, double& z) {
+    double* spacing = reader.GetPixelSpacing();
+    x = spacing[0];
+    y = spacing[1];
+    z = spacing[2];
+}
+
+// Execution
+void dicom_image_reader_update(vtkDICOMImageReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkImageData* dicom_image_reader_get_output(vtkDICOMImageReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkImageData* copy = vtkImageData::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* dicom_image_reader_get_output_port(vtkDICOMImageReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,276 @@
+#include "cxx.h"
+#include "vtk_exodus_ii_reader.h"
+#include "vtk_exodus_ii_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkExodusIIReader.h>
+#include <vtkCompositeDataSet.h>
+#include <vtkInformation.h>
+#include <vtkMultiBlockDataSet.h>
+#include <vtkNew.h>
+#include <vtkStreamingDemandDrivenPipeline.h>
+#include <algorithm>
+#include <cstring>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+// Create/Delete
+vtkExodusIIReader* vtk_exodus_ii_reader_new() {
+    vtkExodusIIReader* obj = vtkExodusIIReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkExodusIIReader");
+    }
+    return obj;
+}
+
+void vtk_exodus_ii_reader_delete(vtkExodusIIReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void exodus_ii_reader_set_file_name(vtkExodusIIReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String exodus_ii_reader_get_file_name(const vtkExodusIIReader& reader) {
+    const char* name = const_cast<vtkExodusIIReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool exodus_ii_reader_can_read_file(vtkExodusIIReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Execution
+void exodus_ii_reader_update_information(vtkExodusIIReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    int success = reader.UpdateInformation();
+    reader.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+    if (!success) {
+        throw std::runtime_error("vtkExodusIIReader failed to read the file metadata");
+    }
+}
+
+void exodus_ii_reader_update(vtkExodusIIReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+
+    // Request the time of the selected step explicitly, as the pipeline would
+    // otherwise keep the time of an earlier request
+    int success = reader.UpdateInformation();
+    vtkInformation* info = reader.GetOutputInformation(0);
+    int count = 0;
+    if (success && info && info->Has(vtkStreamingDemandDrivenPipeline::TIME_STEPS())) {
+        count = info->Length(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+    }
+    if (success && count > 0) {
+        double* times = info->Get(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+        int step = std::min(std::max(reader.GetTimeStep(), 0), count - 1);
+        success = reader.UpdateTimeStep(times[step]);
+    } else if (success) {
+        success = reader.Update(0);
+    }
+    reader.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+    if (!success) {
+        throw std::runtime_error("vtkExodusIIReader failed to read the file");
+    }
+}
+
+// Time steps
+rust::Vec<double> exodus_ii_reader_get_time_values(vtkExodusIIReader& reader) {
+    rust::Vec<double> values;
+    reader.UpdateInformation();
+    vtkInformation* info = reader.GetOutputInformation(0);
+    if (info && info->Has(vtkStreamingDemandDrivenPipeline::TIME_STEPS())) {
+        int count = info->Length(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+        double* times = info->Get(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+        for (int i = 0; i < count; ++i) {
+            values.push_back(times[i]);
+        }
+    }
+    return values;
+}
+
+void exodus_ii_reader_set_time_step(vtkExodusIIReader& reader, int step) {
+    reader.SetTimeStep(step);
+}
+
+int exodus_ii_reader_get_time_step(const vtkExodusIIReader& reader) {
+    return const_cast<vtkExodusIIReader&>(reader).GetTimeStep();
+}
+
+// Objects
+int exodus_ii_reader_get_number_of_objects(vtkExodusIIReader& reader, int object_type) {
+    return reader.GetNumberOfObjects(object_type);
+}
+
+rust::String exodus_ii_reader_get_object_name(vtkExodusIIReader& reader, int object_type, int index) {
+    const char* name = reader.GetObjectName(object_type, index);
+    return rust::String(name ? name : "");
+}
+
+int exodus_ii_reader_get_object_id(vtkExodusIIReader& reader, int object_type, int index) {
+    return reader.GetObjectId(object_type, index);
+}
+
+bool exodus_ii_reader_get_object_status(vtkExodusIIReader& reader, int object_type, int index) {
+    return reader.GetObjectStatus(object_type, index) != 0;
+}
+
+void exodus_ii_reader_set_object_status(vtkExodusIIReader& reader, int object_type, int index, bool enabled) {
+    reader.SetObjectStatus(object_type, index, enabled ? 1 : 0);
+}
+
+// Result variables
+int exodus_ii_reader_get_number_of_object_arrays(vtkExodusIIReader& reader, int object_type) {
+    return reader.GetNumberOfObjectArrays(object_type);
+}
+
+rust::String exodus_ii_reader_get_object_array_name(vtkExodusIIReader& reader, int object_type, int index) {
+    const char* name = reader.GetObjectArrayName(object_type, index);
+    return rust::String(name ? name : "");
+}
+
+bool exodus_ii_reader_get_object_array_status(vtkExodusIIReader& reader, int object_type, int index) {
+    return reader.GetObjectArrayStatus(object_type, index) != 0;
+}
+
+void exodus_ii_reader_set_object_array_status(vtkExodusIIReader& reader, int object_type, rust::Str name, bool enabled) {
+    reader.SetObjectArrayStatus(object_type, std::string(name).c_str(), enabled ? 1 : 0);
+}
+
+void exodus_ii_reader_set_all_array_status(vtkExodusIIReader& reader, int object_type, bool enabled) {
+    reader.SetAllArrayStatus(object_type, enabled ? 1 : 0);
+}
+
+// Options
+void exodus_ii_reader_set_apply_displacements(vtkExodusIIReader& reader, bool enabled) {
+    reader.SetApplyDisplacements(enabled ? 1 : 0);
+}
+
+bool exodus_ii_reader_get_apply_displacements(const vtkExodusIIReader& reader) {
+    return const_cast<vtkExodusIIReader&>(reader).GetApplyDisplacements() != 0;
+}
+
+void exodus_ii_reader_set_displacement_magnitude(vtkExodusIIReader& reader, float magnitude) {
+    reader.SetDisplacementMagnitude(magnitude);
+}
+
+float exodus_ii_reader_get_displacement_magnitude(const vtkExodusIIReader& reader) {
+    return const_cast<vtkExodusIIReader&>(reader).GetDisplacementMagnitude();
+}
+
+void exodus_ii_reader_set_generate_object_id_cell_array(vtkExodusIIReader& reader, bool enabled) {
+    reader.SetGenerateObjectIdCellArray(enabled ? 1 : 0);
+}
+
+void exodus_ii_reader_set_generate_global_node_id_array(vtkExodusIIReader& reader, bool enabled) {
+    reader.SetGenerateGlobalNodeIdArray(enabled ? 1 : 0);
+}
+
+void exodus_ii_reader_set_generate_global_element_id_array(vtkExodusIIReader& reader, bool enabled) {
+    reader.SetGenerateGlobalElementIdArray(enabled ? 1 : 0);
+}
+
+// Output
+// The output has one child multiblock per kind of object, named after the kind,
+// whose children are the blocks and sets. Disabled objects leave empty slots.
+struct ExodusLeaf {
+    vtkUnstructuredGrid* grid;
+    std::string name;
+    int object_type;
+};
+
+static int object_type_of_group(const char* name) {
+    static const struct { const char* name; int type; } groups[] = {
+        { "Element Blocks", vtkExodusIIReader::ELEM_BLOCK },
+        { "Face Blocks", vtkExodusIIReader::FACE_BLOCK },
+        { "Edge Blocks", vtkExodusIIReader::EDGE_BLOCK },
+        { "Element Sets", vtkExodusIIReader::ELEM_SET },
+        { "Side Sets", vtkExodusIIReader::SIDE_SET },
+        { "Face Sets", vtkExodusIIReader::FACE_SET },
+        { "Edge Sets", vtkExodusIIReader::EDGE_SET },
+        { "Node Sets", vtkExodusIIReader::NODE_SET },
+    };
+    for (const auto& group : groups) {
+        if (name && std::strcmp(name, group.name) == 0) {
+            return group.type;
+        }
+    }
+    return -1;
+}
+
+static const char* block_name(vtkMultiBlockDataSet* blocks, unsigned int index) {
+    if (!blocks->HasMetaData(index)) {
+        return nullptr;
+    }
+    return blocks->GetMetaData(index)->Get(vtkCompositeDataSet::NAME());
+}
+
+static std::vector<ExodusLeaf> output_leaves(vtkExodusIIReader& reader) {
+    std::vector<ExodusLeaf> leaves;
+    vtkMultiBlockDataSet* output = reader.GetOutput();
+    if (!output) {
+        return leaves;
+    }
+    for (unsigned int i = 0; i < output->GetNumberOfBlocks(); ++i) {
+        auto* group = vtkMultiBlockDataSet::SafeDownCast(output->GetBlock(i));
+        int object_type = object_type_of_group(block_name(output, i));
+        if (!group || object_type < 0) {
+            continue;
+        }
+        for (unsigned int j = 0; j < group->GetNumberOfBlocks(); ++j) {
+            auto* grid = vtkUnstructuredGrid::SafeDownCast(group->GetBlock(j));
+            if (grid) {
+                const char* name = block_name(group, j);
+                leaves.push_back({ grid, name ? name : "", object_type });
+            }
+        }
+    }
+    return leaves;
+}
+
+int exodus_ii_reader_get_number_of_output_blocks(vtkExodusIIReader& reader) {
+    return static_cast<int>(output_leaves(reader).size());
+}
+
+vtkUnstructuredGrid* exodus_ii_reader_get_output_block(vtkExodusIIReader& reader, int index) {
+    std::vector<ExodusLeaf> leaves = output_leaves(reader);
+    if (index < 0 || index >= static_cast<int>(leaves.size())) {
+        return nullptr;
+    }
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkUnstructuredGrid* copy = vtkUnstructuredGrid::New();
+    copy->ShallowCopy(leaves[index].grid);
+    return copy;
+}
+
+rust::String exodus_ii_reader_get_output_block_name(vtkExodusIIReader& reader, int index) {
+    std::vector<ExodusLeaf> leaves = output_leaves(reader);
+    if (index < 0 || index >= static_cast<int>(leaves.size())) {
+        return rust::String();
+    }
+    return rust::String(leaves[index].name);
+}
+
+int exodus_ii_reader_get_output_block_type(vtkExodusIIReader& reader, int index) {
+    std::vector<ExodusLeaf> leaves = output_leaves(reader);
+    if (index < 0 || index >= static_cast<int>(leaves.size())) {
+        return -1;
+    }
+    return leaves[index].object_type;
+}
+
+vtkAlgorithmOutput* exodus_ii_reader_get_output_port(vtkExodusIIReader& reader) {
+    return reader.GetOutputPort();
+}
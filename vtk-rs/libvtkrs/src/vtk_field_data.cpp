@@ -0,0 +1,73 @@
+#include "vtk_field_data.h"
+#include <vtkDataObject.h>
+#include <vtkFieldData.h>
+#include <vtkAbstractArray.h>
+#include <vtkDoubleArray.h>
+#include <vtkIntArray.h>
+#include <vtkStringArray.h>
+
+namespace {
+
+// The Rust wrapper releases its own reference when dropped
+template <typename ArrayT>
+ArrayT* get_registered(void* field_data, const char* name) {
+    if (!field_data || !name) return nullptr;
+    ArrayT* array =
+        ArrayT::SafeDownCast(static_cast<vtkFieldData*>(field_data)->GetAbstractArray(name));
+    if (array) array->Register(nullptr);
+    return array;
+}
+
+} // namespace
+
+extern "C" {
+
+void* data_object_get_field_data(void* data_object) {
+    if (!data_object) return nullptr;
+    vtkDataObject* object = static_cast<vtkDataObject*>(data_object);
+    if (!object->GetFieldData()) {
+        vtkFieldData* field_data = vtkFieldData::New();
+        object->SetFieldData(field_data);
+        field_data->Delete();
+    }
+    return object->GetFieldData();
+}
+
+void field_data_add_array(void* field_data, void* array) {
+    if (!field_data || !array) return;
+    static_cast<vtkFieldData*>(field_data)->AddArray(static_cast<vtkAbstractArray*>(array));
+}
+
+void field_data_remove_array(void* field_data, const char* name) {
+    if (!field_data || !name) return;
+    static_cast<vtkFieldData*>(field_data)->RemoveArray(name);
+}
+
+bool field_data_has_array(void* field_data, const char* name) {
+    if (!field_data || !name) return false;
+    return static_cast<vtkFieldData*>(field_data)->HasArray(name) != 0;
+}
+
+int64_t field_data_get_number_of_arrays(void* field_data) {
+    if (!field_data) return 0;
+    return static_cast<int64_t>(static_cast<vtkFieldData*>(field_data)->GetNumberOfArrays());
+}
+
+const char* field_data_get_array_name(void* field_data, int64_t index) {
+    if (!field_data) return nullptr;
+    return static_cast<vtkFieldData*>(field_data)->GetArrayName(static_cast<int>(index));
+}
+
+void* field_data_get_double_array(void* field_data, const char* name) {
+    return get_registered<vtkDoubleArray>(field_data, name);
+}
+
+void* field_data_get_int_array(void* field_data, const char* name) {
+    return get_registered<vtkIntArray>(field_data, name);
+}
+
+void* field_data_get_string_array(void* field_data, const char* name) {
+    return get_registered<vtkStringArray>(field_data, name);
+}
+
+} // extern "C"
@@ -0,0 +1,77 @@
+#include "cxx.h"
+#include "vtk_gltf_exporter.h"
+#include "vtk_gltf_exporter.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkGLTFExporter.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkGLTFExporter* vtk_gltf_exporter_new() {
+    vtkGLTFExporter* obj = vtkGLTFExporter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkGLTFExporter");
+    }
+    return obj;
+}
+
+void vtk_gltf_exporter_delete(vtkGLTFExporter& exporter) {
+    exporter.Delete();
+}
+
+// File name
+void gltf_exporter_set_file_name(vtkGLTFExporter& exporter, rust::Str file_name) {
+    exporter.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String gltf_exporter_get_file_name(const vtkGLTFExporter& exporter) {
+    const char* name = const_cast<vtkGLTFExporter&>(exporter).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Input
+void gltf_exporter_set_render_window(vtkGLTFExporter& exporter, vtkRenderWindow* window) {
+    exporter.SetRenderWindow(window);
+}
+
+// Options
+void gltf_exporter_set_inline_data(vtkGLTFExporter& exporter, bool enabled) {
+    exporter.SetInlineData(enabled);
+}
+
+bool gltf_exporter_get_inline_data(const vtkGLTFExporter& exporter) {
+    return const_cast<vtkGLTFExporter&>(exporter).GetInlineData();
+}
+
+void gltf_exporter_set_save_normal(vtkGLTFExporter& exporter, bool enabled) {
+    exporter.SetSaveNormal(enabled);
+}
+
+bool gltf_exporter_get_save_normal(const vtkGLTFExporter& exporter) {
+    return const_cast<vtkGLTFExporter&>(exporter).GetSaveNormal();
+}
+
+void gltf_exporter_set_save_batch_id(vtkGLTFExporter& exporter, bool enabled) {
+    exporter.SetSaveBatchId(enabled);
+}
+
+bool gltf_exporter_get_save_batch_id(const vtkGLTFExporter& exporter) {
+    return const_cast<vtkGLTFExporter&>(exporter).GetSaveBatchId();
+}
+
+// Output
+void gltf_exporter_write(vtkGLTFExporter& exporter) {
+    if (!exporter.GetRenderWindow()) {
+        throw std::runtime_error("no render window set");
+    }
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = exporter.AddObserver(vtkCommand::ErrorEvent, observer);
+    exporter.Write();
+    exporter.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
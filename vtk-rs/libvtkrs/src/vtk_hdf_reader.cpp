@@ -0,0 +1,156 @@
+#include "cxx.h"
+#include "vtk_hdf_reader.h"
+#include "vtk_hdf_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkHDFReader.h>
+#include <vtkDataArraySelection.h>
+#include <vtkInformation.h>
+#include <vtkNew.h>
+#include <vtkStreamingDemandDrivenPipeline.h>
+#include <algorithm>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkHDFReader* vtk_hdf_reader_new() {
+    vtkHDFReader* obj = vtkHDFReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkHDFReader");
+    }
+    return obj;
+}
+
+void vtk_hdf_reader_delete(vtkHDFReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void hdf_reader_set_file_name(vtkHDFReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String hdf_reader_get_file_name(const vtkHDFReader& reader) {
+    const char* name = const_cast<vtkHDFReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool hdf_reader_can_read_file(vtkHDFReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Execution
+void hdf_reader_update(vtkHDFReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+
+    // A plain Update() leaves the time step to the pipeline, so request the
+    // time of the selected step explicitly for temporal files
+    int success = reader.UpdateInformation();
+    vtkInformation* info = reader.GetOutputInformation(0);
+    int count = 0;
+    if (success && info && info->Has(vtkStreamingDemandDrivenPipeline::TIME_STEPS())) {
+        count = info->Length(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+    }
+    if (success && count > 0) {
+        double* times = info->Get(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+        vtkIdType step = std::min<vtkIdType>(std::max<vtkIdType>(reader.GetStep(), 0), count - 1);
+        success = reader.UpdateTimeStep(times[step]);
+    } else if (success) {
+        success = reader.Update(0);
+    }
+    reader.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+    if (!success) {
+        throw std::runtime_error("vtkHDFReader failed to read the file");
+    }
+}
+
+// Time steps
+rust::Vec<double> hdf_reader_get_time_values(vtkHDFReader& reader) {
+    rust::Vec<double> values;
+    reader.UpdateInformation();
+    vtkInformation* info = reader.GetOutputInformation(0);
+    if (info && info->Has(vtkStreamingDemandDrivenPipeline::TIME_STEPS())) {
+        int count = info->Length(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+        double* times = info->Get(vtkStreamingDemandDrivenPipeline::TIME_STEPS());
+        for (int i = 0; i < count; ++i) {
+            values.push_back(times[i]);
+        }
+    }
+    return values;
+}
+
+void hdf_reader_set_step(vtkHDFReader& reader, int64_t step) {
+    reader.SetStep(static_cast<vtkIdType>(step));
+}
+
+int64_t hdf_reader_get_step(const vtkHDFReader& reader) {
+    return static_cast<int64_t>(const_cast<vtkHDFReader&>(reader).GetStep());
+}
+
+double hdf_reader_get_time_value(const vtkHDFReader& reader) {
+    return const_cast<vtkHDFReader&>(reader).GetTimeValue();
+}
+
+// Arrays
+int hdf_reader_get_number_of_point_arrays(vtkHDFReader& reader) {
+    reader.UpdateInformation();
+    return reader.GetPointDataArraySelection()->GetNumberOfArrays();
+}
+
+rust::String hdf_reader_get_point_array_name(vtkHDFReader& reader, int index) {
+    const char* name = reader.GetPointDataArraySelection()->GetArrayName(index);
+    return rust::String(name ? name : "");
+}
+
+void hdf_reader_set_point_array_status(vtkHDFReader& reader, rust::Str name, bool enabled) {
+    reader.GetPointDataArraySelection()->SetArraySetting(std::string(name).c_str(), enabled);
+}
+
+int hdf_reader_get_number_of_cell_arrays(vtkHDFReader& reader) {
+    reader.UpdateInformation();
+    return reader.GetCellDataArraySelection()->GetNumberOfArrays();
+}
+
+rust::String hdf_reader_get_cell_array_name(vtkHDFReader& reader, int index) {
+    const char* name = reader.GetCellDataArraySelection()->GetArrayName(index);
+    return rust::String(name ? name : "");
+}
+
+void hdf_reader_set_cell_array_status(vtkHDFReader& reader, rust::Str name, bool enabled) {
+    reader.GetCellDataArraySelection()->SetArraySetting(std::string(name).c_str(), enabled);
+}
+
+// Output
+// The output type depends on the file, so each accessor returns null if it does not match.
+// Otherwise it hands out a shallow copy so the Rust side owns its own reference.
+template <typename T>
+static T* copy_output(vtkHDFReader& reader) {
+    T* output = T::SafeDownCast(reader.GetOutputDataObject(0));
+    if (!output) {
+        return nullptr;
+    }
+    T* copy = T::New();
+    copy->ShallowCopy(output);
+    return copy;
+}
+
+vtkUnstructuredGrid* hdf_reader_get_output_unstructured_grid(vtkHDFReader& reader) {
+    return copy_output<vtkUnstructuredGrid>(reader);
+}
+
+vtkPolyData* hdf_reader_get_output_poly_data(vtkHDFReader& reader) {
+    return copy_output<vtkPolyData>(reader);
+}
+
+vtkImageData* hdf_reader_get_output_image_data(vtkHDFReader& reader) {
+    return copy_output<vtkImageData>(reader);
+}
+
+vtkAlgorithmOutput* hdf_reader_get_output_port(vtkHDFReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,124 @@
+// vtkHDFWriter was added in VTK 9.4
+#ifdef VTK094
+
+#include "cxx.h"
+#include "vtk_hdf_writer.h"
+#include "vtk_hdf_writer.rs.h"
+#include "vtk_error_observer.h"
+#include "vtk_temporal_source.h"
+
+#include <vtkHDFWriter.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkHDFWriter* vtk_hdf_writer_new() {
+    vtkHDFWriter* obj = vtkHDFWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkHDFWriter");
+    }
+    return obj;
+}
+
+void vtk_hdf_writer_delete(vtkHDFWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void hdf_writer_set_file_name(vtkHDFWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String hdf_writer_get_file_name(const vtkHDFWriter& writer) {
+    const char* name = const_cast<vtkHDFWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void hdf_writer_set_overwrite(vtkHDFWriter& writer, bool overwrite) {
+    writer.SetOverwrite(overwrite);
+}
+
+bool hdf_writer_get_overwrite(const vtkHDFWriter& writer) {
+    return const_cast<vtkHDFWriter&>(writer).GetOverwrite();
+}
+
+void hdf_writer_set_write_all_time_steps(vtkHDFWriter& writer, bool enabled) {
+    writer.SetWriteAllTimeSteps(enabled);
+}
+
+bool hdf_writer_get_write_all_time_steps(const vtkHDFWriter& writer) {
+    return const_cast<vtkHDFWriter&>(writer).GetWriteAllTimeSteps();
+}
+
+void hdf_writer_set_chunk_size(vtkHDFWriter& writer, int size) {
+    writer.SetChunkSize(size);
+}
+
+int hdf_writer_get_chunk_size(const vtkHDFWriter& writer) {
+    return const_cast<vtkHDFWriter&>(writer).GetChunkSize();
+}
+
+void hdf_writer_set_compression_level(vtkHDFWriter& writer, int level) {
+    writer.SetCompressionLevel(level);
+}
+
+int hdf_writer_get_compression_level(const vtkHDFWriter& writer) {
+    return const_cast<vtkHDFWriter&>(writer).GetCompressionLevel();
+}
+
+// Input
+static vtkRsTemporalSource* get_temporal_source(vtkHDFWriter& writer) {
+    if (writer.GetNumberOfInputConnections(0) == 0) {
+        return nullptr;
+    }
+    return vtkRsTemporalSource::SafeDownCast(writer.GetInputAlgorithm(0, 0));
+}
+
+void hdf_writer_set_input_connection(vtkHDFWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void hdf_writer_set_input_data(vtkHDFWriter& writer, vtkDataObject* data) {
+    writer.SetInputData(data);
+}
+
+void hdf_writer_add_time_step(vtkHDFWriter& writer, double time, vtkDataObject* data) {
+    // The steps live in a temporal source connected to the writer, which keeps it alive
+    vtkRsTemporalSource* source = get_temporal_source(writer);
+    if (!source) {
+        vtkNew<vtkRsTemporalSource> created;
+        writer.SetInputConnection(created->GetOutputPort());
+        source = created;
+    }
+    source->AddStep(time, data);
+    writer.SetWriteAllTimeSteps(true);
+}
+
+int hdf_writer_get_number_of_time_steps(const vtkHDFWriter& writer) {
+    vtkRsTemporalSource* source = get_temporal_source(const_cast<vtkHDFWriter&>(writer));
+    return source ? source->GetNumberOfSteps() : 0;
+}
+
+// Output
+void hdf_writer_write(vtkHDFWriter& writer) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = writer.AddObserver(vtkCommand::ErrorEvent, observer);
+    int success = writer.Write();
+    writer.RemoveObserver(tag);
+
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+    if (!success) {
+        throw std::runtime_error("vtkHDFWriter failed (missing input?)");
+    }
+}
+
+#endif // VTK094
@@ -3,6 +3,9 @@
 #include "vtk_image_data.rs.h"
 
 #include <vtkImageData.h>
+#include <vtkPointData.h>
+#include <vtkUnsignedCharArray.h>
+#include <cstring>
 
 // Create/Delete
 vtkImageData* vtk_image_data_new() {
@@ -85,21 +88,61 @@ double image_data_get_scalar_component_as_double(
     return const_cast<vtkImageData&>(image_data).GetScalarComponentAsDouble(x, y, z, component);
 }
 
-// Number of points and cells
-int image_data_get_number_of_points(const vtkImageData& image_data) {
-    // VTK GetNumberOfPoints() is not const
-    return const_cast<vtkImageData&>(image_data).GetNumberOfPoints();
-}
-
-int image_data_get_number_of_cells(const vtkImageData& image_data) {
-    // VTK GetNumberOfCells() is not const
-    return const_cast<vtkImageData&>(image_data).GetNumberOfCells();
-}
-
-// Bounds
-void image_data_get_bounds(const vtkImageData& image_data, double bounds[6]) {
-    // VTK GetBounds() is not const
-    const_cast<vtkImageData&>(image_data).GetBounds(bounds);
+// RGBA pixel transfer
+void image_data_import_rgba(
+    vtkImageData& image_data,
+    int width,
+    int height,
+    rust::Slice<const uint8_t> pixels
+) {
+    size_t expected = static_cast<size_t>(width) * static_cast<size_t>(height) * 4;
+    if (pixels.size() < expected) {
+        throw std::runtime_error("RGBA buffer is smaller than width * height * 4");
+    }
+
+    image_data.SetDimensions(width, height, 1);
+    image_data.AllocateScalars(VTK_UNSIGNED_CHAR, 4);
+    unsigned char* dst = static_cast<unsigned char*>(image_data.GetScalarPointer());
+    std::memcpy(dst, pixels.data(), expected);
+    image_data.Modified();
+}
+
+rust::Vec<uint8_t> image_data_export_rgba(const vtkImageData& image_data, int& width, int& height) {
+    vtkImageData& image = const_cast<vtkImageData&>(image_data);
+    rust::Vec<uint8_t> pixels;
+    width = 0;
+    height = 0;
+
+    vtkUnsignedCharArray* scalars =
+        vtkUnsignedCharArray::SafeDownCast(image.GetPointData()->GetScalars());
+    if (!scalars) {
+        return pixels;
+    }
+
+    int* dims = image.GetDimensions();
+    width = dims[0];
+    height = dims[1];
+    int components = scalars->GetNumberOfComponents();
+    vtkIdType count = static_cast<vtkIdType>(width) * height;
+    pixels.reserve(static_cast<size_t>(count) * 4);
+
+    const unsigned char* raw = scalars->GetPointer(0);
+    for (vtkIdType i = 0; i < count; ++i) {
+        const unsigned char* px = raw + i * components;
+        if (components < 3) {
+            // Gray or gray + alpha
+            pixels.push_back(px[0]);
+            pixels.push_back(px[0]);
+            pixels.push_back(px[0]);
+            pixels.push_back(components == 2 ? px[1] : 255);
+        } else {
+            pixels.push_back(px[0]);
+            pixels.push_back(px[1]);
+            pixels.push_back(px[2]);
+            pixels.push_back(components > 3 ? px[3] : 255);
+        }
+    }
+    return pixels;
 }
 
 vtkAlgorithmOutput* image_data_get_output_port(vtkImageData& image_data) {
@@ -110,3 +153,18 @@ vtkAlgorithmOutput* image_data_get_output_port(vtkImageData& image_data) {
     // The contour filter should use SetInputData instead
     return nullptr;  // ImageData doesn't have output ports - it's a data object
 }
+
+// Point scalars
+extern "C" void image_data_set_scalars(void* image_data_ptr, vtkDataArray* scalars) {
+    vtkImageData* image_data = static_cast<vtkImageData*>(image_data_ptr);
+    image_data->GetPointData()->SetScalars(scalars);
+}
+
+extern "C" vtkDataArray* image_data_get_scalars(void* image_data_ptr, int vtk_type) {
+    vtkImageData* image_data = static_cast<vtkImageData*>(image_data_ptr);
+    vtkDataArray* scalars = image_data->GetPointData()->GetScalars();
+    if (!scalars || scalars->GetDataType() != vtk_type) return nullptr;
+    // The Rust wrapper releases its own reference when dropped
+    scalars->Register(nullptr);
+    return scalars;
+}
@@ -0,0 +1,66 @@
+#include "cxx.h"
+#include "vtk_jpeg_writer.h"
+#include "vtk_jpeg_writer.rs.h"
+
+#include <vtkJPEGWriter.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkJPEGWriter* vtk_jpeg_writer_new() {
+    vtkJPEGWriter* obj = vtkJPEGWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkJPEGWriter");
+    }
+    return obj;
+}
+
+void vtk_jpeg_writer_delete(vtkJPEGWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void jpeg_writer_set_file_name(vtkJPEGWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String jpeg_writer_get_file_name(const vtkJPEGWriter& writer) {
+    const char* name = const_cast<vtkJPEGWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void jpeg_writer_set_quality(vtkJPEGWriter& writer, int quality) {
+    writer.SetQuality(quality);
+}
+
+int jpeg_writer_get_quality(const vtkJPEGWriter& writer) {
+    return const_cast<vtkJPEGWriter&>(writer).GetQuality();
+}
+
+void jpeg_writer_set_progressive(vtkJPEGWriter& writer, bool progressive) {
+    writer.SetProgressive(progressive);
+}
+
+bool jpeg_writer_get_progressive(const vtkJPEGWriter& writer) {
+    return const_cast<vtkJPEGWriter&>(writer).GetProgressive() != 0;
+}
+
+// Input
+void jpeg_writer_set_input_connection(vtkJPEGWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void jpeg_writer_set_input_data(vtkJPEGWriter& writer, vtkImageData* image_data) {
+    writer.SetInputData(image_data);
+}
+
+// Output
+void jpeg_writer_write(vtkJPEGWriter& writer) {
+    writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+}
@@ -0,0 +1,61 @@
+#include "cxx.h"
+#include "vtk_json_scene_exporter.h"
+#include "vtk_json_scene_exporter.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkJSONSceneExporter.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkJSONSceneExporter* vtk_json_scene_exporter_new() {
+    vtkJSONSceneExporter* obj = vtkJSONSceneExporter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkJSONSceneExporter");
+    }
+    return obj;
+}
+
+void vtk_json_scene_exporter_delete(vtkJSONSceneExporter& exporter) {
+    exporter.Delete();
+}
+
+// File name
+void json_scene_exporter_set_file_name(vtkJSONSceneExporter& exporter, rust::Str file_name) {
+    exporter.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String json_scene_exporter_get_file_name(const vtkJSONSceneExporter& exporter) {
+    const char* name = const_cast<vtkJSONSceneExporter&>(exporter).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Input
+void json_scene_exporter_set_render_window(vtkJSONSceneExporter& exporter, vtkRenderWindow* window) {
+    exporter.SetRenderWindow(window);
+}
+
+// Options
+void json_scene_exporter_set_write_textures(vtkJSONSceneExporter& exporter, bool enabled) {
+    exporter.SetWriteTextures(enabled);
+}
+
+bool json_scene_exporter_get_write_textures(const vtkJSONSceneExporter& exporter) {
+    return const_cast<vtkJSONSceneExporter&>(exporter).GetWriteTextures();
+}
+
+// Output
+void json_scene_exporter_write(vtkJSONSceneExporter& exporter) {
+    if (!exporter.GetRenderWindow()) {
+        throw std::runtime_error("no render window set");
+    }
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = exporter.AddObserver(vtkCommand::ErrorEvent, observer);
+    exporter.Write();
+    exporter.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
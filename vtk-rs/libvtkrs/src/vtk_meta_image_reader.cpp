@@ -0,0 +1,65 @@
+#include "cxx.h"
+#include "vtk_meta_image_reader.h"
+#include "vtk_meta_image_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkMetaImageReader.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkMetaImageReader* vtk_meta_image_reader_new() {
+    vtkMetaImageReader* obj = vtkMetaImageReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkMetaImageReader");
+    }
+    return obj;
+}
+
+void vtk_meta_image_reader_delete(vtkMetaImageReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void meta_image_reader_set_file_name(vtkMetaImageReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String meta_image_reader_get_file_name(const vtkMetaImageReader& reader) {
+    const char* name = const_cast<vtkMetaImageReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool meta_image_reader_can_read_file(vtkMetaImageReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Execution
+void meta_image_reader_update(vtkMetaImageReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkImageData* meta_image_reader_get_output(vtkMetaImageReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkImageData* copy = vtkImageData::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* meta_image_reader_get_output_port(vtkMetaImageReader& reader) {
+    return reader.GetOutputPort();
+}
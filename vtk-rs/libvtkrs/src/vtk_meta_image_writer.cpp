@@ -0,0 +1,67 @@
+#include "cxx.h"
+#include "vtk_meta_image_writer.h"
+#include "vtk_meta_image_writer.rs.h"
+
+#include <vtkMetaImageWriter.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkMetaImageWriter* vtk_meta_image_writer_new() {
+    vtkMetaImageWriter* obj = vtkMetaImageWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkMetaImageWriter");
+    }
+    return obj;
+}
+
+void vtk_meta_image_writer_delete(vtkMetaImageWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void meta_image_writer_set_file_name(vtkMetaImageWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String meta_image_writer_get_file_name(const vtkMetaImageWriter& writer) {
+    const char* name = const_cast<vtkMetaImageWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+void meta_image_writer_set_raw_file_name(vtkMetaImageWriter& writer, rust::Str file_name) {
+    writer.SetRAWFileName(std::string(file_name).c_str());
+}
+
+rust::String meta_image_writer_get_raw_file_name(const vtkMetaImageWriter& writer) {
+    const char* name = const_cast<vtkMetaImageWriter&>(writer).GetRAWFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void meta_image_writer_set_compression(vtkMetaImageWriter& writer, bool compression) {
+    writer.SetCompression(compression);
+}
+
+bool meta_image_writer_get_compression(const vtkMetaImageWriter& writer) {
+    return const_cast<vtkMetaImageWriter&>(writer).GetCompression();
+}
+
+// Input
+void meta_image_writer_set_input_connection(vtkMetaImageWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void meta_image_writer_set_input_data(vtkMetaImageWriter& writer, vtkImageData* image_data) {
+    writer.SetInputData(image_data);
+}
+
+// Output
+void meta_image_writer_write(vtkMetaImageWriter& writer) {
+    writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+}
@@ -0,0 +1,63 @@
+#include "vtk_multi_block_data_set.h"
+#include "vtk_composite_data_set.h"
+#include <vtkCompositeDataSet.h>
+#include <vtkInformation.h>
+#include <vtkMultiBlockDataSet.h>
+
+extern "C" {
+
+void* multi_block_data_set_new() {
+    return vtkMultiBlockDataSet::New();
+}
+
+void multi_block_data_set_delete(void* blocks) {
+    if (blocks) {
+        static_cast<vtkMultiBlockDataSet*>(blocks)->Delete();
+    }
+}
+
+void multi_block_data_set_set_number_of_blocks(void* blocks, unsigned int count) {
+    if (!blocks) return;
+    static_cast<vtkMultiBlockDataSet*>(blocks)->SetNumberOfBlocks(count);
+}
+
+unsigned int multi_block_data_set_get_number_of_blocks(void* blocks) {
+    if (!blocks) return 0;
+    return static_cast<vtkMultiBlockDataSet*>(blocks)->GetNumberOfBlocks();
+}
+
+void multi_block_data_set_set_block(void* blocks, unsigned int index, void* block) {
+    if (!blocks) return;
+    static_cast<vtkMultiBlockDataSet*>(blocks)->SetBlock(index, static_cast<vtkDataObject*>(block));
+}
+
+void* multi_block_data_set_get_block(void* blocks, unsigned int index, int* block_type) {
+    *block_type = -1;
+    if (!blocks) return nullptr;
+    vtkMultiBlockDataSet* mb = static_cast<vtkMultiBlockDataSet*>(blocks);
+    if (index >= mb->GetNumberOfBlocks()) return nullptr;
+    return composite_block_register(mb->GetBlock(index), block_type);
+}
+
+void multi_block_data_set_remove_block(void* blocks, unsigned int index) {
+    if (!blocks) return;
+    static_cast<vtkMultiBlockDataSet*>(blocks)->RemoveBlock(index);
+}
+
+void multi_block_data_set_set_block_name(void* blocks, unsigned int index, const char* name) {
+    if (!blocks || !name) return;
+    vtkMultiBlockDataSet* mb = static_cast<vtkMultiBlockDataSet*>(blocks);
+    if (index >= mb->GetNumberOfBlocks()) return;
+    mb->GetMetaData(index)->Set(vtkCompositeDataSet::NAME(), name);
+}
+
+const char* multi_block_data_set_get_block_name(void* blocks, unsigned int index) {
+    if (!blocks) return nullptr;
+    vtkMultiBlockDataSet* mb = static_cast<vtkMultiBlockDataSet*>(blocks);
+    if (index >= mb->GetNumberOfBlocks() || !mb->HasMetaData(index)) return nullptr;
+    vtkInformation* meta_data = mb->GetMetaData(index);
+    if (!meta_data->Has(vtkCompositeDataSet::NAME())) return nullptr;
+    return meta_data->Get(vtkCompositeDataSet::NAME());
+}
+
+}
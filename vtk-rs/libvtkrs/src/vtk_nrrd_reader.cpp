@@ -0,0 +1,65 @@
+#include "cxx.h"
+#include "vtk_nrrd_reader.h"
+#include "vtk_nrrd_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkNrrdReader.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkNrrdReader* vtk_nrrd_reader_new() {
+    vtkNrrdReader* obj = vtkNrrdReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkNrrdReader");
+    }
+    return obj;
+}
+
+void vtk_nrrd_reader_delete(vtkNrrdReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void nrrd_reader_set_file_name(vtkNrrdReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String nrrd_reader_get_file_name(const vtkNrrdReader& reader) {
+    const char* name = const_cast<vtkNrrdReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool nrrd_reader_can_read_file(vtkNrrdReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Execution
+void nrrd_reader_update(vtkNrrdReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkImageData* nrrd_reader_get_output(vtkNrrdReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkImageData* copy = vtkImageData::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* nrrd_reader_get_output_port(vtkNrrdReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,61 @@
+#include "cxx.h"
+#include "vtk_obj_reader.h"
+#include "vtk_obj_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkOBJReader.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkOBJReader* vtk_obj_reader_new() {
+    vtkOBJReader* obj = vtkOBJReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkOBJReader");
+    }
+    return obj;
+}
+
+void vtk_obj_reader_delete(vtkOBJReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void obj_reader_set_file_name(vtkOBJReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String obj_reader_get_file_name(const vtkOBJReader& reader) {
+    const char* name = const_cast<vtkOBJReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Execution
+void obj_reader_update(vtkOBJReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkPolyData* obj_reader_get_output(vtkOBJReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkPolyData* copy = vtkPolyData::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* obj_reader_get_output_port(vtkOBJReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,66 @@
+#include "cxx.h"
+#include "vtk_obj_writer.h"
+#include "vtk_obj_writer.rs.h"
+
+#include <vtkOBJWriter.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkOBJWriter* vtk_obj_writer_new() {
+    vtkOBJWriter* obj = vtkOBJWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkOBJWriter");
+    }
+    return obj;
+}
+
+void vtk_obj_writer_delete(vtkOBJWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void obj_writer_set_file_name(vtkOBJWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String obj_writer_get_file_name(const vtkOBJWriter& writer) {
+    const char* name = const_cast<vtkOBJWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void obj_writer_set_texture_file_name(vtkOBJWriter& writer, rust::Str texture_file_name) {
+    writer.SetTextureFileName(std::string(texture_file_name).c_str());
+}
+
+rust::String obj_writer_get_texture_file_name(const vtkOBJWriter& writer) {
+    const char* value = const_cast<vtkOBJWriter&>(writer).GetTextureFileName();
+    return rust::String(value ? value : "");
+}
+
+void obj_writer_set_texture_data(vtkOBJWriter& writer, vtkImageData* texture) {
+    writer.SetInputData(1, texture);
+}
+
+// Input
+void obj_writer_set_input_connection(vtkOBJWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void obj_writer_set_input_data(vtkOBJWriter& writer, vtkPolyData* poly_data) {
+    writer.SetInputData(poly_data);
+}
+
+// Output
+void obj_writer_write(vtkOBJWriter& writer) {
+    int success = writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (!success) {
+        throw std::runtime_error("vtkOBJWriter failed (missing input?)");
+    }
+}
@@ -0,0 +1,103 @@
+#include "vtk_partitioned_data_set_collection.h"
+#include "vtk_composite_data_set.h"
+#include <vtkCompositeDataSet.h>
+#include <vtkDataSet.h>
+#include <vtkInformation.h>
+#include <vtkPartitionedDataSet.h>
+#include <vtkPartitionedDataSetCollection.h>
+
+extern "C" {
+
+void* partitioned_data_set_collection_new() {
+    return vtkPartitionedDataSetCollection::New();
+}
+
+void partitioned_data_set_collection_delete(void* collection) {
+    if (collection) {
+        static_cast<vtkPartitionedDataSetCollection*>(collection)->Delete();
+    }
+}
+
+void partitioned_data_set_collection_set_number_of_partitioned_data_sets(
+    void* collection,
+    unsigned int count
+) {
+    if (!collection) return;
+    static_cast<vtkPartitionedDataSetCollection*>(collection)->SetNumberOfPartitionedDataSets(count);
+}
+
+unsigned int partitioned_data_set_collection_get_number_of_partitioned_data_sets(
+    void* collection
+) {
+    if (!collection) return 0;
+    return static_cast<vtkPartitionedDataSetCollection*>(collection)
+        ->GetNumberOfPartitionedDataSets();
+}
+
+unsigned int partitioned_data_set_collection_get_number_of_partitions(
+    void* collection,
+    unsigned int index
+) {
+    if (!collection) return 0;
+    auto* pdc = static_cast<vtkPartitionedDataSetCollection*>(collection);
+    if (index >= pdc->GetNumberOfPartitionedDataSets()) return 0;
+    return pdc->GetNumberOfPartitions(index);
+}
+
+void partitioned_data_set_collection_set_partition(
+    void* collection,
+    unsigned int index,
+    unsigned int partition,
+    void* data_set
+) {
+    if (!collection) return;
+    // Grows the collection and the partitioned dataset as needed
+    static_cast<vtkPartitionedDataSetCollection*>(collection)
+        ->SetPartition(index, partition, static_cast<vtkDataSet*>(data_set));
+}
+
+void* partitioned_data_set_collection_get_partition(
+    void* collection,
+    unsigned int index,
+    unsigned int partition,
+    int* block_type
+) {
+    *block_type = -1;
+    if (!collection) return nullptr;
+    auto* pdc = static_cast<vtkPartitionedDataSetCollection*>(collection);
+    if (index >= pdc->GetNumberOfPartitionedDataSets()) return nullptr;
+    if (partition >= pdc->GetNumberOfPartitions(index)) return nullptr;
+    return composite_block_register(pdc->GetPartition(index, partition), block_type);
+}
+
+void partitioned_data_set_collection_remove_partitioned_data_set(
+    void* collection,
+    unsigned int index
+) {
+    if (!collection) return;
+    static_cast<vtkPartitionedDataSetCollection*>(collection)->RemovePartitionedDataSet(index);
+}
+
+void partitioned_data_set_collection_set_name(
+    void* collection,
+    unsigned int index,
+    const char* name
+) {
+    if (!collection || !name) return;
+    auto* pdc = static_cast<vtkPartitionedDataSetCollection*>(collection);
+    if (index >= pdc->GetNumberOfPartitionedDataSets()) return;
+    pdc->GetMetaData(index)->Set(vtkCompositeDataSet::NAME(), name);
+}
+
+const char* partitioned_data_set_collection_get_name(void* collection, unsigned int index) {
+    if (!collection) return nullptr;
+    auto* pdc = static_cast<vtkPartitionedDataSetCollection*>(collection);
+    if (index >= pdc->GetNumberOfPartitionedDataSets() || !pdc->HasMetaData(index)) {
+        return nullptr;
+    }
+    vtkInformation* meta_data = pdc->GetMetaData(index);
+    if (!meta_data->Has(vtkCompositeDataSet::NAME())) return nullptr;
+    return meta_data->Get(vtkCompositeDataSet::NAME());
+}
+
+}
@@ -0,0 +1,74 @@
+#include "cxx.h"
+#include "vtk_ply_reader.h"
+#include "vtk_ply_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkPLYReader.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkPLYReader* vtk_ply_reader_new() {
+    vtkPLYReader* obj = vtkPLYReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkPLYReader");
+    }
+    return obj;
+}
+
+void vtk_ply_reader_delete(vtkPLYReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void ply_reader_set_file_name(vtkPLYReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String ply_reader_get_file_name(const vtkPLYReader& reader) {
+    const char* name = const_cast<vtkPLYReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool ply_reader_can_read_file(vtkPLYReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Options
+void ply_reader_set_duplicate_points_for_face_texture(vtkPLYReader& reader, bool duplicate_points_for_face_texture) {
+    reader.SetDuplicatePointsForFaceTexture(duplicate_points_for_face_texture);
+}
+
+bool ply_reader_get_duplicate_points_for_face_texture(const vtkPLYReader& reader) {
+    return const_cast<vtkPLYReader&>(reader).GetDuplicatePointsForFaceTexture() != 0;
+}
+
+// Execution
+void ply_reader_update(vtkPLYReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkPolyData* ply_reader_get_output(vtkPLYReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkPolyData* copy = vtkPolyData::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* ply_reader_get_output_port(vtkPLYReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,98 @@
+#include "cxx.h"
+#include "vtk_ply_writer.h"
+#include "vtk_ply_writer.rs.h"
+
+#include <vtkPLYWriter.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkPLYWriter* vtk_ply_writer_new() {
+    vtkPLYWriter* obj = vtkPLYWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkPLYWriter");
+    }
+    return obj;
+}
+
+void vtk_ply_writer_delete(vtkPLYWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void ply_writer_set_file_name(vtkPLYWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String ply_writer_get_file_name(const vtkPLYWriter& writer) {
+    const char* name = const_cast<vtkPLYWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void ply_writer_set_array_name(vtkPLYWriter& writer, rust::Str array_name) {
+    writer.SetArrayName(std::string(array_name).c_str());
+}
+
+rust::String ply_writer_get_array_name(const vtkPLYWriter& writer) {
+    const char* value = const_cast<vtkPLYWriter&>(writer).GetArrayName();
+    return rust::String(value ? value : "");
+}
+
+void ply_writer_set_enable_alpha(vtkPLYWriter& writer, bool enable_alpha) {
+    writer.SetEnableAlpha(enable_alpha);
+}
+
+bool ply_writer_get_enable_alpha(const vtkPLYWriter& writer) {
+    return const_cast<vtkPLYWriter&>(writer).GetEnableAlpha() != 0;
+}
+
+void ply_writer_set_file_type(vtkPLYWriter& writer, int file_type) {
+    writer.SetFileType(file_type);
+}
+
+int ply_writer_get_file_type(const vtkPLYWriter& writer) {
+    return const_cast<vtkPLYWriter&>(writer).GetFileType();
+}
+
+void ply_writer_set_color_mode(vtkPLYWriter& writer, int mode) {
+    writer.SetColorMode(mode);
+}
+
+int ply_writer_get_color_mode(const vtkPLYWriter& writer) {
+    return const_cast<vtkPLYWriter&>(writer).GetColorMode();
+}
+
+void ply_writer_set_color(vtkPLYWriter& writer, uint8_t r, uint8_t g, uint8_t b) {
+    writer.SetColor(r, g, b);
+}
+
+void ply_writer_set_alpha(vtkPLYWriter& writer, uint8_t alpha) {
+    writer.SetAlpha(alpha);
+}
+
+void ply_writer_set_texture_coordinates_name(vtkPLYWriter& writer, int name) {
+    writer.SetTextureCoordinatesName(name);
+}
+
+// Input
+void ply_writer_set_input_connection(vtkPLYWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void ply_writer_set_input_data(vtkPLYWriter& writer, vtkPolyData* poly_data) {
+    writer.SetInputData(poly_data);
+}
+
+// Output
+void ply_writer_write(vtkPLYWriter& writer) {
+    int success = writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (!success) {
+        throw std::runtime_error("vtkPLYWriter failed (missing input?)");
+    }
+}
@@ -0,0 +1,56 @@
+#include "cxx.h"
+#include "vtk_png_reader.h"
+#include "vtk_png_reader.rs.h"
+
+#include <vtkPNGReader.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkPNGReader* vtk_png_reader_new() {
+    vtkPNGReader* obj = vtkPNGReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkPNGReader");
+    }
+    return obj;
+}
+
+void vtk_png_reader_delete(vtkPNGReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void png_reader_set_file_name(vtkPNGReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String png_reader_get_file_name(const vtkPNGReader& reader) {
+    const char* name = const_cast<vtkPNGReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool png_reader_can_read_file(vtkPNGReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Execution
+void png_reader_update(vtkPNGReader& reader) {
+    reader.Update();
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+}
+
+// Output
+vtkImageData* png_reader_get_output(vtkPNGReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkImageData* copy = vtkImageData::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* png_reader_get_output_port(vtkPNGReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,58 @@
+#include "cxx.h"
+#include "vtk_png_writer.h"
+#include "vtk_png_writer.rs.h"
+
+#include <vtkPNGWriter.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkPNGWriter* vtk_png_writer_new() {
+    vtkPNGWriter* obj = vtkPNGWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkPNGWriter");
+    }
+    return obj;
+}
+
+void vtk_png_writer_delete(vtkPNGWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void png_writer_set_file_name(vtkPNGWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String png_writer_get_file_name(const vtkPNGWriter& writer) {
+    const char* name = const_cast<vtkPNGWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void png_writer_set_compression_level(vtkPNGWriter& writer, int level) {
+    writer.SetCompressionLevel(level);
+}
+
+int png_writer_get_compression_level(const vtkPNGWriter& writer) {
+    return const_cast<vtkPNGWriter&>(writer).GetCompressionLevel();
+}
+
+// Input
+void png_writer_set_input_connection(vtkPNGWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void png_writer_set_input_data(vtkPNGWriter& writer, vtkImageData* image_data) {
+    writer.SetInputData(image_data);
+}
+
+// Output
+void png_writer_write(vtkPNGWriter& writer) {
+    writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+}
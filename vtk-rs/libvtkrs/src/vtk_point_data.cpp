@@ -1,20 +1,15 @@
 #include "vtk_point_data.h"
-#include <vtkPolyData.h>
 #include <vtkPointData.h>
 #include <vtkDataArray.h>
+#include <vtkUnsignedCharArray.h>
 #include <iostream>
 #include <cstring>
 
 extern "C" {
 
-void* poly_data_get_point_data(void* poly_data) {
-    if (!poly_data) return nullptr;
-    return static_cast<vtkPolyData*>(poly_data)->GetPointData();
-}
-
 void point_data_add_array(void* point_data, void* array) {
     if (!point_data || !array) return;
-    static_cast<vtkPointData*>(point_data)->AddArray(static_cast<vtkDataArray*>(array));
+    static_cast<vtkPointData*>(point_data)->AddArray(static_cast<vtkAbstractArray*>(array));
 }
 
 void point_data_remove_array(void* point_data, const char* name) {
@@ -67,4 +62,61 @@ void point_data_set_active_vectors(void* point_data, const char* name) {
     static_cast<vtkPointData*>(point_data)->SetActiveVectors(name);
 }
 
+void point_data_set_colors(void* point_data, const char* name, const uint8_t* rgba, int64_t num_tuples) {
+    if (!point_data || !name || (!rgba && num_tuples > 0)) return;
+    vtkUnsignedCharArray* colors = vtkUnsignedCharArray::New();
+    colors->SetName(name);
+    colors->SetNumberOfComponents(4);
+    colors->SetNumberOfTuples(num_tuples);
+    std::memcpy(colors->GetPointer(0), rgba, static_cast<size_t>(num_tuples) * 4);
+    static_cast<vtkPointData*>(point_data)->SetScalars(colors);
+    colors->Delete();
+}
+
+int64_t point_data_get_number_of_colors(void* point_data, const char* name) {
+    if (!point_data || !name) return -1;
+    vtkUnsignedCharArray* colors =
+        vtkUnsignedCharArray::SafeDownCast(static_cast<vtkPointData*>(point_data)->GetArray(name));
+    if (!colors || colors->GetNumberOfComponents() < 3 || colors->GetNumberOfComponents() > 4) {
+        return -1;
+    }
+    return static_cast<int64_t>(colors->GetNumberOfTuples());
+}
+
+void point_data_copy_colors(void* point_data, const char* name, uint8_t* rgba) {
+    if (point_data_get_number_of_colors(point_data, name) < 0 || !rgba) return;
+    vtkUnsignedCharArray* colors =
+        vtkUnsignedCharArray::SafeDownCast(static_cast<vtkPointData*>(point_data)->GetArray(name));
+    int components = colors->GetNumberOfComponents();
+    vtkIdType num_tuples = colors->GetNumberOfTuples();
+    for (vtkIdType i = 0; i < num_tuples; ++i) {
+        for (int c = 0; c < 3; ++c) {
+            rgba[i * 4 + c] = colors->GetValue(i * components + c);
+        }
+        rgba[i * 4 + 3] = components == 4 ? colors->GetValue(i * components + 3) : 255;
+    }
+}
+
+void point_data_set_tcoords(void* point_data, void* array) {
+    if (!point_data || !array) return;
+    static_cast<vtkPointData*>(point_data)->SetTCoords(static_cast<vtkDataArray*>(array));
+}
+
+int64_t point_data_get_number_of_tcoords(void* point_data) {
+    if (!point_data) return -1;
+    vtkDataArray* tcoords = static_cast<vtkPointData*>(point_data)->GetTCoords();
+    if (!tcoords || tcoords->GetNumberOfComponents() < 2) return -1;
+    return static_cast<int64_t>(tcoords->GetNumberOfTuples());
+}
+
+void point_data_copy_tcoords(void* point_data, double* uv) {
+    if (point_data_get_number_of_tcoords(point_data) < 0 || !uv) return;
+    vtkDataArray* tcoords = static_cast<vtkPointData*>(point_data)->GetTCoords();
+    vtkIdType num_tuples = tcoords->GetNumberOfTuples();
+    for (vtkIdType i = 0; i < num_tuples; ++i) {
+        uv[i * 2] = tcoords->GetComponent(i, 0);
+        uv[i * 2 + 1] = tcoords->GetComponent(i, 1);
+    }
+}
+
 } // extern "C"
@@ -1,5 +1,7 @@
 #include "vtk_points.h"
 #include <vtkPoints.h>
+#include <vtkDoubleArray.h>
+#include <vtkNew.h>
 #include <iostream>
 
 extern "C" {
@@ -63,4 +65,32 @@ extern "C" {
             points->Resize(number);
         }
     }
+
+    void points_set_data_type_to_double(vtkPoints* points) {
+        if (!points || points->GetDataType() == VTK_DOUBLE) return;
+        // SetDataType() would drop the existing points, so convert them instead
+        vtkNew<vtkDoubleArray> data;
+        data->SetNumberOfComponents(3);
+        data->DeepCopy(points->GetData());
+        data->SetName(points->GetData()->GetName());
+        points->SetData(data);
+    }
+
+    bool points_is_double(vtkPoints* points) {
+        return points && points->GetDataType() == VTK_DOUBLE;
+    }
+
+    void* points_get_data(vtkPoints* points) {
+        if (points) {
+            return points->GetData();
+        }
+        return nullptr;
+    }
+
+    void points_modified(vtkPoints* points) {
+        if (points) {
+            points->GetData()->DataChanged();
+            points->Modified();
+        }
+    }
 }
@@ -5,6 +5,13 @@
 #include <vtkTrivialProducer.h>
 #include <iostream>
 
+// vtkPolyData creates empty cell arrays on first access, so these never return null
+static void* registered_cells(vtkCellArray* ca) {
+    // The Rust wrapper releases its own reference
+    ca->Register(nullptr);
+    return ca;
+}
+
 extern "C" {
 
 void* poly_data_new() {
@@ -33,14 +40,25 @@ void* poly_data_get_points(void* poly_data) {
     if (!poly_data) return nullptr;
     
     vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
-    return pd->GetPoints();
+    vtkPoints* pts = pd->GetPoints();
+    // The Rust wrapper releases its own reference
+    if (pts) pts->Register(nullptr);
+    return pts;
+}
+
+void poly_data_set_verts(void* poly_data, void* verts) {
+    if (!poly_data || !verts) return;
+    static_cast<vtkPolyData*>(poly_data)->SetVerts(static_cast<vtkCellArray*>(verts));
+}
+
+void* poly_data_get_verts(void* poly_data) {
+    if (!poly_data) return nullptr;
+    return registered_cells(static_cast<vtkPolyData*>(poly_data)->GetVerts());
 }
 
-int64_t poly_data_get_number_of_points(void* poly_data) {
+int64_t poly_data_get_number_of_verts(void* poly_data) {
     if (!poly_data) return 0;
-    
-    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
-    return static_cast<int64_t>(pd->GetNumberOfPoints());
+    return static_cast<int64_t>(static_cast<vtkPolyData*>(poly_data)->GetNumberOfVerts());
 }
 
 void poly_data_set_lines(void* poly_data, void* lines) {
@@ -54,9 +72,7 @@ void poly_data_set_lines(void* poly_data, void* lines) {
 
 void* poly_data_get_lines(void* poly_data) {
     if (!poly_data) return nullptr;
-    
-    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
-    return pd->GetLines();
+    return registered_cells(static_cast<vtkPolyData*>(poly_data)->GetLines());
 }
 
 int64_t poly_data_get_number_of_lines(void* poly_data) {
@@ -66,22 +82,34 @@ int64_t poly_data_get_number_of_lines(void* poly_data) {
     return static_cast<int64_t>(pd->GetNumberOfLines());
 }
 
-int64_t poly_data_get_number_of_cells(void* poly_data) {
+void poly_data_set_polys(void* poly_data, void* polys) {
+    if (!poly_data || !polys) return;
+    static_cast<vtkPolyData*>(poly_data)->SetPolys(static_cast<vtkCellArray*>(polys));
+}
+
+void* poly_data_get_polys(void* poly_data) {
+    if (!poly_data) return nullptr;
+    return registered_cells(static_cast<vtkPolyData*>(poly_data)->GetPolys());
+}
+
+int64_t poly_data_get_number_of_polys(void* poly_data) {
     if (!poly_data) return 0;
-    
-    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
-    return static_cast<int64_t>(pd->GetNumberOfCells());
+    return static_cast<int64_t>(static_cast<vtkPolyData*>(poly_data)->GetNumberOfPolys());
 }
 
-void poly_data_get_bounds(void* poly_data, double bounds[6]) {
-    if (!poly_data || !bounds) return;
-    
-    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
-    double* b = pd->GetBounds();
-    
-    for (int i = 0; i < 6; i++) {
-        bounds[i] = b[i];
-    }
+void poly_data_set_strips(void* poly_data, void* strips) {
+    if (!poly_data || !strips) return;
+    static_cast<vtkPolyData*>(poly_data)->SetStrips(static_cast<vtkCellArray*>(strips));
+}
+
+void* poly_data_get_strips(void* poly_data) {
+    if (!poly_data) return nullptr;
+    return registered_cells(static_cast<vtkPolyData*>(poly_data)->GetStrips());
+}
+
+int64_t poly_data_get_number_of_strips(void* poly_data) {
+    if (!poly_data) return 0;
+    return static_cast<int64_t>(static_cast<vtkPolyData*>(poly_data)->GetNumberOfStrips());
 }
 
 void poly_data_allocate(void* poly_data, int64_t num_verts, int64_t connectivity_size) {
@@ -4,6 +4,7 @@
 
 #include <vtkPolyDataMapper.h>
 #include <vtkAlgorithmOutput.h>
+#include <string>
 
 vtkPolyDataMapper* poly_data_mapper_new() {
     return vtkPolyDataMapper::New();
@@ -16,3 +17,30 @@ void poly_data_mapper_delete(vtkPolyDataMapper& pdm) {
 void poly_data_mapper_set_input_connection(vtkPolyDataMapper& mapper, vtkAlgorithmOutput* output) {
     mapper.SetInputConnection(output);
 }
+
+// Coloring
+void poly_data_mapper_set_lookup_table(vtkPolyDataMapper& mapper, vtkLookupTable& lut) {
+    mapper.SetLookupTable(&lut);
+}
+
+void poly_data_mapper_set_scalar_range(vtkPolyDataMapper& mapper, double min, double max) {
+    mapper.SetScalarRange(min, max);
+}
+
+void poly_data_mapper_set_scalar_visibility(vtkPolyDataMapper& mapper, bool visible) {
+    mapper.SetScalarVisibility(visible);
+}
+
+bool poly_data_mapper_get_scalar_visibility(const vtkPolyDataMapper& mapper) {
+    return const_cast<vtkPolyDataMapper&>(mapper).GetScalarVisibility() != 0;
+}
+
+void poly_data_mapper_color_by_array(vtkPolyDataMapper& mapper, rust::Str name, bool cell_data) {
+    if (cell_data) {
+        mapper.SetScalarModeToUseCellFieldData();
+    } else {
+        mapper.SetScalarModeToUsePointFieldData();
+    }
+    mapper.SelectColorArray(std::string(name).c_str());
+    mapper.ScalarVisibilityOn();
+}
@@ -0,0 +1,90 @@
+#include "cxx.h"
+#include "vtk_poly_data_writer.h"
+#include "vtk_poly_data_writer.rs.h"
+
+#include <vtkPolyDataWriter.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkPolyDataWriter* vtk_poly_data_writer_new() {
+    vtkPolyDataWriter* obj = vtkPolyDataWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkPolyDataWriter");
+    }
+    return obj;
+}
+
+void vtk_poly_data_writer_delete(vtkPolyDataWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void poly_data_writer_set_file_name(vtkPolyDataWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String poly_data_writer_get_file_name(const vtkPolyDataWriter& writer) {
+    const char* name = const_cast<vtkPolyDataWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void poly_data_writer_set_file_type(vtkPolyDataWriter& writer, int file_type) {
+    writer.SetFileType(file_type);
+}
+
+int poly_data_writer_get_file_type(const vtkPolyDataWriter& writer) {
+    return const_cast<vtkPolyDataWriter&>(writer).GetFileType();
+}
+
+void poly_data_writer_set_header(vtkPolyDataWriter& writer, rust::Str header) {
+    writer.SetHeader(std::string(header).c_str());
+}
+
+rust::String poly_data_writer_get_header(const vtkPolyDataWriter& writer) {
+    const char* header = const_cast<vtkPolyDataWriter&>(writer).GetHeader();
+    return rust::String(header ? header : "");
+}
+
+void poly_data_writer_set_write_to_output_string(vtkPolyDataWriter& writer, bool enabled) {
+    writer.SetWriteToOutputString(enabled);
+}
+
+rust::Vec<uint8_t> poly_data_writer_get_output_string(const vtkPolyDataWriter& writer) {
+    vtkPolyDataWriter& w = const_cast<vtkPolyDataWriter&>(writer);
+    rust::Vec<uint8_t> bytes;
+    const char* output = w.GetOutputString();
+    if (!output) {
+        return bytes;
+    }
+    // Binary files may contain null bytes, so use the stored length
+    vtkIdType length = w.GetOutputStringLength();
+    bytes.reserve(static_cast<size_t>(length));
+    for (vtkIdType i = 0; i < length; ++i) {
+        bytes.push_back(static_cast<uint8_t>(output[i]));
+    }
+    return bytes;
+}
+
+// Input
+void poly_data_writer_set_input_connection(vtkPolyDataWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void poly_data_writer_set_input_data(vtkPolyDataWriter& writer, vtkPolyData* poly_data) {
+    writer.SetInputData(poly_data);
+}
+
+// Output
+void poly_data_writer_write(vtkPolyDataWriter& writer) {
+    int success = writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (!success) {
+        throw std::runtime_error("vtkPolyDataWriter failed (missing input?)");
+    }
+}
@@ -0,0 +1,62 @@
+#include "cxx.h"
+#include "vtk_rectilinear_grid.h"
+#include "vtk_rectilinear_grid.rs.h"
+
+#include <vtkRectilinearGrid.h>
+#include <vtkDataArray.h>
+#include <vtkDoubleArray.h>
+
+// Create/Delete
+vtkRectilinearGrid* vtk_rectilinear_grid_new() {
+    return vtkRectilinearGrid::New();
+}
+
+void vtk_rectilinear_grid_delete(vtkRectilinearGrid& grid) {
+    grid.Delete();
+}
+
+// Dimensions
+void rectilinear_grid_set_dimensions(vtkRectilinearGrid& grid, int nx, int ny, int nz) {
+    grid.SetDimensions(nx, ny, nz);
+}
+
+void rectilinear_grid_get_dimensions(const vtkRectilinearGrid& grid, int* nx, int* ny, int* nz) {
+    // VTK GetDimensions() is not const, so we need to cast
+    int* dims = const_cast<vtkRectilinearGrid&>(grid).GetDimensions();
+    *nx = dims[0];
+    *ny = dims[1];
+    *nz = dims[2];
+}
+
+// Coordinates
+void rectilinear_grid_set_coordinates(
+    vtkRectilinearGrid& grid,
+    int axis,
+    vtkDoubleArray* coordinates
+) {
+    switch (axis) {
+        case 0: grid.SetXCoordinates(coordinates); break;
+        case 1: grid.SetYCoordinates(coordinates); break;
+        case 2: grid.SetZCoordinates(coordinates); break;
+    }
+}
+
+rust::Vec<double> rectilinear_grid_get_coordinates(const vtkRectilinearGrid& grid, int axis) {
+    vtkRectilinearGrid& g = const_cast<vtkRectilinearGrid&>(grid);
+    vtkDataArray* coordinates = nullptr;
+    switch (axis) {
+        case 0: coordinates = g.GetXCoordinates(); break;
+        case 1: coordinates = g.GetYCoordinates(); break;
+        case 2: coordinates = g.GetZCoordinates(); break;
+    }
+
+    // Coordinates read from files may be stored as float, so convert through GetComponent
+    rust::Vec<double> values;
+    if (!coordinates) return values;
+    vtkIdType count = coordinates->GetNumberOfTuples();
+    values.reserve(count);
+    for (vtkIdType i = 0; i < count; ++i) {
+        values.push_back(coordinates->GetComponent(i, 0));
+    }
+    return values;
+}
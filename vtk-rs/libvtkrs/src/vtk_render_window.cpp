@@ -5,8 +5,13 @@
 #include <vtkRenderWindow.h>
 #include <vtkRenderer.h>
 #include <vtkUnsignedCharArray.h>
+#include <vtkRenderingOpenGLConfigure.h>
 #include <string>
 
+#if defined(VTK_OPENGL_HAS_OSMESA)
+#include <vtkOSOpenGLRenderWindow.h>
+#endif
+
 vtkRenderWindow* render_window_new() {
     vtkRenderWindow* obj = vtkRenderWindow::New();
     if (!obj) {
@@ -15,6 +20,22 @@ vtkRenderWindow* render_window_new() {
     return obj;
 }
 
+vtkRenderWindow* render_window_new_offscreen() {
+    // Prefer the OSMesa software window when VTK provides it, so that rendering
+    // works without any display server (headless CI, batch report jobs).
+#if defined(VTK_OPENGL_HAS_OSMESA)
+    vtkRenderWindow* obj = vtkOSOpenGLRenderWindow::New();
+#else
+    vtkRenderWindow* obj = vtkRenderWindow::New();
+#endif
+    if (!obj) {
+        throw std::runtime_error("Failed to create offscreen vtkRenderWindow");
+    }
+    obj->SetShowWindow(false);
+    obj->SetOffScreenRendering(1);
+    return obj;
+}
+
 void render_window_delete(vtkRenderWindow& window) {
     window.Delete();
 }
@@ -35,6 +56,14 @@ void render_window_render(vtkRenderWindow& window) {
     window.Render();
 }
 
+void render_window_set_off_screen_rendering(vtkRenderWindow& window, bool on) {
+    window.SetOffScreenRendering(on ? 1 : 0);
+}
+
+bool render_window_get_off_screen_rendering(const vtkRenderWindow& window) {
+    return const_cast<vtkRenderWindow&>(window).GetOffScreenRendering() != 0;
+}
+
 void render_window_get_size(vtkRenderWindow& window, int& width, int& height) {
     int* size = window.GetSize();
     width = size[0];
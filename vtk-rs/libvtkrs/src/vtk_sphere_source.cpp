@@ -58,6 +58,15 @@ int64_t vtk_sphere_source_get_theta_resolution(const vtkSphereSource& sphere_sou
     return const_cast<vtkSphereSource&>(sphere_source).GetThetaResolution();
 }
 
+vtkPolyData* sphere_source_get_output(const vtkSphereSource& sphere_source) {
+    vtkSphereSource& source = const_cast<vtkSphereSource&>(sphere_source);
+    source.Update();
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkPolyData* copy = vtkPolyData::New();
+    copy->ShallowCopy(source.GetOutput());
+    return copy;
+}
+
 vtkAlgorithmOutput* sphere_source_get_output_port(vtkSphereSource& sphere_source) {
     return sphere_source.GetOutputPort();
 }
@@ -0,0 +1,78 @@
+#include "cxx.h"
+#include "vtk_stl_reader.h"
+#include "vtk_stl_reader.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkSTLReader.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkSTLReader* vtk_stl_reader_new() {
+    vtkSTLReader* obj = vtkSTLReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkSTLReader");
+    }
+    return obj;
+}
+
+void vtk_stl_reader_delete(vtkSTLReader& reader) {
+    reader.Delete();
+}
+
+// File name
+void stl_reader_set_file_name(vtkSTLReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String stl_reader_get_file_name(const vtkSTLReader& reader) {
+    const char* name = const_cast<vtkSTLReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void stl_reader_set_merging(vtkSTLReader& reader, bool merging) {
+    reader.SetMerging(merging);
+}
+
+bool stl_reader_get_merging(const vtkSTLReader& reader) {
+    return const_cast<vtkSTLReader&>(reader).GetMerging() != 0;
+}
+
+void stl_reader_set_scalar_tags(vtkSTLReader& reader, bool scalar_tags) {
+    reader.SetScalarTags(scalar_tags);
+}
+
+bool stl_reader_get_scalar_tags(const vtkSTLReader& reader) {
+    return const_cast<vtkSTLReader&>(reader).GetScalarTags() != 0;
+}
+
+// Execution
+void stl_reader_update(vtkSTLReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkPolyData* stl_reader_get_output(vtkSTLReader& reader) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkPolyData* copy = vtkPolyData::New();
+    copy->ShallowCopy(reader.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* stl_reader_get_output_port(vtkSTLReader& reader) {
+    return reader.GetOutputPort();
+}
@@ -0,0 +1,70 @@
+#include "cxx.h"
+#include "vtk_stl_writer.h"
+#include "vtk_stl_writer.rs.h"
+
+#include <vtkSTLWriter.h>
+#include <vtkErrorCode.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkSTLWriter* vtk_stl_writer_new() {
+    vtkSTLWriter* obj = vtkSTLWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkSTLWriter");
+    }
+    return obj;
+}
+
+void vtk_stl_writer_delete(vtkSTLWriter& writer) {
+    writer.Delete();
+}
+
+// File name
+void stl_writer_set_file_name(vtkSTLWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String stl_writer_get_file_name(const vtkSTLWriter& writer) {
+    const char* name = const_cast<vtkSTLWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Options
+void stl_writer_set_header(vtkSTLWriter& writer, rust::Str header) {
+    writer.SetHeader(std::string(header).c_str());
+}
+
+rust::String stl_writer_get_header(const vtkSTLWriter& writer) {
+    const char* value = const_cast<vtkSTLWriter&>(writer).GetHeader();
+    return rust::String(value ? value : "");
+}
+
+void stl_writer_set_file_type(vtkSTLWriter& writer, int file_type) {
+    writer.SetFileType(file_type);
+}
+
+int stl_writer_get_file_type(const vtkSTLWriter& writer) {
+    return const_cast<vtkSTLWriter&>(writer).GetFileType();
+}
+
+// Input
+void stl_writer_set_input_connection(vtkSTLWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void stl_writer_set_input_data(vtkSTLWriter& writer, vtkPolyData* poly_data) {
+    writer.SetInputData(poly_data);
+}
+
+// Output
+void stl_writer_write(vtkSTLWriter& writer) {
+    int success = writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (!success) {
+        throw std::runtime_error("vtkSTLWriter failed (missing input?)");
+    }
+}
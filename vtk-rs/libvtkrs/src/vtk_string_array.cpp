@@ -0,0 +1,61 @@
+#include "vtk_string_array.h"
+#include <vtkStringArray.h>
+
+extern "C" {
+
+void* string_array_new() {
+    return vtkStringArray::New();
+}
+
+void string_array_delete(void* array) {
+    if (array) {
+        static_cast<vtkStringArray*>(array)->Delete();
+    }
+}
+
+void string_array_set_number_of_values(void* array, int64_t num_values) {
+    if (!array) return;
+    static_cast<vtkStringArray*>(array)->SetNumberOfValues(static_cast<vtkIdType>(num_values));
+}
+
+int64_t string_array_get_number_of_values(void* array) {
+    if (!array) return 0;
+    return static_cast<int64_t>(static_cast<vtkStringArray*>(array)->GetNumberOfValues());
+}
+
+void string_array_set_name(void* array, const char* name) {
+    if (!array || !name) return;
+    static_cast<vtkStringArray*>(array)->SetName(name);
+}
+
+const char* string_array_get_name(void* array) {
+    if (!array) return nullptr;
+    return static_cast<vtkStringArray*>(array)->GetName();
+}
+
+int64_t string_array_insert_next_value(void* array, const char* value) {
+    if (!array || !value) return -1;
+    return static_cast<int64_t>(static_cast<vtkStringArray*>(array)->InsertNextValue(value));
+}
+
+void string_array_set_value(void* array, int64_t id, const char* value) {
+    if (!array || !value) return;
+    vtkStringArray* strings = static_cast<vtkStringArray*>(array);
+    if (id < 0 || id >= strings->GetNumberOfValues()) return;
+    strings->SetValue(static_cast<vtkIdType>(id), value);
+}
+
+const char* string_array_get_value(void* array, int64_t id) {
+    if (!array) return nullptr;
+    vtkStringArray* strings = static_cast<vtkStringArray*>(array);
+    if (id < 0 || id >= strings->GetNumberOfValues()) return nullptr;
+    // Points into the array, valid until the value is changed
+    return strings->GetValue(static_cast<vtkIdType>(id)).c_str();
+}
+
+void string_array_initialize(void* array) {
+    if (!array) return;
+    static_cast<vtkStringArray*>(array)->Initialize();
+}
+
+} // extern "C"
@@ -0,0 +1,40 @@
+#include "cxx.h"
+#include "vtk_structured_grid.h"
+#include "vtk_structured_grid.rs.h"
+
+#include <vtkStructuredGrid.h>
+#include <vtkPoints.h>
+
+// Create/Delete
+vtkStructuredGrid* vtk_structured_grid_new() {
+    return vtkStructuredGrid::New();
+}
+
+void vtk_structured_grid_delete(vtkStructuredGrid& grid) {
+    grid.Delete();
+}
+
+// Dimensions
+void structured_grid_set_dimensions(vtkStructuredGrid& grid, int nx, int ny, int nz) {
+    grid.SetDimensions(nx, ny, nz);
+}
+
+void structured_grid_get_dimensions(const vtkStructuredGrid& grid, int* nx, int* ny, int* nz) {
+    int dims[3];
+    const_cast<vtkStructuredGrid&>(grid).GetDimensions(dims);
+    *nx = dims[0];
+    *ny = dims[1];
+    *nz = dims[2];
+}
+
+// Points
+void structured_grid_set_points(vtkStructuredGrid& grid, vtkPoints* points) {
+    grid.SetPoints(points);
+}
+
+vtkPoints* structured_grid_get_points(vtkStructuredGrid& grid) {
+    vtkPoints* points = grid.GetPoints();
+    // The Rust wrapper releases its own reference
+    if (points) points->Register(nullptr);
+    return points;
+}
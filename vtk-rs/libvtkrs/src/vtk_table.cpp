@@ -0,0 +1,108 @@
+#include "cxx.h"
+#include "vtk_table.h"
+#include "vtk_table.rs.h"
+
+#include <vtkTable.h>
+#include <vtkDoubleArray.h>
+#include <vtkIntArray.h>
+#include <vtkStringArray.h>
+#include <vtkVariant.h>
+#include <vtkNew.h>
+#include <string>
+
+// Column type codes, matching TableColumnType on the Rust side
+namespace {
+enum ColumnType { Missing = -1, Double = 0, Int = 1, String = 2, Other = 3 };
+
+vtkAbstractArray* column_by_name(const vtkTable& table, rust::Str name) {
+    return const_cast<vtkTable&>(table).GetColumnByName(std::string(name).c_str());
+}
+}
+
+// Create/Delete
+vtkTable* vtk_table_new() {
+    return vtkTable::New();
+}
+
+void vtk_table_delete(vtkTable& table) {
+    table.Delete();
+}
+
+// Shape
+int64_t table_get_number_of_rows(const vtkTable& table) {
+    return static_cast<int64_t>(const_cast<vtkTable&>(table).GetNumberOfRows());
+}
+
+int64_t table_get_number_of_columns(const vtkTable& table) {
+    return static_cast<int64_t>(const_cast<vtkTable&>(table).GetNumberOfColumns());
+}
+
+// Columns
+rust::String table_get_column_name(const vtkTable& table, int64_t index) {
+    const char* name = const_cast<vtkTable&>(table).GetColumnName(static_cast<vtkIdType>(index));
+    return rust::String(name ? name : "");
+}
+
+int table_get_column_type(const vtkTable& table, rust::Str name) {
+    vtkAbstractArray* column = column_by_name(table, name);
+    if (!column) return Missing;
+    if (vtkDoubleArray::SafeDownCast(column)) return Double;
+    if (vtkIntArray::SafeDownCast(column)) return Int;
+    if (vtkStringArray::SafeDownCast(column)) return String;
+    return Other;
+}
+
+void table_remove_column(vtkTable& table, rust::Str name) {
+    table.RemoveColumnByName(std::string(name).c_str());
+}
+
+// String columns
+void table_add_string_column(vtkTable& table, rust::Str name, rust::Vec<rust::String> values) {
+    vtkNew<vtkStringArray> column;
+    column->SetName(std::string(name).c_str());
+    column->SetNumberOfValues(static_cast<vtkIdType>(values.size()));
+    for (size_t i = 0; i < values.size(); ++i) {
+        column->SetValue(static_cast<vtkIdType>(i), std::string(values[i]));
+    }
+    table.AddColumn(column);
+}
+
+rust::Vec<rust::String> table_get_string_column(const vtkTable& table, rust::Str name) {
+    rust::Vec<rust::String> values;
+    vtkStringArray* column = vtkStringArray::SafeDownCast(column_by_name(table, name));
+    if (!column) return values;
+    values.reserve(static_cast<size_t>(column->GetNumberOfValues()));
+    for (vtkIdType i = 0; i < column->GetNumberOfValues(); ++i) {
+        values.push_back(rust::String(column->GetValue(i)));
+    }
+    return values;
+}
+
+rust::String table_get_value_as_string(const vtkTable& table, int64_t row, int64_t column) {
+    vtkVariant value = const_cast<vtkTable&>(table).GetValue(
+        static_cast<vtkIdType>(row), static_cast<vtkIdType>(column));
+    return rust::String(value.ToString());
+}
+
+// Numeric columns
+extern "C" void table_add_column(void* table, void* array) {
+    if (!table || !array) return;
+    static_cast<vtkTable*>(table)->AddColumn(static_cast<vtkAbstractArray*>(array));
+}
+
+extern "C" void* table_get_double_column(void* table, const char* name) {
+    if (!table || !name) return nullptr;
+    vtkDoubleArray* column =
+        vtkDoubleArray::SafeDownCast(static_cast<vtkTable*>(table)->GetColumnByName(name));
+    // The Rust wrapper releases its own reference when dropped
+    if (column) column->Register(nullptr);
+    return column;
+}
+
+extern "C" void* table_get_int_column(void* table, const char* name) {
+    if (!table || !name) return nullptr;
+    vtkIntArray* column =
+        vtkIntArray::SafeDownCast(static_cast<vtkTable*>(table)->GetColumnByName(name));
+    if (column) column->Register(nullptr);
+    return column;
+}
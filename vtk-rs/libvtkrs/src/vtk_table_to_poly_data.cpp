@@ -0,0 +1,89 @@
+#include "cxx.h"
+#include "vtk_table_to_poly_data.h"
+#include "vtk_table_to_poly_data.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkTableToPolyData.h>
+#include <vtkPolyData.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkTableToPolyData* vtk_table_to_poly_data_new() {
+    vtkTableToPolyData* obj = vtkTableToPolyData::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkTableToPolyData");
+    }
+    return obj;
+}
+
+void vtk_table_to_poly_data_delete(vtkTableToPolyData& filter) {
+    filter.Delete();
+}
+
+// Input
+void table_to_poly_data_set_input_connection(vtkTableToPolyData& filter, vtkAlgorithmOutput* port) {
+    filter.SetInputConnection(port);
+}
+
+void table_to_poly_data_set_input_data(vtkTableToPolyData& filter, vtkTable* table) {
+    filter.SetInputData(table);
+}
+
+// Coordinate columns
+void table_to_poly_data_set_x_column(vtkTableToPolyData& filter, rust::Str name) {
+    filter.SetXColumn(std::string(name).c_str());
+}
+
+void table_to_poly_data_set_y_column(vtkTableToPolyData& filter, rust::Str name) {
+    filter.SetYColumn(std::string(name).c_str());
+}
+
+void table_to_poly_data_set_z_column(vtkTableToPolyData& filter, rust::Str name) {
+    filter.SetZColumn(std::string(name).c_str());
+}
+
+void table_to_poly_data_set_x_column_index(vtkTableToPolyData& filter, int index) {
+    filter.SetXColumnIndex(index);
+}
+
+void table_to_poly_data_set_y_column_index(vtkTableToPolyData& filter, int index) {
+    filter.SetYColumnIndex(index);
+}
+
+void table_to_poly_data_set_z_column_index(vtkTableToPolyData& filter, int index) {
+    filter.SetZColumnIndex(index);
+}
+
+void table_to_poly_data_set_create_2d_points(vtkTableToPolyData& filter, bool enabled) {
+    filter.SetCreate2DPoints(enabled);
+}
+
+void table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays(vtkTableToPolyData& filter, bool enabled) {
+    filter.SetPreserveCoordinateColumnsAsDataArrays(enabled);
+}
+
+// Execution
+void table_to_poly_data_update(vtkTableToPolyData& filter) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = filter.AddObserver(vtkCommand::ErrorEvent, observer);
+    filter.Update();
+    filter.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Output
+vtkPolyData* table_to_poly_data_get_output(vtkTableToPolyData& filter) {
+    // Hand out a shallow copy so the Rust side owns its own reference
+    vtkPolyData* copy = vtkPolyData::New();
+    copy->ShallowCopy(filter.GetOutput());
+    return copy;
+}
+
+vtkAlgorithmOutput* table_to_poly_data_get_output_port(vtkTableToPolyData& filter) {
+    return filter.GetOutputPort();
+}
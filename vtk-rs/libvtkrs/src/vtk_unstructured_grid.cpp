@@ -4,6 +4,9 @@
 
 #include <vtkUnstructuredGrid.h>
 #include <vtkPoints.h>
+#include <vtkIdList.h>
+#include <vtkNew.h>
+#include <vtkUnsignedCharArray.h>
 #include <vector>
 
 // Create/Delete
@@ -20,8 +23,11 @@ void unstructured_grid_set_points(vtkUnstructuredGrid& grid, vtkPoints* points)
     grid.SetPoints(points);
 }
 
-vtkPoints* unstructured_grid_get_points(vtkUnstructuredGrid& grid) {
-    return grid.GetPoints();
+vtkPoints* unstructured_grid_get_points(const vtkUnstructuredGrid& grid) {
+    vtkPoints* points = const_cast<vtkUnstructuredGrid&>(grid).GetPoints();
+    // The Rust wrapper releases its own reference
+    if (points) points->Register(nullptr);
+    return points;
 }
 
 // Cell allocation
@@ -44,21 +50,73 @@ void unstructured_grid_insert_next_cell(
     grid.InsertNextCell(cell_type, static_cast<vtkIdType>(num_points), ids.data());
 }
 
-// Metadata
-int unstructured_grid_get_number_of_points(const vtkUnstructuredGrid& grid) {
-    return const_cast<vtkUnstructuredGrid&>(grid).GetNumberOfPoints();
+int64_t unstructured_grid_insert_next_polyhedron(
+    vtkUnstructuredGrid& grid,
+    rust::Slice<const int64_t> face_stream
+) {
+    // (numFaces, numFace0Pts, id1, id2, ..., numFace1Pts, ...)
+    vtkNew<vtkIdList> ids;
+    ids->SetNumberOfIds(static_cast<vtkIdType>(face_stream.size()));
+    for (size_t i = 0; i < face_stream.size(); ++i) {
+        ids->SetId(static_cast<vtkIdType>(i), static_cast<vtkIdType>(face_stream[i]));
+    }
+    return static_cast<int64_t>(grid.InsertNextCell(VTK_POLYHEDRON, ids));
+}
+
+// Cell access
+vtkCellArray* unstructured_grid_get_cells(const vtkUnstructuredGrid& grid) {
+    vtkCellArray* cells = const_cast<vtkUnstructuredGrid&>(grid).GetCells();
+    // The Rust wrapper releases its own reference
+    if (cells) cells->Register(nullptr);
+    return cells;
+}
+
+// Copy the values of an array, widening them to the element type of the Rust vector
+template <typename T, typename ArrayT>
+static rust::Vec<T> copy_values(ArrayT* array) {
+    rust::Vec<T> values;
+    if (!array) return values;
+    vtkIdType count = array->GetNumberOfValues();
+    values.reserve(static_cast<size_t>(count));
+    for (vtkIdType i = 0; i < count; ++i) {
+        values.push_back(static_cast<T>(array->GetValue(i)));
+    }
+    return values;
+}
+
+// Cells read back from files may use 32 bit storage; they are copied as they are
+rust::Vec<int64_t> unstructured_grid_get_connectivity(const vtkUnstructuredGrid& grid) {
+    vtkCellArray* cells = const_cast<vtkUnstructuredGrid&>(grid).GetCells();
+    if (!cells) return rust::Vec<int64_t>();
+    return cells->IsStorage64Bit()
+        ? copy_values<int64_t>(cells->GetConnectivityArray64())
+        : copy_values<int64_t>(cells->GetConnectivityArray32());
 }
 
-int unstructured_grid_get_number_of_cells(const vtkUnstructuredGrid& grid) {
-    return const_cast<vtkUnstructuredGrid&>(grid).GetNumberOfCells();
+rust::Vec<int64_t> unstructured_grid_get_offsets(const vtkUnstructuredGrid& grid) {
+    vtkCellArray* cells = const_cast<vtkUnstructuredGrid&>(grid).GetCells();
+    if (!cells) return rust::Vec<int64_t>();
+    return cells->IsStorage64Bit()
+        ? copy_values<int64_t>(cells->GetOffsetsArray64())
+        : copy_values<int64_t>(cells->GetOffsetsArray32());
 }
 
-void unstructured_grid_get_bounds(const vtkUnstructuredGrid& grid, double* bounds) {
-    const_cast<vtkUnstructuredGrid&>(grid).GetBounds(bounds);
+rust::Vec<uint8_t> unstructured_grid_get_cell_types(const vtkUnstructuredGrid& grid) {
+    return copy_values<uint8_t>(const_cast<vtkUnstructuredGrid&>(grid).GetCellTypesArray());
 }
 
-// Point data access
-extern "C" vtkPointData* unstructured_grid_get_point_data(void* grid_ptr) {
-    vtkUnstructuredGrid* grid = static_cast<vtkUnstructuredGrid*>(grid_ptr);
-    return grid->GetPointData();
+rust::Vec<int64_t> unstructured_grid_get_face_stream(
+    const vtkUnstructuredGrid& grid,
+    int64_t cell_id
+) {
+    rust::Vec<int64_t> face_stream;
+    vtkUnstructuredGrid& ug = const_cast<vtkUnstructuredGrid&>(grid);
+    if (cell_id < 0 || cell_id >= ug.GetNumberOfCells()) return face_stream;
+    if (ug.GetCellType(static_cast<vtkIdType>(cell_id)) != VTK_POLYHEDRON) return face_stream;
+    vtkNew<vtkIdList> ids;
+    ug.GetFaceStream(static_cast<vtkIdType>(cell_id), ids);
+    for (vtkIdType i = 0; i < ids->GetNumberOfIds(); ++i) {
+        face_stream.push_back(static_cast<int64_t>(ids->GetId(i)));
+    }
+    return face_stream;
 }
@@ -0,0 +1,52 @@
+#include "cxx.h"
+#include "vtk_vrml_exporter.h"
+#include "vtk_vrml_exporter.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkVRMLExporter.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkVRMLExporter* vtk_vrml_exporter_new() {
+    vtkVRMLExporter* obj = vtkVRMLExporter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkVRMLExporter");
+    }
+    return obj;
+}
+
+void vtk_vrml_exporter_delete(vtkVRMLExporter& exporter) {
+    exporter.Delete();
+}
+
+// File name
+void vrml_exporter_set_file_name(vtkVRMLExporter& exporter, rust::Str file_name) {
+    exporter.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String vrml_exporter_get_file_name(const vtkVRMLExporter& exporter) {
+    const char* name = const_cast<vtkVRMLExporter&>(exporter).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Input
+void vrml_exporter_set_render_window(vtkVRMLExporter& exporter, vtkRenderWindow* window) {
+    exporter.SetRenderWindow(window);
+}
+
+// Output
+void vrml_exporter_write(vtkVRMLExporter& exporter) {
+    if (!exporter.GetRenderWindow()) {
+        throw std::runtime_error("no render window set");
+    }
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = exporter.AddObserver(vtkCommand::ErrorEvent, observer);
+    exporter.Write();
+    exporter.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
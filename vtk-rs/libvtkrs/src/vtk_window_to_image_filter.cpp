@@ -0,0 +1,80 @@
+#include "cxx.h"
+#include "vtk_window_to_image_filter.h"
+#include "vtk_window_to_image_filter.rs.h"
+#include "vtk_image_data.h"
+
+#include <vtkWindowToImageFilter.h>
+#include <vtkImageData.h>
+
+// Create/Delete
+vtkWindowToImageFilter* vtk_window_to_image_filter_new() {
+    vtkWindowToImageFilter* obj = vtkWindowToImageFilter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkWindowToImageFilter");
+    }
+    return obj;
+}
+
+void vtk_window_to_image_filter_delete(vtkWindowToImageFilter& filter) {
+    filter.Delete();
+}
+
+// Input
+void window_to_image_filter_set_input(vtkWindowToImageFilter& filter, vtkRenderWindow* window) {
+    filter.SetInput(window);
+}
+
+void window_to_image_filter_set_input_buffer_type_to_rgb(vtkWindowToImageFilter& filter) {
+    filter.SetInputBufferTypeToRGB();
+}
+
+void window_to_image_filter_set_input_buffer_type_to_rgba(vtkWindowToImageFilter& filter) {
+    filter.SetInputBufferTypeToRGBA();
+}
+
+void window_to_image_filter_set_read_front_buffer(vtkWindowToImageFilter& filter, bool front) {
+    filter.SetReadFrontBuffer(front);
+}
+
+bool window_to_image_filter_get_read_front_buffer(const vtkWindowToImageFilter& filter) {
+    return const_cast<vtkWindowToImageFilter&>(filter).GetReadFrontBuffer() != 0;
+}
+
+void window_to_image_filter_set_scale(vtkWindowToImageFilter& filter, int scale) {
+    filter.SetScale(scale);
+}
+
+int window_to_image_filter_get_scale(const vtkWindowToImageFilter& filter) {
+    return const_cast<vtkWindowToImageFilter&>(filter).GetScale()[0];
+}
+
+// Execution
+void window_to_image_filter_modified(vtkWindowToImageFilter& filter) {
+    filter.Modified();
+}
+
+void window_to_image_filter_update(vtkWindowToImageFilter& filter) {
+    filter.Update();
+}
+
+// Output
+rust::Vec<uint8_t> window_to_image_filter_get_rgba(
+    vtkWindowToImageFilter& filter,
+    int& width,
+    int& height
+) {
+    filter.Modified();
+    filter.Update();
+
+    vtkImageData* image = filter.GetOutput();
+    if (!image) {
+        width = 0;
+        height = 0;
+        return rust::Vec<uint8_t>();
+    }
+    return image_data_export_rgba(*image, width, height);
+}
+
+vtkAlgorithmOutput* window_to_image_filter_get_output_port(vtkWindowToImageFilter& filter) {
+    return filter.GetOutputPort();
+}
@@ -0,0 +1,69 @@
+#include "cxx.h"
+#include "vtk_x3d_exporter.h"
+#include "vtk_x3d_exporter.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkX3DExporter.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Create/Delete
+vtkX3DExporter* vtk_x3d_exporter_new() {
+    vtkX3DExporter* obj = vtkX3DExporter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkX3DExporter");
+    }
+    return obj;
+}
+
+void vtk_x3d_exporter_delete(vtkX3DExporter& exporter) {
+    exporter.Delete();
+}
+
+// File name
+void x3d_exporter_set_file_name(vtkX3DExporter& exporter, rust::Str file_name) {
+    exporter.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String x3d_exporter_get_file_name(const vtkX3DExporter& exporter) {
+    const char* name = const_cast<vtkX3DExporter&>(exporter).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+// Input
+void x3d_exporter_set_render_window(vtkX3DExporter& exporter, vtkRenderWindow* window) {
+    exporter.SetRenderWindow(window);
+}
+
+// Options
+void x3d_exporter_set_binary(vtkX3DExporter& exporter, bool enabled) {
+    exporter.SetBinary(enabled);
+}
+
+bool x3d_exporter_get_binary(const vtkX3DExporter& exporter) {
+    return const_cast<vtkX3DExporter&>(exporter).GetBinary();
+}
+
+void x3d_exporter_set_fastest(vtkX3DExporter& exporter, bool enabled) {
+    exporter.SetFastest(enabled);
+}
+
+bool x3d_exporter_get_fastest(const vtkX3DExporter& exporter) {
+    return const_cast<vtkX3DExporter&>(exporter).GetFastest();
+}
+
+// Output
+void x3d_exporter_write(vtkX3DExporter& exporter) {
+    if (!exporter.GetRenderWindow()) {
+        throw std::runtime_error("no render window set");
+    }
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = exporter.AddObserver(vtkCommand::ErrorEvent, observer);
+    exporter.Write();
+    exporter.RemoveObserver(tag);
+
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
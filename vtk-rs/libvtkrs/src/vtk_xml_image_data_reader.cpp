@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_image_data_reader.h"
+#include "vtk_xml_image_data_reader.rs.h"
+
+#include <vtkXMLImageDataReader.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLImageDataReader* vtk_xml_image_data_reader_new() {
+    vtkXMLImageDataReader* obj = vtkXMLImageDataReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLImageDataReader");
+    }
+    return obj;
+}
+
+void vtk_xml_image_data_reader_delete(vtkXMLImageDataReader& reader) {
+    reader.Delete();
+}
+
@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_image_data_writer.h"
+#include "vtk_xml_image_data_writer.rs.h"
+
+#include <vtkXMLImageDataWriter.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLImageDataWriter* vtk_xml_image_data_writer_new() {
+    vtkXMLImageDataWriter* obj = vtkXMLImageDataWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLImageDataWriter");
+    }
+    return obj;
+}
+
+void vtk_xml_image_data_writer_delete(vtkXMLImageDataWriter& writer) {
+    writer.Delete();
+}
+
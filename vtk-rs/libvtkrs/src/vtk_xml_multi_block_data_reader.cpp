@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_multi_block_data_reader.h"
+#include "vtk_xml_multi_block_data_reader.rs.h"
+
+#include <vtkXMLMultiBlockDataReader.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLMultiBlockDataReader* vtk_xml_multi_block_data_reader_new() {
+    vtkXMLMultiBlockDataReader* obj = vtkXMLMultiBlockDataReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLMultiBlockDataReader");
+    }
+    return obj;
+}
+
+void vtk_xml_multi_block_data_reader_delete(vtkXMLMultiBlockDataReader& reader) {
+    reader.Delete();
+}
+
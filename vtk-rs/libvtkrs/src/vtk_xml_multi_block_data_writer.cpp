@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_multi_block_data_writer.h"
+#include "vtk_xml_multi_block_data_writer.rs.h"
+
+#include <vtkXMLMultiBlockDataWriter.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLMultiBlockDataWriter* vtk_xml_multi_block_data_writer_new() {
+    vtkXMLMultiBlockDataWriter* obj = vtkXMLMultiBlockDataWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLMultiBlockDataWriter");
+    }
+    return obj;
+}
+
+void vtk_xml_multi_block_data_writer_delete(vtkXMLMultiBlockDataWriter& writer) {
+    writer.Delete();
+}
+
@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_partitioned_data_set_collection_reader.h"
+#include "vtk_xml_partitioned_data_set_collection_reader.rs.h"
+
+#include <vtkXMLPartitionedDataSetCollectionReader.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLPartitionedDataSetCollectionReader* vtk_xml_partitioned_data_set_collection_reader_new() {
+    vtkXMLPartitionedDataSetCollectionReader* obj = vtkXMLPartitionedDataSetCollectionReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLPartitionedDataSetCollectionReader");
+    }
+    return obj;
+}
+
+void vtk_xml_partitioned_data_set_collection_reader_delete(vtkXMLPartitionedDataSetCollectionReader& reader) {
+    reader.Delete();
+}
+
@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_partitioned_data_set_collection_writer.h"
+#include "vtk_xml_partitioned_data_set_collection_writer.rs.h"
+
+#include <vtkXMLPartitionedDataSetCollectionWriter.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLPartitionedDataSetCollectionWriter* vtk_xml_partitioned_data_set_collection_writer_new() {
+    vtkXMLPartitionedDataSetCollectionWriter* obj = vtkXMLPartitionedDataSetCollectionWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLPartitionedDataSetCollectionWriter");
+    }
+    return obj;
+}
+
+void vtk_xml_partitioned_data_set_collection_writer_delete(vtkXMLPartitionedDataSetCollectionWriter& writer) {
+    writer.Delete();
+}
+
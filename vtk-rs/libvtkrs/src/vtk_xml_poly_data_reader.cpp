@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_poly_data_reader.h"
+#include "vtk_xml_poly_data_reader.rs.h"
+
+#include <vtkXMLPolyDataReader.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLPolyDataReader* vtk_xml_poly_data_reader_new() {
+    vtkXMLPolyDataReader* obj = vtkXMLPolyDataReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLPolyDataReader");
+    }
+    return obj;
+}
+
+void vtk_xml_poly_data_reader_delete(vtkXMLPolyDataReader& reader) {
+    reader.Delete();
+}
+
@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_poly_data_writer.h"
+#include "vtk_xml_poly_data_writer.rs.h"
+
+#include <vtkXMLPolyDataWriter.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLPolyDataWriter* vtk_xml_poly_data_writer_new() {
+    vtkXMLPolyDataWriter* obj = vtkXMLPolyDataWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLPolyDataWriter");
+    }
+    return obj;
+}
+
+void vtk_xml_poly_data_writer_delete(vtkXMLPolyDataWriter& writer) {
+    writer.Delete();
+}
+
@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_rectilinear_grid_reader.h"
+#include "vtk_xml_rectilinear_grid_reader.rs.h"
+
+#include <vtkXMLRectilinearGridReader.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLRectilinearGridReader* vtk_xml_rectilinear_grid_reader_new() {
+    vtkXMLRectilinearGridReader* obj = vtkXMLRectilinearGridReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLRectilinearGridReader");
+    }
+    return obj;
+}
+
+void vtk_xml_rectilinear_grid_reader_delete(vtkXMLRectilinearGridReader& reader) {
+    reader.Delete();
+}
+
@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_rectilinear_grid_writer.h"
+#include "vtk_xml_rectilinear_grid_writer.rs.h"
+
+#include <vtkXMLRectilinearGridWriter.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLRectilinearGridWriter* vtk_xml_rectilinear_grid_writer_new() {
+    vtkXMLRectilinearGridWriter* obj = vtkXMLRectilinearGridWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLRectilinearGridWriter");
+    }
+    return obj;
+}
+
+void vtk_xml_rectilinear_grid_writer_delete(vtkXMLRectilinearGridWriter& writer) {
+    writer.Delete();
+}
+
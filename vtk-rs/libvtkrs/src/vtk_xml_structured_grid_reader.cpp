@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_structured_grid_reader.h"
+#include "vtk_xml_structured_grid_reader.rs.h"
+
+#include <vtkXMLStructuredGridReader.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLStructuredGridReader* vtk_xml_structured_grid_reader_new() {
+    vtkXMLStructuredGridReader* obj = vtkXMLStructuredGridReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLStructuredGridReader");
+    }
+    return obj;
+}
+
+void vtk_xml_structured_grid_reader_delete(vtkXMLStructuredGridReader& reader) {
+    reader.Delete();
+}
+
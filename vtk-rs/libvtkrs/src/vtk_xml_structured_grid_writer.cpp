@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_structured_grid_writer.h"
+#include "vtk_xml_structured_grid_writer.rs.h"
+
+#include <vtkXMLStructuredGridWriter.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLStructuredGridWriter* vtk_xml_structured_grid_writer_new() {
+    vtkXMLStructuredGridWriter* obj = vtkXMLStructuredGridWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLStructuredGridWriter");
+    }
+    return obj;
+}
+
+void vtk_xml_structured_grid_writer_delete(vtkXMLStructuredGridWriter& writer) {
+    writer.Delete();
+}
+
@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_unstructured_grid_reader.h"
+#include "vtk_xml_unstructured_grid_reader.rs.h"
+
+#include <vtkXMLUnstructuredGridReader.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLUnstructuredGridReader* vtk_xml_unstructured_grid_reader_new() {
+    vtkXMLUnstructuredGridReader* obj = vtkXMLUnstructuredGridReader::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLUnstructuredGridReader");
+    }
+    return obj;
+}
+
+void vtk_xml_unstructured_grid_reader_delete(vtkXMLUnstructuredGridReader& reader) {
+    reader.Delete();
+}
+
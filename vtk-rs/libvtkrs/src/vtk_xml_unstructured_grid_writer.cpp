@@ -0,0 +1,20 @@
+#include "cxx.h"
+#include "vtk_xml_unstructured_grid_writer.h"
+#include "vtk_xml_unstructured_grid_writer.rs.h"
+
+#include <vtkXMLUnstructuredGridWriter.h>
+#include <stdexcept>
+
+// Create/Delete
+vtkXMLUnstructuredGridWriter* vtk_xml_unstructured_grid_writer_new() {
+    vtkXMLUnstructuredGridWriter* obj = vtkXMLUnstructuredGridWriter::New();
+    if (!obj) {
+        throw std::runtime_error("Failed to create vtkXMLUnstructuredGridWriter");
+    }
+    return obj;
+}
+
+void vtk_xml_unstructured_grid_writer_delete(vtkXMLUnstructuredGridWriter& writer) {
+    writer.Delete();
+}
+
@@ -0,0 +1,155 @@
+#include "cxx.h"
+#include "vtk_xml_writer.h"
+#include "vtk_xml_writer.rs.h"
+#include "vtk_error_observer.h"
+
+#include <vtkXMLWriter.h>
+#include <vtkXMLReader.h>
+#include <vtkErrorCode.h>
+#include <vtkNew.h>
+#include <stdexcept>
+#include <string>
+
+// Writers: file name
+void xml_writer_set_file_name(vtkXMLWriter& writer, rust::Str file_name) {
+    writer.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String xml_writer_get_file_name(const vtkXMLWriter& writer) {
+    const char* name = const_cast<vtkXMLWriter&>(writer).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+rust::String xml_writer_get_default_file_extension(vtkXMLWriter& writer) {
+    return rust::String(writer.GetDefaultFileExtension());
+}
+
+// Writers: encoding
+void xml_writer_set_data_mode(vtkXMLWriter& writer, int mode) {
+    writer.SetDataMode(mode);
+}
+
+int xml_writer_get_data_mode(const vtkXMLWriter& writer) {
+    return const_cast<vtkXMLWriter&>(writer).GetDataMode();
+}
+
+void xml_writer_set_compressor_type(vtkXMLWriter& writer, int compressor) {
+    writer.SetCompressorType(compressor);
+}
+
+int xml_writer_get_compressor_type(const vtkXMLWriter& writer) {
+    return const_cast<vtkXMLWriter&>(writer).GetCompressorType();
+}
+
+void xml_writer_set_compression_level(vtkXMLWriter& writer, int level) {
+    writer.SetCompressionLevel(level);
+}
+
+int xml_writer_get_compression_level(const vtkXMLWriter& writer) {
+    return const_cast<vtkXMLWriter&>(writer).GetCompressionLevel();
+}
+
+void xml_writer_set_encode_appended_data(vtkXMLWriter& writer, bool encode) {
+    writer.SetEncodeAppendedData(encode);
+}
+
+bool xml_writer_get_encode_appended_data(const vtkXMLWriter& writer) {
+    return const_cast<vtkXMLWriter&>(writer).GetEncodeAppendedData() != 0;
+}
+
+// Writers: output string
+void xml_writer_set_write_to_output_string(vtkXMLWriter& writer, bool enabled) {
+    writer.SetWriteToOutputString(enabled);
+}
+
+rust::Vec<uint8_t> xml_writer_get_output_string(const vtkXMLWriter& writer) {
+    std::string output = const_cast<vtkXMLWriter&>(writer).GetOutputString();
+    rust::Vec<uint8_t> bytes;
+    bytes.reserve(output.size());
+    for (char c : output) {
+        bytes.push_back(static_cast<uint8_t>(c));
+    }
+    return bytes;
+}
+
+// Writers: input and output
+void xml_writer_set_input_connection(vtkXMLWriter& writer, vtkAlgorithmOutput* output) {
+    writer.SetInputConnection(output);
+}
+
+void xml_writer_set_input_data(vtkXMLWriter& writer, vtkDataObject* data) {
+    writer.SetInputDataObject(data);
+}
+
+void xml_writer_write(vtkXMLWriter& writer) {
+    int success = writer.Write();
+    unsigned long code = writer.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (!success) {
+        throw std::runtime_error(std::string(writer.GetClassName()) + " failed (missing input?)");
+    }
+}
+
+// Readers: file name
+void xml_reader_set_file_name(vtkXMLReader& reader, rust::Str file_name) {
+    reader.SetFileName(std::string(file_name).c_str());
+}
+
+rust::String xml_reader_get_file_name(const vtkXMLReader& reader) {
+    const char* name = const_cast<vtkXMLReader&>(reader).GetFileName();
+    return rust::String(name ? name : "");
+}
+
+bool xml_reader_can_read_file(vtkXMLReader& reader, rust::Str file_name) {
+    return reader.CanReadFile(std::string(file_name).c_str()) > 0;
+}
+
+// Readers: execution
+void xml_reader_update(vtkXMLReader& reader) {
+    vtkNew<vtkRsErrorObserver> observer;
+    unsigned long tag = reader.AddObserver(vtkCommand::ErrorEvent, observer);
+    reader.Update();
+    reader.RemoveObserver(tag);
+
+    unsigned long code = reader.GetErrorCode();
+    if (code != vtkErrorCode::NoError) {
+        throw std::runtime_error(vtkErrorCode::GetStringFromErrorCode(code));
+    }
+    if (observer->GetHasError()) {
+        throw std::runtime_error(observer->GetErrorText());
+    }
+}
+
+// Readers: arrays
+int xml_reader_get_number_of_point_arrays(vtkXMLReader& reader) {
+    return reader.GetNumberOfPointArrays();
+}
+
+rust::String xml_reader_get_point_array_name(vtkXMLReader& reader, int index) {
+    const char* name = reader.GetPointArrayName(index);
+    return rust::String(name ? name : "");
+}
+
+int xml_reader_get_number_of_cell_arrays(vtkXMLReader& reader) {
+    return reader.GetNumberOfCellArrays();
+}
+
+rust::String xml_reader_get_cell_array_name(vtkXMLReader& reader, int index) {
+    const char* name = reader.GetCellArrayName(index);
+    return rust::String(name ? name : "");
+}
+
+// Readers: output
+vtkDataObject* xml_reader_get_output(vtkXMLReader& reader) {
+    // Hand out a shallow copy of the same type so the Rust side owns its own reference
+    vtkDataObject* output = reader.GetOutputDataObject(0);
+    vtkDataObject* copy = output->NewInstance();
+    copy->ShallowCopy(output);
+    return copy;
+}
+
+vtkAlgorithmOutput* xml_reader_get_output_port(vtkXMLReader& reader) {
+    return reader.GetOutputPort();
+}
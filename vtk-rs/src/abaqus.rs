@@ -0,0 +1,386 @@
+//! Import of Abaqus `.inp` input decks.
+//!
+//! Reads `*NODE`, `*ELEMENT`, `*NSET` and `*ELSET` (including `GENERATE` and
+//! nested set references) from flat input decks and follows `*INCLUDE`.
+//! Assemblies of several parts with their own numbering are not supported.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::fem_mesh::{ cell_type_for, read_with_includes, FemMeshBuilder };
+use crate::{ FemMesh, UnstructuredGrid, VtkCellType, VtkError };
+
+impl FemMesh {
+    /// Read an Abaqus `.inp` input deck
+    pub fn read_inp(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        let path = path.as_ref();
+        let text = read_with_includes(path, &include_target, 0)?;
+        Self::parse_inp(&text).map_err(|message| VtkError::invalid_file(path, message))
+    }
+
+    /// Parse the content of an Abaqus `.inp` input deck (without following `*INCLUDE`)
+    pub fn parse_inp(text: &str) -> Result<Self, String> {
+        let mut parser = Parser::default();
+        for (number, line) in text.lines().enumerate() {
+            parser.line(line.trim()).map_err(|message|
+                format!("line {}: {}", number + 1, message)
+            )?;
+        }
+        parser.finish_element()?;
+        parser.builder.finish()
+    }
+}
+
+impl UnstructuredGrid {
+    /// Load an Abaqus `.inp` input deck
+    ///
+    /// See [`FemMesh`] for the arrays that are created.
+    pub fn read_inp(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        Ok(FemMesh::read_inp(path)?.to_unstructured_grid())
+    }
+}
+
+/// VTK cell type and number of nodes for an Abaqus element type
+fn element_type(name: &str) -> Option<(VtkCellType, usize)> {
+    let name = name.to_ascii_uppercase();
+    let digits_after = |prefix: &&str| -> Option<usize> {
+        let rest = name.strip_prefix(*prefix)?;
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    };
+
+    let (dimension, num_nodes) = if name == "STRI65" {
+        (2, 6)
+    } else if let Some(n) = ["C3D", "DC3D", "DCC3D", "SC"].iter().find_map(digits_after) {
+        (3, n)
+    } else if
+        let Some(n) = [
+            "CPS", "CPE", "CPEG", "CAX", "CGAX", "DC2D", "DCAX", "M3D", "R3D", "SFM3D", "STRI", "S",
+        ]
+            .iter()
+            .find_map(digits_after)
+    {
+        (2, n)
+    } else if let Some(order) = ["B2", "B3", "PIPE2", "PIPE3"].iter().find_map(digits_after) {
+        // B31 and B33 are two node beams, B32 has a middle node
+        (1, if order == 2 { 3 } else { 2 })
+    } else if let Some(n) = ["T2D", "T3D"].iter().find_map(digits_after) {
+        (1, n)
+    } else if
+        name.starts_with("FRAME") ||
+        name.starts_with("CONN") ||
+        matches!(name.as_str(), "SPRINGA" | "SPRING2" | "DASHPOTA" | "DASHPOT2")
+    {
+        (1, 2)
+    } else if matches!(name.as_str(), "MASS" | "ROTARYI" | "SPRING1" | "DASHPOT1") {
+        (0, 1)
+    } else {
+        return None;
+    };
+    Some((cell_type_for(dimension, num_nodes)?, num_nodes))
+}
+
+/// Split a keyword line into the upper case keyword and its parameters
+fn keyword(line: &str) -> (String, HashMap<String, String>) {
+    let mut parts = line.split(',');
+    let name = parts.next().unwrap_or("").trim().to_ascii_uppercase();
+    let parameters = parts
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let (key, value) = part.split_once('=').unwrap_or((part, ""));
+            (key.trim().to_ascii_uppercase(), value.trim().trim_matches('"').to_string())
+        })
+        .collect();
+    (name, parameters)
+}
+
+fn include_target(line: &str) -> Option<String> {
+    let line = line.trim();
+    if !line.starts_with('*') || line.starts_with("**") {
+        return None;
+    }
+    let (name, parameters) = keyword(line);
+    if name != "*INCLUDE" {
+        return None;
+    }
+    parameters.get("INPUT").cloned()
+}
+
+fn values(line: &str) -> impl Iterator<Item = &str> {
+    line.split(',').map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn parse_id(value: &str) -> Result<usize, String> {
+    value.parse().map_err(|_| format!("invalid number '{}'", value))
+}
+
+enum Block {
+    Ignored,
+    Nodes {
+        set: Option<String>,
+    },
+    Elements {
+        element_type: String,
+        cell_type: VtkCellType,
+        num_nodes: usize,
+        set: Option<String>,
+    },
+    NodeSet {
+        name: String,
+        generate: bool,
+    },
+    ElementSet {
+        name: String,
+        generate: bool,
+    },
+}
+
+struct Parser {
+    builder: FemMeshBuilder,
+    block: Block,
+    /// Values of an element whose definition continues on the next line
+    pending: Vec<String>,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self {
+            builder: FemMeshBuilder::default(),
+            block: Block::Ignored,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl Parser {
+    fn line(&mut self, line: &str) -> Result<(), String> {
+        if line.is_empty() || line.starts_with("**") {
+            return Ok(());
+        }
+        if line.starts_with('*') {
+            self.finish_element()?;
+            self.block = Self::block(line)?;
+            return Ok(());
+        }
+
+        match &self.block {
+            Block::Ignored => {}
+            Block::Nodes { set } => {
+                let values: Vec<&str> = values(line).collect();
+                let id = parse_id(values.first().ok_or("empty node definition")?)?;
+                let mut point = [0.0; 3];
+                for (coordinate, value) in point.iter_mut().zip(&values[1..]) {
+                    *coordinate = value.parse().map_err(|_|
+                        format!("invalid coordinate '{}'", value)
+                    )?;
+                }
+                self.builder.add_node(id, point)?;
+                if let Some(set) = set.clone() {
+                    self.builder.add_to_node_set(&set, &[id]);
+                }
+            }
+            Block::Elements { num_nodes, .. } => {
+                let num_nodes = *num_nodes;
+                self.pending.extend(values(line).map(str::to_string));
+                // Long definitions continue on the next line; extra nodes
+                // (such as the orientation node of beams) are ignored
+                if self.pending.len() > num_nodes || !line.ends_with(',') {
+                    self.finish_element()?;
+                }
+            }
+            Block::NodeSet { name, generate } | Block::ElementSet { name, generate } => {
+                let is_node_set = matches!(self.block, Block::NodeSet { .. });
+                let name = name.clone();
+                let ids = if *generate {
+                    let range = values(line).map(parse_id).collect::<Result<Vec<_>, _>>()?;
+                    match range[..] {
+                        [first, last] => (first..=last).collect(),
+                        [first, last, step] if step > 0 => (first..=last).step_by(step).collect(),
+                        _ => {
+                            return Err(format!("invalid GENERATE range '{}'", line));
+                        }
+                    }
+                } else {
+                    self.set_members(line, is_node_set)?
+                };
+                if is_node_set {
+                    self.builder.add_to_node_set(&name, &ids);
+                } else {
+                    self.builder.add_to_element_set(&name, &ids);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn block(line: &str) -> Result<Block, String> {
+        let (name, parameters) = keyword(line);
+        let block = match name.as_str() {
+            "*NODE" => Block::Nodes { set: parameters.get("NSET").cloned() },
+            "*ELEMENT" => {
+                let element_type = parameters
+                    .get("TYPE")
+                    .ok_or("*ELEMENT without TYPE")?
+                    .to_string();
+                let (cell_type, num_nodes) = self::element_type(&element_type).ok_or_else(||
+                    format!("unsupported Abaqus element type {}", element_type)
+                )?;
+                Block::Elements {
+                    element_type,
+                    cell_type,
+                    num_nodes,
+                    set: parameters.get("ELSET").cloned(),
+                }
+            }
+            "*NSET" => {
+                Block::NodeSet {
+                    name: parameters.get("NSET").ok_or("*NSET without name")?.to_string(),
+                    generate: parameters.contains_key("GENERATE"),
+                }
+            }
+            "*ELSET" => {
+                Block::ElementSet {
+                    name: parameters.get("ELSET").ok_or("*ELSET without name")?.to_string(),
+                    generate: parameters.contains_key("GENERATE"),
+                }
+            }
+            _ => Block::Ignored,
+        };
+        Ok(block)
+    }
+
+    /// Numbers of a set data line, expanding references to other sets
+    fn set_members(&self, line: &str, is_node_set: bool) -> Result<Vec<usize>, String> {
+        let mut ids = Vec::new();
+        for value in values(line) {
+            if let Ok(id) = value.parse() {
+                ids.push(id);
+                continue;
+            }
+            let members = if is_node_set {
+                self.builder.node_set_ids(value)
+            } else {
+                self.builder.element_set_ids(value)
+            };
+            ids.extend(members.ok_or_else(|| format!("unknown set '{}'", value))?);
+        }
+        Ok(ids)
+    }
+
+    fn finish_element(&mut self) -> Result<(), String> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let values = std::mem::take(&mut self.pending);
+        if let Block::Elements { element_type, cell_type, num_nodes, set } = &self.block {
+            if values.len() <= *num_nodes {
+                return Err(format!(
+                    "element {} of type {} needs {} nodes",
+                    values[0],
+                    element_type,
+                    num_nodes
+                ));
+            }
+            let id = parse_id(&values[0])?;
+            let nodes = values[1..=*num_nodes]
+                .iter()
+                .map(|value| parse_id(value))
+                .collect::<Result<Vec<_>, _>>()?;
+            let (element_type, cell_type, set) = (element_type.clone(), *cell_type, set.clone());
+            self.builder.add_element(id, &element_type, cell_type, 0, nodes);
+            if let Some(set) = set {
+                self.builder.add_to_element_set(&set, &[id]);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECK: &str = "*HEADING
+Cantilever with a beam stiffener
+** Nodes of the solid
+*NODE, NSET=ALLNODES
+1, 0., 0., 0.
+2, 1., 0., 0.
+3, 1., 1., 0.
+4, 0., 1., 0.
+5, 0., 0., 1.
+6, 1., 0., 1.
+7, 1., 1., 1.
+8, 0., 1., 1.
+9, 2., 0., 0.
+*ELEMENT, TYPE=C3D8R, ELSET=SOLID
+1, 1, 2, 3, 4, 5, 6, 7, 8
+*ELEMENT, TYPE=B31, ELSET=STIFFENER
+2, 2, 9, 4
+*NSET, NSET=CLAMPED
+1, 4,
+5, 8
+*NSET, NSET=EVEN, GENERATE
+2, 8, 2
+*ELSET, ELSET=ALL
+SOLID, STIFFENER
+*MATERIAL, NAME=STEEL
+*ELASTIC
+210000., 0.3
+";
+
+    #[test]
+    fn reads_nodes_elements_and_sets() {
+        let mesh = FemMesh::parse_inp(DECK).unwrap();
+        assert_eq!(mesh.points.len(), 9);
+        assert_eq!(mesh.points[8], [2.0, 0.0, 0.0]);
+        assert_eq!(mesh.elements.len(), 2);
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::Hexahedron);
+        assert_eq!(mesh.elements[0].element_type, "C3D8R");
+        assert_eq!(mesh.elements[1].cell_type, VtkCellType::Line);
+        assert_eq!(mesh.elements[1].point_ids, vec![1, 8]);
+
+        assert_eq!(mesh.node_set("allnodes").unwrap().ids.len(), 9);
+        assert_eq!(mesh.node_set("CLAMPED").unwrap().ids, vec![1, 4, 5, 8]);
+        assert_eq!(mesh.node_set("EVEN").unwrap().ids, vec![2, 4, 6, 8]);
+        assert_eq!(mesh.element_set("ALL").unwrap().ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn element_definitions_continue_on_next_line() {
+        let mut deck = String::from("*NODE\n");
+        for i in 1..=20 {
+            deck.push_str(&format!("{}, {}., 0., 0.\n", i, i));
+        }
+        deck.push_str("*ELEMENT, TYPE=C3D20R\n");
+        deck.push_str("7, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,\n");
+        deck.push_str("16, 17, 18, 19, 20\n");
+        let mesh = FemMesh::parse_inp(&deck).unwrap();
+        assert_eq!(mesh.elements[0].id, 7);
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::QuadraticHexahedron);
+        assert_eq!(mesh.elements[0].point_ids, (0..20).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn element_types_are_mapped() {
+        assert_eq!(element_type("C3D10M"), Some((VtkCellType::QuadraticTetra, 10)));
+        assert_eq!(element_type("CPS4R"), Some((VtkCellType::Quad, 4)));
+        assert_eq!(element_type("CPEG3"), Some((VtkCellType::Triangle, 3)));
+        assert_eq!(element_type("S8R5"), Some((VtkCellType::QuadraticQuad, 8)));
+        assert_eq!(element_type("STRI65"), Some((VtkCellType::QuadraticTriangle, 6)));
+        assert_eq!(element_type("SC8R"), Some((VtkCellType::Hexahedron, 8)));
+        assert_eq!(element_type("b32"), Some((VtkCellType::QuadraticEdge, 3)));
+        assert_eq!(element_type("T3D2"), Some((VtkCellType::Line, 2)));
+        assert_eq!(element_type("MASS"), Some((VtkCellType::Vertex, 1)));
+        assert_eq!(element_type("USER1"), None);
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let error = FemMesh::parse_inp("*ELEMENT, TYPE=XYZ9\n").unwrap_err();
+        assert!(error.starts_with("line 1:"), "{}", error);
+        let deck = "*NODE\n1, 0., 0.\n*ELEMENT, TYPE=T3D2\n1, 1, 2\n";
+        let error = FemMesh::parse_inp(deck).unwrap_err();
+        assert!(error.contains("unknown node 2"), "{}", error);
+    }
+}
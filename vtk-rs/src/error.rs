@@ -0,0 +1,100 @@
+use std::fmt;
+use std::path::{ Path, PathBuf };
+
+/// Errors reported by readers, writers and exporters.
+///
+/// VTK itself signals most I/O failures through error codes on the algorithm and
+/// messages on the console. The bindings translate these into a `VtkError` so that
+/// callers can react with `?` instead of checking for empty outputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VtkError {
+    /// The file does not exist or could not be opened.
+    FileNotFound(PathBuf),
+    /// The file exists but its content could not be interpreted.
+    InvalidFile {
+        path: PathBuf,
+        message: String,
+    },
+    /// Writing the file failed (missing directory, permissions, disk full, ...).
+    WriteFailed {
+        path: PathBuf,
+        message: String,
+    },
+    /// An argument passed to the bindings was rejected before reaching VTK.
+    InvalidArgument(String),
+    /// A rendered image differs from its baseline by more than the allowed tolerance.
+    ImageMismatch {
+        baseline: PathBuf,
+        message: String,
+    },
+}
+
+impl VtkError {
+    pub(crate) fn invalid_file(path: impl AsRef<Path>, message: impl Into<String>) -> Self {
+        VtkError::InvalidFile {
+            path: path.as_ref().to_path_buf(),
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn write_failed(path: impl AsRef<Path>, message: impl Into<String>) -> Self {
+        VtkError::WriteFailed {
+            path: path.as_ref().to_path_buf(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for VtkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VtkError::FileNotFound(path) => write!(f, "file not found: {}", path.display()),
+            VtkError::InvalidFile { path, message } => {
+                write!(f, "could not read {}: {}", path.display(), message)
+            }
+            VtkError::WriteFailed { path, message } => {
+                write!(f, "could not write {}: {}", path.display(), message)
+            }
+            VtkError::InvalidArgument(message) => write!(f, "invalid argument: {}", message),
+            VtkError::ImageMismatch { baseline, message } => {
+                write!(f, "image does not match {}: {}", baseline.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VtkError {}
+
+/// Convert a path into the UTF-8 string VTK expects for file names.
+pub(crate) fn path_to_str(path: &Path) -> Result<&str, VtkError> {
+    path.to_str().ok_or_else(|| {
+        VtkError::InvalidArgument(format!("path is not valid UTF-8: {}", path.display()))
+    })
+}
+
+/// Make sure a file exists before handing it to a VTK reader.
+///
+/// VTK readers only print a warning for missing files and produce an empty output.
+pub(crate) fn check_readable(path: &Path) -> Result<&str, VtkError> {
+    if !path.is_file() {
+        return Err(VtkError::FileNotFound(path.to_path_buf()));
+    }
+    path_to_str(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file_is_reported() {
+        let path = Path::new("this/file/does/not/exist.vtp");
+        assert_eq!(check_readable(path), Err(VtkError::FileNotFound(path.to_path_buf())));
+    }
+
+    #[test]
+    fn display_contains_path() {
+        let err = VtkError::write_failed("out/beam.png", "permission denied");
+        assert_eq!(err.to_string(), "could not write out/beam.png: permission denied");
+    }
+}
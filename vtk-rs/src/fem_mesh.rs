@@ -0,0 +1,312 @@
+//! Finite element meshes imported from solver input decks.
+//!
+//! [`FemMesh`] is filled by the Abaqus (`.inp`) and Nastran (`.bdf`) importers
+//! and converted into an [`UnstructuredGrid`] that can be rendered with a
+//! [`crate::DataSetMapper`] directly. The original node and element numbers
+//! are kept as the [`NODE_ID_ARRAY`] point array and the [`ELEMENT_ID_ARRAY`]
+//! cell array, and every node or element set becomes a point or cell
+//! [`IntArray`] of the same name holding 1 for members and 0 otherwise.
+//!
+//! ```no_run
+//! use vtk_rs as vtk;
+//!
+//! let mesh = vtk::FemMesh::read_inp("bracket.inp")?;
+//! println!("{} element sets", mesh.element_sets.len());
+//!
+//! let mut grid = mesh.to_unstructured_grid();
+//! let mut mapper = vtk::DataSetMapper::new();
+//! mapper.set_input_data(&mut grid);
+//! # Ok::<(), vtk::VtkError>(())
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{ IntArray, Points, UnstructuredGrid, VtkCellType };
+
+/// Name of the point array holding the node number of each point
+pub const NODE_ID_ARRAY: &str = "NodeId";
+
+/// Name of the cell array holding the element number of each cell
+pub const ELEMENT_ID_ARRAY: &str = "ElementId";
+
+/// Name of the cell array holding the property number of each cell (Nastran PID)
+pub const PROPERTY_ID_ARRAY: &str = "PropertyId";
+
+/// A single element of an imported mesh
+#[derive(Debug, Clone, PartialEq)]
+pub struct FemElement {
+    /// Element number in the input deck
+    pub id: usize,
+    /// Element type as written in the input deck (e.g. `C3D8R` or `CBAR`)
+    pub element_type: String,
+    /// VTK cell type the element is mapped to
+    pub cell_type: VtkCellType,
+    /// Property number (0 if the format has none)
+    pub property_id: i32,
+    /// Indices into [`FemMesh::points`], in VTK node order
+    pub point_ids: Vec<i32>,
+}
+
+/// A named set of node or element numbers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FemSet {
+    /// Name of the set
+    pub name: String,
+    /// Node or element numbers of the members
+    pub ids: Vec<usize>,
+}
+
+/// Nodes, elements and sets of a solver input deck
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FemMesh {
+    /// Node coordinates
+    pub points: Vec<[f64; 3]>,
+    /// Node number of each point
+    pub node_ids: Vec<usize>,
+    /// Elements in the order of the input deck
+    pub elements: Vec<FemElement>,
+    /// Node sets
+    pub node_sets: Vec<FemSet>,
+    /// Element sets
+    pub element_sets: Vec<FemSet>,
+}
+
+impl FemMesh {
+    /// Get a node set by name (ignoring case, as the solvers do)
+    pub fn node_set(&self, name: &str) -> Option<&FemSet> {
+        self.node_sets.iter().find(|set| set.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Get an element set by name (ignoring case, as the solvers do)
+    pub fn element_set(&self, name: &str) -> Option<&FemSet> {
+        self.element_sets.iter().find(|set| set.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Convert the mesh into an [`UnstructuredGrid`] with id and set arrays
+    ///
+    /// The [`PROPERTY_ID_ARRAY`] is only added if any element has a property number.
+    pub fn to_unstructured_grid(&self) -> UnstructuredGrid {
+        let mut points = Points::new();
+        for [x, y, z] in &self.points {
+            points.insert_next_point(*x, *y, *z);
+        }
+
+        let mut grid = UnstructuredGrid::new();
+        grid.set_points(&mut points);
+        grid.allocate(self.elements.len() as i32);
+
+        let mut element_ids = IntArray::new_id_array(ELEMENT_ID_ARRAY);
+        let mut property_ids = IntArray::new_id_array(PROPERTY_ID_ARRAY);
+        for element in &self.elements {
+            grid.insert_next_cell(element.cell_type, &element.point_ids);
+            element_ids.insert_next_value(element.id as i32);
+            property_ids.insert_next_value(element.property_id);
+        }
+
+        let mut cell_data = grid.get_cell_data();
+        cell_data.add_int_array(&element_ids);
+        if self.elements.iter().any(|element| element.property_id != 0) {
+            cell_data.add_int_array(&property_ids);
+        }
+        let element_index = index_of(self.elements.iter().map(|element| element.id));
+        for set in &self.element_sets {
+            cell_data.add_int_array(&membership(set, &element_index, self.elements.len()));
+        }
+
+        let mut node_ids = IntArray::new_id_array(NODE_ID_ARRAY);
+        for id in &self.node_ids {
+            node_ids.insert_next_value(*id as i32);
+        }
+        let mut point_data = grid.get_point_data();
+        point_data.add_int_array(&node_ids);
+        let node_index = index_of(self.node_ids.iter().copied());
+        for set in &self.node_sets {
+            point_data.add_int_array(&membership(set, &node_index, self.points.len()));
+        }
+        grid
+    }
+}
+
+fn index_of(ids: impl Iterator<Item = usize>) -> HashMap<usize, usize> {
+    ids.enumerate().map(|(index, id)| (id, index)).collect()
+}
+
+/// Array with 1 for the members of the set and 0 otherwise
+fn membership(set: &FemSet, index: &HashMap<usize, usize>, count: usize) -> IntArray {
+    let mut flags = vec![0; count];
+    for id in &set.ids {
+        if let Some(&i) = index.get(id) {
+            flags[i] = 1;
+        }
+    }
+    let mut array = IntArray::new_id_array(&set.name);
+    for flag in flags {
+        array.insert_next_value(flag);
+    }
+    array
+}
+
+/// Element as read from the input deck, before node numbers are resolved
+struct RawElement {
+    id: usize,
+    element_type: String,
+    cell_type: VtkCellType,
+    property_id: i32,
+    node_ids: Vec<usize>,
+}
+
+/// Collects the content of an input deck and resolves node numbers at the end
+#[derive(Default)]
+pub(crate) struct FemMeshBuilder {
+    mesh: FemMesh,
+    node_index: HashMap<usize, usize>,
+    elements: Vec<RawElement>,
+}
+
+impl FemMeshBuilder {
+    pub(crate) fn add_node(&mut self, id: usize, point: [f64; 3]) -> Result<(), String> {
+        if self.node_index.insert(id, self.mesh.points.len()).is_some() {
+            return Err(format!("node {} is defined twice", id));
+        }
+        self.mesh.node_ids.push(id);
+        self.mesh.points.push(point);
+        Ok(())
+    }
+
+    /// Add an element whose nodes are already in VTK order
+    pub(crate) fn add_element(
+        &mut self,
+        id: usize,
+        element_type: &str,
+        cell_type: VtkCellType,
+        property_id: i32,
+        node_ids: Vec<usize>
+    ) {
+        self.elements.push(RawElement {
+            id,
+            element_type: element_type.to_string(),
+            cell_type,
+            property_id,
+            node_ids,
+        });
+    }
+
+    pub(crate) fn add_to_node_set(&mut self, name: &str, ids: &[usize]) {
+        add_to_set(&mut self.mesh.node_sets, name, ids);
+    }
+
+    pub(crate) fn add_to_element_set(&mut self, name: &str, ids: &[usize]) {
+        add_to_set(&mut self.mesh.element_sets, name, ids);
+    }
+
+    pub(crate) fn node_set_ids(&self, name: &str) -> Option<Vec<usize>> {
+        self.mesh.node_set(name).map(|set| set.ids.clone())
+    }
+
+    pub(crate) fn element_set_ids(&self, name: &str) -> Option<Vec<usize>> {
+        self.mesh.element_set(name).map(|set| set.ids.clone())
+    }
+
+    /// Ids of the elements with one of the given property numbers
+    pub(crate) fn element_ids_with_property(&self, property_ids: &[usize]) -> Vec<usize> {
+        self.elements
+            .iter()
+            .filter(|element| property_ids.contains(&(element.property_id as usize)))
+            .map(|element| element.id)
+            .collect()
+    }
+
+    pub(crate) fn finish(mut self) -> Result<FemMesh, String> {
+        self.mesh.elements.reserve(self.elements.len());
+        for raw in self.elements {
+            let point_ids = raw.node_ids
+                .iter()
+                .map(|node| {
+                    self.node_index.get(node).map(|&i| i as i32).ok_or_else(||
+                        format!("element {} refers to unknown node {}", raw.id, node)
+                    )
+                })
+                .collect::<Result<Vec<i32>, String>>()?;
+            self.mesh.elements.push(FemElement {
+                id: raw.id,
+                element_type: raw.element_type,
+                cell_type: raw.cell_type,
+                property_id: raw.property_id,
+                point_ids,
+            });
+        }
+        Ok(self.mesh)
+    }
+}
+
+fn add_to_set(sets: &mut Vec<FemSet>, name: &str, ids: &[usize]) {
+    match sets.iter_mut().find(|set| set.name.eq_ignore_ascii_case(name)) {
+        Some(set) => set.ids.extend_from_slice(ids),
+        None => {
+            sets.push(FemSet {
+                name: name.to_string(),
+                ids: ids.to_vec(),
+            })
+        }
+    }
+}
+
+/// VTK cell type for an element of the given dimension and number of nodes
+pub(crate) fn cell_type_for(dimension: i32, num_nodes: usize) -> Option<VtkCellType> {
+    use VtkCellType::*;
+    let cell_type = match (dimension, num_nodes) {
+        (0, 1) => Vertex,
+        (1, 2) => Line,
+        (1, 3) => QuadraticEdge,
+        (2, 3) => Triangle,
+        (2, 4) => Quad,
+        (2, 6) => QuadraticTriangle,
+        (2, 8) => QuadraticQuad,
+        (2, 9) => BiquadraticQuad,
+        (3, 4) => Tetra,
+        (3, 5) => Pyramid,
+        (3, 6) => Wedge,
+        (3, 8) => Hexahedron,
+        (3, 10) => QuadraticTetra,
+        (3, 13) => QuadraticPyramid,
+        (3, 15) => QuadraticWedge,
+        (3, 20) => QuadraticHexahedron,
+        (3, 27) => TriquadraticHexahedron,
+        _ => {
+            return None;
+        }
+    };
+    Some(cell_type)
+}
+
+/// Read a text file and replace include statements by the content of the included files
+///
+/// `include_target` returns the file referenced by a line, if it is an include statement.
+/// Relative paths are resolved against the directory of the including file.
+pub(crate) fn read_with_includes(
+    path: &std::path::Path,
+    include_target: &dyn Fn(&str) -> Option<String>,
+    depth: usize
+) -> Result<String, crate::VtkError> {
+    crate::error::check_readable(path)?;
+    let data = std::fs::read(path).map_err(|e| crate::VtkError::invalid_file(path, e.to_string()))?;
+    let text = String::from_utf8_lossy(&data);
+    let mut content = String::with_capacity(text.len());
+    for line in text.lines() {
+        match include_target(line) {
+            Some(target) => {
+                if depth >= 16 {
+                    let message = "includes are nested too deeply";
+                    return Err(crate::VtkError::invalid_file(path, message));
+                }
+                let target = path.parent().unwrap_or(std::path::Path::new("")).join(target);
+                content.push_str(&read_with_includes(&target, include_target, depth + 1)?);
+            }
+            None => {
+                content.push_str(line);
+                content.push('\n');
+            }
+        }
+    }
+    Ok(content)
+}
@@ -0,0 +1,801 @@
+//! Import of Gmsh `.msh` meshes.
+//!
+//! Versions 2.2 and 4.1 of the format are supported, both in ASCII and binary
+//! encoding. Gmsh element types are mapped to [`VtkCellType`] (reordering the
+//! nodes of quadratic cells where the conventions differ), and the physical
+//! group and geometrical entity of every element are stored as cell
+//! [`IntArray`]s named [`GMSH_PHYSICAL_ARRAY`] and [`GMSH_GEOMETRICAL_ARRAY`].
+//!
+//! ```no_run
+//! use vtk_rs as vtk;
+//!
+//! // All elements, including boundary faces and edges
+//! let grid = vtk::UnstructuredGrid::read_msh("bracket.msh")?;
+//!
+//! // Only the volume elements, with the names of the physical groups
+//! let mesh = vtk::GmshMesh::read("bracket.msh")?;
+//! let volume = mesh.to_unstructured_grid_of_dimension(3);
+//! for group in &mesh.physical_names {
+//!     println!("{} {} = {}", group.dimension, group.tag, group.name);
+//! }
+//! # Ok::<(), vtk::VtkError>(())
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::{ IntArray, Points, UnstructuredGrid, VtkCellType, VtkError };
+
+/// Name of the cell array holding the physical group tag of each element (0 if none)
+pub const GMSH_PHYSICAL_ARRAY: &str = "gmsh:physical";
+
+/// Name of the cell array holding the geometrical entity tag of each element
+pub const GMSH_GEOMETRICAL_ARRAY: &str = "gmsh:geometrical";
+
+/// Name of a physical group from the `$PhysicalNames` section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GmshPhysicalName {
+    /// Dimension of the group (0 = points, 1 = curves, 2 = surfaces, 3 = volumes)
+    pub dimension: i32,
+    /// Tag of the group
+    pub tag: i32,
+    /// Name given in Gmsh
+    pub name: String,
+}
+
+/// A single element of a Gmsh mesh
+#[derive(Debug, Clone, PartialEq)]
+pub struct GmshElement {
+    /// Tag of the element in the file
+    pub tag: usize,
+    /// VTK cell type the element is mapped to
+    pub cell_type: VtkCellType,
+    /// Dimension of the element
+    pub dimension: i32,
+    /// Tag of the physical group the element belongs to (0 if none)
+    pub physical_tag: i32,
+    /// Tag of the geometrical entity the element belongs to
+    pub entity_tag: i32,
+    /// Indices into [`GmshMesh::points`], in VTK node order
+    pub point_ids: Vec<i32>,
+}
+
+/// Content of a Gmsh `.msh` file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GmshMesh {
+    /// Node coordinates
+    pub points: Vec<[f64; 3]>,
+    /// Tag of each node in the file
+    pub node_tags: Vec<usize>,
+    /// Elements in the order of the file
+    pub elements: Vec<GmshElement>,
+    /// Names of the physical groups
+    pub physical_names: Vec<GmshPhysicalName>,
+}
+
+impl GmshMesh {
+    /// Read a `.msh` file
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        let path = path.as_ref();
+        crate::error::check_readable(path)?;
+        let data = std::fs::read(path).map_err(|e| VtkError::invalid_file(path, e.to_string()))?;
+        Self::parse(&data).map_err(|message| VtkError::invalid_file(path, message))
+    }
+
+    /// Parse the content of a `.msh` file
+    pub fn parse(data: &[u8]) -> Result<Self, String> {
+        Parser::new(data).parse()
+    }
+
+    /// Get the name of a physical group
+    pub fn physical_name(&self, dimension: i32, tag: i32) -> Option<&str> {
+        self.physical_names
+            .iter()
+            .find(|group| group.dimension == dimension && group.tag == tag)
+            .map(|group| group.name.as_str())
+    }
+
+    /// Get the highest dimension of all elements (-1 for a mesh without elements)
+    pub fn max_dimension(&self) -> i32 {
+        self.elements.iter().map(|element| element.dimension).max().unwrap_or(-1)
+    }
+
+    /// Convert all elements into an [`UnstructuredGrid`]
+    pub fn to_unstructured_grid(&self) -> UnstructuredGrid {
+        self.build_grid(|_| true)
+    }
+
+    /// Convert the elements of one dimension into an [`UnstructuredGrid`]
+    ///
+    /// Useful to drop the boundary elements Gmsh writes for physical groups on
+    /// surfaces and curves, e.g. `to_unstructured_grid_of_dimension(mesh.max_dimension())`.
+    pub fn to_unstructured_grid_of_dimension(&self, dimension: i32) -> UnstructuredGrid {
+        self.build_grid(|element| element.dimension == dimension)
+    }
+
+    fn build_grid(&self, filter: impl Fn(&GmshElement) -> bool) -> UnstructuredGrid {
+        let mut points = Points::new();
+        for [x, y, z] in &self.points {
+            points.insert_next_point(*x, *y, *z);
+        }
+
+        let mut grid = UnstructuredGrid::new();
+        grid.set_points(&mut points);
+
+        let elements: Vec<&GmshElement> = self.elements.iter().filter(|e| filter(e)).collect();
+        grid.allocate(elements.len() as i32);
+
+        let mut physical = IntArray::new_id_array(GMSH_PHYSICAL_ARRAY);
+        let mut geometrical = IntArray::new_id_array(GMSH_GEOMETRICAL_ARRAY);
+        for element in elements {
+            grid.insert_next_cell(element.cell_type, &element.point_ids);
+            physical.insert_next_value(element.physical_tag);
+            geometrical.insert_next_value(element.entity_tag);
+        }
+
+        let mut cell_data = grid.get_cell_data();
+        cell_data.add_int_array(&physical);
+        cell_data.add_int_array(&geometrical);
+        grid
+    }
+}
+
+impl UnstructuredGrid {
+    /// Load a Gmsh `.msh` file (versions 2.2 and 4.1, ASCII or binary)
+    ///
+    /// All elements are included. See [`GmshMesh`] to select elements by dimension.
+    pub fn read_msh(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        Ok(GmshMesh::read(path)?.to_unstructured_grid())
+    }
+}
+
+/// Gmsh node order to VTK node order for the cells where they differ
+const TETRA_10_ORDER: &[usize] = &[0, 1, 2, 3, 4, 5, 6, 7, 9, 8];
+const HEXAHEDRON_20_ORDER: &[usize] = &[
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 11, 13, 9, 16, 18, 19, 17, 10, 12, 14, 15,
+];
+const HEXAHEDRON_27_ORDER: &[usize] = &[
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 11, 13, 9, 16, 18, 19, 17, 10, 12, 14, 15,
+    22, 23, 21, 24, 20, 25, 26,
+];
+const WEDGE_15_ORDER: &[usize] = &[0, 1, 2, 3, 4, 5, 6, 9, 7, 12, 14, 13, 8, 10, 11];
+const PYRAMID_13_ORDER: &[usize] = &[0, 1, 2, 3, 4, 5, 8, 10, 6, 7, 9, 11, 12];
+
+struct ElementType {
+    cell_type: VtkCellType,
+    num_nodes: usize,
+    dimension: i32,
+    order: Option<&'static [usize]>,
+}
+
+fn element_type(gmsh_type: i32) -> Option<ElementType> {
+    use VtkCellType::*;
+    let (cell_type, num_nodes, dimension, order) = match gmsh_type {
+        1 => (Line, 2, 1, None),
+        2 => (Triangle, 3, 2, None),
+        3 => (Quad, 4, 2, None),
+        4 => (Tetra, 4, 3, None),
+        5 => (Hexahedron, 8, 3, None),
+        6 => (Wedge, 6, 3, None),
+        7 => (Pyramid, 5, 3, None),
+        8 => (QuadraticEdge, 3, 1, None),
+        9 => (QuadraticTriangle, 6, 2, None),
+        10 => (BiquadraticQuad, 9, 2, None),
+        11 => (QuadraticTetra, 10, 3, Some(TETRA_10_ORDER)),
+        12 => (TriquadraticHexahedron, 27, 3, Some(HEXAHEDRON_27_ORDER)),
+        15 => (Vertex, 1, 0, None),
+        16 => (QuadraticQuad, 8, 2, None),
+        17 => (QuadraticHexahedron, 20, 3, Some(HEXAHEDRON_20_ORDER)),
+        18 => (QuadraticWedge, 15, 3, Some(WEDGE_15_ORDER)),
+        19 => (QuadraticPyramid, 13, 3, Some(PYRAMID_13_ORDER)),
+        _ => {
+            return None;
+        }
+    };
+    Some(ElementType { cell_type, num_nodes, dimension, order })
+}
+
+fn unsupported_element(gmsh_type: i32) -> String {
+    format!("unsupported Gmsh element type {}", gmsh_type)
+}
+
+/// Element as read from the file, before node tags are resolved
+struct RawElement {
+    tag: usize,
+    gmsh_type: i32,
+    physical_tag: i32,
+    entity_tag: i32,
+    node_tags: Vec<usize>,
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+    version: u32,
+    binary: bool,
+    size_t: usize,
+    mesh: GmshMesh,
+    elements: Vec<RawElement>,
+    /// Physical tags of the geometrical entities, indexed by (dimension, tag) (version 4 only)
+    entity_physicals: HashMap<(i32, i32), i32>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            version: 0,
+            binary: false,
+            size_t: 8,
+            mesh: GmshMesh::default(),
+            elements: Vec::new(),
+            entity_physicals: HashMap::new(),
+        }
+    }
+
+    fn parse(mut self) -> Result<GmshMesh, String> {
+        while let Some(line) = self.line() {
+            let section = match line.strip_prefix('$') {
+                Some(section) => section.to_string(),
+                None => {
+                    continue;
+                }
+            };
+            match section.as_str() {
+                "MeshFormat" => self.parse_mesh_format()?,
+                "PhysicalNames" => self.parse_physical_names()?,
+                "Entities" if self.version == 4 => self.parse_entities()?,
+                "Nodes" if self.version == 2 => self.parse_nodes_v2()?,
+                "Nodes" if self.version == 4 => self.parse_nodes_v4()?,
+                "Elements" if self.version == 2 => self.parse_elements_v2()?,
+                "Elements" if self.version == 4 => self.parse_elements_v4()?,
+                _ if self.version == 0 => {
+                    return Err("missing $MeshFormat section".to_string());
+                }
+                _ => {}
+            }
+            self.skip_past(&format!("$End{}", section))?;
+        }
+        if self.version == 0 {
+            return Err("not a Gmsh mesh file".to_string());
+        }
+        self.resolve()
+    }
+
+    fn parse_mesh_format(&mut self) -> Result<(), String> {
+        let version: String = self.token()?.to_string();
+        let file_type: i32 = self.parse_token()?;
+        let data_size: usize = self.parse_token()?;
+        self.version = if version.starts_with("2.") {
+            2
+        } else if version == "4.1" {
+            4
+        } else {
+            return Err(format!("unsupported .msh version {} (expected 2.x or 4.1)", version));
+        };
+        self.binary = file_type == 1;
+        self.size_t = data_size;
+        if self.binary {
+            if data_size != 4 && data_size != 8 {
+                return Err(format!("unsupported data size {}", data_size));
+            }
+            self.line();
+            let one = self.read_i32()?;
+            if one != 1 {
+                return Err("big-endian binary files are not supported".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_physical_names(&mut self) -> Result<(), String> {
+        let count: usize = self.parse_token()?;
+        self.line();
+        for _ in 0..count {
+            let line = self.line().ok_or("unexpected end of $PhysicalNames")?;
+            let mut parts = line.splitn(3, char::is_whitespace);
+            let dimension = parts.next().and_then(|s| s.parse().ok());
+            let tag = parts.next().and_then(|s| s.parse().ok());
+            let name = parts.next().unwrap_or("").trim().trim_matches('"');
+            match (dimension, tag) {
+                (Some(dimension), Some(tag)) => {
+                    self.mesh.physical_names.push(GmshPhysicalName {
+                        dimension,
+                        tag,
+                        name: name.to_string(),
+                    });
+                }
+                _ => {
+                    return Err(format!("invalid physical name '{}'", line));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_entities(&mut self) -> Result<(), String> {
+        let mut counts = [0usize; 4];
+        for count in counts.iter_mut() {
+            *count = self.read_size()?;
+        }
+        for (dimension, count) in counts.into_iter().enumerate() {
+            for _ in 0..count {
+                let tag = self.read_int()?;
+                // Points store their coordinates, other entities a bounding box
+                let num_coordinates = if dimension == 0 { 3 } else { 6 };
+                for _ in 0..num_coordinates {
+                    self.read_f64()?;
+                }
+                let num_physicals = self.read_size()?;
+                let mut physical = 0;
+                for i in 0..num_physicals {
+                    let tag = self.read_int()?;
+                    if i == 0 {
+                        physical = tag;
+                    }
+                }
+                if dimension > 0 {
+                    let num_bounding = self.read_size()?;
+                    for _ in 0..num_bounding {
+                        self.read_int()?;
+                    }
+                }
+                self.entity_physicals.insert((dimension as i32, tag), physical);
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_nodes_v2(&mut self) -> Result<(), String> {
+        let count: usize = self.parse_token()?;
+        self.line();
+        self.reserve_nodes(count);
+        for _ in 0..count {
+            let tag = self.read_tag()?;
+            let point = [self.read_f64()?, self.read_f64()?, self.read_f64()?];
+            self.mesh.node_tags.push(tag);
+            self.mesh.points.push(point);
+        }
+        Ok(())
+    }
+
+    fn parse_nodes_v4(&mut self) -> Result<(), String> {
+        let num_blocks = self.read_size()?;
+        let count = self.read_size()?;
+        self.read_size()?;
+        self.read_size()?;
+        self.reserve_nodes(count);
+        for _ in 0..num_blocks {
+            let dimension = self.read_int()?;
+            self.read_int()?;
+            let parametric = self.read_int()? != 0;
+            let num_nodes = self.read_size()?;
+            for _ in 0..num_nodes {
+                let tag = self.read_size()?;
+                self.mesh.node_tags.push(tag);
+            }
+            let num_parameters = if parametric { dimension.max(0) as usize } else { 0 };
+            for _ in 0..num_nodes {
+                let point = [self.read_f64()?, self.read_f64()?, self.read_f64()?];
+                for _ in 0..num_parameters {
+                    self.read_f64()?;
+                }
+                self.mesh.points.push(point);
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_elements_v2(&mut self) -> Result<(), String> {
+        let count: usize = self.parse_token()?;
+        self.line();
+        self.reserve_elements(count);
+        if !self.binary {
+            for _ in 0..count {
+                let tag = self.read_tag()?;
+                let gmsh_type = self.read_int()?;
+                let num_tags = self.read_int()?;
+                let tags = self.read_tags(num_tags)?;
+                self.read_element_v2(tag, gmsh_type, &tags)?;
+            }
+            return Ok(());
+        }
+        // Binary elements come in blocks of the same type and number of tags
+        let mut read = 0;
+        while read < count {
+            let gmsh_type = self.read_i32()?;
+            let num_elements = self.read_i32()?.max(0) as usize;
+            let num_tags = self.read_i32()?;
+            for _ in 0..num_elements {
+                let tag = self.read_tag()?;
+                let tags = self.read_tags(num_tags)?;
+                self.read_element_v2(tag, gmsh_type, &tags)?;
+            }
+            read += num_elements.max(1);
+        }
+        Ok(())
+    }
+
+    fn read_tags(&mut self, num_tags: i32) -> Result<Vec<i32>, String> {
+        (0..num_tags).map(|_| self.read_int()).collect()
+    }
+
+    fn read_element_v2(&mut self, tag: usize, gmsh_type: i32, tags: &[i32]) -> Result<(), String> {
+        let num_nodes = element_type(gmsh_type)
+            .ok_or_else(|| unsupported_element(gmsh_type))?
+            .num_nodes;
+        let node_tags = (0..num_nodes).map(|_| self.read_tag()).collect::<Result<_, _>>()?;
+        self.elements.push(RawElement {
+            tag,
+            gmsh_type,
+            physical_tag: tags.first().copied().unwrap_or(0),
+            entity_tag: tags.get(1).copied().unwrap_or(0),
+            node_tags,
+        });
+        Ok(())
+    }
+
+    fn parse_elements_v4(&mut self) -> Result<(), String> {
+        let num_blocks = self.read_size()?;
+        let count = self.read_size()?;
+        self.read_size()?;
+        self.read_size()?;
+        self.reserve_elements(count);
+        for _ in 0..num_blocks {
+            let dimension = self.read_int()?;
+            let entity_tag = self.read_int()?;
+            let gmsh_type = self.read_int()?;
+            let num_elements = self.read_size()?;
+            let num_nodes = element_type(gmsh_type)
+                .ok_or_else(|| unsupported_element(gmsh_type))?
+                .num_nodes;
+            let physical_tag = self.entity_physicals
+                .get(&(dimension, entity_tag))
+                .copied()
+                .unwrap_or(0);
+            for _ in 0..num_elements {
+                let tag = self.read_size()?;
+                let node_tags = (0..num_nodes).map(|_| self.read_size()).collect::<Result<_, _>>()?;
+                self.elements.push(RawElement {
+                    tag,
+                    gmsh_type,
+                    physical_tag,
+                    entity_tag,
+                    node_tags,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Turn node tags into point indices and reorder the nodes for VTK
+    fn resolve(mut self) -> Result<GmshMesh, String> {
+        let index: HashMap<usize, i32> = self.mesh.node_tags
+            .iter()
+            .enumerate()
+            .map(|(i, tag)| (*tag, i as i32))
+            .collect();
+
+        self.mesh.elements.reserve(self.elements.len());
+        for raw in self.elements {
+            let element_type = element_type(raw.gmsh_type).ok_or_else(||
+                unsupported_element(raw.gmsh_type)
+            )?;
+            let ids = raw.node_tags
+                .iter()
+                .map(|node| {
+                    index.get(node).copied().ok_or_else(||
+                        format!("element {} refers to unknown node {}", raw.tag, node)
+                    )
+                })
+                .collect::<Result<Vec<i32>, String>>()?;
+            let point_ids = match element_type.order {
+                Some(order) => order.iter().map(|&i| ids[i]).collect(),
+                None => ids,
+            };
+            self.mesh.elements.push(GmshElement {
+                tag: raw.tag,
+                cell_type: element_type.cell_type,
+                dimension: element_type.dimension,
+                physical_tag: raw.physical_tag,
+                entity_tag: raw.entity_tag,
+                point_ids,
+            });
+        }
+        Ok(self.mesh)
+    }
+
+    fn reserve_nodes(&mut self, count: usize) {
+        // A node takes at least a tag and three coordinates ("1 0 0 0\n")
+        let count = self.capacity(count, 8);
+        self.mesh.node_tags.reserve(count);
+        self.mesh.points.reserve(count);
+    }
+
+    fn reserve_elements(&mut self, count: usize) {
+        // An element takes at least a tag and one node ("1 1\n")
+        let count = self.capacity(count, 4);
+        self.elements.reserve(count);
+    }
+
+    /// Limit a count read from a header to what the rest of the file can hold, so that a
+    /// corrupt header fails while parsing instead of allocating
+    fn capacity(&self, count: usize, min_record_size: usize) -> usize {
+        count.min((self.data.len() - self.pos) / min_record_size)
+    }
+
+    // Low level reading
+
+    /// Read the next line, without the line break and surrounding whitespace
+    fn line(&mut self) -> Option<&'a str> {
+        let data = self.data;
+        if self.pos >= data.len() {
+            return None;
+        }
+        let rest = &data[self.pos..];
+        let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+        self.pos += (end + 1).min(rest.len());
+        // Binary data is never read line by line, so such lines are never section headers
+        Some(std::str::from_utf8(&rest[..end]).map(str::trim).unwrap_or(""))
+    }
+
+    /// Read the next whitespace separated token
+    fn token(&mut self) -> Result<&'a str, String> {
+        let data = self.data;
+        while self.pos < self.data.len() && self.data[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        while self.pos < self.data.len() && !self.data[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err("unexpected end of file".to_string());
+        }
+        std::str::from_utf8(&data[start..self.pos]).map_err(|_|
+            "invalid characters in ASCII data".to_string()
+        )
+    }
+
+    fn parse_token<T: std::str::FromStr>(&mut self) -> Result<T, String> {
+        let token = self.token()?;
+        token.parse().map_err(|_| format!("invalid number '{}'", token))
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let end = self.pos + N;
+        let bytes = self.data
+            .get(self.pos..end)
+            .ok_or_else(|| "unexpected end of binary data".to_string())?;
+        self.pos = end;
+        Ok(bytes.try_into().expect("slice has N bytes"))
+    }
+
+    fn read_i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_le_bytes(self.bytes()?))
+    }
+
+    /// Read an `int` (a token in ASCII files)
+    fn read_int(&mut self) -> Result<i32, String> {
+        if self.binary { self.read_i32() } else { self.parse_token() }
+    }
+
+    /// Read a node or element tag, which must not be negative
+    fn read_tag(&mut self) -> Result<usize, String> {
+        let tag = self.read_int()?;
+        usize::try_from(tag).map_err(|_| format!("invalid tag {}", tag))
+    }
+
+    /// Read a `size_t` (a token in ASCII files)
+    fn read_size(&mut self) -> Result<usize, String> {
+        if !self.binary {
+            return self.parse_token();
+        }
+        if self.size_t == 4 {
+            Ok(u32::from_le_bytes(self.bytes()?) as usize)
+        } else {
+            Ok(u64::from_le_bytes(self.bytes()?) as usize)
+        }
+    }
+
+    /// Read a `double` (a token in ASCII files)
+    fn read_f64(&mut self) -> Result<f64, String> {
+        if self.binary { Ok(f64::from_le_bytes(self.bytes()?)) } else { self.parse_token() }
+    }
+
+    /// Continue after the next occurrence of `marker` (e.g. `$EndNodes`)
+    fn skip_past(&mut self, marker: &str) -> Result<(), String> {
+        let marker = marker.as_bytes();
+        let found = self.data[self.pos..]
+            .windows(marker.len())
+            .position(|window| window == marker)
+            .ok_or_else(|| format!("missing {}", String::from_utf8_lossy(marker)))?;
+        self.pos += found + marker.len();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V2_ASCII: &str = "$MeshFormat
+2.2 0 8
+$EndMeshFormat
+$PhysicalNames
+2
+2 1 \"inlet face\"
+3 2 \"fluid\"
+$EndPhysicalNames
+$Nodes
+5
+1 0 0 0
+2 1 0 0
+3 0 1 0
+4 0 0 1
+10 1 1 1
+$EndNodes
+$Elements
+3
+1 2 2 1 5 1 2 3
+2 4 2 2 7 1 2 3 4
+3 4 0 2 3 4 10
+$EndElements
+";
+
+    #[test]
+    fn reads_ascii_v2() {
+        let mesh = GmshMesh::parse(V2_ASCII.as_bytes()).unwrap();
+        assert_eq!(mesh.points.len(), 5);
+        assert_eq!(mesh.node_tags[4], 10);
+        assert_eq!(mesh.elements.len(), 3);
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::Triangle);
+        assert_eq!(mesh.elements[0].physical_tag, 1);
+        assert_eq!(mesh.elements[0].entity_tag, 5);
+        assert_eq!(mesh.elements[1].cell_type, VtkCellType::Tetra);
+        assert_eq!(mesh.elements[1].physical_tag, 2);
+        assert_eq!(mesh.elements[2].physical_tag, 0);
+        assert_eq!(mesh.elements[2].point_ids, vec![1, 2, 3, 4]);
+        assert_eq!(mesh.max_dimension(), 3);
+        assert_eq!(mesh.physical_name(2, 1), Some("inlet face"));
+        assert_eq!(mesh.physical_name(3, 2), Some("fluid"));
+    }
+
+    #[test]
+    fn reads_ascii_v4() {
+        let text = "$MeshFormat
+4.1 0 8
+$EndMeshFormat
+$Entities
+0 0 1 1
+3 0 0 0 1 1 0 1 7 0
+1 0 0 0 1 1 1 1 9 1 3
+$EndEntities
+$Nodes
+2 4 1 4
+2 3 0 3
+1
+2
+3
+0 0 0
+1 0 0
+0 1 0
+3 1 1 1
+4
+0.5 0.5 0.5 0.1 0.2 0.3
+$EndNodes
+$Elements
+2 2 1 2
+2 3 2 1
+1 1 2 3
+3 1 4 1
+2 1 2 3 4
+$EndElements
+";
+        let mesh = GmshMesh::parse(text.as_bytes()).unwrap();
+        assert_eq!(mesh.points, vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.5, 0.5, 0.5],
+        ]);
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::Triangle);
+        assert_eq!(mesh.elements[0].physical_tag, 7);
+        assert_eq!(mesh.elements[0].entity_tag, 3);
+        assert_eq!(mesh.elements[1].cell_type, VtkCellType::Tetra);
+        assert_eq!(mesh.elements[1].physical_tag, 9);
+        assert_eq!(mesh.elements[1].dimension, 3);
+    }
+
+    #[test]
+    fn reads_binary_v2() {
+        let mut data = b"$MeshFormat\n2.2 1 8\n".to_vec();
+        data.extend(1i32.to_le_bytes());
+        data.extend(b"\n$EndMeshFormat\n$Nodes\n3\n");
+        for (tag, x) in [(1i32, 0.0f64), (2, 1.0), (3, 2.0)] {
+            data.extend(tag.to_le_bytes());
+            for value in [x, 0.0, 0.0] {
+                data.extend(value.to_le_bytes());
+            }
+        }
+        data.extend(b"\n$EndNodes\n$Elements\n2\n");
+        // One block of two lines with two tags each
+        for value in [1i32, 2, 2, 1, 4, 8, 1, 2, 2, 4, 8, 2, 3] {
+            data.extend(value.to_le_bytes());
+        }
+        data.extend(b"\n$EndElements\n");
+
+        let mesh = GmshMesh::parse(&data).unwrap();
+        assert_eq!(mesh.points[2], [2.0, 0.0, 0.0]);
+        assert_eq!(mesh.elements.len(), 2);
+        assert_eq!(mesh.elements[1].cell_type, VtkCellType::Line);
+        assert_eq!(mesh.elements[1].physical_tag, 4);
+        assert_eq!(mesh.elements[1].point_ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn reads_binary_v4() {
+        let mut data = b"$MeshFormat\n4.1 1 8\n".to_vec();
+        data.extend(1i32.to_le_bytes());
+        data.extend(b"\n$EndMeshFormat\n$Nodes\n");
+        for value in [1u64, 2, 1, 2] {
+            data.extend(value.to_le_bytes());
+        }
+        for value in [1i32, 1, 0] {
+            data.extend(value.to_le_bytes());
+        }
+        data.extend(2u64.to_le_bytes());
+        data.extend(1u64.to_le_bytes());
+        data.extend(2u64.to_le_bytes());
+        for value in [0.0f64, 0.0, 0.0, 1.0, 2.0, 3.0] {
+            data.extend(value.to_le_bytes());
+        }
+        data.extend(b"\n$EndNodes\n$Elements\n");
+        for value in [1u64, 1, 1, 1] {
+            data.extend(value.to_le_bytes());
+        }
+        for value in [1i32, 1, 1] {
+            data.extend(value.to_le_bytes());
+        }
+        for value in [1u64, 5, 2, 1] {
+            data.extend(value.to_le_bytes());
+        }
+        data.extend(b"\n$EndElements\n");
+
+        let mesh = GmshMesh::parse(&data).unwrap();
+        assert_eq!(mesh.points[1], [1.0, 2.0, 3.0]);
+        assert_eq!(mesh.elements[0].tag, 5);
+        assert_eq!(mesh.elements[0].entity_tag, 1);
+        assert_eq!(mesh.elements[0].point_ids, vec![1, 0]);
+    }
+
+    #[test]
+    fn quadratic_tetra_is_reordered() {
+        let mut text = String::from("$MeshFormat\n2.2 0 8\n$EndMeshFormat\n$Nodes\n10\n");
+        for i in 1..=10 {
+            text.push_str(&format!("{} {} 0 0\n", i, i));
+        }
+        text.push_str("$EndNodes\n$Elements\n1\n1 11 0 1 2 3 4 5 6 7 8 9 10\n$EndElements\n");
+        let mesh = GmshMesh::parse(text.as_bytes()).unwrap();
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::QuadraticTetra);
+        assert_eq!(mesh.elements[0].point_ids, vec![0, 1, 2, 3, 4, 5, 6, 7, 9, 8]);
+    }
+
+    #[test]
+    fn invalid_files_are_rejected() {
+        let unsupported = "$MeshFormat\n3.0 0 8\n$EndMeshFormat\n";
+        assert!(GmshMesh::parse(unsupported.as_bytes()).unwrap_err().contains("3.0"));
+
+        let unknown_node = V2_ASCII.replace("3 4 0 2 3 4 10", "3 4 0 2 3 4 11");
+        assert!(GmshMesh::parse(unknown_node.as_bytes()).unwrap_err().contains("node 11"));
+
+        assert!(GmshMesh::parse(b"solid cube\nendsolid\n").is_err());
+
+        let negative_tag = V2_ASCII.replace("10 1 1 1", "-10 1 1 1");
+        assert!(GmshMesh::parse(negative_tag.as_bytes()).unwrap_err().contains("invalid tag -10"));
+
+        let huge_count = V2_ASCII.replace("$Nodes\n5\n", "$Nodes\n1000000000000000000\n");
+        assert!(GmshMesh::parse(huge_count.as_bytes()).is_err());
+    }
+}
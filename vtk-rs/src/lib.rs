@@ -10,7 +10,19 @@ mod macros;
 use macros::*;
 
 // Exposed API
+mod abaqus;
 mod algorithm_output_port;
+mod error;
+mod fem_mesh;
+mod gmsh;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
+mod nastran;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
+mod pvd;
+pub mod testing;
+mod vtkjs;
 mod vtk_abstract_mapper;
 mod vtk_abstract_mapper_3d;
 mod vtk_actor;
@@ -83,6 +95,57 @@ mod vtk_contour_filter;
 mod vtk_plane;
 mod vtk_clip_poly_data;
 mod vtk_warp_vector;
+mod vtk_window_to_image_filter;
+mod vtk_png_writer;
+mod vtk_jpeg_writer;
+mod vtk_png_reader;
+mod vtk_xml_writer;
+mod vtk_poly_data_writer;
+mod vtk_xml_poly_data_writer;
+mod vtk_xml_poly_data_reader;
+mod vtk_xml_unstructured_grid_reader;
+mod vtk_xml_image_data_reader;
+mod vtk_xml_unstructured_grid_writer;
+mod vtk_stl_reader;
+mod vtk_stl_writer;
+mod vtk_obj_reader;
+mod vtk_obj_writer;
+mod vtk_ply_reader;
+mod vtk_ply_writer;
+mod vtk_hdf_reader;
+#[cfg(feature = "v094")]
+mod vtk_hdf_writer;
+mod vtk_exodus_ii_reader;
+mod vtk_meta_image_reader;
+mod vtk_nrrd_reader;
+mod vtk_dicom_image_reader;
+mod vtk_meta_image_writer;
+mod vtk_gltf_exporter;
+mod vtk_x3d_exporter;
+mod vtk_vrml_exporter;
+mod vtk_json_scene_exporter;
+mod vtk_table;
+mod vtk_delimited_text_reader;
+mod vtk_table_to_poly_data;
+mod vtk_data_array;
+mod vtk_string_array;
+mod vtk_field_data;
+mod vtk_xml_image_data_writer;
+mod vtk_data_set;
+mod vtk_rectilinear_grid;
+mod vtk_structured_grid;
+mod vtk_xml_rectilinear_grid_reader;
+mod vtk_xml_rectilinear_grid_writer;
+mod vtk_xml_structured_grid_reader;
+mod vtk_xml_structured_grid_writer;
+mod vtk_composite_data_set;
+mod vtk_multi_block_data_set;
+mod vtk_partitioned_data_set_collection;
+mod vtk_xml_multi_block_data_reader;
+mod vtk_xml_multi_block_data_writer;
+mod vtk_xml_partitioned_data_set_collection_reader;
+mod vtk_xml_partitioned_data_set_collection_writer;
+mod vtk_composite_poly_data_mapper;
 
 // VTK Initialization
 // This function must be called before using any VTK objects
@@ -103,6 +166,11 @@ pub fn init_vtk() {
 }
 
 pub use algorithm_output_port::*;
+pub use error::VtkError;
+pub use fem_mesh::*;
+pub use gmsh::*;
+pub use pvd::*;
+pub use vtkjs::*;
 pub use vtk_abstract_mapper::*;
 pub use vtk_abstract_mapper_3d::*;
 pub use vtk_actor::*;
@@ -180,3 +248,55 @@ pub use vtk_contour_filter::*;
 pub use vtk_plane::*;
 pub use vtk_clip_poly_data::*;
 pub use vtk_warp_vector::*;
+pub use vtk_window_to_image_filter::*;
+pub use vtk_png_writer::*;
+pub use vtk_jpeg_writer::*;
+pub use vtk_png_reader::*;
+pub use vtk_xml_writer::*;
+pub use vtk_poly_data_writer::*;
+pub use vtk_xml_poly_data_writer::*;
+pub use vtk_xml_poly_data_reader::*;
+pub use vtk_xml_unstructured_grid_reader::*;
+pub use vtk_xml_image_data_reader::*;
+pub use vtk_xml_unstructured_grid_writer::*;
+pub use vtk_stl_reader::*;
+pub use vtk_stl_writer::*;
+pub use vtk_obj_reader::*;
+pub use vtk_obj_writer::*;
+pub use vtk_ply_reader::*;
+pub use vtk_ply_writer::*;
+pub use vtk_hdf_reader::*;
+#[cfg(feature = "v094")]
+#[cfg_attr(docsrs, doc(cfg(feature = "v094")))]
+pub use vtk_hdf_writer::*;
+pub use vtk_exodus_ii_reader::*;
+pub use vtk_meta_image_reader::*;
+pub use vtk_nrrd_reader::*;
+pub use vtk_dicom_image_reader::*;
+pub use vtk_meta_image_writer::*;
+pub use vtk_gltf_exporter::*;
+pub use vtk_x3d_exporter::*;
+pub use vtk_vrml_exporter::*;
+pub use vtk_json_scene_exporter::*;
+pub use vtk_table::*;
+pub use vtk_delimited_text_reader::*;
+pub use vtk_table_to_poly_data::*;
+pub use vtk_data_array::*;
+pub use vtk_string_array::*;
+pub use vtk_field_data::*;
+pub use vtk_xml_image_data_writer::*;
+pub use vtk_data_set::*;
+pub use vtk_rectilinear_grid::*;
+pub use vtk_structured_grid::*;
+pub use vtk_xml_rectilinear_grid_reader::*;
+pub use vtk_xml_rectilinear_grid_writer::*;
+pub use vtk_xml_structured_grid_reader::*;
+pub use vtk_xml_structured_grid_writer::*;
+pub use vtk_composite_data_set::*;
+pub use vtk_multi_block_data_set::*;
+pub use vtk_partitioned_data_set_collection::*;
+pub use vtk_xml_multi_block_data_reader::*;
+pub use vtk_xml_multi_block_data_writer::*;
+pub use vtk_xml_partitioned_data_set_collection_reader::*;
+pub use vtk_xml_partitioned_data_set_collection_writer::*;
+pub use vtk_composite_poly_data_mapper::*;
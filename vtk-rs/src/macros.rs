@@ -33,6 +33,22 @@ macro_rules! define_object {
                 }
             }
 
+            /// Wrap an already created VTK object, e.g. one returned by a reader or filter.
+            ///
+            /// # Safety
+            /// `raw_ptr` must point to a live object whose reference is handed over to the
+            /// wrapper. It is released with the delete function when the wrapper is dropped.
+            #[allow(dead_code)]
+            pub(crate) unsafe fn from_raw(raw_ptr: *mut $ptr_type) -> Self {
+                if raw_ptr.is_null() {
+                    panic!("VTK object wrapping failed: {} received a null pointer", stringify!($name));
+                }
+
+                Self {
+                    ptr: core::pin::Pin::new_unchecked(&mut *raw_ptr),
+                }
+            }
+
             /// Get a raw pointer to the underlying VTK object.
             /// 
             /// # Safety
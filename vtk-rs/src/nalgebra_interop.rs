@@ -0,0 +1,139 @@
+//! Conversions between [`nalgebra`] matrices and VTK data (feature `nalgebra`).
+//!
+//! Matrices are column-major, so one column per point or tuple matches the VTK memory
+//! layout and the views below need no copy:
+//! - [`Points`]: a `3 x N` matrix ([`Matrix3xX`]) or a slice of [`Point3`]
+//! - [`DoubleArray`] and [`DataArray`]: a `C x N` matrix, one column per tuple of
+//!   `C` components
+
+use nalgebra::{
+    DMatrixView, DMatrixViewMut, Dim, Matrix, Matrix3xX, MatrixView3xX, Point3, Scalar, Storage,
+    U3,
+};
+
+use crate::{ DataArray, DataArrayType, DoubleArray, Points };
+
+impl Points {
+    /// Create double precision points from a `3 x N` matrix, one column per point
+    pub fn from_matrix<C: Dim, S: Storage<f64, U3, C>>(points: &Matrix<f64, U3, C, S>) -> Self {
+        Self::from_vec(points.column_iter().map(|point| [point[0], point[1], point[2]]).collect())
+    }
+
+    /// Copy the coordinates into a `3 x N` matrix
+    pub fn to_matrix(&self) -> Matrix3xX<f64> {
+        // The view is copied right away
+        match unsafe { self.as_slice() } {
+            Some(points) => Matrix3xX::from_column_slice(points.as_flattened()),
+            None => Matrix3xX::from_iterator(
+                self.get_number_of_points() as usize,
+                self.iter().flat_map(|(_, x, y, z)| [x, y, z])
+            ),
+        }
+    }
+
+    /// View the coordinates as a `3 x N` matrix without copying
+    ///
+    /// Returns `None` if the coordinates are stored as floats; see
+    /// [`set_data_type_to_double`](Self::set_data_type_to_double).
+    ///
+    /// # Safety
+    /// Same as [`as_slice`](Self::as_slice).
+    pub unsafe fn as_matrix(&self) -> Option<MatrixView3xX<'_, f64>> {
+        let points = self.as_slice()?;
+        Some(MatrixView3xX::from_slice(points.as_flattened(), points.len()))
+    }
+
+    /// Create double precision points holding a copy of `points`
+    pub fn from_nalgebra_points(points: &[Point3<f64>]) -> Self {
+        Self::from_vec(points.iter().map(|point| [point.x, point.y, point.z]).collect())
+    }
+
+    /// Copy the coordinates into a vector of points
+    pub fn to_nalgebra_points(&self) -> Vec<Point3<f64>> {
+        self.iter().map(|(_, x, y, z)| Point3::new(x, y, z)).collect()
+    }
+}
+
+macro_rules! impl_matrix_conversions {
+    ([$($T:ident)?] $array:ty, $value:ty) => {
+        impl$(<$T: DataArrayType + Scalar>)? $array {
+            /// Create an array from a `C x N` matrix, one column per tuple of `C` components
+            ///
+            /// # Panics
+            /// If the matrix has no rows.
+            pub fn from_matrix<R: Dim, C: Dim, S: Storage<$value, R, C>>(
+                name: &str,
+                values: &Matrix<$value, R, C, S>
+            ) -> Self {
+                let num_components = values.nrows() as i64;
+                Self::from_vec(name, num_components, values.iter().copied().collect())
+            }
+
+            /// View the values as a `C x N` matrix (components x tuples) without copying
+            ///
+            /// # Safety
+            /// Same as [`as_slice`](Self::as_slice).
+            pub unsafe fn as_matrix(&self) -> DMatrixView<'_, $value> {
+                let (num_components, num_tuples) = self.matrix_shape();
+                DMatrixView::from_slice(self.as_slice(), num_components, num_tuples)
+            }
+
+            /// View the values as a mutable `C x N` matrix without copying
+            ///
+            /// Call [`modified`](Self::modified) afterwards if the array is already
+            /// part of a pipeline.
+            ///
+            /// # Safety
+            /// Same as [`as_mut_slice`](Self::as_mut_slice).
+            pub unsafe fn as_matrix_mut(&mut self) -> DMatrixViewMut<'_, $value> {
+                let (num_components, num_tuples) = self.matrix_shape();
+                DMatrixViewMut::from_slice(self.as_mut_slice(), num_components, num_tuples)
+            }
+
+            fn matrix_shape(&self) -> (usize, usize) {
+                let num_components = self.get_number_of_components().max(1) as usize;
+                (num_components, self.get_number_of_values() as usize / num_components)
+            }
+        }
+    };
+}
+
+impl_matrix_conversions!([] DoubleArray, f64);
+impl_matrix_conversions!([T] DataArray<T>, T);
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{ Matrix3x2, Matrix3xX, Point3 };
+
+    use super::*;
+
+    #[test]
+    fn test_points_matrix() {
+        let nodes = Matrix3xX::from_columns(&[
+            Point3::new(0.0, 0.0, 0.0).coords,
+            Point3::new(1.0, 2.0, 0.5).coords,
+        ]);
+        let points = Points::from_matrix(&nodes);
+        assert_eq!(points.get_point(1), (1.0, 2.0, 0.5));
+        assert_eq!(unsafe { points.as_matrix() }.unwrap(), nodes);
+        assert_eq!(points.to_matrix(), nodes);
+
+        let points = Points::from_nalgebra_points(&[Point3::new(4.0, 5.0, 6.0)]);
+        assert_eq!(points.to_nalgebra_points(), vec![Point3::new(4.0, 5.0, 6.0)]);
+    }
+
+    #[test]
+    fn test_data_array_matrix() {
+        // Two tuples of three components
+        let forces = Matrix3x2::new(1.0, 4.0, 2.0, 5.0, 3.0, 6.0);
+        let mut array = DoubleArray::from_matrix("Force", &forces);
+        assert_eq!(array.get_tuple(1), vec![4.0, 5.0, 6.0]);
+
+        unsafe { array.as_matrix_mut()[(2, 0)] = -3.0 };
+        assert_eq!(array.get_tuple(0), vec![1.0, 2.0, -3.0]);
+        assert_eq!(unsafe { array.as_matrix() }.ncols(), 2);
+
+        let ids = DataArray::<i64>::from_matrix("Id", &nalgebra::RowDVector::from_vec(vec![7, 8]));
+        assert_eq!(ids.get_number_of_tuples(), 2);
+    }
+}
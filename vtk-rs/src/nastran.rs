@@ -0,0 +1,541 @@
+//! Import of Nastran bulk data (`.bdf`, `.dat`, `.nas`).
+//!
+//! Reads `GRID` points (in the basic system or `CORD2R`/`CORD2C`/`CORD2S`
+//! systems), line, shell and solid elements and `SET1`/`SET3` sets, in small,
+//! large and free field format, and follows `INCLUDE` statements. When the file
+//! has executive and case control sections only the bulk data after
+//! `BEGIN BULK` is read.
+//!
+//! `SET1` and `SET3` sets of grid points become node sets, `SET3` sets of
+//! elements or properties become element sets. Sets are named `SET<id>`.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::fem_mesh::{ cell_type_for, read_with_includes, FemMeshBuilder };
+use crate::{ FemMesh, UnstructuredGrid, VtkCellType, VtkError };
+
+impl FemMesh {
+    /// Read a Nastran bulk data file
+    pub fn read_bdf(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        let path = path.as_ref();
+        let text = read_with_includes(path, &include_target, 0)?;
+        Self::parse_bdf(&text).map_err(|message| VtkError::invalid_file(path, message))
+    }
+
+    /// Parse Nastran bulk data (without following `INCLUDE`)
+    pub fn parse_bdf(text: &str) -> Result<Self, String> {
+        let mut deck = Deck::default();
+        for card in cards(text) {
+            deck.card(&card).map_err(|message|
+                format!("{} {}: {}", card.name, card.field(0), message)
+            )?;
+        }
+        deck.finish()
+    }
+}
+
+impl UnstructuredGrid {
+    /// Load a Nastran bulk data file
+    ///
+    /// See [`FemMesh`] for the arrays that are created.
+    pub fn read_bdf(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        Ok(FemMesh::read_bdf(path)?.to_unstructured_grid())
+    }
+}
+
+fn include_target(line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.len() < 8 || !line.get(..7).is_some_and(|s| s.eq_ignore_ascii_case("INCLUDE")) {
+        return None;
+    }
+    Some(line[7..].trim().trim_matches(|c| c == '\'' || c == '"').to_string())
+}
+
+/// A bulk data entry with the fields of all its continuation lines
+struct Card {
+    name: String,
+    fields: Vec<String>,
+}
+
+impl Card {
+    /// Get a data field (0 is the field right after the name), empty if missing
+    fn field(&self, index: usize) -> &str {
+        self.fields.get(index).map(String::as_str).unwrap_or("")
+    }
+
+    fn int(&self, index: usize) -> Result<usize, String> {
+        let value = self.field(index);
+        value.parse().map_err(|_| format!("invalid integer '{}' in field {}", value, index + 2))
+    }
+
+    /// Integer field that defaults to 0 when blank
+    fn int_or_zero(&self, index: usize) -> Result<usize, String> {
+        if self.field(index).is_empty() { Ok(0) } else { self.int(index) }
+    }
+
+    /// Real field that defaults to 0 when blank
+    fn real(&self, index: usize) -> Result<f64, String> {
+        let value = self.field(index);
+        if value.is_empty() {
+            return Ok(0.0);
+        }
+        parse_real(value).ok_or_else(|| format!("invalid real '{}' in field {}", value, index + 2))
+    }
+
+    /// Integers starting at a field, expanding `THRU` ranges
+    fn id_list(&self, start: usize) -> Result<Vec<usize>, String> {
+        let mut ids = Vec::new();
+        let mut index = start;
+        while index < self.fields.len() {
+            let value = self.field(index);
+            if value.eq_ignore_ascii_case("THRU") {
+                let first = *ids.last().ok_or("THRU without start")?;
+                let last = self.int(index + 1)?;
+                ids.extend(first + 1..=last);
+                index += 2;
+                continue;
+            }
+            if !value.is_empty() {
+                ids.push(self.int(index)?);
+            }
+            index += 1;
+        }
+        Ok(ids)
+    }
+}
+
+/// Parse a Nastran real, which may omit the `E` of the exponent (`1.5-3`)
+fn parse_real(value: &str) -> Option<f64> {
+    let value = value.replace(['D', 'd'], "E");
+    if let Ok(number) = value.parse() {
+        return Some(number);
+    }
+    let split = value
+        .char_indices()
+        .skip(1)
+        .find(|&(i, c)| (c == '+' || c == '-') && !value[..i].ends_with(['E', 'e']))?.0;
+    format!("{}E{}", &value[..split], &value[split..]).parse().ok()
+}
+
+/// Expand tabs to the next multiple of 8 columns
+fn expand_tabs(line: &str) -> String {
+    let mut expanded = String::with_capacity(line.len());
+    for c in line.chars() {
+        if c == '\t' {
+            let spaces = 8 - (expanded.chars().count() % 8);
+            expanded.extend(std::iter::repeat_n(' ', spaces));
+        } else {
+            expanded.push(c);
+        }
+    }
+    expanded
+}
+
+/// Split a fixed format line into the first field and `count` data fields of `width` characters
+fn fixed_fields(line: &str, width: usize, count: usize) -> (String, Vec<String>) {
+    let chars: Vec<char> = line.chars().collect();
+    let field = |start: usize, end: usize| -> String {
+        chars[start.min(chars.len())..end.min(chars.len())]
+            .iter()
+            .collect::<String>()
+            .trim()
+            .to_string()
+    };
+    let fields = (0..count).map(|i| field(8 + i * width, 8 + (i + 1) * width)).collect();
+    (field(0, 8), fields)
+}
+
+/// Split bulk data into cards, joining continuation lines
+fn cards(text: &str) -> Vec<Card> {
+    let bulk = text
+        .lines()
+        .position(|line| line.trim().to_ascii_uppercase().starts_with("BEGIN BULK"));
+    let lines = text.lines().skip(bulk.map_or(0, |i| i + 1));
+
+    let mut cards: Vec<Card> = Vec::new();
+    for line in lines {
+        let line = expand_tabs(line.split('$').next().unwrap_or(""));
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.trim().to_ascii_uppercase().starts_with("ENDDATA") {
+            break;
+        }
+
+        let (first, fields) = if line.contains(',') {
+            // Free field: up to 8 data fields, the tenth field is a continuation marker
+            let mut values = line.split(',').map(|value| value.trim().to_string());
+            let first = values.next().unwrap_or_default();
+            // Pad short lines so continuations start at the next card field
+            let mut fields: Vec<String> = values.take(8).collect();
+            fields.resize(8, String::new());
+            (first, fields)
+        } else if line.trim_start().starts_with('*')
+            || line.as_bytes().iter().take(8).any(|&b| b == b'*')
+        {
+            fixed_fields(&line, 16, 4)
+        } else {
+            fixed_fields(&line, 8, 8)
+        };
+
+        let is_continuation = first.is_empty() || first.starts_with('+') || first.starts_with('*');
+        match cards.last_mut() {
+            Some(card) if is_continuation => card.fields.extend(fields),
+            _ if is_continuation => {}
+            _ => {
+                cards.push(Card {
+                    name: first.trim_end_matches('*').to_ascii_uppercase(),
+                    fields,
+                })
+            }
+        }
+    }
+    cards
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CoordinateKind {
+    Rectangular,
+    Cylindrical,
+    Spherical,
+}
+
+struct CoordinateSystem {
+    kind: CoordinateKind,
+    reference: usize,
+    /// Points A (origin), B (on the z axis) and C (in the xz plane) in the reference system
+    points: [[f64; 3]; 3],
+}
+
+/// Element card: whether it has a property field, where the nodes start and
+/// the number of corner and all nodes
+struct ElementCard {
+    dimension: i32,
+    has_property: bool,
+    corners: usize,
+    nodes: usize,
+    order: Option<&'static [usize]>,
+}
+
+/// Nastran node order to VTK node order for the cells where they differ
+const CHEXA_20_ORDER: &[usize] = &[
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 16, 17, 18, 19, 12, 13, 14, 15,
+];
+const CPENTA_15_ORDER: &[usize] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 13, 14, 9, 10, 11];
+
+fn element_card(name: &str) -> Option<ElementCard> {
+    let (dimension, has_property, corners, nodes, order) = match name {
+        "CBAR" | "CBEAM" | "CROD" | "CTUBE" | "CBUSH" | "CVISC" | "CGAP" | "CBEND" => {
+            (1, true, 2, 2, None)
+        }
+        "CONROD" => (1, false, 2, 2, None),
+        "CONM1" | "CONM2" => (0, false, 1, 1, None),
+        "CTRIA3" | "CTRIAR" => (2, true, 3, 3, None),
+        "CTRIA6" => (2, true, 3, 6, None),
+        "CQUAD4" | "CQUADR" | "CSHEAR" => (2, true, 4, 4, None),
+        "CQUAD8" => (2, true, 4, 8, None),
+        "CQUAD" => (2, true, 4, 9, None),
+        "CTETRA" => (3, true, 4, 10, None),
+        "CPYRAM" => (3, true, 5, 13, None),
+        "CPENTA" => (3, true, 6, 15, Some(CPENTA_15_ORDER)),
+        "CHEXA" => (3, true, 8, 20, Some(CHEXA_20_ORDER)),
+        _ => {
+            return None;
+        }
+    };
+    Some(ElementCard { dimension, has_property, corners, nodes, order })
+}
+
+#[derive(Default)]
+struct Deck {
+    builder: FemMeshBuilder,
+    grids: Vec<(usize, usize, [f64; 3])>,
+    coordinate_systems: HashMap<usize, CoordinateSystem>,
+    node_sets: Vec<(String, Vec<usize>)>,
+    element_sets: Vec<(String, Vec<usize>)>,
+    property_sets: Vec<(String, Vec<usize>)>,
+}
+
+impl Deck {
+    fn card(&mut self, card: &Card) -> Result<(), String> {
+        match card.name.as_str() {
+            "GRID" => {
+                let point = [card.real(2)?, card.real(3)?, card.real(4)?];
+                self.grids.push((card.int(0)?, card.int_or_zero(1)?, point));
+            }
+            "CORD2R" | "CORD2C" | "CORD2S" => {
+                let kind = match card.name.as_str() {
+                    "CORD2R" => CoordinateKind::Rectangular,
+                    "CORD2C" => CoordinateKind::Cylindrical,
+                    _ => CoordinateKind::Spherical,
+                };
+                let mut points = [[0.0; 3]; 3];
+                for (i, point) in points.iter_mut().enumerate() {
+                    for (j, coordinate) in point.iter_mut().enumerate() {
+                        *coordinate = card.real(2 + i * 3 + j)?;
+                    }
+                }
+                let system = CoordinateSystem { kind, reference: card.int_or_zero(1)?, points };
+                self.coordinate_systems.insert(card.int(0)?, system);
+            }
+            "SET1" => {
+                self.node_sets.push((format!("SET{}", card.int(0)?), card.id_list(1)?));
+            }
+            "SET3" => {
+                let name = format!("SET{}", card.int(0)?);
+                let ids = card.id_list(2)?;
+                match card.field(1).to_ascii_uppercase().as_str() {
+                    "GRID" | "POINT" => self.node_sets.push((name, ids)),
+                    "ELEM" | "ELEMENT" => self.element_sets.push((name, ids)),
+                    "PROP" => self.property_sets.push((name, ids)),
+                    other => {
+                        return Err(format!("unsupported set type '{}'", other));
+                    }
+                }
+            }
+            name => {
+                if let Some(element) = element_card(name) {
+                    self.element(card, &element)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn element(&mut self, card: &Card, element: &ElementCard) -> Result<(), String> {
+        let id = card.int(0)?;
+        let (property_id, first_node) = if element.has_property {
+            (card.int_or_zero(1)? as i32, 2)
+        } else {
+            (0, 1)
+        };
+
+        let fields: Vec<&str> = (first_node..first_node + element.nodes)
+            .map(|i| card.field(i))
+            .collect();
+        // A CBUSH without its second grid point is grounded: keep it as a vertex
+        if card.name == "CBUSH" && fields[1].is_empty() {
+            let node = card.int(first_node)?;
+            self.builder.add_element(id, &card.name, VtkCellType::Vertex, property_id, vec![node]);
+            return Ok(());
+        }
+        // Midside nodes are optional: use a linear cell unless all of them are given
+        let quadratic = fields.iter().all(|field| !field.is_empty());
+        let count = if quadratic { element.nodes } else { element.corners };
+        let mut nodes = (first_node..first_node + count)
+            .map(|i| card.int(i))
+            .collect::<Result<Vec<_>, _>>()?;
+        if quadratic {
+            if let Some(order) = element.order {
+                nodes = order.iter().map(|&i| nodes[i]).collect();
+            }
+        }
+
+        let cell_type = cell_type_for(element.dimension, count).ok_or_else(||
+            format!("unsupported number of nodes ({})", count)
+        )?;
+        self.builder.add_element(id, &card.name, cell_type, property_id, nodes);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<FemMesh, String> {
+        let grids = std::mem::take(&mut self.grids);
+        for (id, system, point) in grids {
+            let point = self.to_basic(system, point, 0).map_err(|message|
+                format!("GRID {}: {}", id, message)
+            )?;
+            self.builder.add_node(id, point)?;
+        }
+        for (name, ids) in &self.node_sets {
+            self.builder.add_to_node_set(name, ids);
+        }
+        for (name, ids) in &self.element_sets {
+            self.builder.add_to_element_set(name, ids);
+        }
+        for (name, property_ids) in &self.property_sets {
+            let ids = self.builder.element_ids_with_property(property_ids);
+            self.builder.add_to_element_set(name, &ids);
+        }
+        self.builder.finish()
+    }
+
+    /// Convert a point given in a coordinate system to the basic system
+    fn to_basic(&self, system: usize, point: [f64; 3], depth: usize) -> Result<[f64; 3], String> {
+        if system == 0 {
+            return Ok(point);
+        }
+        if depth > 32 {
+            return Err("coordinate systems reference each other in a loop".to_string());
+        }
+        let cs = self.coordinate_systems
+            .get(&system)
+            .ok_or_else(|| format!("coordinate system {} is not defined", system))?;
+
+        let [a, b, c] = cs.points.map(|p| self.to_basic(cs.reference, p, depth + 1));
+        let (a, b, c) = (a?, b?, c?);
+        let e3 = normalize(sub(b, a));
+        let ac = sub(c, a);
+        let e1 = normalize(sub(ac, scale(e3, dot(ac, e3))));
+        let e2 = cross(e3, e1);
+
+        let local = match cs.kind {
+            CoordinateKind::Rectangular => point,
+            CoordinateKind::Cylindrical => {
+                let [r, theta, z] = point;
+                let theta = theta.to_radians();
+                [r * theta.cos(), r * theta.sin(), z]
+            }
+            CoordinateKind::Spherical => {
+                let [r, theta, phi] = point;
+                let (theta, phi) = (theta.to_radians(), phi.to_radians());
+                [r * theta.sin() * phi.cos(), r * theta.sin() * phi.sin(), r * theta.cos()]
+            }
+        };
+        let offset = [scale(e1, local[0]), scale(e2, local[1]), scale(e3, local[2])];
+        Ok(offset.iter().fold(a, |sum, v| [sum[0] + v[0], sum[1] + v[1], sum[2] + v[2]]))
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: [f64; 3], factor: f64) -> [f64; 3] {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn normalize(a: [f64; 3]) -> [f64; 3] {
+    let length = dot(a, a).sqrt();
+    if length == 0.0 { a } else { scale(a, 1.0 / length) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_small_large_and_free_field() {
+        let deck = "\
+SOL 101
+CEND
+BEGIN BULK
+$ small field
+GRID           1              0.      0.      0.
+GRID           2             1.0     0.0     0.0
+GRID*                  3                              0.              1.
+*                     0.
+GRID,4,,1.,1.,0.
+CQUAD4        10       1       1       2       4       3
+CBAR          11       2       1       2      0.      0.      1.
+CTRIA3,12,1,1,2,3
+ENDDATA
+GRID           5              9.      9.      9.
+";
+        let mesh = FemMesh::parse_bdf(deck).unwrap();
+        assert_eq!(mesh.node_ids, vec![1, 2, 3, 4]);
+        assert_eq!(mesh.points[2], [0.0, 1.0, 0.0]);
+        assert_eq!(mesh.points[3], [1.0, 1.0, 0.0]);
+        assert_eq!(mesh.elements.len(), 3);
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::Quad);
+        assert_eq!(mesh.elements[0].property_id, 1);
+        assert_eq!(mesh.elements[0].point_ids, vec![0, 1, 3, 2]);
+        assert_eq!(mesh.elements[1].cell_type, VtkCellType::Line);
+        assert_eq!(mesh.elements[1].element_type, "CBAR");
+        assert_eq!(mesh.elements[1].property_id, 2);
+        assert_eq!(mesh.elements[2].cell_type, VtkCellType::Triangle);
+    }
+
+    #[test]
+    fn continuation_lines_and_quadratic_cells() {
+        let mut deck = String::new();
+        for i in 1..=20 {
+            deck.push_str(&format!("GRID,{},,{}.,0.,0.\n", i, i));
+        }
+        deck.push_str("CHEXA,1,5,1,2,3,4,5,6,+C1\n+C1,7,8,9,10,11,12,13,14,+C2\n");
+        deck.push_str("+C2,15,16,17,18,19,20\n");
+        deck.push_str("CHEXA,2,5,1,2,3,4,5,6,\n,7,8\n");
+        let mesh = FemMesh::parse_bdf(&deck).unwrap();
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::QuadraticHexahedron);
+        assert_eq!(mesh.elements[0].point_ids[12..16], [16, 17, 18, 19]);
+        assert_eq!(mesh.elements[0].point_ids[16..20], [12, 13, 14, 15]);
+        assert_eq!(mesh.elements[1].cell_type, VtkCellType::Hexahedron);
+    }
+
+    #[test]
+    fn coordinate_systems_are_applied() {
+        let deck = "\
+CORD2R,1,,10.,0.,0.,10.,0.,1.,+
++,11.,0.,0.
+CORD2C,2,1,0.,0.,0.,0.,0.,1.,+
++,1.,0.,0.
+GRID,1,1,1.,2.,3.
+GRID,2,2,2.,90.,5.
+";
+        let mesh = FemMesh::parse_bdf(deck).unwrap();
+        let close = |a: [f64; 3], b: [f64; 3]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12);
+        assert!(close(mesh.points[0], [11.0, 2.0, 3.0]), "{:?}", mesh.points[0]);
+        assert!(close(mesh.points[1], [10.0, 2.0, 5.0]), "{:?}", mesh.points[1]);
+    }
+
+    #[test]
+    fn sets_are_collected() {
+        let deck = "\
+GRID,1,,0.,0.,0.
+GRID,2,,1.,0.,0.
+GRID,3,,2.,0.,0.
+CROD,7,4,1,2
+CROD,8,5,2,3
+SET1,10,1,THRU,3
+SET3,11,ELEM,8
+SET3,12,PROP,4
+";
+        let mesh = FemMesh::parse_bdf(deck).unwrap();
+        assert_eq!(mesh.node_set("SET10").unwrap().ids, vec![1, 2, 3]);
+        assert_eq!(mesh.element_set("SET11").unwrap().ids, vec![8]);
+        assert_eq!(mesh.element_set("SET12").unwrap().ids, vec![7]);
+    }
+
+    #[test]
+    fn reals_without_exponent_letter() {
+        assert_eq!(parse_real("1.5-3"), Some(1.5e-3));
+        assert_eq!(parse_real("-2.+2"), Some(-200.0));
+        assert_eq!(parse_real("1.0D+1"), Some(10.0));
+        assert_eq!(parse_real(".5"), Some(0.5));
+        assert_eq!(parse_real("abc"), None);
+    }
+
+    #[test]
+    fn errors_name_the_card() {
+        let error = FemMesh::parse_bdf("GRID,1,,x,0.,0.\n").unwrap_err();
+        assert!(error.starts_with("GRID 1:"), "{}", error);
+        let error = FemMesh::parse_bdf("GRID,1,5,0.,0.,0.\n").unwrap_err();
+        assert!(error.contains("coordinate system 5"), "{}", error);
+    }
+
+    #[test]
+    fn non_ascii_comments() {
+        assert_eq!(include_target("$ ÄÄÄÄ Lastfall"), None);
+        assert_eq!(include_target("INCLUDE 'mesh.bdf'"), Some("mesh.bdf".to_string()));
+        let mesh = FemMesh::parse_bdf("$ ÄÄÄÄ Lastfall\nGRID,1,,0.,0.,0. $ Knoten ÄÖÜ\n").unwrap();
+        assert_eq!(mesh.node_ids, vec![1]);
+    }
+
+    #[test]
+    fn short_free_field_lines_with_continuation() {
+        let deck = "GRID,1,,0.,0.\n+,5.\nGRID,2,,1.,0.,0.\nCBUSH,5,3,1,2\nCBUSH,6,3,2\n";
+        let mesh = FemMesh::parse_bdf(deck).unwrap();
+        assert_eq!(mesh.points[0], [0.0, 0.0, 0.0]);
+        assert_eq!(mesh.elements[0].cell_type, VtkCellType::Line);
+        assert_eq!(mesh.elements[1].cell_type, VtkCellType::Vertex);
+        assert_eq!(mesh.elements[1].point_ids, vec![1]);
+    }
+}
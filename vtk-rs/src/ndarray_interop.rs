@@ -0,0 +1,246 @@
+//! Conversions between [`ndarray`] arrays and VTK data (feature `ndarray`).
+//!
+//! - [`Points`]: an `N x 3` array, one row per point
+//! - [`DoubleArray`] and [`DataArray`]: an `N x C` array, one row per tuple
+//! - [`ImageData`] scalars: an array indexed `[i, j, k]` (or `[i, j, k, component]`),
+//!   with `i` along x, so that the shape matches [`ImageData::get_dimensions`]
+//!
+//! ```no_run
+//! use ndarray::Array3;
+//! use vtk_rs as vtk;
+//!
+//! // Temperature on a 64 x 32 x 16 grid
+//! let temperature = Array3::from_shape_fn((64, 32, 16), |(i, j, k)| (i + j + k) as f64);
+//! let mut image = vtk::ImageData::from_array3("Temperature", &temperature);
+//! image.set_spacing(0.5, 0.5, 0.5);
+//!
+//! let back = image.scalars_to_array3::<f64>()?;
+//! assert_eq!(back, temperature);
+//! # Ok::<(), vtk::VtkError>(())
+//! ```
+
+use ndarray::{
+    Array2, Array3, Array4, ArrayBase, ArrayView2, ArrayViewMut2, Axis, Data, Ix1, Ix2, Ix3, Ix4,
+};
+
+use crate::{ DataArray, DataArrayType, DoubleArray, ImageData, Points, VtkError };
+
+impl Points {
+    /// Create double precision points from an `N x 3` array, one row per point
+    ///
+    /// Fails if the array does not have 3 columns.
+    pub fn from_array2<S: Data<Elem = f64>>(points: &ArrayBase<S, Ix2>) -> Result<Self, VtkError> {
+        if points.ncols() != 3 {
+            return Err(VtkError::InvalidArgument(format!(
+                "points need 3 coordinates per row, got {}",
+                points.ncols()
+            )));
+        }
+        let points = points.rows().into_iter().map(|row| [row[0], row[1], row[2]]).collect();
+        Ok(Self::from_vec(points))
+    }
+
+    /// Copy the coordinates into an `N x 3` array
+    pub fn to_array2(&self) -> Array2<f64> {
+        // The view is copied right away
+        let values = match unsafe { self.as_slice() } {
+            Some(points) => points.as_flattened().to_vec(),
+            None => self.iter().flat_map(|(_, x, y, z)| [x, y, z]).collect(),
+        };
+        let num_points = values.len() / 3;
+        Array2::from_shape_vec((num_points, 3), values).expect("3 values per point")
+    }
+
+    /// View the coordinates as an `N x 3` array without copying
+    ///
+    /// Returns `None` if the coordinates are stored as floats; see
+    /// [`set_data_type_to_double`](Self::set_data_type_to_double).
+    ///
+    /// # Safety
+    /// Same as [`as_slice`](Self::as_slice).
+    pub unsafe fn as_array2(&self) -> Option<ArrayView2<'_, f64>> {
+        let points = self.as_slice()?;
+        Some(ArrayView2::from_shape((points.len(), 3), points.as_flattened()).expect("N x 3"))
+    }
+}
+
+macro_rules! impl_array_conversions {
+    ([$($T:ident)?] $array:ty, $value:ty) => {
+        impl$(<$T: DataArrayType>)? $array {
+            /// Create an array from an `N x C` array, one row per tuple of `C` components
+            ///
+            /// # Panics
+            /// If the array has no columns.
+            pub fn from_array2<S: Data<Elem = $value>>(
+                name: &str,
+                values: &ArrayBase<S, Ix2>
+            ) -> Self {
+                let num_components = values.ncols() as i64;
+                Self::from_vec(name, num_components, values.iter().copied().collect())
+            }
+
+            /// Create a single component array with one tuple per element
+            pub fn from_array1<S: Data<Elem = $value>>(
+                name: &str,
+                values: &ArrayBase<S, Ix1>
+            ) -> Self {
+                Self::from_vec(name, 1, values.iter().copied().collect())
+            }
+
+            /// View the values as an `N x C` array (tuples x components) without copying
+            ///
+            /// # Safety
+            /// Same as [`as_slice`](Self::as_slice).
+            pub unsafe fn as_array2(&self) -> ArrayView2<'_, $value> {
+                let shape = self.array2_shape();
+                ArrayView2::from_shape(shape, self.as_slice()).expect("whole tuples")
+            }
+
+            /// View the values as a mutable `N x C` array without copying
+            ///
+            /// Call [`modified`](Self::modified) afterwards if the array is already
+            /// part of a pipeline.
+            ///
+            /// # Safety
+            /// Same as [`as_mut_slice`](Self::as_mut_slice).
+            pub unsafe fn as_array2_mut(&mut self) -> ArrayViewMut2<'_, $value> {
+                let shape = self.array2_shape();
+                ArrayViewMut2::from_shape(shape, self.as_mut_slice()).expect("whole tuples")
+            }
+
+            fn array2_shape(&self) -> (usize, usize) {
+                let num_components = self.get_number_of_components().max(1) as usize;
+                (self.get_number_of_values() as usize / num_components, num_components)
+            }
+        }
+    };
+}
+
+impl_array_conversions!([] DoubleArray, f64);
+impl_array_conversions!([T] DataArray<T>, T);
+
+impl ImageData {
+    /// Create an image with one scalar per point from an array indexed `[i, j, k]`
+    ///
+    /// The dimensions are the shape of the array; origin and spacing keep their defaults.
+    pub fn from_array3<T, S>(name: &str, values: &ArrayBase<S, Ix3>) -> Self
+    where
+        T: DataArrayType,
+        S: Data<Elem = T>,
+    {
+        Self::from_array4(name, &values.view().insert_axis(Axis(3)))
+    }
+
+    /// Create an image from an array indexed `[i, j, k, component]`
+    ///
+    /// The last axis is the number of components per point (e.g. 3 for a vector field).
+    ///
+    /// # Panics
+    /// If the last axis is empty or a dimension does not fit in an `i32`.
+    pub fn from_array4<T, S>(name: &str, values: &ArrayBase<S, Ix4>) -> Self
+    where
+        T: DataArrayType,
+        S: Data<Elem = T>,
+    {
+        let (nx, ny, nz, num_components) = values.dim();
+        let dimension = |n: usize| i32::try_from(n).expect("image dimension exceeds i32");
+
+        // VTK stores the components of a point together, and x varies fastest
+        let ordered = values.view().permuted_axes([2, 1, 0, 3]).iter().copied().collect();
+        let scalars = DataArray::<T>::from_vec(name, num_components as i64, ordered);
+
+        let mut image = Self::new();
+        image.set_dimensions(dimension(nx), dimension(ny), dimension(nz));
+        image.set_scalars(&scalars);
+        image
+    }
+
+    /// Copy single component scalars into an array indexed `[i, j, k]`
+    ///
+    /// Fails if there are no scalars stored as `T`, or they have several components.
+    pub fn scalars_to_array3<T: DataArrayType>(&self) -> Result<Array3<T>, VtkError> {
+        let values = self.scalars_to_array4::<T>()?;
+        let num_components = values.len_of(Axis(3));
+        if num_components != 1 {
+            return Err(VtkError::InvalidArgument(format!(
+                "image has {} components per point, use scalars_to_array4",
+                num_components
+            )));
+        }
+        Ok(values.index_axis_move(Axis(3), 0))
+    }
+
+    /// Copy the scalars into an array indexed `[i, j, k, component]`
+    ///
+    /// Fails if there are no scalars stored as `T`, or they do not match the dimensions.
+    pub fn scalars_to_array4<T: DataArrayType>(&self) -> Result<Array4<T>, VtkError> {
+        let (nx, ny, nz) = self.get_dimensions();
+        let scalars = self.get_scalars::<T>().ok_or_else(|| {
+            VtkError::InvalidArgument(format!("image has no {:?} scalars", T::DATA_TYPE))
+        })?;
+        let num_components = scalars.get_number_of_components() as usize;
+        let shape = (nz as usize, ny as usize, nx as usize, num_components);
+        let values = unsafe { scalars.as_slice() }.to_vec();
+        let values = Array4::from_shape_vec(shape, values).map_err(|_| {
+            VtkError::InvalidArgument(format!(
+                "{} scalar values do not match dimensions {} x {} x {}",
+                scalars.get_number_of_values(),
+                nx,
+                ny,
+                nz
+            ))
+        })?;
+        Ok(values.permuted_axes([2, 1, 0, 3]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{ array, Array3 };
+
+    use super::*;
+
+    #[test]
+    fn test_points_array2() {
+        let nodes = array![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 2.0, 0.5]];
+        let points = Points::from_array2(&nodes).unwrap();
+        assert_eq!(points.get_point(2), (1.0, 2.0, 0.5));
+        assert_eq!(unsafe { points.as_array2() }.unwrap(), nodes);
+        assert_eq!(points.to_array2(), nodes);
+
+        assert!(Points::from_array2(&array![[0.0, 1.0]]).is_err());
+    }
+
+    #[test]
+    fn test_data_array_array2() {
+        let forces = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let mut array = DoubleArray::from_array2("Force", &forces);
+        assert_eq!(array.get_number_of_tuples(), 2);
+        assert_eq!(array.get_tuple(1), vec![4.0, 5.0, 6.0]);
+
+        unsafe { array.as_array2_mut()[[0, 2]] = -3.0 };
+        assert_eq!(unsafe { array.as_array2() }, array![[1.0, 2.0, -3.0], [4.0, 5.0, 6.0]]);
+
+        // Column views are copied in logical order
+        let ids = DataArray::<i32>::from_array1("Id", &array![[1, 2], [3, 4]].column(1));
+        assert_eq!(unsafe { ids.as_slice() }, &[2, 4]);
+    }
+
+    #[test]
+    fn test_image_data_array3() {
+        let values = Array3::from_shape_fn((4, 3, 2), |(i, j, k)| (100 * k + 10 * j + i) as f32);
+        let image = ImageData::from_array3("Density", &values);
+        assert_eq!(image.get_dimensions(), (4, 3, 2));
+        assert_eq!(image.get_scalar_component(3, 1, 1, 0), 113.0);
+
+        assert_eq!(image.scalars_to_array3::<f32>().unwrap(), values);
+        assert!(image.scalars_to_array3::<f64>().is_err());
+
+        let vectors =
+            Array4::from_shape_fn((2, 2, 1, 3), |(i, j, _, c)| (i + 2 * j + 4 * c) as f64);
+        let image = ImageData::from_array4("Velocity", &vectors);
+        assert_eq!(image.get_scalar_component(1, 1, 0, 2), 11.0);
+        assert!(image.scalars_to_array3::<f64>().is_err());
+        assert_eq!(image.scalars_to_array4::<f64>().unwrap(), vectors);
+    }
+}
@@ -0,0 +1,322 @@
+//! ParaView data (`.pvd`) collection files for time series.
+//!
+//! A `.pvd` file is a small XML index that lists one dataset file per time step
+//! (and optionally per part). ParaView opens it as a single time-dependent source.
+//!
+//! ```no_run
+//! use vtk_rs as vtk;
+//!
+//! let mut collection = vtk::PVDWriter::new("results/beam.pvd");
+//! for step in 0..10 {
+//!     let time = step as f64 * 0.1;
+//!     let mut grid = vtk::UnstructuredGrid::new();
+//!     // ... fill the grid with the results of this step ...
+//!     collection.write_unstructured_grid(time, &grid)?;
+//! }
+//! # Ok::<(), vtk::VtkError>(())
+//! ```
+
+use std::fmt::Write as _;
+use std::path::{ Path, PathBuf };
+
+use crate::{ PolyData, UnstructuredGrid, VtkError };
+
+/// A single `<DataSet>` entry of a `.pvd` collection
+#[derive(Debug, Clone, PartialEq)]
+pub struct PVDEntry {
+    /// Time value of the step
+    pub timestep: f64,
+    /// Index of the part (for collections that split each step into several files)
+    pub part: i32,
+    /// Path of the dataset file, relative to the `.pvd` file
+    pub file: String,
+}
+
+/// Writes a ParaView `.pvd` collection that references one dataset per time step.
+///
+/// The collection file is rewritten after every added step, so that ParaView can
+/// already load the steps computed so far while the solver is still running.
+#[derive(Debug, Clone)]
+pub struct PVDWriter {
+    path: PathBuf,
+    entries: Vec<PVDEntry>,
+}
+
+impl PVDWriter {
+    /// Start a new, empty collection
+    ///
+    /// Nothing is written until the first step is added.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Continue an existing collection, e.g. when a simulation is restarted
+    ///
+    /// Starts empty if the file does not exist yet.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::new(path));
+        }
+        let content = std::fs::read_to_string(path).map_err(|e|
+            VtkError::invalid_file(path, e.to_string())
+        )?;
+        let entries = parse_entries(&content).map_err(|message|
+            VtkError::invalid_file(path, message)
+        )?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// Get the path of the `.pvd` file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get the steps added so far
+    pub fn entries(&self) -> &[PVDEntry] {
+        &self.entries
+    }
+
+    /// Reference an already written dataset file at the given time
+    pub fn add_dataset(&mut self, timestep: f64, file: impl AsRef<Path>) -> Result<(), VtkError> {
+        self.add_part(timestep, 0, file)
+    }
+
+    /// Reference an already written dataset file as one part of the given time step
+    ///
+    /// Absolute paths inside the directory of the collection are stored relative to it. The
+    /// step is only added if the collection file could be written.
+    pub fn add_part(
+        &mut self,
+        timestep: f64,
+        part: i32,
+        file: impl AsRef<Path>
+    ) -> Result<(), VtkError> {
+        let file = file.as_ref();
+        let relative = file.strip_prefix(self.directory()).unwrap_or(file);
+        let file = crate::error::path_to_str(relative)?.replace('\\', "/");
+        let mut entries = self.entries.clone();
+        entries.push(PVDEntry { timestep, part, file });
+        self.write_entries(&entries)?;
+        self.entries = entries;
+        Ok(())
+    }
+
+    /// Write a `.vtp` file for the given time and add it to the collection
+    ///
+    /// The file is placed next to the collection and named after it, e.g.
+    /// `beam_0003.vtp` for the fourth step of `beam.pvd`. Returns its path.
+    pub fn write_poly_data(
+        &mut self,
+        timestep: f64,
+        poly_data: &PolyData
+    ) -> Result<PathBuf, VtkError> {
+        let path = self.next_file_name("vtp");
+        poly_data.write_vtp(&path)?;
+        self.add_dataset(timestep, &path)?;
+        Ok(path)
+    }
+
+    /// Write a `.vtu` file for the given time and add it to the collection
+    ///
+    /// See [`write_poly_data`](Self::write_poly_data) for the naming of the file.
+    pub fn write_unstructured_grid(
+        &mut self,
+        timestep: f64,
+        grid: &UnstructuredGrid
+    ) -> Result<PathBuf, VtkError> {
+        let path = self.next_file_name("vtu");
+        grid.write_vtu(&path)?;
+        self.add_dataset(timestep, &path)?;
+        Ok(path)
+    }
+
+    /// Write the collection file with all steps added so far
+    ///
+    /// The file is replaced atomically so readers never see a partially written file.
+    pub fn write(&self) -> Result<(), VtkError> {
+        self.write_entries(&self.entries)
+    }
+
+    /// Get the content of the collection file
+    pub fn to_xml(&self) -> String {
+        entries_to_xml(&self.entries)
+    }
+
+    fn write_entries(&self, entries: &[PVDEntry]) -> Result<(), VtkError> {
+        let dir = self.directory();
+        if !dir.as_os_str().is_empty() {
+            std::fs::create_dir_all(dir).map_err(|e|
+                VtkError::write_failed(&self.path, e.to_string())
+            )?;
+        }
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        std::fs::write(&tmp, entries_to_xml(entries)).map_err(|e|
+            VtkError::write_failed(&self.path, e.to_string())
+        )?;
+        std::fs::rename(&tmp, &self.path).map_err(|e|
+            VtkError::write_failed(&self.path, e.to_string())
+        )
+    }
+
+    fn directory(&self) -> &Path {
+        self.path.parent().unwrap_or_else(|| Path::new(""))
+    }
+
+    fn next_file_name(&self, extension: &str) -> PathBuf {
+        let stem = self.path.file_stem().unwrap_or_default().to_string_lossy();
+        let steps = self.entries.iter().filter(|e| e.part == 0).count();
+        self.directory().join(format!("{}_{:04}.{}", stem, steps, extension))
+    }
+}
+
+fn entries_to_xml(entries: &[PVDEntry]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\"?>\n");
+    xml.push_str("<VTKFile type=\"Collection\" version=\"0.1\" byte_order=\"LittleEndian\">\n");
+    xml.push_str("  <Collection>\n");
+    for entry in entries {
+        let _ = writeln!(
+            xml,
+            "    <DataSet timestep=\"{}\" group=\"\" part=\"{}\" file=\"{}\"/>",
+            entry.timestep,
+            entry.part,
+            escape(&entry.file)
+        );
+    }
+    xml.push_str("  </Collection>\n");
+    xml.push_str("</VTKFile>\n");
+    xml
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn attribute<'a>(element: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!(" {}=\"", name);
+    let start = element.find(&pattern)? + pattern.len();
+    let end = element[start..].find('"')?;
+    Some(&element[start..start + end])
+}
+
+fn parse_entries(content: &str) -> Result<Vec<PVDEntry>, String> {
+    if !content.contains("type=\"Collection\"") {
+        return Err("not a VTK collection file".to_string());
+    }
+    let mut entries = Vec::new();
+    for chunk in content.split("<DataSet").skip(1) {
+        let element = chunk.split('>').next().unwrap_or("");
+        let element = format!(" {}", element.trim());
+        let timestep = attribute(&element, "timestep")
+            .unwrap_or("0")
+            .parse()
+            .map_err(|_| format!("invalid timestep in <DataSet{}>", element))?;
+        let part = attribute(&element, "part")
+            .unwrap_or("0")
+            .parse()
+            .map_err(|_| format!("invalid part in <DataSet{}>", element))?;
+        let file = attribute(&element, "file")
+            .ok_or_else(|| format!("missing file in <DataSet{}>", element))?;
+        entries.push(PVDEntry {
+            timestep,
+            part,
+            file: unescape(file),
+        });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vtk-rs-pvd-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn entries_are_written_incrementally() {
+        let dir = temp_dir("incremental");
+        let path = dir.join("beam.pvd");
+        let mut writer = PVDWriter::new(&path);
+
+        writer.add_dataset(0.0, dir.join("beam_0000.vtu")).unwrap();
+        let first = std::fs::read_to_string(&path).unwrap();
+        assert!(first.contains("timestep=\"0\" group=\"\" part=\"0\" file=\"beam_0000.vtu\""));
+
+        writer.add_dataset(0.25, "beam_0001.vtu").unwrap();
+        let second = std::fs::read_to_string(&path).unwrap();
+        assert!(second.contains("timestep=\"0.25\" group=\"\" part=\"0\" file=\"beam_0001.vtu\""));
+        assert_eq!(writer.next_file_name("vtu"), dir.join("beam_0002.vtu"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_write_does_not_add_step() {
+        let dir = temp_dir("failed");
+        let path = dir.join("beam.pvd");
+        // A directory in place of the collection file makes the write fail
+        std::fs::create_dir_all(&path).unwrap();
+        let mut writer = PVDWriter::new(&path);
+
+        assert!(writer.add_dataset(0.0, "beam_0000.vtu").is_err());
+        assert!(writer.entries().is_empty());
+        assert_eq!(writer.next_file_name("vtu"), dir.join("beam_0000.vtu"));
+
+        std::fs::remove_dir_all(&path).unwrap();
+        writer.add_dataset(0.0, "beam_0000.vtu").unwrap();
+        assert_eq!(writer.entries().len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn open_continues_existing_collection() {
+        let dir = temp_dir("reopen");
+        let path = dir.join("run.pvd");
+        let mut writer = PVDWriter::new(&path);
+        writer.add_part(1.5, 0, "a & b.vtp").unwrap();
+        writer.add_part(1.5, 1, "c.vtp").unwrap();
+
+        let reopened = PVDWriter::open(&path).unwrap();
+        assert_eq!(reopened.entries(), writer.entries());
+        assert_eq!(reopened.entries()[0].file, "a & b.vtp");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn open_missing_file_starts_empty() {
+        let writer = PVDWriter::open(temp_dir("missing").join("none.pvd")).unwrap();
+        assert!(writer.entries().is_empty());
+    }
+
+    #[test]
+    fn open_rejects_other_files() {
+        assert!(parse_entries("<VTKFile type=\"UnstructuredGrid\">").is_err());
+    }
+}
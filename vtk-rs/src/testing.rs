@@ -0,0 +1,342 @@
+//! Image regression testing for rendered scenes.
+//!
+//! A scene is rendered offscreen, captured as an [`RgbaImage`] and compared
+//! pixel by pixel against a baseline PNG checked into the repository. When the
+//! images differ, the captured image and a diff image are written next to the
+//! baseline so that the failure can be inspected.
+//!
+//! Baselines are created or refreshed by running the tests with the
+//! `VTK_RS_UPDATE_BASELINES` environment variable set.
+//!
+//! ```no_run
+//! use vtk_rs as vtk;
+//! use vtk::testing::ImageRegression;
+//!
+//! let mut cone = vtk::ConeSource::new();
+//! let mut mapper = vtk::PolyDataMapper::new();
+//! mapper.set_input_connection(cone.get_output_port());
+//! let mut actor = vtk::Actor::new();
+//! actor.set_mapper(&mut mapper);
+//! let mut renderer = vtk::Renderer::new();
+//! renderer.add_actor(&mut actor);
+//!
+//! let mut window = vtk::RenderWindow::new_offscreen();
+//! window.set_size(300, 300);
+//! window.add_renderer(&mut renderer);
+//!
+//! ImageRegression::new("tests/baselines/cone.png")
+//!     .with_threshold(8)
+//!     .with_allowed_fraction(0.001)
+//!     .assert_window_matches(&mut window);
+//! ```
+
+use std::path::{ Path, PathBuf };
+
+use crate::{ ImageData, PNGReader, PNGWriter, RenderWindow, RgbaImage, VtkError };
+
+/// Environment variable that makes [`ImageRegression`] overwrite baselines instead of comparing.
+pub const UPDATE_BASELINES_ENV: &str = "VTK_RS_UPDATE_BASELINES";
+
+/// Result of comparing two images pixel by pixel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageDifference {
+    /// Number of pixels with at least one channel differing by more than the threshold
+    pub differing_pixels: usize,
+    /// Number of pixels that were compared
+    pub total_pixels: usize,
+    /// Largest difference found in any channel
+    pub max_channel_error: u8,
+    /// Mean absolute channel difference over the whole image
+    pub mean_error: f64,
+    /// `true` if the two images do not have the same size
+    pub size_mismatch: bool,
+    /// Visualization of the difference: differing pixels in red, the rest as a faded copy
+    pub diff_image: RgbaImage,
+}
+
+impl ImageDifference {
+    /// Fraction of pixels that differ (between 0 and 1)
+    pub fn differing_fraction(&self) -> f64 {
+        if self.total_pixels == 0 {
+            return 0.0;
+        }
+        self.differing_pixels as f64 / self.total_pixels as f64
+    }
+
+    /// Check whether the difference is within the given tolerance
+    pub fn is_within(&self, allowed_fraction: f64) -> bool {
+        !self.size_mismatch && self.differing_fraction() <= allowed_fraction
+    }
+
+    fn summary(&self) -> String {
+        if self.size_mismatch {
+            return "image sizes differ".to_string();
+        }
+        format!(
+            "{} of {} pixels differ ({:.3}%), max channel error {}, mean error {:.3}",
+            self.differing_pixels,
+            self.total_pixels,
+            self.differing_fraction() * 100.0,
+            self.max_channel_error,
+            self.mean_error
+        )
+    }
+}
+
+/// Compare two images pixel by pixel.
+///
+/// A pixel counts as different if any of its channels differs by more than
+/// `threshold`. Images of different sizes are reported as a size mismatch in
+/// which every pixel of `actual` differs.
+pub fn compare_images(baseline: &RgbaImage, actual: &RgbaImage, threshold: u8) -> ImageDifference {
+    let total_pixels = actual.width * actual.height;
+
+    if baseline.width != actual.width || baseline.height != actual.height {
+        return ImageDifference {
+            differing_pixels: total_pixels,
+            total_pixels,
+            max_channel_error: u8::MAX,
+            mean_error: f64::from(u8::MAX),
+            size_mismatch: true,
+            diff_image: RgbaImage::new(actual.width, actual.height, [255, 0, 0, 255]),
+        };
+    }
+
+    let mut diff_image = RgbaImage::new(actual.width, actual.height, [0, 0, 0, 255]);
+    let mut differing_pixels = 0;
+    let mut max_channel_error = 0;
+    let mut error_sum = 0u64;
+
+    for (i, (expected, found)) in baseline.data
+        .chunks_exact(4)
+        .zip(actual.data.chunks_exact(4))
+        .enumerate() {
+        let pixel_error = expected
+            .iter()
+            .zip(found)
+            .map(|(a, b)| a.abs_diff(*b))
+            .inspect(|e| {
+                error_sum += u64::from(*e);
+            })
+            .max()
+            .unwrap_or(0);
+        max_channel_error = max_channel_error.max(pixel_error);
+
+        let out = &mut diff_image.data[i * 4..i * 4 + 4];
+        if pixel_error > threshold {
+            differing_pixels += 1;
+            out.copy_from_slice(&[255, 0, 0, 255]);
+        } else {
+            // Faded gray version of the baseline to give context to the red pixels
+            let gray = (u16::from(expected[0]) + u16::from(expected[1]) + u16::from(expected[2])) / 3;
+            let faded = (128 + gray / 2) as u8;
+            out.copy_from_slice(&[faded, faded, faded, 255]);
+        }
+    }
+
+    let mean_error = if total_pixels == 0 {
+        0.0
+    } else {
+        error_sum as f64 / (total_pixels * 4) as f64
+    };
+
+    ImageDifference {
+        differing_pixels,
+        total_pixels,
+        max_channel_error,
+        mean_error,
+        size_mismatch: false,
+        diff_image,
+    }
+}
+
+/// Read a PNG file into an [`RgbaImage`]
+pub fn read_png(path: impl AsRef<Path>) -> Result<RgbaImage, VtkError> {
+    let path = path.as_ref();
+    let image = PNGReader::read(path)?;
+    image.to_rgba_image().ok_or_else(|| VtkError::invalid_file(path, "unsupported pixel format"))
+}
+
+/// Write an [`RgbaImage`] to a PNG file
+pub fn write_png(path: impl AsRef<Path>, image: &RgbaImage) -> Result<(), VtkError> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).map_err(|e| VtkError::write_failed(path, e.to_string()))?;
+        }
+    }
+    let mut image_data = ImageData::from_rgba_image(image);
+    let mut writer = PNGWriter::new();
+    writer.set_file_name(crate::error::path_to_str(path)?);
+    writer.set_input_data(&mut image_data);
+    writer.write()
+}
+
+/// Compares rendered images against a baseline PNG file.
+#[derive(Debug, Clone)]
+pub struct ImageRegression {
+    baseline: PathBuf,
+    threshold: u8,
+    allowed_fraction: f64,
+    output_dir: Option<PathBuf>,
+}
+
+impl ImageRegression {
+    /// Create a comparison against the given baseline file
+    ///
+    /// By default a channel may differ by up to 10 levels and no pixel may exceed that.
+    pub fn new(baseline: impl AsRef<Path>) -> Self {
+        Self {
+            baseline: baseline.as_ref().to_path_buf(),
+            threshold: 10,
+            allowed_fraction: 0.0,
+            output_dir: None,
+        }
+    }
+
+    /// Set the largest per-channel difference that still counts as equal
+    pub fn with_threshold(mut self, threshold: u8) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the fraction of pixels (between 0 and 1) that may differ
+    pub fn with_allowed_fraction(mut self, allowed_fraction: f64) -> Self {
+        self.allowed_fraction = allowed_fraction;
+        self
+    }
+
+    /// Write the actual and diff images of failed comparisons into this directory
+    ///
+    /// By default they are written next to the baseline.
+    pub fn with_output_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.output_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Get the baseline file
+    pub fn baseline(&self) -> &Path {
+        &self.baseline
+    }
+
+    /// File the captured image is written to when the comparison fails
+    pub fn actual_path(&self) -> PathBuf {
+        self.output_path("actual")
+    }
+
+    /// File the diff image is written to when the comparison fails
+    pub fn diff_path(&self) -> PathBuf {
+        self.output_path("diff")
+    }
+
+    fn output_path(&self, suffix: &str) -> PathBuf {
+        let stem = self.baseline.file_stem().unwrap_or_default().to_string_lossy();
+        let file_name = format!("{}.{}.png", stem, suffix);
+        match &self.output_dir {
+            Some(dir) => dir.join(file_name),
+            None => self.baseline.with_file_name(file_name),
+        }
+    }
+
+    /// Compare an image against the baseline
+    ///
+    /// If [`UPDATE_BASELINES_ENV`] is set, the image is written as the new
+    /// baseline instead. On failure the actual and diff images are saved to
+    /// [`actual_path`](Self::actual_path) and [`diff_path`](Self::diff_path).
+    pub fn compare(&self, actual: &RgbaImage) -> Result<ImageDifference, VtkError> {
+        if std::env::var_os(UPDATE_BASELINES_ENV).is_some() {
+            write_png(&self.baseline, actual)?;
+            return Ok(compare_images(actual, actual, self.threshold));
+        }
+
+        let baseline = match read_png(&self.baseline) {
+            Ok(image) => image,
+            Err(e) => {
+                write_png(self.actual_path(), actual)?;
+                return Err(e);
+            }
+        };
+
+        let difference = compare_images(&baseline, actual, self.threshold);
+        if difference.is_within(self.allowed_fraction) {
+            return Ok(difference);
+        }
+
+        write_png(self.actual_path(), actual)?;
+        write_png(self.diff_path(), &difference.diff_image)?;
+        Err(VtkError::ImageMismatch {
+            baseline: self.baseline.clone(),
+            message: format!("{} (see {})", difference.summary(), self.diff_path().display()),
+        })
+    }
+
+    /// Render the window, capture it and compare it against the baseline
+    pub fn compare_window(&self, window: &mut RenderWindow) -> Result<ImageDifference, VtkError> {
+        window.render();
+        self.compare(&window.capture_rgba())
+    }
+
+    /// Like [`compare`](Self::compare), but panics with a descriptive message on failure
+    pub fn assert_matches(&self, actual: &RgbaImage) {
+        if let Err(e) = self.compare(actual) {
+            panic!("image regression failed: {}", e);
+        }
+    }
+
+    /// Like [`compare_window`](Self::compare_window), but panics with a descriptive message on failure
+    pub fn assert_window_matches(&self, window: &mut RenderWindow) {
+        if let Err(e) = self.compare_window(window) {
+            panic!("image regression failed: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_images_match() {
+        let image = RgbaImage::new(4, 3, [10, 20, 30, 255]);
+        let difference = compare_images(&image, &image, 0);
+        assert_eq!(difference.differing_pixels, 0);
+        assert_eq!(difference.total_pixels, 12);
+        assert_eq!(difference.max_channel_error, 0);
+        assert!(difference.is_within(0.0));
+    }
+
+    #[test]
+    fn threshold_is_respected() {
+        let baseline = RgbaImage::new(2, 2, [100, 100, 100, 255]);
+        let mut actual = baseline.clone();
+        actual.set_pixel(1, 0, [105, 100, 100, 255]);
+        actual.set_pixel(0, 1, [100, 140, 100, 255]);
+
+        let difference = compare_images(&baseline, &actual, 5);
+        assert_eq!(difference.differing_pixels, 1);
+        assert_eq!(difference.max_channel_error, 40);
+        assert_eq!(difference.diff_image.pixel(0, 1), [255, 0, 0, 255]);
+        assert_ne!(difference.diff_image.pixel(1, 0), [255, 0, 0, 255]);
+        assert!(!difference.is_within(0.0));
+        assert!(difference.is_within(0.25));
+    }
+
+    #[test]
+    fn size_mismatch_fails() {
+        let baseline = RgbaImage::new(2, 2, [0, 0, 0, 255]);
+        let actual = RgbaImage::new(3, 2, [0, 0, 0, 255]);
+        let difference = compare_images(&baseline, &actual, 255);
+        assert!(difference.size_mismatch);
+        assert!(!difference.is_within(1.0));
+    }
+
+    #[test]
+    fn output_paths_follow_baseline() {
+        let regression = ImageRegression::new("tests/baselines/cone.png");
+        assert_eq!(regression.actual_path(), Path::new("tests/baselines/cone.actual.png"));
+        assert_eq!(regression.diff_path(), Path::new("tests/baselines/cone.diff.png"));
+
+        let regression = regression.with_output_dir("target/regression");
+        assert_eq!(regression.diff_path(), Path::new("target/regression/cone.diff.png"));
+    }
+}
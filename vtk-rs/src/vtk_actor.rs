@@ -64,6 +64,14 @@ impl Actor {
         }
     }
 
+    /// Set the mapper for this actor (CompositePolyDataMapper variant)
+    pub fn set_composite_mapper(&mut self, mapper: &mut crate::CompositePolyDataMapper) {
+        unsafe {
+            let mapper_ptr = mapper.as_mapper_ptr() as *mut ffi::vtkMapper;
+            ffi::actor_set_mapper(self.ptr.as_mut(), mapper_ptr)
+        }
+    }
+
     /// Get the property for this actor.
     /// Returns a non-owning reference to the property managed by the actor.
     pub fn get_property(&mut self) -> PropertyRef {
@@ -19,6 +19,9 @@ extern "C" {
         npts: *mut i64,
         pts: *mut *mut i64
     ) -> bool;
+    fn cell_array_get_number_of_offsets(cells: *mut vtkCellArray) -> i64;
+    fn cell_array_copy_offsets(cells: *mut vtkCellArray, ids: *mut i64);
+    fn cell_array_copy_connectivity(cells: *mut vtkCellArray, ids: *mut i64);
     fn cell_array_reset(cells: *mut vtkCellArray);
     fn cell_array_initialize(cells: *mut vtkCellArray);
 }
@@ -39,6 +42,12 @@ impl CellArray {
         Self { ptr }
     }
 
+    /// Take ownership of a reference to an existing vtkCellArray (e.g. the cells of a dataset)
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkCellArray) -> Self {
+        assert!(!ptr.is_null(), "vtkCellArray pointer is null");
+        Self { ptr }
+    }
+
     /// Insert a cell defined by a list of point IDs
     ///
     /// For a beam element (line), pass 2 point IDs.
@@ -98,6 +107,25 @@ impl CellArray {
         }
     }
 
+    /// Copy the offsets
+    ///
+    /// Cell `i` is made of the connectivity ids from `offsets[i]` to `offsets[i + 1]`, so
+    /// there is one more offset than cells.
+    #[doc(alias = "GetOffsetsArray")]
+    pub fn get_offsets(&self) -> Vec<i64> {
+        let mut offsets = vec![0; unsafe { cell_array_get_number_of_offsets(self.ptr) } as usize];
+        unsafe { cell_array_copy_offsets(self.ptr, offsets.as_mut_ptr()) };
+        offsets
+    }
+
+    /// Copy the point ids of all cells, one after the other
+    #[doc(alias = "GetConnectivityArray")]
+    pub fn get_connectivity(&self) -> Vec<i64> {
+        let mut connectivity = vec![0; self.get_number_of_connectivity_ids() as usize];
+        unsafe { cell_array_copy_connectivity(self.ptr, connectivity.as_mut_ptr()) };
+        connectivity
+    }
+
     /// Reset the array to empty state (reuse allocated memory)
     pub fn reset(&mut self) {
         unsafe { cell_array_reset(self.ptr) }
@@ -200,6 +228,16 @@ mod tests {
         assert_eq!(point_ids, vec![5, 10]);
     }
 
+    #[test]
+    fn test_offsets_and_connectivity() {
+        let mut cells = CellArray::new();
+        cells.insert_next_cell(&[0, 1, 2]);
+        cells.insert_next_cell(&[2, 3]);
+
+        assert_eq!(cells.get_offsets(), &[0, 3, 5]);
+        assert_eq!(cells.get_connectivity(), &[0, 1, 2, 2, 3]);
+    }
+
     #[test]
     fn test_iterator() {
         let mut cells = CellArray::new();
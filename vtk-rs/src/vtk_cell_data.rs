@@ -1,5 +1,5 @@
 use std::ffi::{ CStr, CString };
-use crate::{ DoubleArray, IntArray };
+use crate::{ AbstractArray, DoubleArray, IntArray };
 
 /// Opaque handle to vtkCellData from VTK
 #[repr(C)]
@@ -9,9 +9,6 @@ pub struct vtkCellData {
 
 #[link(name = "vtkrs", kind = "static")]
 extern "C" {
-    fn poly_data_get_cell_data(
-        poly_data: *mut crate::vtk_poly_data::vtkPolyData
-    ) -> *mut vtkCellData;
     fn cell_data_add_array(cell_data: *mut vtkCellData, array: *mut std::ffi::c_void);
     fn cell_data_remove_array(cell_data: *mut vtkCellData, name: *const i8);
     fn cell_data_get_array(cell_data: *mut vtkCellData, name: *const i8) -> *mut std::ffi::c_void;
@@ -23,6 +20,14 @@ extern "C" {
     fn cell_data_get_vectors(cell_data: *mut vtkCellData) -> *mut std::ffi::c_void;
     fn cell_data_set_active_scalars(cell_data: *mut vtkCellData, name: *const i8);
     fn cell_data_set_active_vectors(cell_data: *mut vtkCellData, name: *const i8);
+    fn cell_data_set_colors(
+        cell_data: *mut vtkCellData,
+        name: *const i8,
+        rgba: *const u8,
+        num_tuples: i64
+    );
+    fn cell_data_get_number_of_colors(cell_data: *mut vtkCellData, name: *const i8) -> i64;
+    fn cell_data_copy_colors(cell_data: *mut vtkCellData, name: *const i8, rgba: *mut u8);
 }
 
 /// Safe wrapper for vtkCellData
@@ -44,10 +49,12 @@ impl CellData {
 
     /// Add a data array to the cell data
     ///
+    /// Accepts a [`DoubleArray`], [`IntArray`], [`crate::StringArray`] or any typed
+    /// [`crate::DataArray`].
     /// The array should have the same number of tuples as there are cells
-    pub fn add_array(&mut self, array: &DoubleArray) {
+    pub fn add_array(&mut self, array: &impl AbstractArray) {
         unsafe {
-            cell_data_add_array(self.ptr, array.as_ptr() as *mut std::ffi::c_void);
+            cell_data_add_array(self.ptr, array.as_abstract_array_ptr());
         }
     }
 
@@ -117,6 +124,38 @@ impl CellData {
         }
     }
 
+    /// Store one RGBA color per cell as the active scalars
+    ///
+    /// The colors are kept as unsigned chars so that mappers use them directly and
+    /// writers such as [`crate::PLYWriter`] export them as face colors.
+    pub fn set_colors(&mut self, name: &str, colors: &[[u8; 4]]) {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            cell_data_set_colors(
+                self.ptr,
+                c_name.as_ptr(),
+                colors.as_ptr() as *const u8,
+                colors.len() as i64
+            );
+        }
+    }
+
+    /// Get an RGB or RGBA unsigned char array as RGBA colors
+    ///
+    /// Returns `None` if there is no such array or it does not hold colors.
+    pub fn get_colors(&self, name: &str) -> Option<Vec<[u8; 4]>> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let num_tuples = cell_data_get_number_of_colors(self.ptr, c_name.as_ptr());
+            if num_tuples < 0 {
+                return None;
+            }
+            let mut colors = vec![[0u8; 4]; num_tuples as usize];
+            cell_data_copy_colors(self.ptr, c_name.as_ptr(), colors.as_mut_ptr() as *mut u8);
+            Some(colors)
+        }
+    }
+
     /// Get raw pointer (for internal use)
     pub fn as_ptr(&self) -> *mut vtkCellData {
         self.ptr
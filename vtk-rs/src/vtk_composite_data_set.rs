@@ -0,0 +1,156 @@
+use std::ffi::c_void;
+
+use crate::{
+    DataSet, FieldData, ImageData, MultiBlockDataSet, PolyData, RectilinearGrid, StructuredGrid,
+    UnstructuredGrid,
+};
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn composite_data_set_get_number_of_points(composite: *mut c_void) -> i64;
+    fn composite_data_set_get_number_of_cells(composite: *mut c_void) -> i64;
+    fn composite_data_set_get_bounds(composite: *mut c_void, bounds: *mut f64);
+    fn composite_data_set_get_flat_index(composite: *mut c_void, index: u32) -> u32;
+}
+
+// Data object types of vtkType.h
+const VTK_POLY_DATA: i32 = 0;
+const VTK_STRUCTURED_GRID: i32 = 2;
+const VTK_RECTILINEAR_GRID: i32 = 3;
+const VTK_UNSTRUCTURED_GRID: i32 = 4;
+const VTK_IMAGE_DATA: i32 = 6;
+const VTK_MULTIBLOCK_DATA_SET: i32 = 13;
+
+/// A block of a [`MultiBlockDataSet`] or a partition of a
+/// [`crate::PartitionedDataSetCollection`]
+///
+/// Blocks share their data with the composite dataset they were taken from.
+pub enum Block {
+    PolyData(PolyData),
+    UnstructuredGrid(UnstructuredGrid),
+    ImageData(ImageData),
+    RectilinearGrid(RectilinearGrid),
+    StructuredGrid(StructuredGrid),
+    /// A nested multiblock dataset
+    MultiBlock(MultiBlockDataSet),
+}
+
+impl Block {
+    /// Take ownership of a reference to a block of the given data object type
+    pub(crate) unsafe fn from_raw(ptr: *mut c_void, block_type: i32) -> Option<Self> {
+        if ptr.is_null() {
+            return None;
+        }
+        let block = match block_type {
+            VTK_POLY_DATA => Block::PolyData(PolyData::from_raw(ptr as *mut _)),
+            VTK_STRUCTURED_GRID => Block::StructuredGrid(StructuredGrid::from_raw(ptr as *mut _)),
+            VTK_RECTILINEAR_GRID => {
+                Block::RectilinearGrid(RectilinearGrid::from_raw(ptr as *mut _))
+            }
+            VTK_UNSTRUCTURED_GRID => {
+                Block::UnstructuredGrid(UnstructuredGrid::from_raw(ptr as *mut _))
+            }
+            VTK_IMAGE_DATA => Block::ImageData(ImageData::from_raw(ptr as *mut _)),
+            VTK_MULTIBLOCK_DATA_SET => {
+                Block::MultiBlock(MultiBlockDataSet::from_raw(ptr as *mut _))
+            }
+            _ => return None,
+        };
+        Some(block)
+    }
+
+    /// View the block as a dataset, `None` for a nested [`MultiBlockDataSet`]
+    pub fn as_data_set(&self) -> Option<&dyn DataSet> {
+        match self {
+            Block::PolyData(poly_data) => Some(poly_data),
+            Block::UnstructuredGrid(grid) => Some(grid),
+            Block::ImageData(image) => Some(image),
+            Block::RectilinearGrid(grid) => Some(grid),
+            Block::StructuredGrid(grid) => Some(grid),
+            Block::MultiBlock(_) => None,
+        }
+    }
+}
+
+/// Data that can be stored as a block of a [`MultiBlockDataSet`]: any [`DataSet`],
+/// a nested [`MultiBlockDataSet`] or a [`Block`]
+///
+/// This trait is sealed: it is only implemented by the data types of this crate.
+pub trait BlockData: private::Sealed {
+    #[doc(hidden)]
+    fn as_data_object_ptr(&self) -> *mut c_void;
+}
+
+impl<T: DataSet> private::Sealed for T {}
+impl<T: DataSet> BlockData for T {
+    fn as_data_object_ptr(&self) -> *mut c_void {
+        self.as_data_set_ptr()
+    }
+}
+
+impl private::Sealed for MultiBlockDataSet {}
+impl BlockData for MultiBlockDataSet {
+    fn as_data_object_ptr(&self) -> *mut c_void {
+        self.as_ptr() as *mut c_void
+    }
+}
+
+impl private::Sealed for Block {}
+impl BlockData for Block {
+    fn as_data_object_ptr(&self) -> *mut c_void {
+        match self {
+            Block::MultiBlock(blocks) => blocks.as_data_object_ptr(),
+            data_set => data_set.as_data_set().expect("dataset block").as_data_set_ptr(),
+        }
+    }
+}
+
+/// Common interface of datasets made of other datasets (vtkCompositeDataSet)
+///
+/// Implemented by [`MultiBlockDataSet`] and [`crate::PartitionedDataSetCollection`]; the
+/// trait is sealed.
+pub trait CompositeDataSet: private::Sealed {
+    #[doc(hidden)]
+    fn as_composite_data_set_ptr(&self) -> *mut c_void;
+
+    /// Get the total number of points of all blocks
+    #[doc(alias = "GetNumberOfPoints")]
+    fn get_number_of_points(&self) -> i64 {
+        unsafe { composite_data_set_get_number_of_points(self.as_composite_data_set_ptr()) }
+    }
+
+    /// Get the total number of cells of all blocks
+    #[doc(alias = "GetNumberOfCells")]
+    fn get_number_of_cells(&self) -> i64 {
+        unsafe { composite_data_set_get_number_of_cells(self.as_composite_data_set_ptr()) }
+    }
+
+    /// Get the bounds of all blocks as `[xmin, xmax, ymin, ymax, zmin, zmax]`
+    #[doc(alias = "GetBounds")]
+    fn get_bounds(&self) -> [f64; 6] {
+        let mut bounds = [0.0; 6];
+        unsafe {
+            composite_data_set_get_bounds(self.as_composite_data_set_ptr(), bounds.as_mut_ptr());
+        }
+        bounds
+    }
+
+    /// Get the flat index of the child at `index`
+    ///
+    /// Flat indices number all nodes of the tree depth first, starting with 0 for the
+    /// composite dataset itself. They address blocks in
+    /// [`crate::CompositePolyDataMapper`].
+    fn get_flat_index(&self, index: u32) -> u32 {
+        unsafe { composite_data_set_get_flat_index(self.as_composite_data_set_ptr(), index) }
+    }
+
+    /// Get the field data (metadata describing the whole composite dataset)
+    #[doc(alias = "GetFieldData")]
+    fn get_field_data(&self) -> FieldData {
+        unsafe { FieldData::from_data_object(self.as_composite_data_set_ptr()) }
+    }
+}
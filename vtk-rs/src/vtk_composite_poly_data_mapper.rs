@@ -0,0 +1,184 @@
+use std::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_composite_poly_data_mapper.h");
+        include!("vtk_mapper.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkCompositePolyDataMapper;
+        type vtkMapper;
+        type vtkDataObject;
+        type vtkAlgorithmOutput;
+
+        fn vtk_composite_poly_data_mapper_new() -> *mut vtkCompositePolyDataMapper;
+        fn vtk_composite_poly_data_mapper_delete(mapper: Pin<&mut vtkCompositePolyDataMapper>);
+
+        unsafe fn composite_poly_data_mapper_set_input_connection(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn composite_poly_data_mapper_set_input_data(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>,
+            composite: *mut vtkDataObject
+        );
+
+        fn composite_poly_data_mapper_set_block_color(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>,
+            flat_index: u32,
+            r: f64,
+            g: f64,
+            b: f64
+        );
+        fn composite_poly_data_mapper_remove_block_color(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>,
+            flat_index: u32
+        );
+        fn composite_poly_data_mapper_remove_block_colors(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>
+        );
+        fn composite_poly_data_mapper_set_block_visibility(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>,
+            flat_index: u32,
+            visible: bool
+        );
+        fn composite_poly_data_mapper_get_block_visibility(
+            mapper: &vtkCompositePolyDataMapper,
+            flat_index: u32
+        ) -> bool;
+        fn composite_poly_data_mapper_set_block_opacity(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>,
+            flat_index: u32,
+            opacity: f64
+        );
+        fn composite_poly_data_mapper_remove_block_attributes(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>
+        );
+
+        fn composite_poly_data_mapper_set_scalar_visibility(
+            mapper: Pin<&mut vtkCompositePolyDataMapper>,
+            visible: bool
+        );
+        fn composite_poly_data_mapper_get_scalar_visibility(
+            mapper: &vtkCompositePolyDataMapper
+        ) -> bool;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkCompositePolyDataMapper.html",
+    @name CompositePolyDataMapper, ffi::vtkCompositePolyDataMapper,
+    @new ffi::vtk_composite_poly_data_mapper_new,
+    @delete ffi::vtk_composite_poly_data_mapper_delete,
+    @inherit vtkMapper
+);
+
+impl CompositePolyDataMapper {
+    /// Sets the input from an algorithm output port
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::composite_poly_data_mapper_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the composite dataset to render
+    ///
+    /// Every [`crate::PolyData`] block is drawn by the one actor; other block types are
+    /// skipped, so extract their surface first. Blocks are addressed by flat index, see
+    /// [`crate::CompositeDataSet::get_flat_index`].
+    ///
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let assembly = MultiBlockDataSet::read_vtm("assembly.vtm")?;
+    /// let mut mapper = CompositePolyDataMapper::new();
+    /// mapper.set_input_data(&assembly);
+    /// mapper.set_scalar_visibility(false);
+    /// mapper.set_block_color(assembly.get_flat_index(0), 0.8, 0.2, 0.2);
+    /// mapper.set_block_opacity(assembly.get_flat_index(1), 0.5);
+    ///
+    /// let mut actor = Actor::new();
+    /// actor.set_composite_mapper(&mut mapper);
+    /// # Ok::<(), VtkError>(())
+    /// ```
+    #[doc(alias = "SetInputDataObject")]
+    pub fn set_input_data(&mut self, composite: &impl crate::CompositeDataSet) {
+        let ptr = composite.as_composite_data_set_ptr() as *mut ffi::vtkDataObject;
+        unsafe {
+            ffi::composite_poly_data_mapper_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Override the color of a block (and its children)
+    ///
+    /// Only used when scalars are not shown, see
+    /// [`set_scalar_visibility`](Self::set_scalar_visibility).
+    #[doc(alias = "SetBlockColor")]
+    pub fn set_block_color(&mut self, flat_index: u32, r: f64, g: f64, b: f64) {
+        ffi::composite_poly_data_mapper_set_block_color(self.ptr.as_mut(), flat_index, r, g, b);
+    }
+
+    /// Use the actor color again for a block
+    #[doc(alias = "RemoveBlockColor")]
+    pub fn remove_block_color(&mut self, flat_index: u32) {
+        ffi::composite_poly_data_mapper_remove_block_color(self.ptr.as_mut(), flat_index);
+    }
+
+    #[doc(alias = "RemoveBlockColors")]
+    pub fn remove_block_colors(&mut self) {
+        ffi::composite_poly_data_mapper_remove_block_colors(self.ptr.as_mut());
+    }
+
+    /// Show or hide a block (and its children)
+    #[doc(alias = "SetBlockVisibility")]
+    pub fn set_block_visibility(&mut self, flat_index: u32, visible: bool) {
+        ffi::composite_poly_data_mapper_set_block_visibility(
+            self.ptr.as_mut(),
+            flat_index,
+            visible
+        );
+    }
+
+    #[doc(alias = "GetBlockVisibility")]
+    pub fn get_block_visibility(&self, flat_index: u32) -> bool {
+        ffi::composite_poly_data_mapper_get_block_visibility(&self.ptr.as_ref(), flat_index)
+    }
+
+    /// Set the opacity of a block (and its children), from 0 (invisible) to 1 (opaque)
+    #[doc(alias = "SetBlockOpacity")]
+    pub fn set_block_opacity(&mut self, flat_index: u32, opacity: f64) {
+        ffi::composite_poly_data_mapper_set_block_opacity(self.ptr.as_mut(), flat_index, opacity);
+    }
+
+    /// Reset the color, visibility and opacity of all blocks
+    #[doc(alias = "RemoveBlockVisibilities")]
+    #[doc(alias = "RemoveBlockOpacities")]
+    pub fn remove_block_attributes(&mut self) {
+        ffi::composite_poly_data_mapper_remove_block_attributes(self.ptr.as_mut());
+    }
+
+    /// Color by scalars (on by default) or by the block and actor colors
+    #[doc(alias = "SetScalarVisibility")]
+    pub fn set_scalar_visibility(&mut self, visible: bool) {
+        ffi::composite_poly_data_mapper_set_scalar_visibility(self.ptr.as_mut(), visible);
+    }
+
+    #[doc(alias = "GetScalarVisibility")]
+    pub fn get_scalar_visibility(&self) -> bool {
+        ffi::composite_poly_data_mapper_get_scalar_visibility(&self.ptr.as_ref())
+    }
+
+    /// Get raw pointer for VTK pipeline connections
+    pub fn as_raw_ptr(&mut self) -> *mut ffi::vtkCompositePolyDataMapper {
+        unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
+    }
+
+    /// Get mapper base pointer for Actor
+    pub fn as_mapper_ptr(&mut self) -> *mut ffi::vtkMapper {
+        self.as_raw_ptr() as *mut ffi::vtkMapper
+    }
+}
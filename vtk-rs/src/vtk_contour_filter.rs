@@ -52,11 +52,11 @@ impl ContourFilter {
         }
     }
 
-    /// Sets the input data directly (for data objects like ImageData)
+    /// Sets the input data directly (any [`crate::DataSet`] with point scalars)
     #[doc(alias = "SetInputData")]
-    pub fn set_input_data(&mut self, data: &mut crate::ImageData) {
+    pub fn set_input_data(&mut self, data: &impl crate::DataSet) {
         unsafe {
-            let ptr = data.as_raw_ptr() as *mut ffi::vtkDataSet;
+            let ptr = data.as_data_set_ptr() as *mut ffi::vtkDataSet;
             ffi::contour_filter_set_input_data(self.ptr.as_mut(), ptr);
         }
     }
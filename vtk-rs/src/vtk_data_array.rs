@@ -0,0 +1,557 @@
+use std::collections::BTreeMap;
+use std::ffi::{ c_void, CStr, CString };
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::VtkDataType;
+
+/// Opaque handle to vtkDataArray from VTK
+#[repr(C)]
+pub struct vtkDataArray {
+    _private: [u8; 0],
+}
+
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn data_array_new(data_type: i32) -> *mut vtkDataArray;
+    fn data_array_delete(array: *mut vtkDataArray);
+    fn data_array_set_number_of_components(array: *mut vtkDataArray, num_components: i64);
+    fn data_array_get_number_of_components(array: *mut vtkDataArray) -> i64;
+    fn data_array_set_number_of_tuples(array: *mut vtkDataArray, num_tuples: i64);
+    fn data_array_get_number_of_tuples(array: *mut vtkDataArray) -> i64;
+    fn data_array_get_number_of_values(array: *mut vtkDataArray) -> i64;
+    fn data_array_set_name(array: *mut vtkDataArray, name: *const i8);
+    fn data_array_get_name(array: *mut vtkDataArray) -> *const i8;
+    fn data_array_insert_next_value(array: *mut vtkDataArray, value: *const u8) -> i64;
+    fn data_array_insert_next_tuple(array: *mut vtkDataArray, tuple: *const u8) -> i64;
+    fn data_array_set_value(array: *mut vtkDataArray, id: i64, value: *const u8);
+    fn data_array_set_tuple(array: *mut vtkDataArray, id: i64, tuple: *const u8);
+    fn data_array_get_value(array: *mut vtkDataArray, id: i64, value: *mut u8);
+    fn data_array_get_tuple(array: *mut vtkDataArray, id: i64, tuple: *mut u8);
+    fn data_array_get_range(array: *mut vtkDataArray, component: i32, range: *mut [f64; 2]);
+    fn data_array_initialize(array: *mut vtkDataArray);
+    fn data_array_squeeze(array: *mut vtkDataArray);
+    pub(crate) fn data_array_modified(array: *mut vtkDataArray);
+    fn data_array_get_void_pointer(array: *mut vtkDataArray) -> *mut c_void;
+    fn data_array_adopt(
+        array: *mut vtkDataArray,
+        values: *mut c_void,
+        num_values: i64,
+        free_function: extern "C" fn(*mut c_void)
+    );
+}
+
+/// Length and capacity of the Rust buffers handed to VTK arrays, by address
+static ADOPTED: Mutex<BTreeMap<usize, (usize, usize)>> = Mutex::new(BTreeMap::new());
+
+/// Called by VTK when an array releases a buffer adopted with [`adopt_vec`]
+extern "C" fn free_adopted<T>(values: *mut c_void) {
+    let entry = ADOPTED.lock().unwrap_or_else(|e| e.into_inner()).remove(&(values as usize));
+    if let Some((len, capacity)) = entry {
+        unsafe { drop(Vec::from_raw_parts(values as *mut T, len, capacity)) };
+    }
+}
+
+/// View the values of an array with `T` values as a slice
+///
+/// # Safety
+/// `array` must be a live array storing `T` values, and stay unchanged for `'a`.
+pub(crate) unsafe fn values_slice<'a, T>(array: *mut vtkDataArray) -> &'a [T] {
+    let len = data_array_get_number_of_values(array) as usize;
+    if len == 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(data_array_get_void_pointer(array) as *const T, len)
+}
+
+/// View the values of an array with `T` values as a mutable slice
+///
+/// # Safety
+/// As for [`values_slice`], and nothing else may access the values for `'a`.
+pub(crate) unsafe fn values_slice_mut<'a, T>(array: *mut vtkDataArray) -> &'a mut [T] {
+    let len = data_array_get_number_of_values(array) as usize;
+    if len == 0 {
+        return &mut [];
+    }
+    std::slice::from_raw_parts_mut(data_array_get_void_pointer(array) as *mut T, len)
+}
+
+/// Fill an array with `T` values with a copy of `values`
+///
+/// # Safety
+/// `array` must be a live array storing `T` values, with the number of components set.
+pub(crate) unsafe fn copy_values<T: Copy>(array: *mut vtkDataArray, values: &[T]) {
+    let num_components = data_array_get_number_of_components(array).max(1);
+    data_array_set_number_of_tuples(array, values.len() as i64 / num_components);
+    values_slice_mut(array).copy_from_slice(values);
+}
+
+/// Hand the buffer of `values` to an array with `T` values, without copying
+///
+/// The buffer is freed by the Rust allocator once VTK releases it, which may be after
+/// the wrapper is dropped if the array was added to a data set.
+///
+/// # Safety
+/// `array` must be a live array storing `T` values, with the number of components set.
+pub(crate) unsafe fn adopt_vec<T>(array: *mut vtkDataArray, values: Vec<T>) {
+    if values.is_empty() {
+        return;
+    }
+    let mut values = std::mem::ManuallyDrop::new(values);
+    let (ptr, len, capacity) = (values.as_mut_ptr(), values.len(), values.capacity());
+    ADOPTED.lock().unwrap_or_else(|e| e.into_inner()).insert(ptr as usize, (len, capacity));
+    data_array_adopt(array, ptr as *mut c_void, len as i64, free_adopted::<T>);
+}
+
+/// Turn a vector of tuples into a vector of values, reusing the allocation
+pub(crate) fn flatten_tuples<T, const N: usize>(tuples: Vec<[T; N]>) -> Vec<T> {
+    let mut tuples = std::mem::ManuallyDrop::new(tuples);
+    let (ptr, len, capacity) = (tuples.as_mut_ptr(), tuples.len(), tuples.capacity());
+    // [T; N] has the size of N values and the alignment of T, so the layout is the same
+    unsafe { Vec::from_raw_parts(ptr as *mut T, len * N, capacity * N) }
+}
+
+/// Number of components for a bulk constructor, checking that `len` values fit
+pub(crate) fn check_bulk_length(len: usize, num_components: i64) {
+    assert!(num_components > 0, "arrays need at least one component");
+    assert_eq!(
+        len % num_components as usize,
+        0,
+        "{} values do not fill whole tuples of {} components",
+        len,
+        num_components
+    );
+}
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+/// Value types that can be stored in a [`DataArray`]
+///
+/// Each type maps to the VTK array class of the same width, e.g. `f32` to
+/// vtkFloatArray and `u8` to vtkUnsignedCharArray.
+pub trait DataArrayType: private::Sealed + Copy + Default + 'static {
+    /// The VTK type code of the matching array class
+    const DATA_TYPE: VtkDataType;
+}
+
+macro_rules! data_array_type {
+    ($($rust:ty => $vtk:ident),* $(,)?) => {
+        $(
+            impl private::Sealed for $rust {}
+            impl DataArrayType for $rust {
+                const DATA_TYPE: VtkDataType = VtkDataType::$vtk;
+            }
+        )*
+    };
+}
+
+data_array_type!(
+    f32 => Float,
+    f64 => Double,
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+);
+
+/// Arrays that can be attached to [`crate::PointData`] and [`crate::CellData`]
+///
+/// This trait is sealed: it is only implemented by the array types of this crate.
+pub trait AbstractArray: private::Sealed {
+    /// Get the raw vtkAbstractArray pointer (for internal use with other VTK functions)
+    #[doc(hidden)]
+    fn as_abstract_array_ptr(&self) -> *mut std::ffi::c_void;
+}
+
+impl private::Sealed for crate::DoubleArray {}
+impl AbstractArray for crate::DoubleArray {
+    fn as_abstract_array_ptr(&self) -> *mut std::ffi::c_void {
+        self.as_ptr() as *mut std::ffi::c_void
+    }
+}
+
+impl private::Sealed for crate::IntArray {}
+impl AbstractArray for crate::IntArray {
+    fn as_abstract_array_ptr(&self) -> *mut std::ffi::c_void {
+        self.as_ptr() as *mut std::ffi::c_void
+    }
+}
+
+impl<T: DataArrayType> private::Sealed for DataArray<T> {}
+impl<T: DataArrayType> AbstractArray for DataArray<T> {
+    fn as_abstract_array_ptr(&self) -> *mut std::ffi::c_void {
+        self.ptr as *mut std::ffi::c_void
+    }
+}
+
+/// Safe wrapper for the typed VTK data arrays
+///
+/// `DataArray<f32>` stores single precision results at half the memory of a
+/// [`crate::DoubleArray`], and `DataArray<u8>` suits label masks and flags:
+///
+/// ```no_run
+/// # use vtk_rs::{DataArray, DataSet, PolyData};
+/// let mut stress = DataArray::<f32>::new_scalar("VonMises");
+/// stress.insert_next_value(215.0);
+///
+/// let mut failed = DataArray::<u8>::new_scalar("Failed");
+/// failed.insert_next_value(1);
+///
+/// let poly_data = PolyData::new();
+/// poly_data.get_point_data().add_array(&stress);
+/// poly_data.get_point_data().add_array(&failed);
+/// ```
+pub struct DataArray<T: DataArrayType> {
+    ptr: *mut vtkDataArray,
+    _type: PhantomData<T>,
+}
+
+impl<T: DataArrayType> DataArray<T> {
+    /// Create a new empty array
+    pub fn new() -> Self {
+        let ptr = unsafe { data_array_new(T::DATA_TYPE as i32) };
+        assert!(!ptr.is_null(), "Failed to create vtkDataArray of type {:?}", T::DATA_TYPE);
+        Self { ptr, _type: PhantomData }
+    }
+
+    /// Take ownership of a reference to an existing array (e.g. from a dataset)
+    ///
+    /// The caller must make sure the array stores values of type `T`.
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkDataArray) -> Self {
+        assert!(!ptr.is_null(), "vtkDataArray pointer is null");
+        Self { ptr, _type: PhantomData }
+    }
+
+    /// Set the number of components per tuple
+    pub fn set_number_of_components(&mut self, num_components: i64) {
+        unsafe {
+            data_array_set_number_of_components(self.ptr, num_components);
+        }
+    }
+
+    /// Get the number of components per tuple
+    pub fn get_number_of_components(&self) -> i64 {
+        unsafe { data_array_get_number_of_components(self.ptr) }
+    }
+
+    /// Set the number of tuples (pre-allocate memory)
+    pub fn set_number_of_tuples(&mut self, num_tuples: i64) {
+        unsafe {
+            data_array_set_number_of_tuples(self.ptr, num_tuples);
+        }
+    }
+
+    /// Get the number of tuples
+    pub fn get_number_of_tuples(&self) -> i64 {
+        unsafe { data_array_get_number_of_tuples(self.ptr) }
+    }
+
+    /// Get the total number of values (tuples × components)
+    pub fn get_number_of_values(&self) -> i64 {
+        unsafe { data_array_get_number_of_values(self.ptr) }
+    }
+
+    /// Get the VTK type of the stored values
+    pub fn get_data_type(&self) -> VtkDataType {
+        T::DATA_TYPE
+    }
+
+    /// Set the array name (used for identifying data in VTK pipeline)
+    pub fn set_name(&mut self, name: &str) {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            data_array_set_name(self.ptr, c_name.as_ptr());
+        }
+    }
+
+    /// Get the array name
+    pub fn get_name(&self) -> Option<String> {
+        unsafe {
+            let name_ptr = data_array_get_name(self.ptr);
+            if name_ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name_ptr).to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Insert a single value (for 1-component arrays)
+    pub fn insert_next_value(&mut self, value: T) -> i64 {
+        unsafe { data_array_insert_next_value(self.ptr, &value as *const T as *const u8) }
+    }
+
+    /// Insert a tuple and return its index
+    ///
+    /// # Panics
+    /// If the tuple length does not match the number of components.
+    pub fn insert_next_tuple(&mut self, tuple: &[T]) -> i64 {
+        self.check_tuple_length(tuple.len());
+        unsafe { data_array_insert_next_tuple(self.ptr, tuple.as_ptr() as *const u8) }
+    }
+
+    /// Insert a 1-component tuple
+    pub fn insert_next_tuple1(&mut self, value: T) -> i64 {
+        self.insert_next_tuple(&[value])
+    }
+
+    /// Insert a 2-component tuple
+    pub fn insert_next_tuple2(&mut self, v0: T, v1: T) -> i64 {
+        self.insert_next_tuple(&[v0, v1])
+    }
+
+    /// Insert a 3-component tuple (e.g., displacement vector)
+    pub fn insert_next_tuple3(&mut self, v0: T, v1: T, v2: T) -> i64 {
+        self.insert_next_tuple(&[v0, v1, v2])
+    }
+
+    /// Set a single value at specific index
+    pub fn set_value(&mut self, id: i64, value: T) {
+        unsafe {
+            data_array_set_value(self.ptr, id, &value as *const T as *const u8);
+        }
+    }
+
+    /// Set a tuple at specific index
+    ///
+    /// # Panics
+    /// If the tuple length does not match the number of components.
+    pub fn set_tuple(&mut self, id: i64, tuple: &[T]) {
+        self.check_tuple_length(tuple.len());
+        unsafe {
+            data_array_set_tuple(self.ptr, id, tuple.as_ptr() as *const u8);
+        }
+    }
+
+    /// Set a 1-component tuple at specific index
+    pub fn set_tuple1(&mut self, id: i64, value: T) {
+        self.set_tuple(id, &[value]);
+    }
+
+    /// Set a 2-component tuple at specific index
+    pub fn set_tuple2(&mut self, id: i64, v0: T, v1: T) {
+        self.set_tuple(id, &[v0, v1]);
+    }
+
+    /// Set a 3-component tuple at specific index
+    pub fn set_tuple3(&mut self, id: i64, v0: T, v1: T, v2: T) {
+        self.set_tuple(id, &[v0, v1, v2]);
+    }
+
+    /// Get a single value
+    pub fn get_value(&self, id: i64) -> T {
+        let mut value = T::default();
+        unsafe {
+            data_array_get_value(self.ptr, id, &mut value as *mut T as *mut u8);
+        }
+        value
+    }
+
+    /// Get a tuple as a vector
+    ///
+    /// The vector length will match the number of components
+    pub fn get_tuple(&self, id: i64) -> Vec<T> {
+        let num_components = self.get_number_of_components() as usize;
+        let mut tuple = vec![T::default(); num_components];
+        unsafe {
+            data_array_get_tuple(self.ptr, id, tuple.as_mut_ptr() as *mut u8);
+        }
+        tuple
+    }
+
+    /// Get the (min, max) range of a component, or of the vector magnitude for `-1`
+    pub fn get_range(&self, component: i32) -> [f64; 2] {
+        let mut range = [0.0; 2];
+        unsafe {
+            data_array_get_range(self.ptr, component, &mut range);
+        }
+        range
+    }
+
+    /// Clear all data
+    pub fn initialize(&mut self) {
+        unsafe {
+            data_array_initialize(self.ptr);
+        }
+    }
+
+    /// Reclaim unused memory
+    pub fn squeeze(&mut self) {
+        unsafe {
+            data_array_squeeze(self.ptr);
+        }
+    }
+
+    /// Signal that the values were changed through [`as_mut_slice`](Self::as_mut_slice)
+    ///
+    /// Needed when the array is already part of a pipeline, so that filters re-execute.
+    #[doc(alias = "Modified")]
+    pub fn modified(&mut self) {
+        unsafe {
+            data_array_modified(self.ptr);
+        }
+    }
+
+    /// View all values (tuples × components) without copying
+    ///
+    /// # Safety
+    /// Several handles can share the same VTK array, e.g. the ones returned by
+    /// [`crate::ImageData::get_scalars`]. None of them may change, insert or remove values while the slice is alive.
+    pub unsafe fn as_slice(&self) -> &[T] {
+        unsafe { values_slice(self.ptr) }
+    }
+
+    /// View all values (tuples × components) mutably without copying
+    ///
+    /// # Safety
+    /// No other handle sharing the same VTK array may be used while the slice is alive,
+    /// see [`as_slice`](Self::as_slice).
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { values_slice_mut(self.ptr) }
+    }
+
+    /// Create an array holding a copy of `values`, `num_components` values per tuple
+    ///
+    /// # Panics
+    /// If the values do not fill whole tuples.
+    pub fn from_slice(name: &str, num_components: i64, values: &[T]) -> Self {
+        check_bulk_length(values.len(), num_components);
+        let mut array = Self::new();
+        array.set_number_of_components(num_components);
+        array.set_name(name);
+        unsafe { copy_values(array.ptr, values) };
+        array
+    }
+
+    /// Create an array holding a copy of `tuples`
+    pub fn from_tuples<const N: usize>(name: &str, tuples: &[[T; N]]) -> Self {
+        Self::from_slice(name, N as i64, tuples.as_flattened())
+    }
+
+    /// Create an array that takes over the buffer of `values` without copying
+    ///
+    /// # Panics
+    /// If the values do not fill whole tuples.
+    #[doc(alias = "SetArray")]
+    pub fn from_vec(name: &str, num_components: i64, values: Vec<T>) -> Self {
+        check_bulk_length(values.len(), num_components);
+        let mut array = Self::new();
+        array.set_number_of_components(num_components);
+        array.set_name(name);
+        unsafe { adopt_vec(array.ptr, values) };
+        array
+    }
+
+    /// Create an array that takes over the buffer of `tuples` without copying
+    #[doc(alias = "SetArray")]
+    pub fn from_tuple_vec<const N: usize>(name: &str, tuples: Vec<[T; N]>) -> Self {
+        Self::from_vec(name, N as i64, flatten_tuples(tuples))
+    }
+
+    /// Get the raw pointer (for internal use with other VTK functions)
+    pub fn as_ptr(&self) -> *mut vtkDataArray {
+        self.ptr
+    }
+
+    /// Create a 1-component array
+    pub fn new_scalar(name: &str) -> Self {
+        let mut array = Self::new();
+        array.set_number_of_components(1);
+        array.set_name(name);
+        array
+    }
+
+    /// Create a 3-component array
+    pub fn new_vector(name: &str) -> Self {
+        let mut array = Self::new();
+        array.set_number_of_components(3);
+        array.set_name(name);
+        array
+    }
+
+    fn check_tuple_length(&self, len: usize) {
+        let num_components = self.get_number_of_components();
+        assert_eq!(
+            len as i64,
+            num_components,
+            "tuple has {} values but the array has {} components",
+            len,
+            num_components
+        );
+    }
+}
+
+impl<T: DataArrayType> Default for DataArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DataArrayType> Drop for DataArray<T> {
+    fn drop(&mut self) {
+        unsafe {
+            data_array_delete(self.ptr);
+        }
+    }
+}
+
+unsafe impl<T: DataArrayType> Send for DataArray<T> {}
+unsafe impl<T: DataArrayType> Sync for DataArray<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_array_f32_vector() {
+        let mut array = DataArray::<f32>::new_vector("Displacement");
+        assert_eq!(array.get_data_type(), VtkDataType::Float);
+
+        array.insert_next_tuple3(1.0, 2.0, 3.0);
+        array.insert_next_tuple(&[4.0, 5.0, 6.0]);
+        array.set_tuple3(0, 0.5, 0.25, 0.0);
+        assert_eq!(array.get_number_of_tuples(), 2);
+        assert_eq!(array.get_tuple(1), vec![4.0, 5.0, 6.0]);
+        assert_eq!(array.get_value(1), 0.25);
+    }
+
+    #[test]
+    fn test_data_array_integer_types() {
+        let mut mask = DataArray::<u8>::new_scalar("Mask");
+        mask.insert_next_value(0);
+        mask.insert_next_value(255);
+        assert_eq!(mask.get_value(1), 255);
+        assert_eq!(mask.get_range(0), [0.0, 255.0]);
+
+        let mut ids = DataArray::<i64>::new_scalar("GlobalIds");
+        ids.insert_next_value(i64::MAX);
+        assert_eq!(ids.get_value(0), i64::MAX);
+    }
+
+    #[test]
+    fn test_data_array_bulk() {
+        let copied = DataArray::<f32>::from_tuples("Velocity", &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(copied.get_number_of_tuples(), 2);
+        assert_eq!(unsafe { copied.as_slice() }, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let values: Vec<u16> = (0..1000).collect();
+        let address = values.as_ptr();
+        let mut adopted = DataArray::from_vec("Labels", 2, values);
+        assert_eq!(adopted.get_number_of_tuples(), 500);
+        assert_eq!(unsafe { adopted.as_slice() }.as_ptr(), address);
+        unsafe { adopted.as_mut_slice()[999] = 7 };
+        assert_eq!(adopted.get_tuple(499), vec![998, 7]);
+
+        // Growing past the adopted buffer moves the values to VTK's own memory
+        adopted.insert_next_tuple2(1, 2);
+        assert_eq!(adopted.get_value(1001), 2);
+        drop(adopted);
+        assert!(ADOPTED.lock().unwrap().get(&(address as usize)).is_none());
+    }
+}
@@ -0,0 +1,335 @@
+use std::ffi::c_void;
+
+use crate::{
+    CellData, FieldData, ImageData, PointData, PolyData, RectilinearGrid, StructuredGrid,
+    UnstructuredGrid, VtkCellType,
+};
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn data_set_get_number_of_points(data_set: *mut c_void) -> i64;
+    fn data_set_get_number_of_cells(data_set: *mut c_void) -> i64;
+    fn data_set_get_point(data_set: *mut c_void, point_id: i64, point: *mut f64) -> bool;
+    fn data_set_get_bounds(data_set: *mut c_void, bounds: *mut f64);
+    fn data_set_find_point(data_set: *mut c_void, x: f64, y: f64, z: f64) -> i64;
+    fn data_set_get_cell_type(data_set: *mut c_void, cell_id: i64) -> i32;
+    fn data_set_get_cell_size(data_set: *mut c_void, cell_id: i64) -> i64;
+    fn data_set_get_cell_points(
+        data_set: *mut c_void,
+        cell_id: i64,
+        point_ids: *mut i64,
+        capacity: i64,
+    ) -> i64;
+    fn data_set_get_point_data(data_set: *mut c_void) -> *mut crate::vtk_point_data::vtkPointData;
+    fn data_set_get_cell_data(data_set: *mut c_void) -> *mut crate::vtk_cell_data::vtkCellData;
+    fn data_set_deep_copy(target: *mut c_void, source: *mut c_void);
+    fn data_set_shallow_copy(target: *mut c_void, source: *mut c_void);
+}
+
+/// Common interface of all dataset types (vtkDataSet)
+///
+/// Implemented by [`PolyData`], [`UnstructuredGrid`], [`ImageData`], [`RectilinearGrid`]
+/// and [`StructuredGrid`], so that generic code and filters such as
+/// [`crate::ContourFilter`] or [`crate::DataSetMapper`] accept any of them.
+///
+/// ```no_run
+/// use vtk_rs::*;
+///
+/// fn describe(data_set: &impl DataSet) {
+///     let [xmin, xmax, ..] = data_set.get_bounds();
+///     println!("{} points, {} cells, x from {} to {}",
+///         data_set.get_number_of_points(), data_set.get_number_of_cells(), xmin, xmax);
+/// }
+///
+/// describe(&PolyData::new());
+/// describe(&ImageData::new());
+/// ```
+///
+/// This trait is sealed: it is only implemented by the dataset types of this crate.
+pub trait DataSet: private::Sealed {
+    #[doc(hidden)]
+    fn as_data_set_ptr(&self) -> *mut c_void;
+
+    #[doc(alias = "GetNumberOfPoints")]
+    fn get_number_of_points(&self) -> i64 {
+        unsafe { data_set_get_number_of_points(self.as_data_set_ptr()) }
+    }
+
+    #[doc(alias = "GetNumberOfCells")]
+    fn get_number_of_cells(&self) -> i64 {
+        unsafe { data_set_get_number_of_cells(self.as_data_set_ptr()) }
+    }
+
+    /// Get the coordinates of a point, or `None` if the id is out of range
+    #[doc(alias = "GetPoint")]
+    fn get_point(&self, point_id: i64) -> Option<[f64; 3]> {
+        let mut point = [0.0; 3];
+        let found =
+            unsafe { data_set_get_point(self.as_data_set_ptr(), point_id, point.as_mut_ptr()) };
+        found.then_some(point)
+    }
+
+    /// Get the spatial bounds as `[xmin, xmax, ymin, ymax, zmin, zmax]`
+    #[doc(alias = "GetBounds")]
+    fn get_bounds(&self) -> [f64; 6] {
+        let mut bounds = [0.0; 6];
+        unsafe {
+            data_set_get_bounds(self.as_data_set_ptr(), bounds.as_mut_ptr());
+        }
+        bounds
+    }
+
+    /// Find the id of the point closest to `point`
+    ///
+    /// Returns `None` for an empty dataset, or for an [`ImageData`] if `point` lies
+    /// outside of the image.
+    #[doc(alias = "FindPoint")]
+    fn find_point(&self, point: [f64; 3]) -> Option<i64> {
+        let [x, y, z] = point;
+        let point_id = unsafe { data_set_find_point(self.as_data_set_ptr(), x, y, z) };
+        (point_id >= 0).then_some(point_id)
+    }
+
+    /// Get the type of a cell
+    ///
+    /// Returns `None` if the id is out of range or the cell type has no [`VtkCellType`].
+    #[doc(alias = "GetCellType")]
+    fn get_cell_type(&self, cell_id: i64) -> Option<VtkCellType> {
+        VtkCellType::from_raw(unsafe { data_set_get_cell_type(self.as_data_set_ptr(), cell_id) })
+    }
+
+    /// Get the point ids of a cell, or `None` if the id is out of range
+    #[doc(alias = "GetCellPoints")]
+    fn get_cell_points(&self, cell_id: i64) -> Option<Vec<i64>> {
+        if !(0..self.get_number_of_cells()).contains(&cell_id) {
+            return None;
+        }
+        let data_set = self.as_data_set_ptr();
+        unsafe {
+            let size = data_set_get_cell_size(data_set, cell_id);
+            let mut point_ids = vec![0; usize::try_from(size).unwrap_or(0)];
+            let count = data_set_get_cell_points(
+                data_set,
+                cell_id,
+                point_ids.as_mut_ptr(),
+                point_ids.len() as i64,
+            );
+            point_ids.truncate(usize::try_from(count).unwrap_or(0));
+            Some(point_ids)
+        }
+    }
+
+    /// Get the type and point ids of a cell
+    ///
+    /// Returns `None` if the id is out of range or the cell type has no [`VtkCellType`].
+    #[doc(alias = "GetCell")]
+    fn get_cell(&self, cell_id: i64) -> Option<(VtkCellType, Vec<i64>)> {
+        Some((self.get_cell_type(cell_id)?, self.get_cell_points(cell_id)?))
+    }
+
+    /// Get the point data (attributes associated with points/nodes)
+    ///
+    /// Use this to attach displacement, stress, temperature, or other node-based data
+    #[doc(alias = "GetPointData")]
+    fn get_point_data(&self) -> PointData {
+        unsafe { PointData::from_raw(data_set_get_point_data(self.as_data_set_ptr())) }
+    }
+
+    /// Get the cell data (attributes associated with cells/elements)
+    ///
+    /// Use this to attach material IDs, element stresses, or other element-based data
+    #[doc(alias = "GetCellData")]
+    fn get_cell_data(&self) -> CellData {
+        unsafe { CellData::from_raw(data_set_get_cell_data(self.as_data_set_ptr())) }
+    }
+
+    /// Get the field data (metadata describing the whole dataset)
+    ///
+    /// Use this to record load case names, units or solver version alongside the results
+    #[doc(alias = "GetFieldData")]
+    fn get_field_data(&self) -> FieldData {
+        unsafe { FieldData::from_data_object(self.as_data_set_ptr()) }
+    }
+
+    /// Replace the contents with an independent copy of `source`
+    #[doc(alias = "DeepCopy")]
+    fn deep_copy(&mut self, source: &Self)
+    where
+        Self: Sized,
+    {
+        unsafe {
+            data_set_deep_copy(self.as_data_set_ptr(), source.as_data_set_ptr());
+        }
+    }
+
+    /// Replace the contents with those of `source`, sharing points, cells and arrays
+    #[doc(alias = "ShallowCopy")]
+    fn shallow_copy(&mut self, source: &Self)
+    where
+        Self: Sized,
+    {
+        unsafe {
+            data_set_shallow_copy(self.as_data_set_ptr(), source.as_data_set_ptr());
+        }
+    }
+}
+
+impl private::Sealed for PolyData {}
+impl DataSet for PolyData {
+    fn as_data_set_ptr(&self) -> *mut c_void {
+        self.as_ptr() as *mut c_void
+    }
+}
+
+impl private::Sealed for UnstructuredGrid {}
+impl DataSet for UnstructuredGrid {
+    fn as_data_set_ptr(&self) -> *mut c_void {
+        self.as_ptr() as *mut c_void
+    }
+}
+
+impl private::Sealed for ImageData {}
+impl DataSet for ImageData {
+    fn as_data_set_ptr(&self) -> *mut c_void {
+        self.as_ptr() as *mut c_void
+    }
+}
+
+impl private::Sealed for RectilinearGrid {}
+impl DataSet for RectilinearGrid {
+    fn as_data_set_ptr(&self) -> *mut c_void {
+        self.as_ptr() as *mut c_void
+    }
+}
+
+impl private::Sealed for StructuredGrid {}
+impl DataSet for StructuredGrid {
+    fn as_data_set_ptr(&self) -> *mut c_void {
+        self.as_ptr() as *mut c_void
+    }
+}
+
+// Cloning is a deep copy, as with vtkDataSet::NewInstance followed by DeepCopy
+macro_rules! impl_clone_by_deep_copy {
+    ($($data_set:ty),*) => {
+        $(
+            impl Clone for $data_set {
+                fn clone(&self) -> Self {
+                    let mut copy = Self::new();
+                    copy.deep_copy(self);
+                    copy
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_by_deep_copy!(PolyData, UnstructuredGrid, ImageData, RectilinearGrid, StructuredGrid);
+
+// The common accessors are also inherent so that they can be called without importing
+// `DataSet`; they have the same signatures and forward to the trait.
+macro_rules! impl_inherent_accessors {
+    ($($data_set:ty),*) => {
+        $(
+            impl $data_set {
+                /// See [`DataSet::get_number_of_points`]
+                #[doc(alias = "GetNumberOfPoints")]
+                pub fn get_number_of_points(&self) -> i64 {
+                    DataSet::get_number_of_points(self)
+                }
+
+                /// See [`DataSet::get_number_of_cells`]
+                #[doc(alias = "GetNumberOfCells")]
+                pub fn get_number_of_cells(&self) -> i64 {
+                    DataSet::get_number_of_cells(self)
+                }
+
+                /// See [`DataSet::get_bounds`]
+                #[doc(alias = "GetBounds")]
+                pub fn get_bounds(&self) -> [f64; 6] {
+                    DataSet::get_bounds(self)
+                }
+
+                /// See [`DataSet::get_point_data`]
+                #[doc(alias = "GetPointData")]
+                pub fn get_point_data(&self) -> PointData {
+                    DataSet::get_point_data(self)
+                }
+
+                /// See [`DataSet::get_cell_data`]
+                #[doc(alias = "GetCellData")]
+                pub fn get_cell_data(&self) -> CellData {
+                    DataSet::get_cell_data(self)
+                }
+
+                /// See [`DataSet::get_field_data`]
+                #[doc(alias = "GetFieldData")]
+                pub fn get_field_data(&self) -> FieldData {
+                    DataSet::get_field_data(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_inherent_accessors!(PolyData, UnstructuredGrid, ImageData, RectilinearGrid, StructuredGrid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_triangles() -> UnstructuredGrid {
+        let mut points = crate::Points::new();
+        points.insert_next_point(0.0, 0.0, 0.0);
+        points.insert_next_point(1.0, 0.0, 0.0);
+        points.insert_next_point(0.0, 1.0, 0.0);
+        points.insert_next_point(1.0, 1.0, 2.0);
+
+        let mut grid = UnstructuredGrid::new();
+        grid.set_points(&mut points);
+        grid.allocate(2);
+        grid.insert_next_cell(VtkCellType::Triangle, &[0, 1, 2]);
+        grid.insert_next_cell(VtkCellType::Triangle, &[1, 3, 2]);
+        grid
+    }
+
+    #[test]
+    fn test_data_set_geometry() {
+        let grid = two_triangles();
+        assert_eq!(grid.get_number_of_points(), 4);
+        assert_eq!(grid.get_number_of_cells(), 2);
+        assert_eq!(grid.get_point(3), Some([1.0, 1.0, 2.0]));
+        assert_eq!(grid.get_point(4), None);
+        assert_eq!(grid.get_bounds(), [0.0, 1.0, 0.0, 1.0, 0.0, 2.0]);
+        assert_eq!(grid.find_point([0.9, 0.1, 0.0]), Some(1));
+        assert_eq!(grid.get_cell(1), Some((VtkCellType::Triangle, vec![1, 3, 2])));
+        assert_eq!(grid.get_cell(2), None);
+    }
+
+    #[test]
+    fn test_data_set_copies() {
+        let grid = two_triangles();
+        let mut scalars = crate::DoubleArray::new_scalar("Thickness");
+        scalars.insert_next_value(0.01);
+        scalars.insert_next_value(0.02);
+        grid.get_cell_data().add_array(&scalars);
+
+        let copy = grid.clone();
+        assert_eq!(copy.get_number_of_cells(), 2);
+        assert_eq!(copy.get_cell_data().get_number_of_arrays(), 1);
+
+        let mut shared = UnstructuredGrid::new();
+        shared.shallow_copy(&grid);
+        assert_eq!(shared.get_number_of_points(), 4);
+
+        let mut image = ImageData::new();
+        image.set_dimensions(3, 3, 1);
+        assert_eq!(image.get_number_of_points(), 9);
+        assert_eq!(image.get_cell(0).map(|(cell_type, _)| cell_type), Some(VtkCellType::Pixel));
+        assert_eq!(image.find_point([5.0, 0.0, 0.0]), None);
+    }
+}
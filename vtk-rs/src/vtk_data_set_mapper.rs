@@ -11,6 +11,7 @@ mod ffi {
         type vtkMapper;
         type vtkDataSet;
         type vtkAlgorithmOutput;
+        type vtkLookupTable = crate::vtk_lookup_table::ffi::vtkLookupTable;
 
         fn vtk_data_set_mapper_new() -> *mut vtkDataSetMapper;
         fn vtk_data_set_mapper_delete(mapper: Pin<&mut vtkDataSetMapper>);
@@ -23,6 +24,19 @@ mod ffi {
             mapper: Pin<&mut vtkDataSetMapper>,
             data_set: *mut vtkDataSet
         );
+
+        fn data_set_mapper_set_lookup_table(
+            mapper: Pin<&mut vtkDataSetMapper>,
+            lut: Pin<&mut vtkLookupTable>
+        );
+        fn data_set_mapper_set_scalar_range(mapper: Pin<&mut vtkDataSetMapper>, min: f64, max: f64);
+        fn data_set_mapper_set_scalar_visibility(mapper: Pin<&mut vtkDataSetMapper>, visible: bool);
+        fn data_set_mapper_get_scalar_visibility(mapper: &vtkDataSetMapper) -> bool;
+        fn data_set_mapper_color_by_array(
+            mapper: Pin<&mut vtkDataSetMapper>,
+            name: &str,
+            cell_data: bool
+        );
     }
 }
 
@@ -47,13 +61,53 @@ impl DataSetMapper {
 
     /// Sets the input data from any VTK dataset (PolyData, UnstructuredGrid, ImageData, etc.)
     #[doc(alias = "SetInputData")]
-    pub fn set_input_data(&mut self, data_set: &mut crate::UnstructuredGrid) {
-        let ptr = data_set.as_raw_ptr() as *mut ffi::vtkDataSet;
+    pub fn set_input_data(&mut self, data_set: &impl crate::DataSet) {
+        let ptr = data_set.as_data_set_ptr() as *mut ffi::vtkDataSet;
         unsafe {
             ffi::data_set_mapper_set_input_data(self.ptr.as_mut(), ptr);
         }
     }
 
+    /// Set the lookup table mapping scalar values to colors
+    #[doc(alias = "SetLookupTable")]
+    pub fn set_lookup_table(&mut self, lut: &mut crate::LookupTable) {
+        unsafe {
+            let lut = Pin::new_unchecked(&mut *lut.as_raw_ptr());
+            ffi::data_set_mapper_set_lookup_table(self.ptr.as_mut(), lut);
+        }
+    }
+
+    /// Set the scalar values mapped to the first and last color of the lookup table
+    #[doc(alias = "SetScalarRange")]
+    pub fn set_scalar_range(&mut self, min: f64, max: f64) {
+        ffi::data_set_mapper_set_scalar_range(self.ptr.as_mut(), min, max);
+    }
+
+    /// Color by scalars (on by default) or by the actor color
+    #[doc(alias = "SetScalarVisibility")]
+    pub fn set_scalar_visibility(&mut self, visible: bool) {
+        ffi::data_set_mapper_set_scalar_visibility(self.ptr.as_mut(), visible);
+    }
+
+    #[doc(alias = "GetScalarVisibility")]
+    pub fn get_scalar_visibility(&self) -> bool {
+        ffi::data_set_mapper_get_scalar_visibility(&self.ptr.as_ref())
+    }
+
+    /// Color by a named point array instead of the active scalars
+    #[doc(alias = "SelectColorArray")]
+    #[doc(alias = "SetScalarModeToUsePointFieldData")]
+    pub fn color_by_point_array(&mut self, name: &str) {
+        ffi::data_set_mapper_color_by_array(self.ptr.as_mut(), name, false);
+    }
+
+    /// Color by a named cell array instead of the active scalars
+    #[doc(alias = "SelectColorArray")]
+    #[doc(alias = "SetScalarModeToUseCellFieldData")]
+    pub fn color_by_cell_array(&mut self, name: &str) {
+        ffi::data_set_mapper_color_by_array(self.ptr.as_mut(), name, true);
+    }
+
     /// Get raw pointer for VTK pipeline connections
     pub fn as_raw_ptr(&mut self) -> *mut ffi::vtkDataSetMapper {
         unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
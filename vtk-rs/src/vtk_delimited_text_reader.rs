@@ -0,0 +1,219 @@
+use std::path::Path;
+
+use crate::{ Table, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_delimited_text_reader.h");
+        include!("vtk_table.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkDelimitedTextReader;
+        type vtkTable = crate::vtk_table::ffi::vtkTable;
+        type vtkAlgorithmOutput;
+
+        fn vtk_delimited_text_reader_new() -> *mut vtkDelimitedTextReader;
+        fn vtk_delimited_text_reader_delete(reader: Pin<&mut vtkDelimitedTextReader>);
+
+        fn delimited_text_reader_set_file_name(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            file_name: &str
+        );
+        fn delimited_text_reader_get_file_name(reader: &vtkDelimitedTextReader) -> String;
+
+        fn delimited_text_reader_set_field_delimiter_characters(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            delimiters: &str
+        );
+        fn delimited_text_reader_get_field_delimiter_characters(
+            reader: &vtkDelimitedTextReader
+        ) -> String;
+        fn delimited_text_reader_set_add_tab_field_delimiter(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            enabled: bool
+        );
+        fn delimited_text_reader_set_merge_consecutive_delimiters(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            enabled: bool
+        );
+        fn delimited_text_reader_set_string_delimiter(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            delimiter: u8
+        );
+        fn delimited_text_reader_set_use_string_delimiter(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            enabled: bool
+        );
+        fn delimited_text_reader_set_have_headers(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            enabled: bool
+        );
+        fn delimited_text_reader_get_have_headers(reader: &vtkDelimitedTextReader) -> bool;
+        fn delimited_text_reader_set_detect_numeric_columns(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            enabled: bool
+        );
+        fn delimited_text_reader_get_detect_numeric_columns(
+            reader: &vtkDelimitedTextReader
+        ) -> bool;
+        fn delimited_text_reader_set_force_double(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            enabled: bool
+        );
+        fn delimited_text_reader_set_max_records(
+            reader: Pin<&mut vtkDelimitedTextReader>,
+            max_records: i64
+        );
+
+        fn delimited_text_reader_update(reader: Pin<&mut vtkDelimitedTextReader>) -> Result<()>;
+        fn delimited_text_reader_get_output(
+            reader: Pin<&mut vtkDelimitedTextReader>
+        ) -> *mut vtkTable;
+        unsafe fn delimited_text_reader_get_output_port(
+            reader: Pin<&mut vtkDelimitedTextReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkDelimitedTextReader.html",
+    @name DelimitedTextReader, ffi::vtkDelimitedTextReader,
+    @new ffi::vtk_delimited_text_reader_new,
+    @delete ffi::vtk_delimited_text_reader_delete
+);
+
+impl DelimitedTextReader {
+    /// Set the name of the CSV or other delimited text file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::delimited_text_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::delimited_text_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the characters that separate fields (default `","`)
+    ///
+    /// Any one of the characters ends a field, e.g. `",;"`.
+    #[doc(alias = "SetFieldDelimiterCharacters")]
+    pub fn set_field_delimiter_characters(&mut self, delimiters: &str) {
+        ffi::delimited_text_reader_set_field_delimiter_characters(self.ptr.as_mut(), delimiters);
+    }
+
+    #[doc(alias = "GetFieldDelimiterCharacters")]
+    pub fn get_field_delimiter_characters(&self) -> String {
+        ffi::delimited_text_reader_get_field_delimiter_characters(&self.ptr.as_ref())
+    }
+
+    /// Also split fields at tabs, in addition to the field delimiter characters
+    #[doc(alias = "SetAddTabFieldDelimiter")]
+    pub fn set_add_tab_field_delimiter(&mut self, enabled: bool) {
+        ffi::delimited_text_reader_set_add_tab_field_delimiter(self.ptr.as_mut(), enabled);
+    }
+
+    /// Treat runs of delimiters as one, e.g. for space aligned columns
+    #[doc(alias = "SetMergeConsecutiveDelimiters")]
+    pub fn set_merge_consecutive_delimiters(&mut self, enabled: bool) {
+        ffi::delimited_text_reader_set_merge_consecutive_delimiters(self.ptr.as_mut(), enabled);
+    }
+
+    /// Set the quote character around fields that contain delimiters (default `'"'`)
+    ///
+    /// # Panics
+    /// If `delimiter` is not an ASCII character.
+    #[doc(alias = "SetStringDelimiter")]
+    pub fn set_string_delimiter(&mut self, delimiter: char) {
+        assert!(delimiter.is_ascii(), "string delimiter must be an ASCII character");
+        ffi::delimited_text_reader_set_string_delimiter(self.ptr.as_mut(), delimiter as u8);
+    }
+
+    #[doc(alias = "SetUseStringDelimiter")]
+    pub fn set_use_string_delimiter(&mut self, enabled: bool) {
+        ffi::delimited_text_reader_set_use_string_delimiter(self.ptr.as_mut(), enabled);
+    }
+
+    /// Use the first line as column names (default off: columns are named `Field 0`, ...)
+    #[doc(alias = "SetHaveHeaders")]
+    pub fn set_have_headers(&mut self, enabled: bool) {
+        ffi::delimited_text_reader_set_have_headers(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetHaveHeaders")]
+    pub fn get_have_headers(&self) -> bool {
+        ffi::delimited_text_reader_get_have_headers(&self.ptr.as_ref())
+    }
+
+    /// Store columns holding only numbers as numeric columns (default off: all text)
+    ///
+    /// Whole numbers become [`crate::TableColumnType::Int`] columns and other numbers
+    /// [`crate::TableColumnType::Double`] columns, unless
+    /// [`set_force_double`](Self::set_force_double) is on.
+    #[doc(alias = "SetDetectNumericColumns")]
+    pub fn set_detect_numeric_columns(&mut self, enabled: bool) {
+        ffi::delimited_text_reader_set_detect_numeric_columns(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetDetectNumericColumns")]
+    pub fn get_detect_numeric_columns(&self) -> bool {
+        ffi::delimited_text_reader_get_detect_numeric_columns(&self.ptr.as_ref())
+    }
+
+    /// Store all detected numeric columns as doubles, including whole numbers
+    #[doc(alias = "SetForceDouble")]
+    pub fn set_force_double(&mut self, enabled: bool) {
+        ffi::delimited_text_reader_set_force_double(self.ptr.as_mut(), enabled);
+    }
+
+    /// Read at most this many records (0 reads the whole file)
+    #[doc(alias = "SetMaxRecords")]
+    pub fn set_max_records(&mut self, max_records: i64) {
+        ffi::delimited_text_reader_set_max_records(self.ptr.as_mut(), max_records);
+    }
+
+    /// Read the file
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        ffi::delimited_text_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get a copy of the table that was read
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> Table {
+        unsafe { Table::from_raw(ffi::delimited_text_reader_get_output(self.ptr.as_mut())) }
+    }
+
+    /// Get the output port for connecting to filters
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::delimited_text_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read a comma separated file with a header line into a [`Table`]
+    ///
+    /// Numeric columns are detected, so coordinates and results can be used as
+    /// [`crate::DoubleArray`]s and [`crate::IntArray`]s directly.
+    pub fn read_csv(path: impl AsRef<Path>) -> Result<Table, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.set_have_headers(true);
+        reader.set_detect_numeric_columns(true);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
+
+impl Table {
+    /// Read a comma separated file with a header line, see [`DelimitedTextReader::read_csv`]
+    pub fn read_csv(path: impl AsRef<Path>) -> Result<Table, VtkError> {
+        DelimitedTextReader::read_csv(path)
+    }
+}
@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use crate::{ ImageData, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_dicom_image_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkDICOMImageReader;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_dicom_image_reader_new() -> *mut vtkDICOMImageReader;
+        fn vtk_dicom_image_reader_delete(reader: Pin<&mut vtkDICOMImageReader>);
+
+        fn dicom_image_reader_set_file_name(reader: Pin<&mut vtkDICOMImageReader>, file_name: &str);
+        fn dicom_image_reader_get_file_name(reader: &vtkDICOMImageReader) -> String;
+        fn dicom_image_reader_can_read_file(
+            reader: Pin<&mut vtkDICOMImageReader>,
+            file_name: &str
+        ) -> bool;
+        fn dicom_image_reader_set_directory_name(
+            reader: Pin<&mut vtkDICOMImageReader>,
+            directory_name: &str
+        );
+        fn dicom_image_reader_get_directory_name(reader: &vtkDICOMImageReader) -> String;
+        fn dicom_image_reader_get_patient_name(reader: Pin<&mut vtkDICOMImageReader>) -> String;
+        fn dicom_image_reader_get_study_uid(reader: Pin<&mut vtkDICOMImageReader>) -> String;
+        fn dicom_image_reader_get_study_id(reader: Pin<&mut vtkDICOMImageReader>) -> String;
+        fn dicom_image_reader_get_rescale_slope(reader: Pin<&mut vtkDICOMImageReader>) -> f32;
+        fn dicom_image_reader_get_rescale_offset(reader: Pin<&mut vtkDICOMImageReader>) -> f32;
+        fn dicom_image_reader_get_pixel_spacing(
+            reader: Pin<&mut vtkDICOMImageReader>,
+            x: &mut f64,
+            y: &mut f64,
+            z: &mut f64
+        );
+        fn dicom_image_reader_update(reader: Pin<&mut vtkDICOMImageReader>) -> Result<()>;
+        fn dicom_image_reader_get_output(
+            reader: Pin<&mut vtkDICOMImageReader>
+        ) -> *mut vtkImageData;
+        unsafe fn dicom_image_reader_get_output_port(
+            reader: Pin<&mut vtkDICOMImageReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkDICOMImageReader.html",
+    @name DICOMImageReader, ffi::vtkDICOMImageReader,
+    @new ffi::vtk_dicom_image_reader_new,
+    @delete ffi::vtk_dicom_image_reader_delete
+);
+
+impl DICOMImageReader {
+    /// Set the name of a single DICOM file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::dicom_image_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::dicom_image_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Check whether the given file is a DICOM file
+    #[doc(alias = "CanReadFile")]
+    pub fn can_read_file(&mut self, file_name: &str) -> bool {
+        ffi::dicom_image_reader_can_read_file(self.ptr.as_mut(), file_name)
+    }
+
+    /// Set the directory holding the slices of a series
+    ///
+    /// All files in the directory are read as one volume, ordered by slice position.
+    /// Takes precedence over [`set_file_name`](Self::set_file_name).
+    #[doc(alias = "SetDirectoryName")]
+    pub fn set_directory_name(&mut self, directory_name: &str) {
+        ffi::dicom_image_reader_set_directory_name(self.ptr.as_mut(), directory_name);
+    }
+
+    #[doc(alias = "GetDirectoryName")]
+    pub fn get_directory_name(&self) -> String {
+        ffi::dicom_image_reader_get_directory_name(&self.ptr.as_ref())
+    }
+
+    /// Read the file or series directory
+    ///
+    /// Fails if the file or directory does not exist or holds no readable DICOM image.
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let directory_name = self.get_directory_name();
+        let source = if directory_name.is_empty() {
+            let file_name = self.get_file_name();
+            crate::error::check_readable(Path::new(&file_name))?;
+            if !self.can_read_file(&file_name) {
+                return Err(VtkError::invalid_file(&file_name, "not a DICOM file"));
+            }
+            file_name
+        } else {
+            if !Path::new(&directory_name).is_dir() {
+                return Err(VtkError::FileNotFound(directory_name.into()));
+            }
+            directory_name
+        };
+        ffi::dicom_image_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&source, e.what()))
+    }
+
+    /// Get the patient name (available after [`update`](Self::update))
+    #[doc(alias = "GetPatientName")]
+    pub fn get_patient_name(&mut self) -> String {
+        ffi::dicom_image_reader_get_patient_name(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetStudyUID")]
+    pub fn get_study_uid(&mut self) -> String {
+        ffi::dicom_image_reader_get_study_uid(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetStudyID")]
+    pub fn get_study_id(&mut self) -> String {
+        ffi::dicom_image_reader_get_study_id(self.ptr.as_mut())
+    }
+
+    /// Get the slope mapping stored values to physical values (e.g. Hounsfield units)
+    #[doc(alias = "GetRescaleSlope")]
+    pub fn get_rescale_slope(&mut self) -> f32 {
+        ffi::dicom_image_reader_get_rescale_slope(self.ptr.as_mut())
+    }
+
+    /// Get the offset mapping stored values to physical values
+    #[doc(alias = "GetRescaleOffset")]
+    pub fn get_rescale_offset(&mut self) -> f32 {
+        ffi::dicom_image_reader_get_rescale_offset(self.ptr.as_mut())
+    }
+
+    /// Get the pixel spacing, with the slice distance as the third component
+    #[doc(alias = "GetPixelSpacing")]
+    pub fn get_pixel_spacing(&mut self) -> (f64, f64, f64) {
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut z = 0.0;
+        ffi::dicom_image_reader_get_pixel_spacing(self.ptr.as_mut(), &mut x, &mut y, &mut z);
+        (x, y, z)
+    }
+
+    /// Get a copy of the volume that was read
+    ///
+    /// Spacing and origin are taken from the file header.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> ImageData {
+        let ptr = ffi::dicom_image_reader_get_output(self.ptr.as_mut());
+        unsafe { ImageData::from_raw(ptr as *mut _) }
+    }
+
+    /// Get the output port for connecting to filters
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::dicom_image_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read all slices in a directory into an [`ImageData`]
+    pub fn read_directory(path: impl AsRef<Path>) -> Result<ImageData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_directory_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+
+    /// Read a single DICOM file into an [`ImageData`]
+    pub fn read(path: impl AsRef<Path>) -> Result<ImageData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
@@ -1,5 +1,15 @@
 use std::ffi::{ CStr, CString };
 
+use crate::vtk_data_array::{
+    adopt_vec,
+    check_bulk_length,
+    copy_values,
+    data_array_modified,
+    flatten_tuples,
+    values_slice,
+    values_slice_mut,
+};
+
 /// Opaque handle to vtkDoubleArray from VTK
 #[repr(C)]
 pub struct vtkDoubleArray {
@@ -54,6 +64,12 @@ impl DoubleArray {
         Self { ptr }
     }
 
+    /// Take ownership of a reference to an existing vtkDoubleArray (e.g. a table column)
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkDoubleArray) -> Self {
+        assert!(!ptr.is_null(), "vtkDoubleArray pointer is null");
+        Self { ptr }
+    }
+
     /// Set the number of components per tuple
     ///
     /// - 1 component: Scalars (e.g., displacement magnitude, stress)
@@ -186,6 +202,72 @@ impl DoubleArray {
         }
     }
 
+    /// Signal that the values were changed through [`as_mut_slice`](Self::as_mut_slice)
+    ///
+    /// Needed when the array is already part of a pipeline, so that filters re-execute.
+    #[doc(alias = "Modified")]
+    pub fn modified(&mut self) {
+        unsafe {
+            data_array_modified(self.ptr as *mut _);
+        }
+    }
+
+    /// View all values (tuples × components) without copying
+    ///
+    /// # Safety
+    /// Several handles can share the same VTK array, e.g. the ones returned by
+    /// `get_double_array` of [`crate::FieldData`]. None of them may change, insert or remove values while the slice is alive.
+    pub unsafe fn as_slice(&self) -> &[f64] {
+        unsafe { values_slice(self.ptr as *mut _) }
+    }
+
+    /// View all values (tuples × components) mutably without copying
+    ///
+    /// # Safety
+    /// No other handle sharing the same VTK array may be used while the slice is alive,
+    /// see [`as_slice`](Self::as_slice).
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [f64] {
+        unsafe { values_slice_mut(self.ptr as *mut _) }
+    }
+
+    /// Create an array holding a copy of `values`, `num_components` values per tuple
+    ///
+    /// # Panics
+    /// If the values do not fill whole tuples.
+    pub fn from_slice(name: &str, num_components: i64, values: &[f64]) -> Self {
+        check_bulk_length(values.len(), num_components);
+        let mut array = Self::new();
+        array.set_number_of_components(num_components);
+        array.set_name(name);
+        unsafe { copy_values(array.ptr as *mut _, values) };
+        array
+    }
+
+    /// Create an array holding a copy of `tuples`
+    pub fn from_tuples<const N: usize>(name: &str, tuples: &[[f64; N]]) -> Self {
+        Self::from_slice(name, N as i64, tuples.as_flattened())
+    }
+
+    /// Create an array that takes over the buffer of `values` without copying
+    ///
+    /// # Panics
+    /// If the values do not fill whole tuples.
+    #[doc(alias = "SetArray")]
+    pub fn from_vec(name: &str, num_components: i64, values: Vec<f64>) -> Self {
+        check_bulk_length(values.len(), num_components);
+        let mut array = Self::new();
+        array.set_number_of_components(num_components);
+        array.set_name(name);
+        unsafe { adopt_vec(array.ptr as *mut _, values) };
+        array
+    }
+
+    /// Create an array that takes over the buffer of `tuples` without copying
+    #[doc(alias = "SetArray")]
+    pub fn from_tuple_vec<const N: usize>(name: &str, tuples: Vec<[f64; N]>) -> Self {
+        Self::from_vec(name, N as i64, flatten_tuples(tuples))
+    }
+
     /// Get the raw pointer (for internal use with other VTK functions)
     pub fn as_ptr(&self) -> *mut vtkDoubleArray {
         self.ptr
@@ -258,4 +340,18 @@ mod tests {
         let tuple1 = array.get_tuple(1);
         assert_eq!(tuple1, vec![4.0, 5.0, 6.0]);
     }
+
+    #[test]
+    fn test_double_array_bulk() {
+        let tuples = [[0.0, 0.1, 0.2], [1.0, 1.1, 1.2]];
+        let mut array = DoubleArray::from_tuples("Displacement", &tuples);
+        assert_eq!(array.get_number_of_components(), 3);
+        assert_eq!(array.get_tuple(1), vec![1.0, 1.1, 1.2]);
+
+        unsafe { array.as_mut_slice()[0] = -0.5 };
+        assert_eq!(array.get_value(0), -0.5);
+
+        let adopted = DoubleArray::from_vec("Stress", 1, vec![10.0, 20.0, 30.0]);
+        assert_eq!(unsafe { adopted.as_slice() }, &[10.0, 20.0, 30.0]);
+    }
 }
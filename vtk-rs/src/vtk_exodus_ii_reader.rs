@@ -0,0 +1,427 @@
+use std::path::Path;
+
+use crate::{ UnstructuredGrid, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_exodus_ii_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkExodusIIReader;
+        type vtkUnstructuredGrid;
+        type vtkAlgorithmOutput;
+
+        fn vtk_exodus_ii_reader_new() -> *mut vtkExodusIIReader;
+        fn vtk_exodus_ii_reader_delete(reader: Pin<&mut vtkExodusIIReader>);
+
+        fn exodus_ii_reader_set_file_name(reader: Pin<&mut vtkExodusIIReader>, file_name: &str);
+        fn exodus_ii_reader_get_file_name(reader: &vtkExodusIIReader) -> String;
+        fn exodus_ii_reader_can_read_file(
+            reader: Pin<&mut vtkExodusIIReader>,
+            file_name: &str
+        ) -> bool;
+        fn exodus_ii_reader_update_information(reader: Pin<&mut vtkExodusIIReader>) -> Result<()>;
+        fn exodus_ii_reader_update(reader: Pin<&mut vtkExodusIIReader>) -> Result<()>;
+
+        fn exodus_ii_reader_get_time_values(reader: Pin<&mut vtkExodusIIReader>) -> Vec<f64>;
+        fn exodus_ii_reader_set_time_step(reader: Pin<&mut vtkExodusIIReader>, step: i32);
+        fn exodus_ii_reader_get_time_step(reader: &vtkExodusIIReader) -> i32;
+
+        fn exodus_ii_reader_get_number_of_objects(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32
+        ) -> i32;
+        fn exodus_ii_reader_get_object_name(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            index: i32
+        ) -> String;
+        fn exodus_ii_reader_get_object_id(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            index: i32
+        ) -> i32;
+        fn exodus_ii_reader_get_object_status(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            index: i32
+        ) -> bool;
+        fn exodus_ii_reader_set_object_status(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            index: i32,
+            enabled: bool
+        );
+        fn exodus_ii_reader_get_number_of_object_arrays(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32
+        ) -> i32;
+        fn exodus_ii_reader_get_object_array_name(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            index: i32
+        ) -> String;
+        fn exodus_ii_reader_get_object_array_status(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            index: i32
+        ) -> bool;
+        fn exodus_ii_reader_set_object_array_status(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            name: &str,
+            enabled: bool
+        );
+        fn exodus_ii_reader_set_all_array_status(
+            reader: Pin<&mut vtkExodusIIReader>,
+            object_type: i32,
+            enabled: bool
+        );
+
+        fn exodus_ii_reader_set_apply_displacements(
+            reader: Pin<&mut vtkExodusIIReader>,
+            enabled: bool
+        );
+        fn exodus_ii_reader_get_apply_displacements(reader: &vtkExodusIIReader) -> bool;
+        fn exodus_ii_reader_set_displacement_magnitude(
+            reader: Pin<&mut vtkExodusIIReader>,
+            magnitude: f32
+        );
+        fn exodus_ii_reader_get_displacement_magnitude(reader: &vtkExodusIIReader) -> f32;
+        fn exodus_ii_reader_set_generate_object_id_cell_array(
+            reader: Pin<&mut vtkExodusIIReader>,
+            enabled: bool
+        );
+        fn exodus_ii_reader_set_generate_global_node_id_array(
+            reader: Pin<&mut vtkExodusIIReader>,
+            enabled: bool
+        );
+        fn exodus_ii_reader_set_generate_global_element_id_array(
+            reader: Pin<&mut vtkExodusIIReader>,
+            enabled: bool
+        );
+
+        fn exodus_ii_reader_get_number_of_output_blocks(
+            reader: Pin<&mut vtkExodusIIReader>
+        ) -> i32;
+        fn exodus_ii_reader_get_output_block(
+            reader: Pin<&mut vtkExodusIIReader>,
+            index: i32
+        ) -> *mut vtkUnstructuredGrid;
+        fn exodus_ii_reader_get_output_block_name(
+            reader: Pin<&mut vtkExodusIIReader>,
+            index: i32
+        ) -> String;
+        fn exodus_ii_reader_get_output_block_type(
+            reader: Pin<&mut vtkExodusIIReader>,
+            index: i32
+        ) -> i32;
+        unsafe fn exodus_ii_reader_get_output_port(
+            reader: Pin<&mut vtkExodusIIReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+/// Kinds of objects and result variables in an Exodus II file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ExodusObjectType {
+    ElementBlock = 1,
+    NodeSet = 2,
+    SideSet = 3,
+    EdgeBlock = 6,
+    EdgeSet = 7,
+    FaceBlock = 8,
+    FaceSet = 9,
+    ElementSet = 10,
+    /// Global (per time step) variables
+    Global = 13,
+    /// Nodal (point) variables
+    Nodal = 14,
+}
+
+impl ExodusObjectType {
+    fn from_i32(value: i32) -> Option<Self> {
+        use ExodusObjectType::*;
+        [
+            ElementBlock, NodeSet, SideSet, EdgeBlock, EdgeSet, FaceBlock, FaceSet, ElementSet,
+            Global, Nodal,
+        ]
+            .into_iter()
+            .find(|object_type| *object_type as i32 == value)
+    }
+}
+
+/// A dataset of the reader output: one element block or set
+pub struct ExodusBlock {
+    /// Kind of object the block was read from
+    pub object_type: ExodusObjectType,
+    /// Name of the block (`Unnamed block ID: ...` if the file has none)
+    pub name: String,
+    /// Cells and arrays of the block
+    pub grid: UnstructuredGrid,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkExodusIIReader.html",
+    @name ExodusIIReader, ffi::vtkExodusIIReader,
+    @new ffi::vtk_exodus_ii_reader_new,
+    @delete ffi::vtk_exodus_ii_reader_delete
+);
+
+impl ExodusIIReader {
+    /// Set the name of the Exodus II file (`.exo`, `.e`, `.g`) to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::exodus_ii_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::exodus_ii_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Check whether the given file is an Exodus II file
+    #[doc(alias = "CanReadFile")]
+    pub fn can_read_file(&mut self, file_name: &str) -> bool {
+        ffi::exodus_ii_reader_can_read_file(self.ptr.as_mut(), file_name)
+    }
+
+    /// Read the metadata of the file (objects, variables and time steps)
+    ///
+    /// Needed before objects and variables can be listed and selected.
+    #[doc(alias = "UpdateInformation")]
+    pub fn update_information(&mut self) -> Result<(), VtkError> {
+        let file_name = self.check_file()?;
+        ffi::exodus_ii_reader_update_information(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Read the selected objects and variables at the selected time step
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.check_file()?;
+        ffi::exodus_ii_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    fn check_file(&mut self) -> Result<String, VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        if !self.can_read_file(&file_name) {
+            return Err(VtkError::invalid_file(&file_name, "not an Exodus II file"));
+        }
+        Ok(file_name)
+    }
+
+    /// Get the time values stored in the file
+    ///
+    /// Empty if the file holds no results.
+    #[doc(alias = "TIME_STEPS")]
+    pub fn get_time_values(&mut self) -> Vec<f64> {
+        ffi::exodus_ii_reader_get_time_values(self.ptr.as_mut())
+    }
+
+    /// Get the number of time steps stored in the file
+    #[doc(alias = "GetNumberOfTimeSteps")]
+    pub fn get_number_of_time_steps(&mut self) -> usize {
+        self.get_time_values().len()
+    }
+
+    /// Select the time step read by the next [`update`](Self::update)
+    #[doc(alias = "SetTimeStep")]
+    pub fn set_time_step(&mut self, step: usize) {
+        ffi::exodus_ii_reader_set_time_step(self.ptr.as_mut(), step as i32);
+    }
+
+    #[doc(alias = "GetTimeStep")]
+    pub fn get_time_step(&self) -> usize {
+        ffi::exodus_ii_reader_get_time_step(&self.ptr.as_ref()).max(0) as usize
+    }
+
+    /// Get the number of objects (blocks or sets) of a kind
+    #[doc(alias = "GetNumberOfObjects")]
+    pub fn get_number_of_objects(&mut self, object_type: ExodusObjectType) -> i32 {
+        ffi::exodus_ii_reader_get_number_of_objects(self.ptr.as_mut(), object_type as i32)
+    }
+
+    #[doc(alias = "GetObjectName")]
+    pub fn get_object_name(&mut self, object_type: ExodusObjectType, index: i32) -> String {
+        ffi::exodus_ii_reader_get_object_name(self.ptr.as_mut(), object_type as i32, index)
+    }
+
+    /// Get the id of an object as stored in the file
+    #[doc(alias = "GetObjectId")]
+    pub fn get_object_id(&mut self, object_type: ExodusObjectType, index: i32) -> i32 {
+        ffi::exodus_ii_reader_get_object_id(self.ptr.as_mut(), object_type as i32, index)
+    }
+
+    /// Get the names of all objects of a kind
+    pub fn get_object_names(&mut self, object_type: ExodusObjectType) -> Vec<String> {
+        (0..self.get_number_of_objects(object_type))
+            .map(|index| self.get_object_name(object_type, index))
+            .collect()
+    }
+
+    #[doc(alias = "GetObjectStatus")]
+    pub fn get_object_status(&mut self, object_type: ExodusObjectType, index: i32) -> bool {
+        ffi::exodus_ii_reader_get_object_status(self.ptr.as_mut(), object_type as i32, index)
+    }
+
+    /// Enable or disable reading of an object
+    ///
+    /// Element blocks are read by default, sets are not.
+    #[doc(alias = "SetObjectStatus")]
+    pub fn set_object_status(&mut self, object_type: ExodusObjectType, index: i32, enabled: bool) {
+        ffi::exodus_ii_reader_set_object_status(
+            self.ptr.as_mut(),
+            object_type as i32,
+            index,
+            enabled
+        );
+    }
+
+    /// Enable or disable reading of all objects of a kind
+    pub fn set_all_object_status(&mut self, object_type: ExodusObjectType, enabled: bool) {
+        for index in 0..self.get_number_of_objects(object_type) {
+            self.set_object_status(object_type, index, enabled);
+        }
+    }
+
+    /// Get the number of result variables defined on objects of a kind
+    #[doc(alias = "GetNumberOfObjectArrays")]
+    pub fn get_number_of_object_arrays(&mut self, object_type: ExodusObjectType) -> i32 {
+        ffi::exodus_ii_reader_get_number_of_object_arrays(self.ptr.as_mut(), object_type as i32)
+    }
+
+    #[doc(alias = "GetObjectArrayName")]
+    pub fn get_object_array_name(&mut self, object_type: ExodusObjectType, index: i32) -> String {
+        ffi::exodus_ii_reader_get_object_array_name(self.ptr.as_mut(), object_type as i32, index)
+    }
+
+    /// Get the names of all result variables defined on objects of a kind
+    ///
+    /// Vector components are combined into one variable (e.g. `DISPL`).
+    pub fn get_object_array_names(&mut self, object_type: ExodusObjectType) -> Vec<String> {
+        (0..self.get_number_of_object_arrays(object_type))
+            .map(|index| self.get_object_array_name(object_type, index))
+            .collect()
+    }
+
+    #[doc(alias = "GetObjectArrayStatus")]
+    pub fn get_object_array_status(&mut self, object_type: ExodusObjectType, index: i32) -> bool {
+        ffi::exodus_ii_reader_get_object_array_status(self.ptr.as_mut(), object_type as i32, index)
+    }
+
+    /// Enable or disable reading of a result variable
+    #[doc(alias = "SetObjectArrayStatus")]
+    pub fn set_object_array_status(
+        &mut self,
+        object_type: ExodusObjectType,
+        name: &str,
+        enabled: bool
+    ) {
+        ffi::exodus_ii_reader_set_object_array_status(
+            self.ptr.as_mut(),
+            object_type as i32,
+            name,
+            enabled
+        );
+    }
+
+    /// Enable or disable reading of all result variables of a kind
+    #[doc(alias = "SetAllArrayStatus")]
+    pub fn set_all_array_status(&mut self, object_type: ExodusObjectType, enabled: bool) {
+        ffi::exodus_ii_reader_set_all_array_status(self.ptr.as_mut(), object_type as i32, enabled);
+    }
+
+    /// Add the displacement variable to the point coordinates (enabled by default)
+    #[doc(alias = "SetApplyDisplacements")]
+    pub fn set_apply_displacements(&mut self, enabled: bool) {
+        ffi::exodus_ii_reader_set_apply_displacements(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetApplyDisplacements")]
+    pub fn get_apply_displacements(&self) -> bool {
+        ffi::exodus_ii_reader_get_apply_displacements(&self.ptr.as_ref())
+    }
+
+    /// Set the scale factor of applied displacements
+    #[doc(alias = "SetDisplacementMagnitude")]
+    pub fn set_displacement_magnitude(&mut self, magnitude: f32) {
+        ffi::exodus_ii_reader_set_displacement_magnitude(self.ptr.as_mut(), magnitude);
+    }
+
+    #[doc(alias = "GetDisplacementMagnitude")]
+    pub fn get_displacement_magnitude(&self) -> f32 {
+        ffi::exodus_ii_reader_get_displacement_magnitude(&self.ptr.as_ref())
+    }
+
+    /// Add the `ObjectId` cell array holding the id of the block of each cell
+    #[doc(alias = "SetGenerateObjectIdCellArray")]
+    pub fn set_generate_object_id_cell_array(&mut self, enabled: bool) {
+        ffi::exodus_ii_reader_set_generate_object_id_cell_array(self.ptr.as_mut(), enabled);
+    }
+
+    /// Add the `GlobalNodeId` point array
+    #[doc(alias = "SetGenerateGlobalNodeIdArray")]
+    pub fn set_generate_global_node_id_array(&mut self, enabled: bool) {
+        ffi::exodus_ii_reader_set_generate_global_node_id_array(self.ptr.as_mut(), enabled);
+    }
+
+    /// Add the `GlobalElementId` cell array
+    #[doc(alias = "SetGenerateGlobalElementIdArray")]
+    pub fn set_generate_global_element_id_array(&mut self, enabled: bool) {
+        ffi::exodus_ii_reader_set_generate_global_element_id_array(self.ptr.as_mut(), enabled);
+    }
+
+    /// Get the number of datasets in the output
+    ///
+    /// The output is a multiblock dataset with one [`UnstructuredGrid`] per
+    /// enabled block or set.
+    pub fn get_number_of_output_blocks(&mut self) -> i32 {
+        ffi::exodus_ii_reader_get_number_of_output_blocks(self.ptr.as_mut())
+    }
+
+    /// Get a copy of a dataset of the output
+    pub fn get_output_block(&mut self, index: i32) -> Option<ExodusBlock> {
+        let object_type = ExodusObjectType::from_i32(
+            ffi::exodus_ii_reader_get_output_block_type(self.ptr.as_mut(), index)
+        )?;
+        let ptr = ffi::exodus_ii_reader_get_output_block(self.ptr.as_mut(), index);
+        if ptr.is_null() {
+            return None;
+        }
+        Some(ExodusBlock {
+            object_type,
+            name: ffi::exodus_ii_reader_get_output_block_name(self.ptr.as_mut(), index),
+            grid: unsafe { UnstructuredGrid::from_raw(ptr as *mut _) },
+        })
+    }
+
+    /// Iterate over the datasets of the output
+    pub fn output_blocks(&mut self) -> impl Iterator<Item = ExodusBlock> + '_ {
+        let count = self.get_number_of_output_blocks();
+        (0..count).filter_map(move |index| self.get_output_block(index))
+    }
+
+    /// Get the output port for connecting to filters
+    ///
+    /// The port produces the multiblock dataset.
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::exodus_ii_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read the element blocks of the first time step of an Exodus II file
+    pub fn read_element_blocks(path: impl AsRef<Path>) -> Result<Vec<ExodusBlock>, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.output_blocks().collect())
+    }
+}
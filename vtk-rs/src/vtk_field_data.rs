@@ -0,0 +1,211 @@
+use std::ffi::{ CStr, CString };
+use crate::{ AbstractArray, DoubleArray, IntArray, StringArray };
+
+/// Opaque handle to vtkFieldData from VTK
+#[repr(C)]
+pub struct vtkFieldData {
+    _private: [u8; 0],
+}
+
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn data_object_get_field_data(data_object: *mut std::ffi::c_void) -> *mut vtkFieldData;
+    fn field_data_add_array(field_data: *mut vtkFieldData, array: *mut std::ffi::c_void);
+    fn field_data_remove_array(field_data: *mut vtkFieldData, name: *const i8);
+    fn field_data_has_array(field_data: *mut vtkFieldData, name: *const i8) -> bool;
+    fn field_data_get_number_of_arrays(field_data: *mut vtkFieldData) -> i64;
+    fn field_data_get_array_name(field_data: *mut vtkFieldData, index: i64) -> *const i8;
+    fn field_data_get_double_array(
+        field_data: *mut vtkFieldData,
+        name: *const i8
+    ) -> *mut crate::vtk_double_array::vtkDoubleArray;
+    fn field_data_get_int_array(
+        field_data: *mut vtkFieldData,
+        name: *const i8
+    ) -> *mut crate::vtk_int_array::vtkIntArray;
+    fn field_data_get_string_array(
+        field_data: *mut vtkFieldData,
+        name: *const i8
+    ) -> *mut crate::vtk_string_array::vtkStringArray;
+}
+
+/// Safe wrapper for vtkFieldData
+///
+/// FieldData holds arrays that describe a whole dataset rather than its points or cells,
+/// with any number of tuples. For FEM: load case names, units, solver version, time value.
+/// It is stored by the XML writers, e.g. [`crate::PolyData::write_vtp`].
+pub struct FieldData {
+    ptr: *mut vtkFieldData,
+    // FieldData is owned by the dataset, so we don't delete it
+    _owned: bool,
+}
+
+impl FieldData {
+    /// Get the field data of any vtkDataObject (internal use - FieldData owned by the dataset)
+    pub(crate) unsafe fn from_data_object(data_object: *mut std::ffi::c_void) -> Self {
+        let ptr = data_object_get_field_data(data_object);
+        assert!(!ptr.is_null(), "FieldData pointer is null");
+        Self { ptr, _owned: false }
+    }
+
+    /// Add an array to the field data, replacing any array with the same name
+    ///
+    /// Accepts a [`DoubleArray`], [`IntArray`], [`StringArray`] or any typed
+    /// [`crate::DataArray`].
+    #[doc(alias = "AddArray")]
+    pub fn add_array(&mut self, array: &impl AbstractArray) {
+        unsafe {
+            field_data_add_array(self.ptr, array.as_abstract_array_ptr());
+        }
+    }
+
+    /// Remove an array by name
+    #[doc(alias = "RemoveArray")]
+    pub fn remove_array(&mut self, name: &str) {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            field_data_remove_array(self.ptr, c_name.as_ptr());
+        }
+    }
+
+    /// Check whether there is an array with that name
+    #[doc(alias = "HasArray")]
+    pub fn has_array(&self, name: &str) -> bool {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe { field_data_has_array(self.ptr, c_name.as_ptr()) }
+    }
+
+    /// Get the number of arrays
+    #[doc(alias = "GetNumberOfArrays")]
+    pub fn get_number_of_arrays(&self) -> i64 {
+        unsafe { field_data_get_number_of_arrays(self.ptr) }
+    }
+
+    /// Get array name by index
+    #[doc(alias = "GetArrayName")]
+    pub fn get_array_name(&self, index: i64) -> Option<String> {
+        unsafe {
+            let name_ptr = field_data_get_array_name(self.ptr, index);
+            if name_ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name_ptr).to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Get a floating-point array
+    ///
+    /// The returned array shares its values with the dataset. Returns `None` if there is
+    /// no such array or it is not a `vtkDoubleArray`.
+    #[doc(alias = "GetArray")]
+    pub fn get_double_array(&self, name: &str) -> Option<DoubleArray> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let ptr = field_data_get_double_array(self.ptr, c_name.as_ptr());
+            (!ptr.is_null()).then(|| DoubleArray::from_raw(ptr))
+        }
+    }
+
+    /// Get an integer array
+    ///
+    /// The returned array shares its values with the dataset. Returns `None` if there is
+    /// no such array or it is not a `vtkIntArray`.
+    #[doc(alias = "GetArray")]
+    pub fn get_int_array(&self, name: &str) -> Option<IntArray> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let ptr = field_data_get_int_array(self.ptr, c_name.as_ptr());
+            (!ptr.is_null()).then(|| IntArray::from_raw(ptr))
+        }
+    }
+
+    /// Get a text array
+    ///
+    /// The returned array shares its values with the dataset. Returns `None` if there is
+    /// no such array or it is not a `vtkStringArray`.
+    #[doc(alias = "GetAbstractArray")]
+    pub fn get_string_array(&self, name: &str) -> Option<StringArray> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let ptr = field_data_get_string_array(self.ptr, c_name.as_ptr());
+            (!ptr.is_null()).then(|| StringArray::from_raw(ptr))
+        }
+    }
+
+    /// Store a single text value, e.g. `set_string("Units", "mm, N, MPa")`
+    pub fn set_string(&mut self, name: &str, value: &str) {
+        self.add_array(&StringArray::from_strings(name, &[value]));
+    }
+
+    /// Get the first value of a text array, if there is one
+    pub fn get_string(&self, name: &str) -> Option<String> {
+        let array = self.get_string_array(name)?;
+        (array.get_number_of_values() > 0).then(|| array.get_value(0))
+    }
+
+    /// Get raw pointer (for internal use)
+    pub fn as_ptr(&self) -> *mut vtkFieldData {
+        self.ptr
+    }
+}
+
+// FieldData is owned by the dataset, so no Drop implementation
+
+unsafe impl Send for FieldData {}
+unsafe impl Sync for FieldData {}
+
+#[cfg(test)]
+mod tests {
+    use crate::PolyData;
+
+    #[test]
+    fn test_field_data_metadata() {
+        let poly_data = PolyData::new();
+        let mut field_data = poly_data.get_field_data();
+        field_data.set_string("LoadCase", "LC1 dead load");
+
+        let mut time = crate::DoubleArray::new_scalar("TimeValue");
+        time.insert_next_value(0.5);
+        field_data.add_array(&time);
+
+        let field_data = poly_data.get_field_data();
+        assert_eq!(field_data.get_number_of_arrays(), 2);
+        assert!(field_data.has_array("TimeValue"));
+        assert_eq!(field_data.get_string("LoadCase").as_deref(), Some("LC1 dead load"));
+        assert_eq!(field_data.get_double_array("TimeValue").unwrap().get_value(0), 0.5);
+        assert!(field_data.get_string_array("TimeValue").is_none());
+        assert!(field_data.get_int_array("Missing").is_none());
+    }
+
+    #[test]
+    fn test_field_data_xml_round_trip() {
+        let base = std::env::temp_dir().join(format!("vtk-rs-field-data-{}", std::process::id()));
+
+        let mut poly_data = PolyData::new();
+        poly_data.set_points(&crate::Points::from_slice(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]));
+        let mut field_data = poly_data.get_field_data();
+        field_data.set_string("Units", "mm");
+        field_data.add_array(&crate::DoubleArray::from_slice("TimeValue", 1, &[2.5]));
+        let path = base.with_extension("vtp");
+        poly_data.write_vtp(&path).unwrap();
+        let read = PolyData::read_vtp(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let field_data = read.get_field_data();
+        assert_eq!(field_data.get_string("Units").as_deref(), Some("mm"));
+        assert_eq!(field_data.get_double_array("TimeValue").unwrap().get_value(0), 2.5);
+
+        let mut grid = crate::UnstructuredGrid::new();
+        grid.set_points(&mut crate::Points::from_slice(&[[0.0, 0.0, 0.0]]));
+        let mut field_data = grid.get_field_data();
+        field_data.set_string("Solver", "CalculiX 2.21");
+        field_data.add_array(&crate::IntArray::from_slice("Step", 1, &[3]));
+        let path = base.with_extension("vtu");
+        grid.write_vtu(&path).unwrap();
+        let read = crate::UnstructuredGrid::read_vtu(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let field_data = read.get_field_data();
+        assert_eq!(field_data.get_string("Solver").as_deref(), Some("CalculiX 2.21"));
+        assert_eq!(field_data.get_int_array("Step").unwrap().get_value(0), 3);
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_gltf_exporter.h");
+
+        type vtkGLTFExporter;
+        type vtkRenderWindow;
+
+        fn vtk_gltf_exporter_new() -> *mut vtkGLTFExporter;
+        fn vtk_gltf_exporter_delete(exporter: Pin<&mut vtkGLTFExporter>);
+
+        fn gltf_exporter_set_file_name(exporter: Pin<&mut vtkGLTFExporter>, file_name: &str);
+        fn gltf_exporter_get_file_name(exporter: &vtkGLTFExporter) -> String;
+        unsafe fn gltf_exporter_set_render_window(
+            exporter: Pin<&mut vtkGLTFExporter>,
+            window: *mut vtkRenderWindow
+        );
+        fn gltf_exporter_set_inline_data(exporter: Pin<&mut vtkGLTFExporter>, enabled: bool);
+        fn gltf_exporter_get_inline_data(exporter: &vtkGLTFExporter) -> bool;
+        fn gltf_exporter_set_save_normal(exporter: Pin<&mut vtkGLTFExporter>, enabled: bool);
+        fn gltf_exporter_get_save_normal(exporter: &vtkGLTFExporter) -> bool;
+        fn gltf_exporter_set_save_batch_id(exporter: Pin<&mut vtkGLTFExporter>, enabled: bool);
+        fn gltf_exporter_get_save_batch_id(exporter: &vtkGLTFExporter) -> bool;
+        fn gltf_exporter_write(exporter: Pin<&mut vtkGLTFExporter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkGLTFExporter.html",
+    @name GLTFExporter, ffi::vtkGLTFExporter,
+    @new ffi::vtk_gltf_exporter_new,
+    @delete ffi::vtk_gltf_exporter_delete
+);
+
+impl GLTFExporter {
+    /// Set the name of the glTF (`.gltf`) file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::gltf_exporter_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::gltf_exporter_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the render window whose actors, colors and camera are exported
+    ///
+    /// The window can be an offscreen window.
+    #[doc(alias = "SetRenderWindow")]
+    pub fn set_render_window(&mut self, window: &mut crate::RenderWindow) {
+        unsafe {
+            let window_ptr = window.as_mut_ptr() as *mut ffi::vtkRenderWindow;
+            ffi::gltf_exporter_set_render_window(self.ptr.as_mut(), window_ptr);
+        }
+    }
+
+    /// Embed the geometry in the `.gltf` file as base64 instead of separate `.bin` files
+    #[doc(alias = "SetInlineData")]
+    pub fn set_inline_data(&mut self, enabled: bool) {
+        ffi::gltf_exporter_set_inline_data(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetInlineData")]
+    pub fn get_inline_data(&self) -> bool {
+        ffi::gltf_exporter_get_inline_data(&self.ptr.as_ref())
+    }
+
+    /// Export point normals (off by default)
+    #[doc(alias = "SetSaveNormal")]
+    pub fn set_save_normal(&mut self, enabled: bool) {
+        ffi::gltf_exporter_set_save_normal(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetSaveNormal")]
+    pub fn get_save_normal(&self) -> bool {
+        ffi::gltf_exporter_get_save_normal(&self.ptr.as_ref())
+    }
+
+    /// Export the `_BATCHID` point array used by 3D Tiles (off by default)
+    #[doc(alias = "SetSaveBatchId")]
+    pub fn set_save_batch_id(&mut self, enabled: bool) {
+        ffi::gltf_exporter_set_save_batch_id(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetSaveBatchId")]
+    pub fn get_save_batch_id(&self) -> bool {
+        ffi::gltf_exporter_get_save_batch_id(&self.ptr.as_ref())
+    }
+
+    /// Write the scene
+    ///
+    /// Geometry buffers are written to `.bin` files next to the `.gltf` file unless
+    /// [`set_inline_data`](Self::set_inline_data) is enabled.
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::gltf_exporter_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+
+    /// Render the window and export its scene to a glTF (`.gltf`) file
+    pub fn export(
+        path: impl AsRef<Path>,
+        window: &mut crate::RenderWindow
+    ) -> Result<(), VtkError> {
+        let mut exporter = Self::new();
+        exporter.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        window.render();
+        exporter.set_render_window(window);
+        exporter.write()
+    }
+}
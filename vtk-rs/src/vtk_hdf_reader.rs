@@ -0,0 +1,224 @@
+use std::path::Path;
+
+use crate::{ ImageData, PolyData, UnstructuredGrid, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_hdf_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkHDFReader;
+        type vtkUnstructuredGrid;
+        type vtkPolyData;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_hdf_reader_new() -> *mut vtkHDFReader;
+        fn vtk_hdf_reader_delete(reader: Pin<&mut vtkHDFReader>);
+
+        fn hdf_reader_set_file_name(reader: Pin<&mut vtkHDFReader>, file_name: &str);
+        fn hdf_reader_get_file_name(reader: &vtkHDFReader) -> String;
+        fn hdf_reader_can_read_file(reader: Pin<&mut vtkHDFReader>, file_name: &str) -> bool;
+        fn hdf_reader_update(reader: Pin<&mut vtkHDFReader>) -> Result<()>;
+        fn hdf_reader_get_time_values(reader: Pin<&mut vtkHDFReader>) -> Vec<f64>;
+        fn hdf_reader_set_step(reader: Pin<&mut vtkHDFReader>, step: i64);
+        fn hdf_reader_get_step(reader: &vtkHDFReader) -> i64;
+        fn hdf_reader_get_time_value(reader: &vtkHDFReader) -> f64;
+        fn hdf_reader_get_number_of_point_arrays(reader: Pin<&mut vtkHDFReader>) -> i32;
+        fn hdf_reader_get_point_array_name(reader: Pin<&mut vtkHDFReader>, index: i32) -> String;
+        fn hdf_reader_set_point_array_status(
+            reader: Pin<&mut vtkHDFReader>,
+            name: &str,
+            enabled: bool
+        );
+        fn hdf_reader_get_number_of_cell_arrays(reader: Pin<&mut vtkHDFReader>) -> i32;
+        fn hdf_reader_get_cell_array_name(reader: Pin<&mut vtkHDFReader>, index: i32) -> String;
+        fn hdf_reader_set_cell_array_status(
+            reader: Pin<&mut vtkHDFReader>,
+            name: &str,
+            enabled: bool
+        );
+        fn hdf_reader_get_output_unstructured_grid(
+            reader: Pin<&mut vtkHDFReader>
+        ) -> *mut vtkUnstructuredGrid;
+        fn hdf_reader_get_output_poly_data(reader: Pin<&mut vtkHDFReader>) -> *mut vtkPolyData;
+        fn hdf_reader_get_output_image_data(reader: Pin<&mut vtkHDFReader>) -> *mut vtkImageData;
+        unsafe fn hdf_reader_get_output_port(
+            reader: Pin<&mut vtkHDFReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkHDFReader.html",
+    @name HDFReader, ffi::vtkHDFReader,
+    @new ffi::vtk_hdf_reader_new,
+    @delete ffi::vtk_hdf_reader_delete
+);
+
+impl HDFReader {
+    /// Set the name of the `.vtkhdf` file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::hdf_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::hdf_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Check whether the given file is a VTKHDF file
+    #[doc(alias = "CanReadFile")]
+    pub fn can_read_file(&mut self, file_name: &str) -> bool {
+        ffi::hdf_reader_can_read_file(self.ptr.as_mut(), file_name)
+    }
+
+    /// Read the file
+    ///
+    /// For temporal files only the step selected with [`set_step`](Self::set_step) is read.
+    /// Fails if the file does not exist, is not a VTKHDF file or its content is corrupt.
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        if !self.can_read_file(&file_name) {
+            return Err(VtkError::invalid_file(&file_name, "not a VTKHDF file"));
+        }
+        ffi::hdf_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get the time values stored in the file
+    ///
+    /// Empty if the file holds no temporal data.
+    #[doc(alias = "TIME_STEPS")]
+    pub fn get_time_values(&mut self) -> Vec<f64> {
+        ffi::hdf_reader_get_time_values(self.ptr.as_mut())
+    }
+
+    /// Get the number of time steps stored in the file
+    #[doc(alias = "GetNumberOfSteps")]
+    pub fn get_number_of_steps(&mut self) -> usize {
+        self.get_time_values().len()
+    }
+
+    /// Select the time step read by the next [`update`](Self::update)
+    #[doc(alias = "SetStep")]
+    pub fn set_step(&mut self, step: usize) {
+        ffi::hdf_reader_set_step(self.ptr.as_mut(), step as i64);
+    }
+
+    #[doc(alias = "GetStep")]
+    pub fn get_step(&self) -> usize {
+        ffi::hdf_reader_get_step(&self.ptr.as_ref()).max(0) as usize
+    }
+
+    /// Get the time value of the step that was read last
+    #[doc(alias = "GetTimeValue")]
+    pub fn get_time_value(&self) -> f64 {
+        ffi::hdf_reader_get_time_value(&self.ptr.as_ref())
+    }
+
+    /// Get the number of point arrays in the file
+    #[doc(alias = "GetNumberOfPointArrays")]
+    pub fn get_number_of_point_arrays(&mut self) -> i32 {
+        ffi::hdf_reader_get_number_of_point_arrays(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetPointArrayName")]
+    pub fn get_point_array_name(&mut self, index: i32) -> String {
+        ffi::hdf_reader_get_point_array_name(self.ptr.as_mut(), index)
+    }
+
+    /// Enable or disable reading of a point array (all arrays are read by default)
+    #[doc(alias = "SetPointArrayStatus")]
+    pub fn set_point_array_status(&mut self, name: &str, enabled: bool) {
+        ffi::hdf_reader_set_point_array_status(self.ptr.as_mut(), name, enabled);
+    }
+
+    /// Get the number of cell arrays in the file
+    #[doc(alias = "GetNumberOfCellArrays")]
+    pub fn get_number_of_cell_arrays(&mut self) -> i32 {
+        ffi::hdf_reader_get_number_of_cell_arrays(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetCellArrayName")]
+    pub fn get_cell_array_name(&mut self, index: i32) -> String {
+        ffi::hdf_reader_get_cell_array_name(self.ptr.as_mut(), index)
+    }
+
+    /// Enable or disable reading of a cell array (all arrays are read by default)
+    #[doc(alias = "SetCellArrayStatus")]
+    pub fn set_cell_array_status(&mut self, name: &str, enabled: bool) {
+        ffi::hdf_reader_set_cell_array_status(self.ptr.as_mut(), name, enabled);
+    }
+
+    /// Get a copy of the unstructured grid that was read
+    ///
+    /// Returns `None` if the file holds another kind of dataset.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_unstructured_grid(&mut self) -> Option<UnstructuredGrid> {
+        let ptr = ffi::hdf_reader_get_output_unstructured_grid(self.ptr.as_mut());
+        if ptr.is_null() {
+            return None;
+        }
+        Some(unsafe { UnstructuredGrid::from_raw(ptr as *mut _) })
+    }
+
+    /// Get a copy of the poly data that was read
+    ///
+    /// Returns `None` if the file holds another kind of dataset.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_poly_data(&mut self) -> Option<PolyData> {
+        let ptr = ffi::hdf_reader_get_output_poly_data(self.ptr.as_mut());
+        if ptr.is_null() {
+            return None;
+        }
+        Some(unsafe { PolyData::from_raw(ptr as *mut _) })
+    }
+
+    /// Get a copy of the image data that was read
+    ///
+    /// Returns `None` if the file holds another kind of dataset.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_image_data(&mut self) -> Option<ImageData> {
+        let ptr = ffi::hdf_reader_get_output_image_data(self.ptr.as_mut());
+        if ptr.is_null() {
+            return None;
+        }
+        Some(unsafe { ImageData::from_raw(ptr as *mut _) })
+    }
+
+    /// Get the output port for connecting to filters
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::hdf_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read the first time step of a `.vtkhdf` file into an [`UnstructuredGrid`]
+    pub fn read_unstructured_grid(path: impl AsRef<Path>) -> Result<UnstructuredGrid, VtkError> {
+        let path = path.as_ref();
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path)?);
+        reader.update()?;
+        reader
+            .get_output_unstructured_grid()
+            .ok_or_else(|| VtkError::invalid_file(path, "file does not hold an unstructured grid"))
+    }
+
+    /// Read the first time step of a `.vtkhdf` file into a [`PolyData`]
+    pub fn read_poly_data(path: impl AsRef<Path>) -> Result<PolyData, VtkError> {
+        let path = path.as_ref();
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path)?);
+        reader.update()?;
+        reader
+            .get_output_poly_data()
+            .ok_or_else(|| VtkError::invalid_file(path, "file does not hold poly data"))
+    }
+}
@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use crate::{ PolyData, UnstructuredGrid, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_hdf_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkHDFWriter;
+        type vtkDataObject;
+        type vtkAlgorithmOutput;
+
+        fn vtk_hdf_writer_new() -> *mut vtkHDFWriter;
+        fn vtk_hdf_writer_delete(writer: Pin<&mut vtkHDFWriter>);
+
+        fn hdf_writer_set_file_name(writer: Pin<&mut vtkHDFWriter>, file_name: &str);
+        fn hdf_writer_get_file_name(writer: &vtkHDFWriter) -> String;
+        fn hdf_writer_set_overwrite(writer: Pin<&mut vtkHDFWriter>, overwrite: bool);
+        fn hdf_writer_get_overwrite(writer: &vtkHDFWriter) -> bool;
+        fn hdf_writer_set_write_all_time_steps(writer: Pin<&mut vtkHDFWriter>, enabled: bool);
+        fn hdf_writer_get_write_all_time_steps(writer: &vtkHDFWriter) -> bool;
+        fn hdf_writer_set_chunk_size(writer: Pin<&mut vtkHDFWriter>, size: i32);
+        fn hdf_writer_get_chunk_size(writer: &vtkHDFWriter) -> i32;
+        fn hdf_writer_set_compression_level(writer: Pin<&mut vtkHDFWriter>, level: i32);
+        fn hdf_writer_get_compression_level(writer: &vtkHDFWriter) -> i32;
+        unsafe fn hdf_writer_set_input_connection(
+            writer: Pin<&mut vtkHDFWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn hdf_writer_set_input_data(
+            writer: Pin<&mut vtkHDFWriter>,
+            data: *mut vtkDataObject
+        );
+        unsafe fn hdf_writer_add_time_step(
+            writer: Pin<&mut vtkHDFWriter>,
+            time: f64,
+            data: *mut vtkDataObject
+        ) -> Result<()>;
+        fn hdf_writer_get_number_of_time_steps(writer: &vtkHDFWriter) -> i32;
+        fn hdf_writer_write(writer: Pin<&mut vtkHDFWriter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkHDFWriter.html",
+    @name HDFWriter, ffi::vtkHDFWriter,
+    @new ffi::vtk_hdf_writer_new,
+    @delete ffi::vtk_hdf_writer_delete
+);
+
+impl HDFWriter {
+    /// Set the name of the file to write (usually ending in `.vtkhdf`)
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::hdf_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::hdf_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Replace an existing file instead of failing
+    #[doc(alias = "SetOverwrite")]
+    pub fn set_overwrite(&mut self, overwrite: bool) {
+        ffi::hdf_writer_set_overwrite(self.ptr.as_mut(), overwrite);
+    }
+
+    #[doc(alias = "GetOverwrite")]
+    pub fn get_overwrite(&self) -> bool {
+        ffi::hdf_writer_get_overwrite(&self.ptr.as_ref())
+    }
+
+    /// Write every time step of a temporal input instead of only the current one
+    #[doc(alias = "SetWriteAllTimeSteps")]
+    pub fn set_write_all_time_steps(&mut self, enabled: bool) {
+        ffi::hdf_writer_set_write_all_time_steps(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetWriteAllTimeSteps")]
+    pub fn get_write_all_time_steps(&self) -> bool {
+        ffi::hdf_writer_get_write_all_time_steps(&self.ptr.as_ref())
+    }
+
+    /// Set the number of values per HDF5 chunk
+    #[doc(alias = "SetChunkSize")]
+    pub fn set_chunk_size(&mut self, size: i32) {
+        ffi::hdf_writer_set_chunk_size(self.ptr.as_mut(), size);
+    }
+
+    #[doc(alias = "GetChunkSize")]
+    pub fn get_chunk_size(&self) -> i32 {
+        ffi::hdf_writer_get_chunk_size(&self.ptr.as_ref())
+    }
+
+    /// Set the gzip compression level (0 = none, 9 = smallest)
+    #[doc(alias = "SetCompressionLevel")]
+    pub fn set_compression_level(&mut self, level: i32) {
+        ffi::hdf_writer_set_compression_level(self.ptr.as_mut(), level);
+    }
+
+    #[doc(alias = "GetCompressionLevel")]
+    pub fn get_compression_level(&self) -> i32 {
+        ffi::hdf_writer_get_compression_level(&self.ptr.as_ref())
+    }
+
+    /// Sets the input from an algorithm output port
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::hdf_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the unstructured grid to write
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, grid: &UnstructuredGrid) {
+        unsafe {
+            let ptr = grid.as_ptr() as *mut ffi::vtkDataObject;
+            ffi::hdf_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Sets the poly data to write
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_poly_data(&mut self, poly_data: &PolyData) {
+        unsafe {
+            let ptr = poly_data.as_ptr() as *mut ffi::vtkDataObject;
+            ffi::hdf_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Add the unstructured grid of one time step
+    ///
+    /// All steps added this way are written into the same file by [`write`](Self::write),
+    /// sorted by time; adding a step at a time that was already added replaces it.
+    /// Each step keeps a shallow copy of the grid, so build a new grid for every
+    /// step instead of modifying the arrays of the previous one.
+    ///
+    /// All steps of one writer must be of the same type; fails if poly data steps were
+    /// already added.
+    pub fn add_time_step(&mut self, time: f64, grid: &UnstructuredGrid) -> Result<(), VtkError> {
+        unsafe {
+            let ptr = grid.as_ptr() as *mut ffi::vtkDataObject;
+            ffi::hdf_writer_add_time_step(self.ptr.as_mut(), time, ptr)
+                .map_err(|e| VtkError::InvalidArgument(e.what().to_string()))
+        }
+    }
+
+    /// Add the poly data of one time step
+    ///
+    /// See [`add_time_step`](Self::add_time_step); fails if unstructured grid steps were
+    /// already added.
+    pub fn add_poly_data_time_step(
+        &mut self,
+        time: f64,
+        poly_data: &PolyData
+    ) -> Result<(), VtkError> {
+        unsafe {
+            let ptr = poly_data.as_ptr() as *mut ffi::vtkDataObject;
+            ffi::hdf_writer_add_time_step(self.ptr.as_mut(), time, ptr)
+                .map_err(|e| VtkError::InvalidArgument(e.what().to_string()))
+        }
+    }
+
+    /// Get the number of time steps added with [`add_time_step`](Self::add_time_step)
+    pub fn get_number_of_time_steps(&self) -> i32 {
+        ffi::hdf_writer_get_number_of_time_steps(&self.ptr.as_ref())
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::hdf_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+
+    /// Write an [`UnstructuredGrid`] to a `.vtkhdf` file
+    pub fn write_unstructured_grid(
+        path: impl AsRef<Path>,
+        grid: &UnstructuredGrid
+    ) -> Result<(), VtkError> {
+        let mut writer = Self::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_input_data(grid);
+        writer.write()
+    }
+
+    /// Write a [`PolyData`] to a `.vtkhdf` file
+    pub fn write_poly_data(path: impl AsRef<Path>, poly_data: &PolyData) -> Result<(), VtkError> {
+        let mut writer = Self::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_input_poly_data(poly_data);
+        writer.write()
+    }
+}
@@ -1,5 +1,21 @@
+use std::path::Path;
 use std::pin::Pin;
 
+use crate::{ DataArray, DataArrayType, VtkError };
+
+// Direct extern "C" for scalars, which are not part of the cxx bridge
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn image_data_set_scalars(
+        image_data: *mut std::ffi::c_void,
+        scalars: *mut crate::vtk_data_array::vtkDataArray
+    );
+    fn image_data_get_scalars(
+        image_data: *mut std::ffi::c_void,
+        vtk_type: i32
+    ) -> *mut crate::vtk_data_array::vtkDataArray;
+}
+
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
@@ -59,9 +75,18 @@ mod ffi {
             component: i32
         ) -> f64;
 
-        fn image_data_get_number_of_points(image_data: &vtkImageData) -> i32;
-        fn image_data_get_number_of_cells(image_data: &vtkImageData) -> i32;
-        unsafe fn image_data_get_bounds(image_data: &vtkImageData, bounds: *mut f64);
+
+        fn image_data_import_rgba(
+            image_data: Pin<&mut vtkImageData>,
+            width: i32,
+            height: i32,
+            pixels: &[u8]
+        );
+        fn image_data_export_rgba(
+            image_data: &vtkImageData,
+            width: &mut i32,
+            height: &mut i32
+        ) -> Vec<u8>;
     }
 }
 
@@ -174,30 +199,80 @@ impl ImageData {
         )
     }
 
-    /// Get the number of points in the dataset
-    #[doc(alias = "GetNumberOfPoints")]
-    pub fn get_number_of_points(&self) -> i32 {
-        ffi::image_data_get_number_of_points(self.ptr.as_ref().get_ref())
-    }
-
-    /// Get the number of cells in the dataset
-    #[doc(alias = "GetNumberOfCells")]
-    pub fn get_number_of_cells(&self) -> i32 {
-        ffi::image_data_get_number_of_cells(self.ptr.as_ref().get_ref())
+    /// Create a 2D image holding the pixels of an [`RgbaImage`](crate::RgbaImage)
+    ///
+    /// The result has unsigned char scalars with four components and can be passed
+    /// to image writers such as [`PNGWriter`](crate::PNGWriter).
+    pub fn from_rgba_image(image: &crate::RgbaImage) -> Self {
+        let mut image_data = Self::new();
+        ffi::image_data_import_rgba(
+            image_data.ptr.as_mut(),
+            image.width as i32,
+            image.height as i32,
+            &image.data
+        );
+        image_data
     }
 
-    /// Get the bounding box
-    #[doc(alias = "GetBounds")]
-    pub fn get_bounds(&self) -> [f64; 6] {
-        let mut bounds = [0.0; 6];
-        unsafe {
-            ffi::image_data_get_bounds(self.ptr.as_ref().get_ref(), bounds.as_mut_ptr());
+    /// Copy the scalars of a 2D unsigned char image into an [`RgbaImage`](crate::RgbaImage)
+    ///
+    /// Gray, gray-alpha and RGB images are expanded to RGBA. Returns `None` if the
+    /// scalars are missing or not of type unsigned char.
+    pub fn to_rgba_image(&self) -> Option<crate::RgbaImage> {
+        let mut width = 0;
+        let mut height = 0;
+        let data = ffi::image_data_export_rgba(self.ptr.as_ref().get_ref(), &mut width, &mut height);
+        if data.is_empty() {
+            return None;
         }
-        bounds
+        Some(crate::RgbaImage {
+            width: width as usize,
+            height: height as usize,
+            data,
+        })
     }
 
     /// Get raw pointer for VTK pipeline connections
     pub fn as_raw_ptr(&mut self) -> *mut ffi::vtkImageData {
         unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
     }
+
+    /// Use `array` as the voxel values (one tuple per point, x fastest)
+    #[doc(alias = "SetScalars")]
+    pub fn set_scalars<T: DataArrayType>(&mut self, array: &DataArray<T>) {
+        unsafe {
+            image_data_set_scalars(self.as_raw_ptr() as *mut std::ffi::c_void, array.as_ptr());
+        }
+    }
+
+    /// Get the voxel values, sharing them with the image
+    ///
+    /// Returns `None` if there are no scalars or they are not stored as `T`.
+    #[doc(alias = "GetScalars")]
+    pub fn get_scalars<T: DataArrayType>(&self) -> Option<DataArray<T>> {
+        unsafe {
+            let image_data = self.as_ptr() as *mut std::ffi::c_void;
+            let ptr = image_data_get_scalars(image_data, T::DATA_TYPE as i32);
+            (!ptr.is_null()).then(|| DataArray::from_raw(ptr))
+        }
+    }
+
+    /// Load an XML `.vti` file
+    ///
+    /// See [`crate::XMLImageDataReader`] for more options.
+    pub fn read_vti(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::XMLImageDataReader::read(path)
+    }
+
+    /// Save as an XML `.vti` file with zlib compressed appended data
+    ///
+    /// See [`crate::XMLImageDataWriter`] for other encodings.
+    pub fn write_vti(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let mut writer = crate::XMLImageDataWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_data_mode_to_appended();
+        writer.set_compressor_type_to_zlib();
+        writer.set_input_data(self);
+        writer.write()
+    }
 }
@@ -1,5 +1,15 @@
 use std::ffi::{CStr, CString};
 
+use crate::vtk_data_array::{
+    adopt_vec,
+    check_bulk_length,
+    copy_values,
+    data_array_modified,
+    flatten_tuples,
+    values_slice,
+    values_slice_mut,
+};
+
 /// Opaque handle to vtkIntArray from VTK
 #[repr(C)]
 pub struct vtkIntArray {
@@ -46,6 +56,12 @@ impl IntArray {
         Self { ptr }
     }
 
+    /// Take ownership of a reference to an existing vtkIntArray (e.g. a table column)
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkIntArray) -> Self {
+        assert!(!ptr.is_null(), "vtkIntArray pointer is null");
+        Self { ptr }
+    }
+
     /// Set the number of components per tuple
     pub fn set_number_of_components(&mut self, num_components: i64) {
         unsafe {
@@ -148,6 +164,72 @@ impl IntArray {
         }
     }
 
+    /// Signal that the values were changed through [`as_mut_slice`](Self::as_mut_slice)
+    ///
+    /// Needed when the array is already part of a pipeline, so that filters re-execute.
+    #[doc(alias = "Modified")]
+    pub fn modified(&mut self) {
+        unsafe {
+            data_array_modified(self.ptr as *mut _);
+        }
+    }
+
+    /// View all values (tuples × components) without copying
+    ///
+    /// # Safety
+    /// Several handles can share the same VTK array, e.g. the ones returned by
+    /// `get_int_array` of [`crate::FieldData`]. None of them may change, insert or remove values while the slice is alive.
+    pub unsafe fn as_slice(&self) -> &[i32] {
+        unsafe { values_slice(self.ptr as *mut _) }
+    }
+
+    /// View all values (tuples × components) mutably without copying
+    ///
+    /// # Safety
+    /// No other handle sharing the same VTK array may be used while the slice is alive,
+    /// see [`as_slice`](Self::as_slice).
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [i32] {
+        unsafe { values_slice_mut(self.ptr as *mut _) }
+    }
+
+    /// Create an array holding a copy of `values`, `num_components` values per tuple
+    ///
+    /// # Panics
+    /// If the values do not fill whole tuples.
+    pub fn from_slice(name: &str, num_components: i64, values: &[i32]) -> Self {
+        check_bulk_length(values.len(), num_components);
+        let mut array = Self::new();
+        array.set_number_of_components(num_components);
+        array.set_name(name);
+        unsafe { copy_values(array.ptr as *mut _, values) };
+        array
+    }
+
+    /// Create an array holding a copy of `tuples`
+    pub fn from_tuples<const N: usize>(name: &str, tuples: &[[i32; N]]) -> Self {
+        Self::from_slice(name, N as i64, tuples.as_flattened())
+    }
+
+    /// Create an array that takes over the buffer of `values` without copying
+    ///
+    /// # Panics
+    /// If the values do not fill whole tuples.
+    #[doc(alias = "SetArray")]
+    pub fn from_vec(name: &str, num_components: i64, values: Vec<i32>) -> Self {
+        check_bulk_length(values.len(), num_components);
+        let mut array = Self::new();
+        array.set_number_of_components(num_components);
+        array.set_name(name);
+        unsafe { adopt_vec(array.ptr as *mut _, values) };
+        array
+    }
+
+    /// Create an array that takes over the buffer of `tuples` without copying
+    #[doc(alias = "SetArray")]
+    pub fn from_tuple_vec<const N: usize>(name: &str, tuples: Vec<[i32; N]>) -> Self {
+        Self::from_vec(name, N as i64, flatten_tuples(tuples))
+    }
+
     /// Get the raw pointer (for internal use with other VTK functions)
     pub fn as_ptr(&self) -> *mut vtkIntArray {
         self.ptr
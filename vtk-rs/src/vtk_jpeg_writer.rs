@@ -0,0 +1,102 @@
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_jpeg_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkJPEGWriter;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_jpeg_writer_new() -> *mut vtkJPEGWriter;
+        fn vtk_jpeg_writer_delete(writer: Pin<&mut vtkJPEGWriter>);
+
+        fn jpeg_writer_set_file_name(writer: Pin<&mut vtkJPEGWriter>, file_name: &str);
+        fn jpeg_writer_get_file_name(writer: &vtkJPEGWriter) -> String;
+        fn jpeg_writer_set_quality(writer: Pin<&mut vtkJPEGWriter>, quality: i32);
+        fn jpeg_writer_get_quality(writer: &vtkJPEGWriter) -> i32;
+        fn jpeg_writer_set_progressive(writer: Pin<&mut vtkJPEGWriter>, progressive: bool);
+        fn jpeg_writer_get_progressive(writer: &vtkJPEGWriter) -> bool;
+        unsafe fn jpeg_writer_set_input_connection(
+            writer: Pin<&mut vtkJPEGWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn jpeg_writer_set_input_data(
+            writer: Pin<&mut vtkJPEGWriter>,
+            image_data: *mut vtkImageData
+        );
+        fn jpeg_writer_write(writer: Pin<&mut vtkJPEGWriter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkJPEGWriter.html",
+    @name JPEGWriter, ffi::vtkJPEGWriter,
+    @new ffi::vtk_jpeg_writer_new,
+    @delete ffi::vtk_jpeg_writer_delete
+);
+
+impl JPEGWriter {
+    /// Set the name of the file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::jpeg_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::jpeg_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the compression quality (0 = smallest file, 100 = best quality)
+    #[doc(alias = "SetQuality")]
+    pub fn set_quality(&mut self, quality: i32) {
+        ffi::jpeg_writer_set_quality(self.ptr.as_mut(), quality);
+    }
+
+    #[doc(alias = "GetQuality")]
+    pub fn get_quality(&self) -> i32 {
+        ffi::jpeg_writer_get_quality(&self.ptr.as_ref())
+    }
+
+    /// Write a progressive JPEG
+    #[doc(alias = "SetProgressive")]
+    pub fn set_progressive(&mut self, progressive: bool) {
+        ffi::jpeg_writer_set_progressive(self.ptr.as_mut(), progressive);
+    }
+
+    #[doc(alias = "GetProgressive")]
+    pub fn get_progressive(&self) -> bool {
+        ffi::jpeg_writer_get_progressive(&self.ptr.as_ref())
+    }
+
+    /// Sets the input from an algorithm output port (e.g. a [`crate::WindowToImageFilter`])
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::jpeg_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the input image directly
+    ///
+    /// JPEG only supports images with one or three components.
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, image_data: &mut crate::ImageData) {
+        unsafe {
+            let ptr = image_data.as_raw_ptr() as *mut ffi::vtkImageData;
+            ffi::jpeg_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::jpeg_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+}
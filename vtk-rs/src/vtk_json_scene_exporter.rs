@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_json_scene_exporter.h");
+
+        type vtkJSONSceneExporter;
+        type vtkRenderWindow;
+
+        fn vtk_json_scene_exporter_new() -> *mut vtkJSONSceneExporter;
+        fn vtk_json_scene_exporter_delete(exporter: Pin<&mut vtkJSONSceneExporter>);
+
+        fn json_scene_exporter_set_file_name(
+            exporter: Pin<&mut vtkJSONSceneExporter>,
+            file_name: &str
+        );
+        fn json_scene_exporter_get_file_name(exporter: &vtkJSONSceneExporter) -> String;
+        unsafe fn json_scene_exporter_set_render_window(
+            exporter: Pin<&mut vtkJSONSceneExporter>,
+            window: *mut vtkRenderWindow
+        );
+        fn json_scene_exporter_set_write_textures(
+            exporter: Pin<&mut vtkJSONSceneExporter>,
+            enabled: bool
+        );
+        fn json_scene_exporter_get_write_textures(exporter: &vtkJSONSceneExporter) -> bool;
+        fn json_scene_exporter_write(exporter: Pin<&mut vtkJSONSceneExporter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkJSONSceneExporter.html",
+    @name JSONSceneExporter, ffi::vtkJSONSceneExporter,
+    @new ffi::vtk_json_scene_exporter_new,
+    @delete ffi::vtk_json_scene_exporter_delete
+);
+
+impl JSONSceneExporter {
+    /// Set the directory to write the vtk.js scene to
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::json_scene_exporter_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::json_scene_exporter_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the render window whose actors, colors and camera are exported
+    ///
+    /// The window can be an offscreen window.
+    #[doc(alias = "SetRenderWindow")]
+    pub fn set_render_window(&mut self, window: &mut crate::RenderWindow) {
+        unsafe {
+            let window_ptr = window.as_mut_ptr() as *mut ffi::vtkRenderWindow;
+            ffi::json_scene_exporter_set_render_window(self.ptr.as_mut(), window_ptr);
+        }
+    }
+
+    /// Export the textures of actors (off by default)
+    #[doc(alias = "SetWriteTextures")]
+    pub fn set_write_textures(&mut self, enabled: bool) {
+        ffi::json_scene_exporter_set_write_textures(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetWriteTextures")]
+    pub fn get_write_textures(&self) -> bool {
+        ffi::json_scene_exporter_get_write_textures(&self.ptr.as_ref())
+    }
+
+    /// Write the scene
+    ///
+    /// Creates the directory given as file name with an `index.json` describing the
+    /// actors, lookup tables and camera, and the geometry and color arrays in `data/`.
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::json_scene_exporter_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+
+    /// Render the window and export its scene to a vtk.js scene directory
+    pub fn export(
+        path: impl AsRef<Path>,
+        window: &mut crate::RenderWindow
+    ) -> Result<(), VtkError> {
+        let mut exporter = Self::new();
+        exporter.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        window.render();
+        exporter.set_render_window(window);
+        exporter.write()
+    }
+
+    /// Render the window and export its scene to a `.vtkjs` archive for vtk.js
+    ///
+    /// The scene directory `<path>.parts` is written next to the archive and removed
+    /// afterwards; fails if it already exists. See
+    /// [`write_vtkjs_archive`](crate::write_vtkjs_archive).
+    pub fn export_archive(
+        path: impl AsRef<Path>,
+        window: &mut crate::RenderWindow
+    ) -> Result<(), VtkError> {
+        let path = path.as_ref();
+        let mut directory = path.as_os_str().to_owned();
+        directory.push(".parts");
+        let directory = std::path::PathBuf::from(directory);
+        // Never write into (and then remove) a directory that is not ours
+        std::fs::create_dir(&directory)
+            .map_err(|e| VtkError::write_failed(&directory, e.to_string()))?;
+
+        let result = Self::export(&directory, window)
+            .and_then(|()| crate::write_vtkjs_archive(&directory, path));
+        let _ = std::fs::remove_dir_all(&directory);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_archive_keeps_existing_directory() {
+        let path = std::env::temp_dir().join(format!("vtk-rs-{}.vtkjs", std::process::id()));
+        let parts = path.with_extension("vtkjs.parts");
+        std::fs::create_dir_all(parts.join("data")).unwrap();
+
+        let mut window = crate::RenderWindow::new();
+        assert!(JSONSceneExporter::export_archive(&path, &mut window).is_err());
+        assert!(parts.join("data").is_dir());
+        std::fs::remove_dir_all(&parts).unwrap();
+    }
+}
@@ -0,0 +1,96 @@
+use std::path::Path;
+
+use crate::{ ImageData, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_meta_image_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkMetaImageReader;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_meta_image_reader_new() -> *mut vtkMetaImageReader;
+        fn vtk_meta_image_reader_delete(reader: Pin<&mut vtkMetaImageReader>);
+
+        fn meta_image_reader_set_file_name(reader: Pin<&mut vtkMetaImageReader>, file_name: &str);
+        fn meta_image_reader_get_file_name(reader: &vtkMetaImageReader) -> String;
+        fn meta_image_reader_can_read_file(
+            reader: Pin<&mut vtkMetaImageReader>,
+            file_name: &str
+        ) -> bool;
+        fn meta_image_reader_update(reader: Pin<&mut vtkMetaImageReader>) -> Result<()>;
+        fn meta_image_reader_get_output(reader: Pin<&mut vtkMetaImageReader>) -> *mut vtkImageData;
+        unsafe fn meta_image_reader_get_output_port(
+            reader: Pin<&mut vtkMetaImageReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkMetaImageReader.html",
+    @name MetaImageReader, ffi::vtkMetaImageReader,
+    @new ffi::vtk_meta_image_reader_new,
+    @delete ffi::vtk_meta_image_reader_delete
+);
+
+impl MetaImageReader {
+    /// Set the name of the `.mhd` or `.mha` file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::meta_image_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::meta_image_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Check whether the given file is a MetaImage file
+    #[doc(alias = "CanReadFile")]
+    pub fn can_read_file(&mut self, file_name: &str) -> bool {
+        ffi::meta_image_reader_can_read_file(self.ptr.as_mut(), file_name)
+    }
+
+    /// Read the file
+    ///
+    /// Fails if the file does not exist, is not a MetaImage file or its data cannot be read.
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        if !self.can_read_file(&file_name) {
+            return Err(VtkError::invalid_file(&file_name, "not a MetaImage file"));
+        }
+        ffi::meta_image_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get a copy of the volume that was read
+    ///
+    /// Spacing and origin are taken from the file header.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> ImageData {
+        let ptr = ffi::meta_image_reader_get_output(self.ptr.as_mut());
+        unsafe { ImageData::from_raw(ptr as *mut _) }
+    }
+
+    /// Get the output port for connecting to filters
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::meta_image_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read a MetaImage (`.mhd`/`.mha`) file into an [`ImageData`]
+    pub fn read(path: impl AsRef<Path>) -> Result<ImageData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_meta_image_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkMetaImageWriter;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_meta_image_writer_new() -> *mut vtkMetaImageWriter;
+        fn vtk_meta_image_writer_delete(writer: Pin<&mut vtkMetaImageWriter>);
+
+        fn meta_image_writer_set_file_name(writer: Pin<&mut vtkMetaImageWriter>, file_name: &str);
+        fn meta_image_writer_get_file_name(writer: &vtkMetaImageWriter) -> String;
+        fn meta_image_writer_set_raw_file_name(
+            writer: Pin<&mut vtkMetaImageWriter>,
+            file_name: &str
+        );
+        fn meta_image_writer_get_raw_file_name(writer: &vtkMetaImageWriter) -> String;
+        fn meta_image_writer_set_compression(
+            writer: Pin<&mut vtkMetaImageWriter>,
+            compression: bool
+        );
+        fn meta_image_writer_get_compression(writer: &vtkMetaImageWriter) -> bool;
+        unsafe fn meta_image_writer_set_input_connection(
+            writer: Pin<&mut vtkMetaImageWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn meta_image_writer_set_input_data(
+            writer: Pin<&mut vtkMetaImageWriter>,
+            image_data: *mut vtkImageData
+        );
+        fn meta_image_writer_write(writer: Pin<&mut vtkMetaImageWriter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkMetaImageWriter.html",
+    @name MetaImageWriter, ffi::vtkMetaImageWriter,
+    @new ffi::vtk_meta_image_writer_new,
+    @delete ffi::vtk_meta_image_writer_delete
+);
+
+impl MetaImageWriter {
+    /// Set the name of the file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::meta_image_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::meta_image_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the name of the raw data file
+    ///
+    /// By default the data is stored in a separate file next to a `.mhd` header,
+    /// or inside the file for `.mha`.
+    #[doc(alias = "SetRAWFileName")]
+    pub fn set_raw_file_name(&mut self, file_name: &str) {
+        ffi::meta_image_writer_set_raw_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetRAWFileName")]
+    pub fn get_raw_file_name(&self) -> String {
+        ffi::meta_image_writer_get_raw_file_name(&self.ptr.as_ref())
+    }
+
+    /// Compress the voxel data with zlib (enabled by default)
+    #[doc(alias = "SetCompression")]
+    pub fn set_compression(&mut self, compression: bool) {
+        ffi::meta_image_writer_set_compression(self.ptr.as_mut(), compression);
+    }
+
+    #[doc(alias = "GetCompression")]
+    pub fn get_compression(&self) -> bool {
+        ffi::meta_image_writer_get_compression(&self.ptr.as_ref())
+    }
+
+    /// Sets the input from an algorithm output port (e.g. a reader or image filter)
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::meta_image_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the input volume directly
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, image_data: &mut crate::ImageData) {
+        unsafe {
+            let ptr = image_data.as_raw_ptr() as *mut ffi::vtkImageData;
+            ffi::meta_image_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::meta_image_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+
+    /// Write an [`ImageData`] to a MetaImage (`.mhd`/`.mha`) file
+    pub fn write_image(
+        path: impl AsRef<Path>,
+        image: &mut crate::ImageData
+    ) -> Result<(), VtkError> {
+        let mut writer = Self::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_input_data(image);
+        writer.write()
+    }
+}
@@ -0,0 +1,262 @@
+use std::ffi::{ c_void, CStr, CString };
+use std::path::Path;
+
+use crate::{ Block, BlockData, CompositeDataSet, VtkError };
+
+/// Opaque handle to vtkMultiBlockDataSet from VTK
+#[repr(C)]
+pub struct vtkMultiBlockDataSet {
+    _private: [u8; 0],
+}
+
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn multi_block_data_set_new() -> *mut vtkMultiBlockDataSet;
+    fn multi_block_data_set_delete(blocks: *mut vtkMultiBlockDataSet);
+    fn multi_block_data_set_set_number_of_blocks(blocks: *mut vtkMultiBlockDataSet, count: u32);
+    fn multi_block_data_set_get_number_of_blocks(blocks: *mut vtkMultiBlockDataSet) -> u32;
+    fn multi_block_data_set_set_block(
+        blocks: *mut vtkMultiBlockDataSet,
+        index: u32,
+        block: *mut c_void
+    );
+    fn multi_block_data_set_get_block(
+        blocks: *mut vtkMultiBlockDataSet,
+        index: u32,
+        block_type: *mut i32
+    ) -> *mut c_void;
+    fn multi_block_data_set_remove_block(blocks: *mut vtkMultiBlockDataSet, index: u32);
+    fn multi_block_data_set_set_block_name(
+        blocks: *mut vtkMultiBlockDataSet,
+        index: u32,
+        name: *const i8
+    );
+    fn multi_block_data_set_get_block_name(
+        blocks: *mut vtkMultiBlockDataSet,
+        index: u32
+    ) -> *const i8;
+}
+
+/// A block of a [`MultiBlockDataSet`] with its position and name
+pub struct NamedBlock {
+    /// Position of the block in its parent
+    pub index: u32,
+    /// Name stored in the block metadata, if any
+    pub name: Option<String>,
+    pub block: Block,
+}
+
+/// Safe wrapper for vtkMultiBlockDataSet
+///
+/// A tree of datasets, e.g. one block per part, material or load case of a model.
+/// Blocks can be any [`crate::DataSet`] or nested multiblock datasets, and are
+/// named through their metadata, which ParaView shows in its block hierarchy.
+///
+/// ```no_run
+/// use vtk_rs::*;
+///
+/// let mut assembly = MultiBlockDataSet::new();
+/// assembly.add_block("Flange", &PolyData::read_vtp("flange.vtp")?);
+/// assembly.add_block("Shaft", &UnstructuredGrid::read_vtu("shaft.vtu")?);
+///
+/// for NamedBlock { name, block, .. } in assembly.iter() {
+///     if let Some(data_set) = block.as_data_set() {
+///         println!("{:?}: {} cells", name, data_set.get_number_of_cells());
+///     }
+/// }
+/// assembly.write_vtm("assembly.vtm")?;
+/// # Ok::<(), VtkError>(())
+/// ```
+pub struct MultiBlockDataSet {
+    ptr: *mut vtkMultiBlockDataSet,
+}
+
+impl MultiBlockDataSet {
+    /// Create a new empty MultiBlockDataSet
+    pub fn new() -> Self {
+        let ptr = unsafe { multi_block_data_set_new() };
+        assert!(!ptr.is_null(), "Failed to create vtkMultiBlockDataSet");
+        Self { ptr }
+    }
+
+    /// Take ownership of a reference to an existing vtkMultiBlockDataSet
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkMultiBlockDataSet) -> Self {
+        assert!(!ptr.is_null(), "vtkMultiBlockDataSet pointer is null");
+        Self { ptr }
+    }
+
+    /// Set the number of blocks, adding empty blocks or dropping the last ones
+    #[doc(alias = "SetNumberOfBlocks")]
+    pub fn set_number_of_blocks(&mut self, count: u32) {
+        unsafe {
+            multi_block_data_set_set_number_of_blocks(self.ptr, count);
+        }
+    }
+
+    #[doc(alias = "GetNumberOfBlocks")]
+    pub fn get_number_of_blocks(&self) -> u32 {
+        unsafe { multi_block_data_set_get_number_of_blocks(self.ptr) }
+    }
+
+    /// Store `block` at `index`, growing the number of blocks if needed
+    ///
+    /// The block is shared, not copied: later changes to it are visible here.
+    #[doc(alias = "SetBlock")]
+    pub fn set_block(&mut self, index: u32, block: &impl BlockData) {
+        unsafe {
+            multi_block_data_set_set_block(self.ptr, index, block.as_data_object_ptr());
+        }
+    }
+
+    /// Get the block at `index`, shared with this dataset
+    ///
+    /// Returns `None` for an empty block, an index out of range or a data type
+    /// without a [`Block`] variant.
+    #[doc(alias = "GetBlock")]
+    pub fn get_block(&self, index: u32) -> Option<Block> {
+        let mut block_type = -1;
+        unsafe {
+            let ptr = multi_block_data_set_get_block(self.ptr, index, &mut block_type);
+            Block::from_raw(ptr, block_type)
+        }
+    }
+
+    /// Remove the block at `index`, moving the following blocks down
+    #[doc(alias = "RemoveBlock")]
+    pub fn remove_block(&mut self, index: u32) {
+        unsafe {
+            multi_block_data_set_remove_block(self.ptr, index);
+        }
+    }
+
+    /// Name the block at `index` (ignored if the index is out of range)
+    #[doc(alias = "GetMetaData")]
+    #[doc(alias = "NAME")]
+    pub fn set_block_name(&mut self, index: u32, name: &str) {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            multi_block_data_set_set_block_name(self.ptr, index, c_name.as_ptr());
+        }
+    }
+
+    /// Get the name of the block at `index`, if it has one
+    pub fn get_block_name(&self, index: u32) -> Option<String> {
+        unsafe {
+            let name_ptr = multi_block_data_set_get_block_name(self.ptr, index);
+            if name_ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name_ptr).to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Append a named block and return its index
+    pub fn add_block(&mut self, name: &str, block: &impl BlockData) -> u32 {
+        let index = self.get_number_of_blocks();
+        self.set_block(index, block);
+        self.set_block_name(index, name);
+        index
+    }
+
+    /// Iterate over the non-empty blocks (not descending into nested multiblock datasets)
+    pub fn iter(&self) -> impl Iterator<Item = NamedBlock> + '_ {
+        (0..self.get_number_of_blocks()).filter_map(move |index| {
+            let block = self.get_block(index)?;
+            Some(NamedBlock { index, name: self.get_block_name(index), block })
+        })
+    }
+
+    /// Get raw pointer (for internal use)
+    pub fn as_ptr(&self) -> *mut vtkMultiBlockDataSet {
+        self.ptr
+    }
+
+    /// Load an XML `.vtm` file and the files of its blocks
+    ///
+    /// See [`crate::XMLMultiBlockDataReader`] for more options.
+    pub fn read_vtm(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::XMLMultiBlockDataReader::read(path)
+    }
+
+    /// Save as an XML `.vtm` file with zlib compressed appended data
+    ///
+    /// The blocks are written to a directory named after the file, without the extension.
+    /// See [`crate::XMLMultiBlockDataWriter`] for other encodings.
+    pub fn write_vtm(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let mut writer = crate::XMLMultiBlockDataWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_data_mode_to_appended();
+        writer.set_compressor_type_to_zlib();
+        writer.set_input_data(self);
+        writer.write()
+    }
+}
+
+impl CompositeDataSet for MultiBlockDataSet {
+    fn as_composite_data_set_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
+}
+
+impl Default for MultiBlockDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for MultiBlockDataSet {
+    fn drop(&mut self) {
+        unsafe {
+            multi_block_data_set_delete(self.ptr);
+        }
+    }
+}
+
+unsafe impl Send for MultiBlockDataSet {}
+unsafe impl Sync for MultiBlockDataSet {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ ImageData, Points, PolyData };
+
+    #[test]
+    fn test_multi_block_data_set_blocks() {
+        let mut points = Points::new();
+        points.insert_next_point(0.0, 0.0, 0.0);
+        points.insert_next_point(4.0, 1.0, 0.0);
+        let mut poly_data = PolyData::new();
+        poly_data.set_points(&points);
+
+        let mut image = ImageData::new();
+        image.set_dimensions(2, 2, 2);
+
+        let mut assembly = MultiBlockDataSet::new();
+        assert_eq!(assembly.add_block("Beam", &poly_data), 0);
+        assert_eq!(assembly.add_block("Voxels", &image), 1);
+
+        let mut nested = MultiBlockDataSet::new();
+        nested.set_block(0, &PolyData::new());
+        assembly.set_block(3, &nested);
+        assert_eq!(assembly.get_number_of_blocks(), 4);
+
+        let blocks: Vec<_> = assembly.iter().collect();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1].name.as_deref(), Some("Voxels"));
+        assert!(matches!(blocks[1].block, Block::ImageData(_)));
+        assert_eq!(blocks[2].index, 3);
+        assert_eq!(blocks[2].name, None);
+        assert!(blocks[2].block.as_data_set().is_none());
+
+        let beam = assembly.get_block(0).unwrap();
+        assert_eq!(beam.as_data_set().unwrap().get_number_of_points(), 2);
+        assert_eq!(assembly.get_number_of_points(), 10);
+        assert_eq!(assembly.get_bounds(), [0.0, 4.0, 0.0, 1.0, 0.0, 1.0]);
+        // Root, Beam, Voxels, empty block 2, then the nested dataset and its block
+        assert_eq!(assembly.get_flat_index(3), 4);
+
+        assembly.remove_block(0);
+        assert_eq!(assembly.get_block_name(0).as_deref(), Some("Voxels"));
+    }
+}
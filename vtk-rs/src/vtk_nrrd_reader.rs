@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use crate::{ ImageData, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_nrrd_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkNrrdReader;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_nrrd_reader_new() -> *mut vtkNrrdReader;
+        fn vtk_nrrd_reader_delete(reader: Pin<&mut vtkNrrdReader>);
+
+        fn nrrd_reader_set_file_name(reader: Pin<&mut vtkNrrdReader>, file_name: &str);
+        fn nrrd_reader_get_file_name(reader: &vtkNrrdReader) -> String;
+        fn nrrd_reader_can_read_file(reader: Pin<&mut vtkNrrdReader>, file_name: &str) -> bool;
+        fn nrrd_reader_update(reader: Pin<&mut vtkNrrdReader>) -> Result<()>;
+        fn nrrd_reader_get_output(reader: Pin<&mut vtkNrrdReader>) -> *mut vtkImageData;
+        unsafe fn nrrd_reader_get_output_port(
+            reader: Pin<&mut vtkNrrdReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkNrrdReader.html",
+    @name NrrdReader, ffi::vtkNrrdReader,
+    @new ffi::vtk_nrrd_reader_new,
+    @delete ffi::vtk_nrrd_reader_delete
+);
+
+impl NrrdReader {
+    /// Set the name of the `.nrrd` or `.nhdr` file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::nrrd_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::nrrd_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Check whether the given file is an NRRD file
+    #[doc(alias = "CanReadFile")]
+    pub fn can_read_file(&mut self, file_name: &str) -> bool {
+        ffi::nrrd_reader_can_read_file(self.ptr.as_mut(), file_name)
+    }
+
+    /// Read the file
+    ///
+    /// Fails if the file does not exist, is not an NRRD file or its data cannot be read.
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        if !self.can_read_file(&file_name) {
+            return Err(VtkError::invalid_file(&file_name, "not an NRRD file"));
+        }
+        ffi::nrrd_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get a copy of the volume that was read
+    ///
+    /// Spacing and origin are taken from the file header.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> ImageData {
+        unsafe { ImageData::from_raw(ffi::nrrd_reader_get_output(self.ptr.as_mut()) as *mut _) }
+    }
+
+    /// Get the output port for connecting to filters
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::nrrd_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read an NRRD (`.nrrd`/`.nhdr`) file into an [`ImageData`]
+    pub fn read(path: impl AsRef<Path>) -> Result<ImageData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
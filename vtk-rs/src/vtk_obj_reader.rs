@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use crate::{ PolyData, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_obj_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkOBJReader;
+        type vtkPolyData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_obj_reader_new() -> *mut vtkOBJReader;
+        fn vtk_obj_reader_delete(reader: Pin<&mut vtkOBJReader>);
+
+        fn obj_reader_set_file_name(reader: Pin<&mut vtkOBJReader>, file_name: &str);
+        fn obj_reader_get_file_name(reader: &vtkOBJReader) -> String;
+        fn obj_reader_update(reader: Pin<&mut vtkOBJReader>) -> Result<()>;
+        fn obj_reader_get_output(reader: Pin<&mut vtkOBJReader>) -> *mut vtkPolyData;
+        unsafe fn obj_reader_get_output_port(
+            reader: Pin<&mut vtkOBJReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkOBJReader.html",
+    @name OBJReader, ffi::vtkOBJReader,
+    @new ffi::vtk_obj_reader_new,
+    @delete ffi::vtk_obj_reader_delete
+);
+
+impl OBJReader {
+    /// Set the name of the file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::obj_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::obj_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Read the file
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        ffi::obj_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get a copy of the surface that was read
+    ///
+    /// Texture coordinates and normals are stored in the point data, see
+    /// [`crate::PointData::get_tcoords`].
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> PolyData {
+        unsafe { PolyData::from_raw(ffi::obj_reader_get_output(self.ptr.as_mut()) as *mut _) }
+    }
+
+    /// Get the output port for connecting to filters and mappers
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::obj_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read a Wavefront OBJ file into a [`PolyData`]
+    pub fn read(path: impl AsRef<Path>) -> Result<PolyData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
@@ -0,0 +1,106 @@
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_obj_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkOBJWriter;
+        type vtkPolyData;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_obj_writer_new() -> *mut vtkOBJWriter;
+        fn vtk_obj_writer_delete(writer: Pin<&mut vtkOBJWriter>);
+
+        fn obj_writer_set_file_name(writer: Pin<&mut vtkOBJWriter>, file_name: &str);
+        fn obj_writer_get_file_name(writer: &vtkOBJWriter) -> String;
+        fn obj_writer_set_texture_file_name(
+            writer: Pin<&mut vtkOBJWriter>,
+            texture_file_name: &str
+        );
+        fn obj_writer_get_texture_file_name(writer: &vtkOBJWriter) -> String;
+        unsafe fn obj_writer_set_texture_data(
+            writer: Pin<&mut vtkOBJWriter>,
+            texture: *mut vtkImageData
+        );
+        unsafe fn obj_writer_set_input_connection(
+            writer: Pin<&mut vtkOBJWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn obj_writer_set_input_data(
+            writer: Pin<&mut vtkOBJWriter>,
+            poly_data: *mut vtkPolyData
+        );
+        fn obj_writer_write(writer: Pin<&mut vtkOBJWriter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkOBJWriter.html",
+    @name OBJWriter, ffi::vtkOBJWriter,
+    @new ffi::vtk_obj_writer_new,
+    @delete ffi::vtk_obj_writer_delete
+);
+
+impl OBJWriter {
+    /// Set the name of the file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::obj_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::obj_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the image referenced by the generated `.mtl` material file
+    #[doc(alias = "SetTextureFileName")]
+    pub fn set_texture_file_name(&mut self, texture_file_name: &str) {
+        ffi::obj_writer_set_texture_file_name(self.ptr.as_mut(), texture_file_name);
+    }
+
+    #[doc(alias = "GetTextureFileName")]
+    pub fn get_texture_file_name(&self) -> String {
+        ffi::obj_writer_get_texture_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set a texture image that is written next to the `.obj` file
+    ///
+    /// The image is saved as PNG under [`set_texture_file_name`](Self::set_texture_file_name)
+    /// and referenced from a `.mtl` file. Texture coordinates are taken from the point data.
+    pub fn set_texture_data(&mut self, texture: &mut crate::ImageData) {
+        unsafe {
+            let ptr = texture.as_raw_ptr() as *mut ffi::vtkImageData;
+            ffi::obj_writer_set_texture_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Sets the input from an algorithm output port
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::obj_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the poly data to write
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, poly_data: &crate::PolyData) {
+        unsafe {
+            let ptr = poly_data.as_ptr() as *mut ffi::vtkPolyData;
+            ffi::obj_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::obj_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+}
@@ -0,0 +1,293 @@
+use std::ffi::{ c_void, CStr, CString };
+use std::path::Path;
+
+use crate::{ Block, CompositeDataSet, DataSet, VtkError };
+
+/// Opaque handle to vtkPartitionedDataSetCollection from VTK
+#[repr(C)]
+pub struct vtkPartitionedDataSetCollection {
+    _private: [u8; 0],
+}
+
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn partitioned_data_set_collection_new() -> *mut vtkPartitionedDataSetCollection;
+    fn partitioned_data_set_collection_delete(collection: *mut vtkPartitionedDataSetCollection);
+    fn partitioned_data_set_collection_set_number_of_partitioned_data_sets(
+        collection: *mut vtkPartitionedDataSetCollection,
+        count: u32
+    );
+    fn partitioned_data_set_collection_get_number_of_partitioned_data_sets(
+        collection: *mut vtkPartitionedDataSetCollection
+    ) -> u32;
+    fn partitioned_data_set_collection_get_number_of_partitions(
+        collection: *mut vtkPartitionedDataSetCollection,
+        index: u32
+    ) -> u32;
+    fn partitioned_data_set_collection_set_partition(
+        collection: *mut vtkPartitionedDataSetCollection,
+        index: u32,
+        partition: u32,
+        data_set: *mut c_void
+    );
+    fn partitioned_data_set_collection_get_partition(
+        collection: *mut vtkPartitionedDataSetCollection,
+        index: u32,
+        partition: u32,
+        block_type: *mut i32
+    ) -> *mut c_void;
+    fn partitioned_data_set_collection_remove_partitioned_data_set(
+        collection: *mut vtkPartitionedDataSetCollection,
+        index: u32
+    );
+    fn partitioned_data_set_collection_set_name(
+        collection: *mut vtkPartitionedDataSetCollection,
+        index: u32,
+        name: *const i8
+    );
+    fn partitioned_data_set_collection_get_name(
+        collection: *mut vtkPartitionedDataSetCollection,
+        index: u32
+    ) -> *const i8;
+}
+
+/// A partitioned dataset of a [`PartitionedDataSetCollection`] with its partitions
+pub struct PartitionedBlock {
+    /// Position of the partitioned dataset in the collection
+    pub index: u32,
+    /// Name stored in the metadata, if any
+    pub name: Option<String>,
+    /// Non-empty partitions, e.g. one per MPI rank that wrote the part
+    pub partitions: Vec<Block>,
+}
+
+/// Safe wrapper for vtkPartitionedDataSetCollection
+///
+/// The successor of [`crate::MultiBlockDataSet`] for distributed data: a flat list of
+/// partitioned datasets, each made of one or more [`DataSet`] partitions of the same
+/// part (for instance split across processes).
+///
+/// ```no_run
+/// use vtk_rs::*;
+///
+/// let mut model = PartitionedDataSetCollection::new();
+/// let wing = model.add_partitioned_data_set("Wing", &[
+///     &UnstructuredGrid::read_vtu("wing_0.vtu")?,
+///     &UnstructuredGrid::read_vtu("wing_1.vtu")?,
+/// ]);
+/// assert_eq!(model.get_number_of_partitions(wing), 2);
+/// model.write_vtpc("model.vtpc")?;
+/// # Ok::<(), VtkError>(())
+/// ```
+pub struct PartitionedDataSetCollection {
+    ptr: *mut vtkPartitionedDataSetCollection,
+}
+
+impl PartitionedDataSetCollection {
+    /// Create a new empty PartitionedDataSetCollection
+    pub fn new() -> Self {
+        let ptr = unsafe { partitioned_data_set_collection_new() };
+        assert!(!ptr.is_null(), "Failed to create vtkPartitionedDataSetCollection");
+        Self { ptr }
+    }
+
+    /// Take ownership of a reference to an existing vtkPartitionedDataSetCollection
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkPartitionedDataSetCollection) -> Self {
+        assert!(!ptr.is_null(), "vtkPartitionedDataSetCollection pointer is null");
+        Self { ptr }
+    }
+
+    /// Set the number of partitioned datasets
+    #[doc(alias = "SetNumberOfPartitionedDataSets")]
+    pub fn set_number_of_partitioned_data_sets(&mut self, count: u32) {
+        unsafe {
+            partitioned_data_set_collection_set_number_of_partitioned_data_sets(self.ptr, count);
+        }
+    }
+
+    #[doc(alias = "GetNumberOfPartitionedDataSets")]
+    pub fn get_number_of_partitioned_data_sets(&self) -> u32 {
+        unsafe { partitioned_data_set_collection_get_number_of_partitioned_data_sets(self.ptr) }
+    }
+
+    /// Get the number of partitions of the partitioned dataset at `index`
+    #[doc(alias = "GetNumberOfPartitions")]
+    pub fn get_number_of_partitions(&self, index: u32) -> u32 {
+        unsafe { partitioned_data_set_collection_get_number_of_partitions(self.ptr, index) }
+    }
+
+    /// Store `data_set` as a partition, growing the collection if needed
+    ///
+    /// The dataset is shared, not copied: later changes to it are visible here.
+    #[doc(alias = "SetPartition")]
+    pub fn set_partition(
+        &mut self,
+        index: u32,
+        partition: u32,
+        data_set: &(impl DataSet + ?Sized)
+    ) {
+        unsafe {
+            let data_set = data_set.as_data_set_ptr();
+            partitioned_data_set_collection_set_partition(self.ptr, index, partition, data_set);
+        }
+    }
+
+    /// Get a partition, shared with this collection
+    ///
+    /// Returns `None` for an empty partition or an index out of range.
+    #[doc(alias = "GetPartition")]
+    pub fn get_partition(&self, index: u32, partition: u32) -> Option<Block> {
+        let mut block_type = -1;
+        unsafe {
+            let ptr = partitioned_data_set_collection_get_partition(
+                self.ptr,
+                index,
+                partition,
+                &mut block_type
+            );
+            Block::from_raw(ptr, block_type)
+        }
+    }
+
+    /// Remove the partitioned dataset at `index`, moving the following ones down
+    #[doc(alias = "RemovePartitionedDataSet")]
+    pub fn remove_partitioned_data_set(&mut self, index: u32) {
+        unsafe {
+            partitioned_data_set_collection_remove_partitioned_data_set(self.ptr, index);
+        }
+    }
+
+    /// Name the partitioned dataset at `index` (ignored if the index is out of range)
+    #[doc(alias = "GetMetaData")]
+    #[doc(alias = "NAME")]
+    pub fn set_name(&mut self, index: u32, name: &str) {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            partitioned_data_set_collection_set_name(self.ptr, index, c_name.as_ptr());
+        }
+    }
+
+    /// Get the name of the partitioned dataset at `index`, if it has one
+    pub fn get_name(&self, index: u32) -> Option<String> {
+        unsafe {
+            let name_ptr = partitioned_data_set_collection_get_name(self.ptr, index);
+            if name_ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name_ptr).to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Append a named partitioned dataset made of `partitions` and return its index
+    pub fn add_partitioned_data_set(&mut self, name: &str, partitions: &[&dyn DataSet]) -> u32 {
+        let index = self.get_number_of_partitioned_data_sets();
+        self.set_number_of_partitioned_data_sets(index + 1);
+        for (partition, data_set) in partitions.iter().enumerate() {
+            self.set_partition(index, partition as u32, *data_set);
+        }
+        self.set_name(index, name);
+        index
+    }
+
+    /// Iterate over the partitioned datasets
+    pub fn iter(&self) -> impl Iterator<Item = PartitionedBlock> + '_ {
+        (0..self.get_number_of_partitioned_data_sets()).map(move |index| PartitionedBlock {
+            index,
+            name: self.get_name(index),
+            partitions: (0..self.get_number_of_partitions(index))
+                .filter_map(|partition| self.get_partition(index, partition))
+                .collect(),
+        })
+    }
+
+    /// Get raw pointer (for internal use)
+    pub fn as_ptr(&self) -> *mut vtkPartitionedDataSetCollection {
+        self.ptr
+    }
+
+    /// Load an XML `.vtpc` file and the files of its partitions
+    ///
+    /// See [`crate::XMLPartitionedDataSetCollectionReader`] for more options.
+    pub fn read_vtpc(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::XMLPartitionedDataSetCollectionReader::read(path)
+    }
+
+    /// Save as an XML `.vtpc` file with zlib compressed appended data
+    ///
+    /// The partitions are written to a directory named after the file, without the
+    /// extension. See [`crate::XMLPartitionedDataSetCollectionWriter`] for other encodings.
+    pub fn write_vtpc(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let mut writer = crate::XMLPartitionedDataSetCollectionWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_data_mode_to_appended();
+        writer.set_compressor_type_to_zlib();
+        writer.set_input_data(self);
+        writer.write()
+    }
+}
+
+impl crate::vtk_composite_data_set::private::Sealed for PartitionedDataSetCollection {}
+impl CompositeDataSet for PartitionedDataSetCollection {
+    fn as_composite_data_set_ptr(&self) -> *mut c_void {
+        self.ptr as *mut c_void
+    }
+}
+
+impl Default for PartitionedDataSetCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for PartitionedDataSetCollection {
+    fn drop(&mut self) {
+        unsafe {
+            partitioned_data_set_collection_delete(self.ptr);
+        }
+    }
+}
+
+unsafe impl Send for PartitionedDataSetCollection {}
+unsafe impl Sync for PartitionedDataSetCollection {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ Points, PolyData, UnstructuredGrid };
+
+    fn poly_data(x: f64) -> PolyData {
+        let mut points = Points::new();
+        points.insert_next_point(x, 0.0, 0.0);
+        points.insert_next_point(x + 1.0, 1.0, 0.0);
+        let mut poly_data = PolyData::new();
+        poly_data.set_points(&points);
+        poly_data
+    }
+
+    #[test]
+    fn test_partitioned_data_set_collection_vtpc_round_trip() {
+        let mut model = PartitionedDataSetCollection::new();
+        let wing = model.add_partitioned_data_set("Wing", &[&poly_data(0.0), &poly_data(2.0)]);
+        let fuselage = model.add_partitioned_data_set("Fuselage", &[&UnstructuredGrid::new()]);
+        assert_eq!((wing, fuselage), (0, 1));
+        assert_eq!(model.get_number_of_partitions(wing), 2);
+        assert!(matches!(model.get_partition(wing, 1), Some(Block::PolyData(_))));
+        assert!(model.get_partition(wing, 2).is_none());
+
+        assert_eq!(model.get_number_of_points(), 4);
+        assert_eq!(model.get_bounds(), [0.0, 3.0, 0.0, 1.0, 0.0, 0.0]);
+
+        let path = std::env::temp_dir().join(format!("vtk-rs-{}.vtpc", std::process::id()));
+        model.write_vtpc(&path).unwrap();
+        let read = PartitionedDataSetCollection::read_vtpc(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_dir_all(path.with_extension("")).unwrap();
+
+        let parts: Vec<_> = read.iter().collect();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].name.as_deref(), Some("Wing"));
+        assert_eq!(parts[0].partitions.len(), 2);
+        assert_eq!(read.get_number_of_points(), 4);
+    }
+}
@@ -0,0 +1,108 @@
+use std::path::Path;
+
+use crate::{ PolyData, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_ply_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPLYReader;
+        type vtkPolyData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_ply_reader_new() -> *mut vtkPLYReader;
+        fn vtk_ply_reader_delete(reader: Pin<&mut vtkPLYReader>);
+
+        fn ply_reader_set_file_name(reader: Pin<&mut vtkPLYReader>, file_name: &str);
+        fn ply_reader_get_file_name(reader: &vtkPLYReader) -> String;
+        fn ply_reader_can_read_file(reader: Pin<&mut vtkPLYReader>, file_name: &str) -> bool;
+        fn ply_reader_set_duplicate_points_for_face_texture(
+            reader: Pin<&mut vtkPLYReader>,
+            duplicate_points_for_face_texture: bool
+        );
+        fn ply_reader_get_duplicate_points_for_face_texture(reader: &vtkPLYReader) -> bool;
+        fn ply_reader_update(reader: Pin<&mut vtkPLYReader>) -> Result<()>;
+        fn ply_reader_get_output(reader: Pin<&mut vtkPLYReader>) -> *mut vtkPolyData;
+        unsafe fn ply_reader_get_output_port(
+            reader: Pin<&mut vtkPLYReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPLYReader.html",
+    @name PLYReader, ffi::vtkPLYReader,
+    @new ffi::vtk_ply_reader_new,
+    @delete ffi::vtk_ply_reader_delete
+);
+
+impl PLYReader {
+    /// Set the name of the file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::ply_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::ply_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Check whether the given file looks like a PLY file
+    #[doc(alias = "CanReadFile")]
+    pub fn can_read_file(&mut self, file_name: &str) -> bool {
+        ffi::ply_reader_can_read_file(self.ptr.as_mut(), file_name)
+    }
+
+    /// Duplicate points shared by faces with different texture coordinates (on by default)
+    #[doc(alias = "SetDuplicatePointsForFaceTexture")]
+    pub fn set_duplicate_points_for_face_texture(&mut self, duplicate_points_for_face_texture: bool) {
+        ffi::ply_reader_set_duplicate_points_for_face_texture(self.ptr.as_mut(), duplicate_points_for_face_texture);
+    }
+
+    #[doc(alias = "GetDuplicatePointsForFaceTexture")]
+    pub fn get_duplicate_points_for_face_texture(&self) -> bool {
+        ffi::ply_reader_get_duplicate_points_for_face_texture(&self.ptr.as_ref())
+    }
+
+    /// Read the file
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        if !self.can_read_file(&file_name) {
+            return Err(VtkError::invalid_file(&file_name, "not a PLY file"));
+        }
+        ffi::ply_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get a copy of the surface that was read
+    ///
+    /// Vertex and face colors end up in an unsigned char array named `RGB` (or `RGBA`)
+    /// in the point or cell data, see [`crate::PointData::get_colors`]. Texture
+    /// coordinates are available through [`crate::PointData::get_tcoords`].
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> PolyData {
+        unsafe { PolyData::from_raw(ffi::ply_reader_get_output(self.ptr.as_mut()) as *mut _) }
+    }
+
+    /// Get the output port for connecting to filters and mappers
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::ply_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read a PLY file into a [`PolyData`]
+    pub fn read(path: impl AsRef<Path>) -> Result<PolyData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{ FileType, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_ply_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPLYWriter;
+        type vtkPolyData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_ply_writer_new() -> *mut vtkPLYWriter;
+        fn vtk_ply_writer_delete(writer: Pin<&mut vtkPLYWriter>);
+
+        fn ply_writer_set_file_name(writer: Pin<&mut vtkPLYWriter>, file_name: &str);
+        fn ply_writer_get_file_name(writer: &vtkPLYWriter) -> String;
+        fn ply_writer_set_array_name(writer: Pin<&mut vtkPLYWriter>, array_name: &str);
+        fn ply_writer_get_array_name(writer: &vtkPLYWriter) -> String;
+        fn ply_writer_set_enable_alpha(writer: Pin<&mut vtkPLYWriter>, enable_alpha: bool);
+        fn ply_writer_get_enable_alpha(writer: &vtkPLYWriter) -> bool;
+        fn ply_writer_set_file_type(writer: Pin<&mut vtkPLYWriter>, file_type: i32);
+        fn ply_writer_get_file_type(writer: &vtkPLYWriter) -> i32;
+        fn ply_writer_set_color_mode(writer: Pin<&mut vtkPLYWriter>, mode: i32);
+        fn ply_writer_get_color_mode(writer: &vtkPLYWriter) -> i32;
+        fn ply_writer_set_color(writer: Pin<&mut vtkPLYWriter>, r: u8, g: u8, b: u8);
+        fn ply_writer_set_alpha(writer: Pin<&mut vtkPLYWriter>, alpha: u8);
+        fn ply_writer_set_texture_coordinates_name(
+            writer: Pin<&mut vtkPLYWriter>,
+            name: i32
+        );
+        unsafe fn ply_writer_set_input_connection(
+            writer: Pin<&mut vtkPLYWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn ply_writer_set_input_data(
+            writer: Pin<&mut vtkPLYWriter>,
+            poly_data: *mut vtkPolyData
+        );
+        fn ply_writer_write(writer: Pin<&mut vtkPLYWriter>) -> Result<()>;
+    }
+}
+
+/// Where [`PLYWriter`] takes the vertex and face colors from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PLYColorMode {
+    /// Use the unsigned char array named by [`PLYWriter::set_array_name`]
+    Default = 0,
+    /// Give every face the color set with [`PLYWriter::set_color`]
+    UniformCellColor = 1,
+    /// Give every vertex the color set with [`PLYWriter::set_color`]
+    UniformPointColor = 2,
+    /// Give every vertex and face the color set with [`PLYWriter::set_color`]
+    UniformColor = 3,
+    /// Do not write colors
+    Off = 4,
+}
+
+/// Property names used for texture coordinates in PLY files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PLYTextureCoordinatesName {
+    /// `u` and `v`
+    UV = 0,
+    /// `texture_u` and `texture_v`
+    TextureUV = 1,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPLYWriter.html",
+    @name PLYWriter, ffi::vtkPLYWriter,
+    @new ffi::vtk_ply_writer_new,
+    @delete ffi::vtk_ply_writer_delete
+);
+
+impl PLYWriter {
+    /// Set the name of the file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::ply_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::ply_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the name of the point or cell array holding the colors (with [`PLYColorMode::Default`])
+    #[doc(alias = "SetArrayName")]
+    pub fn set_array_name(&mut self, array_name: &str) {
+        ffi::ply_writer_set_array_name(self.ptr.as_mut(), array_name);
+    }
+
+    #[doc(alias = "GetArrayName")]
+    pub fn get_array_name(&self) -> String {
+        ffi::ply_writer_get_array_name(&self.ptr.as_ref())
+    }
+
+    /// Also write an alpha channel
+    #[doc(alias = "SetEnableAlpha")]
+    pub fn set_enable_alpha(&mut self, enable_alpha: bool) {
+        ffi::ply_writer_set_enable_alpha(self.ptr.as_mut(), enable_alpha);
+    }
+
+    #[doc(alias = "GetEnableAlpha")]
+    pub fn get_enable_alpha(&self) -> bool {
+        ffi::ply_writer_get_enable_alpha(&self.ptr.as_ref())
+    }
+
+    /// Choose between ASCII and binary (default) PLY
+    #[doc(alias = "SetFileType")]
+    pub fn set_file_type(&mut self, file_type: FileType) {
+        ffi::ply_writer_set_file_type(self.ptr.as_mut(), file_type as i32);
+    }
+
+    #[doc(alias = "GetFileType")]
+    pub fn get_file_type(&self) -> FileType {
+        match ffi::ply_writer_get_file_type(&self.ptr.as_ref()) {
+            1 => FileType::Ascii,
+            _ => FileType::Binary,
+        }
+    }
+
+    /// Choose where the vertex and face colors come from
+    #[doc(alias = "SetColorMode")]
+    pub fn set_color_mode(&mut self, mode: PLYColorMode) {
+        ffi::ply_writer_set_color_mode(self.ptr.as_mut(), mode as i32);
+    }
+
+    #[doc(alias = "GetColorMode")]
+    pub fn get_color_mode(&self) -> PLYColorMode {
+        match ffi::ply_writer_get_color_mode(&self.ptr.as_ref()) {
+            1 => PLYColorMode::UniformCellColor,
+            2 => PLYColorMode::UniformPointColor,
+            3 => PLYColorMode::UniformColor,
+            4 => PLYColorMode::Off,
+            _ => PLYColorMode::Default,
+        }
+    }
+
+    /// Set the color used by the uniform color modes
+    #[doc(alias = "SetColor")]
+    pub fn set_color(&mut self, r: u8, g: u8, b: u8) {
+        ffi::ply_writer_set_color(self.ptr.as_mut(), r, g, b);
+    }
+
+    /// Set the alpha value used by the uniform color modes
+    #[doc(alias = "SetAlpha")]
+    pub fn set_alpha(&mut self, alpha: u8) {
+        ffi::ply_writer_set_alpha(self.ptr.as_mut(), alpha);
+    }
+
+    /// Choose the property names of the texture coordinates
+    #[doc(alias = "SetTextureCoordinatesName")]
+    pub fn set_texture_coordinates_name(&mut self, name: PLYTextureCoordinatesName) {
+        ffi::ply_writer_set_texture_coordinates_name(self.ptr.as_mut(), name as i32);
+    }
+
+    /// Sets the input from an algorithm output port
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::ply_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the poly data to write
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, poly_data: &crate::PolyData) {
+        unsafe {
+            let ptr = poly_data.as_ptr() as *mut ffi::vtkPolyData;
+            ffi::ply_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::ply_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+}
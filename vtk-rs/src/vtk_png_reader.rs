@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use crate::{ ImageData, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_png_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPNGReader;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_png_reader_new() -> *mut vtkPNGReader;
+        fn vtk_png_reader_delete(reader: Pin<&mut vtkPNGReader>);
+
+        fn png_reader_set_file_name(reader: Pin<&mut vtkPNGReader>, file_name: &str);
+        fn png_reader_get_file_name(reader: &vtkPNGReader) -> String;
+        fn png_reader_can_read_file(reader: Pin<&mut vtkPNGReader>, file_name: &str) -> bool;
+        fn png_reader_update(reader: Pin<&mut vtkPNGReader>) -> Result<()>;
+        fn png_reader_get_output(reader: Pin<&mut vtkPNGReader>) -> *mut vtkImageData;
+        unsafe fn png_reader_get_output_port(
+            reader: Pin<&mut vtkPNGReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPNGReader.html",
+    @name PNGReader, ffi::vtkPNGReader,
+    @new ffi::vtk_png_reader_new,
+    @delete ffi::vtk_png_reader_delete
+);
+
+impl PNGReader {
+    /// Set the name of the file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::png_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::png_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Check whether the given file looks like a PNG file
+    #[doc(alias = "CanReadFile")]
+    pub fn can_read_file(&mut self, file_name: &str) -> bool {
+        ffi::png_reader_can_read_file(self.ptr.as_mut(), file_name)
+    }
+
+    /// Read the file
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        if !self.can_read_file(&file_name) {
+            return Err(VtkError::invalid_file(&file_name, "not a PNG file"));
+        }
+        ffi::png_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get a copy of the image that was read
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> ImageData {
+        unsafe { ImageData::from_raw(ffi::png_reader_get_output(self.ptr.as_mut()) as *mut _) }
+    }
+
+    /// Get the output port for connecting to filters
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::png_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read a PNG file into an [`ImageData`]
+    pub fn read(path: impl AsRef<Path>) -> Result<ImageData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_png_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPNGWriter;
+        type vtkImageData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_png_writer_new() -> *mut vtkPNGWriter;
+        fn vtk_png_writer_delete(writer: Pin<&mut vtkPNGWriter>);
+
+        fn png_writer_set_file_name(writer: Pin<&mut vtkPNGWriter>, file_name: &str);
+        fn png_writer_get_file_name(writer: &vtkPNGWriter) -> String;
+        fn png_writer_set_compression_level(writer: Pin<&mut vtkPNGWriter>, level: i32);
+        fn png_writer_get_compression_level(writer: &vtkPNGWriter) -> i32;
+        unsafe fn png_writer_set_input_connection(
+            writer: Pin<&mut vtkPNGWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn png_writer_set_input_data(
+            writer: Pin<&mut vtkPNGWriter>,
+            image_data: *mut vtkImageData
+        );
+        fn png_writer_write(writer: Pin<&mut vtkPNGWriter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPNGWriter.html",
+    @name PNGWriter, ffi::vtkPNGWriter,
+    @new ffi::vtk_png_writer_new,
+    @delete ffi::vtk_png_writer_delete
+);
+
+impl PNGWriter {
+    /// Set the name of the file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::png_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::png_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the zlib compression level (0 = none, 9 = best)
+    #[doc(alias = "SetCompressionLevel")]
+    pub fn set_compression_level(&mut self, level: i32) {
+        ffi::png_writer_set_compression_level(self.ptr.as_mut(), level);
+    }
+
+    #[doc(alias = "GetCompressionLevel")]
+    pub fn get_compression_level(&self) -> i32 {
+        ffi::png_writer_get_compression_level(&self.ptr.as_ref())
+    }
+
+    /// Sets the input from an algorithm output port (e.g. a [`crate::WindowToImageFilter`])
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::png_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the input image directly
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, image_data: &mut crate::ImageData) {
+        unsafe {
+            let ptr = image_data.as_raw_ptr() as *mut ffi::vtkImageData;
+            ffi::png_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::png_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+}
@@ -1,5 +1,5 @@
 use std::ffi::{ CStr, CString };
-use crate::{ DoubleArray, IntArray };
+use crate::{ AbstractArray, DoubleArray, IntArray };
 
 /// Opaque handle to vtkPointData from VTK
 #[repr(C)]
@@ -9,9 +9,6 @@ pub struct vtkPointData {
 
 #[link(name = "vtkrs", kind = "static")]
 extern "C" {
-    fn poly_data_get_point_data(
-        poly_data: *mut crate::vtk_poly_data::vtkPolyData
-    ) -> *mut vtkPointData;
     fn point_data_add_array(point_data: *mut vtkPointData, array: *mut std::ffi::c_void);
     fn point_data_remove_array(point_data: *mut vtkPointData, name: *const i8);
     fn point_data_get_array(
@@ -26,6 +23,17 @@ extern "C" {
     fn point_data_get_vectors(point_data: *mut vtkPointData) -> *mut std::ffi::c_void;
     fn point_data_set_active_scalars(point_data: *mut vtkPointData, name: *const i8);
     fn point_data_set_active_vectors(point_data: *mut vtkPointData, name: *const i8);
+    fn point_data_set_colors(
+        point_data: *mut vtkPointData,
+        name: *const i8,
+        rgba: *const u8,
+        num_tuples: i64
+    );
+    fn point_data_get_number_of_colors(point_data: *mut vtkPointData, name: *const i8) -> i64;
+    fn point_data_copy_colors(point_data: *mut vtkPointData, name: *const i8, rgba: *mut u8);
+    fn point_data_set_tcoords(point_data: *mut vtkPointData, array: *mut std::ffi::c_void);
+    fn point_data_get_number_of_tcoords(point_data: *mut vtkPointData) -> i64;
+    fn point_data_copy_tcoords(point_data: *mut vtkPointData, uv: *mut f64);
 }
 
 /// Safe wrapper for vtkPointData
@@ -47,10 +55,12 @@ impl PointData {
 
     /// Add a data array to the point data
     ///
+    /// Accepts a [`DoubleArray`], [`IntArray`], [`crate::StringArray`] or any typed
+    /// [`crate::DataArray`].
     /// The array should have the same number of tuples as there are points
-    pub fn add_array(&mut self, array: &DoubleArray) {
+    pub fn add_array(&mut self, array: &impl AbstractArray) {
         unsafe {
-            point_data_add_array(self.ptr, array.as_ptr() as *mut std::ffi::c_void);
+            point_data_add_array(self.ptr, array.as_abstract_array_ptr());
         }
     }
 
@@ -120,6 +130,58 @@ impl PointData {
         }
     }
 
+    /// Store one RGBA color per point as the active scalars
+    ///
+    /// The colors are kept as unsigned chars so that mappers use them directly and
+    /// writers such as [`crate::PLYWriter`] export them as vertex colors.
+    pub fn set_colors(&mut self, name: &str, colors: &[[u8; 4]]) {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            point_data_set_colors(
+                self.ptr,
+                c_name.as_ptr(),
+                colors.as_ptr() as *const u8,
+                colors.len() as i64
+            );
+        }
+    }
+
+    /// Get an RGB or RGBA unsigned char array as RGBA colors
+    ///
+    /// Returns `None` if there is no such array or it does not hold colors.
+    pub fn get_colors(&self, name: &str) -> Option<Vec<[u8; 4]>> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let num_tuples = point_data_get_number_of_colors(self.ptr, c_name.as_ptr());
+            if num_tuples < 0 {
+                return None;
+            }
+            let mut colors = vec![[0u8; 4]; num_tuples as usize];
+            point_data_copy_colors(self.ptr, c_name.as_ptr(), colors.as_mut_ptr() as *mut u8);
+            Some(colors)
+        }
+    }
+
+    /// Set the texture coordinates (a 2 component array)
+    pub fn set_tcoords(&mut self, array: &DoubleArray) {
+        unsafe {
+            point_data_set_tcoords(self.ptr, array.as_ptr() as *mut std::ffi::c_void);
+        }
+    }
+
+    /// Get the texture coordinates as (u, v) pairs, if present
+    pub fn get_tcoords(&self) -> Option<Vec<[f64; 2]>> {
+        unsafe {
+            let num_tuples = point_data_get_number_of_tcoords(self.ptr);
+            if num_tuples < 0 {
+                return None;
+            }
+            let mut uv = vec![[0.0; 2]; num_tuples as usize];
+            point_data_copy_tcoords(self.ptr, uv.as_mut_ptr() as *mut f64);
+            Some(uv)
+        }
+    }
+
     /// Get raw pointer (for internal use)
     pub fn as_ptr(&self) -> *mut vtkPointData {
         self.ptr
@@ -16,6 +16,10 @@ extern "C" {
     fn points_reset(points: *mut vtkPoints);
     fn points_set_number_of_points(points: *mut vtkPoints, number: i64);
     fn points_resize(points: *mut vtkPoints, number: i64);
+    fn points_set_data_type_to_double(points: *mut vtkPoints);
+    fn points_is_double(points: *mut vtkPoints) -> bool;
+    fn points_get_data(points: *mut vtkPoints) -> *mut crate::vtk_data_array::vtkDataArray;
+    fn points_modified(points: *mut vtkPoints);
 }
 
 /// Safe wrapper for vtkPoints - manages a collection of 3D points
@@ -34,6 +38,12 @@ impl Points {
         Self { ptr }
     }
 
+    /// Take ownership of a reference to an existing vtkPoints (e.g. the points of a dataset)
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkPoints) -> Self {
+        assert!(!ptr.is_null(), "vtkPoints pointer is null");
+        Self { ptr }
+    }
+
     /// Get raw pointer (for internal use)
     pub fn as_mut_ptr(&mut self) -> *mut vtkPoints {
         self.ptr
@@ -115,6 +125,90 @@ impl Points {
         }
     }
 
+    /// Store coordinates as doubles (VTK stores them as floats by default)
+    ///
+    /// Existing points are converted. Double precision is needed for
+    /// [`as_slice`](Self::as_slice) and keeps coordinates exact.
+    #[doc(alias = "SetDataTypeToDouble")]
+    pub fn set_data_type_to_double(&mut self) {
+        unsafe {
+            points_set_data_type_to_double(self.ptr);
+        }
+    }
+
+    /// Check whether coordinates are stored as doubles
+    pub fn is_double(&self) -> bool {
+        unsafe { points_is_double(self.ptr) }
+    }
+
+    /// Signal that coordinates were changed through [`as_mut_slice`](Self::as_mut_slice)
+    #[doc(alias = "Modified")]
+    pub fn modified(&mut self) {
+        unsafe {
+            points_modified(self.ptr);
+        }
+    }
+
+    /// View the coordinates without copying
+    ///
+    /// Returns `None` if the coordinates are stored as floats, as for points read from
+    /// most files; see [`set_data_type_to_double`](Self::set_data_type_to_double).
+    ///
+    /// # Safety
+    /// Several `Points` can share the same VTK object, e.g. the ones returned by
+    /// `get_points` of a dataset. None of them may change, insert or remove points while
+    /// the slice is alive.
+    pub unsafe fn as_slice(&self) -> Option<&[[f64; 3]]> {
+        if !self.is_double() {
+            return None;
+        }
+        unsafe {
+            let values: &[f64] = crate::vtk_data_array::values_slice(points_get_data(self.ptr));
+            Some(std::slice::from_raw_parts(values.as_ptr() as *const [f64; 3], values.len() / 3))
+        }
+    }
+
+    /// View the coordinates mutably without copying
+    ///
+    /// Returns `None` if the coordinates are stored as floats.
+    ///
+    /// # Safety
+    /// No other `Points` sharing the same VTK object may be used while the slice is
+    /// alive, see [`as_slice`](Self::as_slice).
+    pub unsafe fn as_mut_slice(&mut self) -> Option<&mut [[f64; 3]]> {
+        if !self.is_double() {
+            return None;
+        }
+        unsafe {
+            let values: &mut [f64] =
+                crate::vtk_data_array::values_slice_mut(points_get_data(self.ptr));
+            let len = values.len() / 3;
+            Some(std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut [f64; 3], len))
+        }
+    }
+
+    /// Create double precision points holding a copy of `points`
+    pub fn from_slice(points: &[[f64; 3]]) -> Self {
+        let mut result = Self::new();
+        result.set_data_type_to_double();
+        unsafe {
+            crate::vtk_data_array::copy_values(points_get_data(result.ptr), points.as_flattened());
+        }
+        result
+    }
+
+    /// Create double precision points that take over the buffer of `points` without copying
+    #[doc(alias = "SetArray")]
+    pub fn from_vec(points: Vec<[f64; 3]>) -> Self {
+        let mut result = Self::new();
+        result.set_data_type_to_double();
+        unsafe {
+            let values = crate::vtk_data_array::flatten_tuples(points);
+            crate::vtk_data_array::adopt_vec(points_get_data(result.ptr), values);
+        }
+        result
+    }
+
     /// Iterator over all points
     pub fn iter(&self) -> PointsIterator {
         PointsIterator {
@@ -1,5 +1,8 @@
 // Direct extern "C" bindings (no cxx bridge)
-use crate::{ Points, CellArray, PointData, CellData };
+use std::path::Path;
+
+use crate::{ Points, CellArray, FileType, VtkError };
+use crate::vtk_cell_array::vtkCellArray;
 
 #[repr(C)]
 pub struct vtkPolyData {
@@ -12,24 +15,21 @@ extern "C" {
     fn poly_data_delete(poly_data: *mut vtkPolyData);
     fn poly_data_set_points(poly_data: *mut vtkPolyData, points: *mut crate::vtk_points::vtkPoints);
     fn poly_data_get_points(poly_data: *mut vtkPolyData) -> *mut crate::vtk_points::vtkPoints;
-    fn poly_data_get_number_of_points(poly_data: *mut vtkPolyData) -> i64;
-    fn poly_data_set_lines(
-        poly_data: *mut vtkPolyData,
-        lines: *mut crate::vtk_cell_array::vtkCellArray
-    );
-    fn poly_data_get_lines(poly_data: *mut vtkPolyData) -> *mut crate::vtk_cell_array::vtkCellArray;
+    fn poly_data_set_verts(poly_data: *mut vtkPolyData, verts: *mut vtkCellArray);
+    fn poly_data_get_verts(poly_data: *mut vtkPolyData) -> *mut vtkCellArray;
+    fn poly_data_get_number_of_verts(poly_data: *mut vtkPolyData) -> i64;
+    fn poly_data_set_lines(poly_data: *mut vtkPolyData, lines: *mut vtkCellArray);
+    fn poly_data_get_lines(poly_data: *mut vtkPolyData) -> *mut vtkCellArray;
     fn poly_data_get_number_of_lines(poly_data: *mut vtkPolyData) -> i64;
-    fn poly_data_get_number_of_cells(poly_data: *mut vtkPolyData) -> i64;
-    fn poly_data_get_bounds(poly_data: *mut vtkPolyData, bounds: *mut [f64; 6]);
+    fn poly_data_set_polys(poly_data: *mut vtkPolyData, polys: *mut vtkCellArray);
+    fn poly_data_get_polys(poly_data: *mut vtkPolyData) -> *mut vtkCellArray;
+    fn poly_data_get_number_of_polys(poly_data: *mut vtkPolyData) -> i64;
+    fn poly_data_set_strips(poly_data: *mut vtkPolyData, strips: *mut vtkCellArray);
+    fn poly_data_get_strips(poly_data: *mut vtkPolyData) -> *mut vtkCellArray;
+    fn poly_data_get_number_of_strips(poly_data: *mut vtkPolyData) -> i64;
     fn poly_data_allocate(poly_data: *mut vtkPolyData, num_verts: i64, connectivity_size: i64);
     fn poly_data_modified(poly_data: *mut vtkPolyData);
     fn poly_data_compute_bounds(poly_data: *mut vtkPolyData);
-    fn poly_data_get_point_data(
-        poly_data: *mut vtkPolyData
-    ) -> *mut crate::vtk_point_data::vtkPointData;
-    fn poly_data_get_cell_data(
-        poly_data: *mut vtkPolyData
-    ) -> *mut crate::vtk_cell_data::vtkCellData;
     fn poly_data_get_producer_port(poly_data: *mut vtkPolyData) -> *mut crate::AlgorithmOutputPort;
 }
 
@@ -39,7 +39,12 @@ extern "C" {
 /// For FEM beam structures, it combines:
 /// - Points: Node locations (x, y, z coordinates)
 /// - Lines: Beam element connectivity (which nodes connect to form beams)
+/// - Polys: Shell element connectivity (triangles, quads and other polygons)
 /// - Data Arrays: Properties like deformations, displacements, stress, etc.
+///
+/// Cells are stored in four arrays: vertices, lines, polygons and triangle strips.
+/// Cell ids number them in that order, so with 2 vertices and 3 lines the first
+/// polygon is cell 5.
 pub struct PolyData {
     ptr: *mut vtkPolyData,
 }
@@ -52,6 +57,12 @@ impl PolyData {
         Self { ptr }
     }
 
+    /// Take ownership of an existing vtkPolyData (e.g. a copy returned by a reader)
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkPolyData) -> Self {
+        assert!(!ptr.is_null(), "vtkPolyData pointer is null");
+        Self { ptr }
+    }
+
     /// Set the points (node positions) for this PolyData
     ///
     /// # Example
@@ -70,9 +81,33 @@ impl PolyData {
         }
     }
 
-    /// Get the number of points in this PolyData
-    pub fn get_number_of_points(&self) -> i64 {
-        unsafe { poly_data_get_number_of_points(self.ptr) }
+    /// Get the points, shared with the poly data, or `None` if none were set
+    #[doc(alias = "GetPoints")]
+    pub fn get_points(&self) -> Option<Points> {
+        let ptr = unsafe { poly_data_get_points(self.ptr) };
+        (!ptr.is_null()).then(|| unsafe { Points::from_raw(ptr) })
+    }
+
+    /// Set the vertex cells (point masses, springs to ground, ...)
+    #[doc(alias = "SetVerts")]
+    pub fn set_verts(&mut self, verts: &CellArray) {
+        unsafe {
+            poly_data_set_verts(self.ptr, verts.as_ptr());
+        }
+    }
+
+    /// Get the vertex cells, shared with the poly data
+    ///
+    /// Pass the array back to [`set_verts`](Self::set_verts) after inserting cells so that
+    /// cell ids are rebuilt.
+    #[doc(alias = "GetVerts")]
+    pub fn get_verts(&self) -> CellArray {
+        unsafe { CellArray::from_raw(poly_data_get_verts(self.ptr)) }
+    }
+
+    #[doc(alias = "GetNumberOfVerts")]
+    pub fn get_number_of_verts(&self) -> i64 {
+        unsafe { poly_data_get_number_of_verts(self.ptr) }
     }
 
     /// Set the lines (beam elements) for this PolyData
@@ -95,25 +130,78 @@ impl PolyData {
         }
     }
 
+    /// Get the line cells, shared with the poly data
+    ///
+    /// Pass the array back to [`set_lines`](Self::set_lines) after inserting cells so that
+    /// cell ids are rebuilt.
+    #[doc(alias = "GetLines")]
+    pub fn get_lines(&self) -> CellArray {
+        unsafe { CellArray::from_raw(poly_data_get_lines(self.ptr)) }
+    }
+
     /// Get the number of line cells (beam elements) in this PolyData
     pub fn get_number_of_lines(&self) -> i64 {
         unsafe { poly_data_get_number_of_lines(self.ptr) }
     }
 
-    /// Get the total number of cells (all types) in this PolyData
-    pub fn get_number_of_cells(&self) -> i64 {
-        unsafe { poly_data_get_number_of_cells(self.ptr) }
+    /// Set the polygon cells (shell elements)
+    ///
+    /// Each cell lists the corner points of a triangle, quad or general polygon in order
+    /// around its boundary.
+    ///
+    /// # Example
+    /// ```
+    /// # use vtk_rs::{CellArray, PolyData};
+    /// let mut cells = CellArray::new();
+    /// cells.insert_next_cell(&[0, 1, 4, 3]); // Quad shell
+    /// cells.insert_next_cell(&[1, 2, 4]); // Triangle shell
+    ///
+    /// let mut poly_data = PolyData::new();
+    /// poly_data.set_polys(&cells);
+    /// ```
+    #[doc(alias = "SetPolys")]
+    pub fn set_polys(&mut self, polys: &CellArray) {
+        unsafe {
+            poly_data_set_polys(self.ptr, polys.as_ptr());
+        }
+    }
+
+    /// Get the polygon cells, shared with the poly data
+    ///
+    /// Pass the array back to [`set_polys`](Self::set_polys) after inserting cells so that
+    /// cell ids are rebuilt.
+    #[doc(alias = "GetPolys")]
+    pub fn get_polys(&self) -> CellArray {
+        unsafe { CellArray::from_raw(poly_data_get_polys(self.ptr)) }
     }
 
-    /// Get the spatial bounds of this PolyData
+    #[doc(alias = "GetNumberOfPolys")]
+    pub fn get_number_of_polys(&self) -> i64 {
+        unsafe { poly_data_get_number_of_polys(self.ptr) }
+    }
+
+    /// Set the triangle strip cells
     ///
-    /// Returns (xmin, xmax, ymin, ymax, zmin, zmax)
-    pub fn get_bounds(&self) -> (f64, f64, f64, f64, f64, f64) {
-        let mut bounds = [0.0; 6];
+    /// A strip of `n` points describes `n - 2` triangles sharing their edges.
+    #[doc(alias = "SetStrips")]
+    pub fn set_strips(&mut self, strips: &CellArray) {
         unsafe {
-            poly_data_get_bounds(self.ptr, &mut bounds);
+            poly_data_set_strips(self.ptr, strips.as_ptr());
         }
-        (bounds[0], bounds[1], bounds[2], bounds[3], bounds[4], bounds[5])
+    }
+
+    /// Get the triangle strip cells, shared with the poly data
+    ///
+    /// Pass the array back to [`set_strips`](Self::set_strips) after inserting cells so that
+    /// cell ids are rebuilt.
+    #[doc(alias = "GetStrips")]
+    pub fn get_strips(&self) -> CellArray {
+        unsafe { CellArray::from_raw(poly_data_get_strips(self.ptr)) }
+    }
+
+    #[doc(alias = "GetNumberOfStrips")]
+    pub fn get_number_of_strips(&self) -> i64 {
+        unsafe { poly_data_get_number_of_strips(self.ptr) }
     }
 
     /// Allocate memory for cells
@@ -144,26 +232,6 @@ impl PolyData {
         self.ptr
     }
 
-    /// Get the point data (attributes associated with points/nodes)
-    ///
-    /// Use this to attach displacement, stress, temperature, or other node-based data
-    pub fn get_point_data(&self) -> PointData {
-        unsafe {
-            let ptr = poly_data_get_point_data(self.ptr);
-            PointData::from_raw(ptr)
-        }
-    }
-
-    /// Get the cell data (attributes associated with cells/elements)
-    ///
-    /// Use this to attach material IDs, element stresses, or other element-based data
-    pub fn get_cell_data(&self) -> CellData {
-        unsafe {
-            let ptr = poly_data_get_cell_data(self.ptr);
-            CellData::from_raw(ptr)
-        }
-    }
-
     /// Get the output port for connecting to filters (like Glyph3D)
     ///
     /// This creates a vtkTrivialProducer to wrap the PolyData as an algorithm output,
@@ -182,6 +250,52 @@ impl PolyData {
         poly_data.modified();
         poly_data
     }
+
+    /// Save as a legacy `.vtk` file
+    ///
+    /// See [`crate::PolyDataWriter`] for more options.
+    pub fn write_vtk(&self, path: impl AsRef<Path>, file_type: FileType) -> Result<(), VtkError> {
+        let mut writer = crate::PolyDataWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_file_type(file_type);
+        writer.set_input_data(self);
+        writer.write()
+    }
+
+    /// Load an XML `.vtp` file
+    ///
+    /// See [`crate::XMLPolyDataReader`] for more options.
+    pub fn read_vtp(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::XMLPolyDataReader::read(path)
+    }
+
+    /// Save as an XML `.vtp` file with zlib compressed appended data
+    ///
+    /// See [`crate::XMLPolyDataWriter`] for other encodings.
+    pub fn write_vtp(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let mut writer = crate::XMLPolyDataWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_data_mode_to_appended();
+        writer.set_compressor_type_to_zlib();
+        writer.set_input_data(self);
+        writer.write()
+    }
+
+    /// Load a `.vtkhdf` file
+    ///
+    /// Reads the first time step of temporal files; see [`crate::HDFReader`] to select others.
+    pub fn read_vtkhdf(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::HDFReader::read_poly_data(path)
+    }
+
+    /// Save as a `.vtkhdf` file
+    ///
+    /// See [`crate::HDFWriter`] for compression and time series. Requires VTK 9.4.
+    #[cfg(feature = "v094")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "v094")))]
+    pub fn write_vtkhdf(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        crate::HDFWriter::write_poly_data(path, self)
+    }
 }
 
 impl Default for PolyData {
@@ -204,6 +318,7 @@ unsafe impl Sync for PolyData {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ DataSet, VtkCellType };
 
     #[test]
     fn test_poly_data_creation() {
@@ -243,6 +358,46 @@ mod tests {
         assert_eq!(poly_data.get_number_of_cells(), 2);
     }
 
+    #[test]
+    fn test_poly_data_cell_arrays() {
+        // Two quad shells, a triangle strip and a point mass on a 3 x 2 grid of nodes
+        let mut points = Points::new();
+        for y in [0.0, 1.0] {
+            for x in [0.0, 1.0, 2.0] {
+                points.insert_next_point(x, y, 0.0);
+            }
+        }
+        let mut verts = CellArray::new();
+        verts.insert_next_cell(&[5]);
+        let mut polys = CellArray::new();
+        polys.insert_next_cell(&[0, 1, 4, 3]);
+        polys.insert_next_cell(&[1, 2, 5, 4]);
+        let mut strips = CellArray::new();
+        strips.insert_next_cell(&[0, 3, 1, 4]);
+
+        let mut poly_data = PolyData::new();
+        poly_data.set_points(&points);
+        poly_data.set_polys(&polys);
+        poly_data.set_strips(&strips);
+        poly_data.set_verts(&verts);
+
+        assert_eq!(poly_data.get_number_of_verts(), 1);
+        assert_eq!(poly_data.get_number_of_lines(), 0);
+        assert_eq!(poly_data.get_number_of_polys(), 2);
+        assert_eq!(poly_data.get_number_of_strips(), 1);
+        assert_eq!(poly_data.get_polys().get_cell(1), Some(vec![1, 2, 5, 4]));
+        assert_eq!(poly_data.get_lines().get_number_of_cells(), 0);
+        assert_eq!(poly_data.get_points().unwrap().get_number_of_points(), 6);
+
+        // Vertices come first, then lines, polygons and strips
+        assert_eq!(poly_data.get_cell_type(0), Some(VtkCellType::Vertex));
+        assert_eq!(poly_data.get_cell_type(1), Some(VtkCellType::Quad));
+        assert_eq!(poly_data.get_cell_points(2), Some(vec![1, 2, 5, 4]));
+        assert_eq!(poly_data.get_cell_type(3), Some(VtkCellType::TriangleStrip));
+        assert_eq!(poly_data.get_cell_type(4), None);
+        assert_eq!(poly_data.get_cell_points(4), None);
+    }
+
     #[test]
     fn test_poly_data_bounds() {
         let mut points = Points::new();
@@ -253,7 +408,7 @@ mod tests {
         poly_data.set_points(&points);
         poly_data.compute_bounds();
 
-        let (xmin, xmax, ymin, ymax, zmin, zmax) = poly_data.get_bounds();
+        let [xmin, xmax, ymin, ymax, zmin, zmax] = poly_data.get_bounds();
         assert_eq!(xmin, -1.0);
         assert_eq!(xmax, 4.0);
         assert_eq!(ymin, -2.0);
@@ -1,3 +1,5 @@
+use std::pin::Pin;
+
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
@@ -8,6 +10,7 @@ mod ffi {
         type vtkPolyDataMapper;
         type vtkAlgorithmOutput;
         type vtkMapper;
+        type vtkLookupTable = crate::vtk_lookup_table::ffi::vtkLookupTable;
 
         fn poly_data_mapper_new() -> *mut vtkPolyDataMapper;
         fn poly_data_mapper_delete(pdm: Pin<&mut vtkPolyDataMapper>);
@@ -15,6 +18,26 @@ mod ffi {
             mapper: Pin<&mut vtkPolyDataMapper>,
             output: *mut vtkAlgorithmOutput
         );
+
+        fn poly_data_mapper_set_lookup_table(
+            mapper: Pin<&mut vtkPolyDataMapper>,
+            lut: Pin<&mut vtkLookupTable>
+        );
+        fn poly_data_mapper_set_scalar_range(
+            mapper: Pin<&mut vtkPolyDataMapper>,
+            min: f64,
+            max: f64
+        );
+        fn poly_data_mapper_set_scalar_visibility(
+            mapper: Pin<&mut vtkPolyDataMapper>,
+            visible: bool
+        );
+        fn poly_data_mapper_get_scalar_visibility(mapper: &vtkPolyDataMapper) -> bool;
+        fn poly_data_mapper_color_by_array(
+            mapper: Pin<&mut vtkPolyDataMapper>,
+            name: &str,
+            cell_data: bool
+        );
     }
 }
 
@@ -51,6 +74,46 @@ impl PolyDataMapper {
     pub(crate) fn as_raw_ptr(&mut self) -> *mut ffi::vtkMapper {
         self.as_mut_ptr() as *mut ffi::vtkMapper
     }
+
+    /// Set the lookup table mapping scalar values to colors
+    #[doc(alias = "SetLookupTable")]
+    pub fn set_lookup_table(&mut self, lut: &mut crate::LookupTable) {
+        unsafe {
+            let lut = Pin::new_unchecked(&mut *lut.as_raw_ptr());
+            ffi::poly_data_mapper_set_lookup_table(self.ptr.as_mut(), lut);
+        }
+    }
+
+    /// Set the scalar values mapped to the first and last color of the lookup table
+    #[doc(alias = "SetScalarRange")]
+    pub fn set_scalar_range(&mut self, min: f64, max: f64) {
+        ffi::poly_data_mapper_set_scalar_range(self.ptr.as_mut(), min, max);
+    }
+
+    /// Color by scalars (on by default) or by the actor color
+    #[doc(alias = "SetScalarVisibility")]
+    pub fn set_scalar_visibility(&mut self, visible: bool) {
+        ffi::poly_data_mapper_set_scalar_visibility(self.ptr.as_mut(), visible);
+    }
+
+    #[doc(alias = "GetScalarVisibility")]
+    pub fn get_scalar_visibility(&self) -> bool {
+        ffi::poly_data_mapper_get_scalar_visibility(&self.ptr.as_ref())
+    }
+
+    /// Color by a named point array instead of the active scalars
+    #[doc(alias = "SelectColorArray")]
+    #[doc(alias = "SetScalarModeToUsePointFieldData")]
+    pub fn color_by_point_array(&mut self, name: &str) {
+        ffi::poly_data_mapper_color_by_array(self.ptr.as_mut(), name, false);
+    }
+
+    /// Color by a named cell array instead of the active scalars
+    #[doc(alias = "SelectColorArray")]
+    #[doc(alias = "SetScalarModeToUseCellFieldData")]
+    pub fn color_by_cell_array(&mut self, name: &str) {
+        ffi::poly_data_mapper_color_by_array(self.ptr.as_mut(), name, true);
+    }
 }
 
 pub(crate) mod private {
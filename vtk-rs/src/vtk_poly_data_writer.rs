@@ -0,0 +1,143 @@
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_poly_data_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPolyDataWriter;
+        type vtkPolyData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_poly_data_writer_new() -> *mut vtkPolyDataWriter;
+        fn vtk_poly_data_writer_delete(writer: Pin<&mut vtkPolyDataWriter>);
+
+        fn poly_data_writer_set_file_name(writer: Pin<&mut vtkPolyDataWriter>, file_name: &str);
+        fn poly_data_writer_get_file_name(writer: &vtkPolyDataWriter) -> String;
+        fn poly_data_writer_set_file_type(writer: Pin<&mut vtkPolyDataWriter>, file_type: i32);
+        fn poly_data_writer_get_file_type(writer: &vtkPolyDataWriter) -> i32;
+        fn poly_data_writer_set_header(writer: Pin<&mut vtkPolyDataWriter>, header: &str);
+        fn poly_data_writer_get_header(writer: &vtkPolyDataWriter) -> String;
+        fn poly_data_writer_set_write_to_output_string(
+            writer: Pin<&mut vtkPolyDataWriter>,
+            enabled: bool
+        );
+        fn poly_data_writer_get_output_string(writer: &vtkPolyDataWriter) -> Vec<u8>;
+        unsafe fn poly_data_writer_set_input_connection(
+            writer: Pin<&mut vtkPolyDataWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn poly_data_writer_set_input_data(
+            writer: Pin<&mut vtkPolyDataWriter>,
+            poly_data: *mut vtkPolyData
+        );
+        fn poly_data_writer_write(writer: Pin<&mut vtkPolyDataWriter>) -> Result<()>;
+    }
+}
+
+/// Encoding of legacy `.vtk` files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileType {
+    Ascii = 1,
+    Binary = 2,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPolyDataWriter.html",
+    @name PolyDataWriter, ffi::vtkPolyDataWriter,
+    @new ffi::vtk_poly_data_writer_new,
+    @delete ffi::vtk_poly_data_writer_delete
+);
+
+impl PolyDataWriter {
+    /// Set the name of the file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::poly_data_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::poly_data_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Choose between ASCII and binary (big endian) encoding
+    #[doc(alias = "SetFileType")]
+    pub fn set_file_type(&mut self, file_type: FileType) {
+        ffi::poly_data_writer_set_file_type(self.ptr.as_mut(), file_type as i32);
+    }
+
+    #[doc(alias = "GetFileType")]
+    pub fn get_file_type(&self) -> FileType {
+        match ffi::poly_data_writer_get_file_type(&self.ptr.as_ref()) {
+            2 => FileType::Binary,
+            _ => FileType::Ascii,
+        }
+    }
+
+    #[doc(alias = "SetFileTypeToASCII")]
+    pub fn set_file_type_to_ascii(&mut self) {
+        self.set_file_type(FileType::Ascii);
+    }
+
+    #[doc(alias = "SetFileTypeToBinary")]
+    pub fn set_file_type_to_binary(&mut self) {
+        self.set_file_type(FileType::Binary);
+    }
+
+    /// Set the title line of the file (a single line of at most 256 characters)
+    #[doc(alias = "SetHeader")]
+    pub fn set_header(&mut self, header: &str) {
+        ffi::poly_data_writer_set_header(self.ptr.as_mut(), header);
+    }
+
+    #[doc(alias = "GetHeader")]
+    pub fn get_header(&self) -> String {
+        ffi::poly_data_writer_get_header(&self.ptr.as_ref())
+    }
+
+    /// Write into a string instead of a file (see [`get_output_string`](Self::get_output_string))
+    #[doc(alias = "SetWriteToOutputString")]
+    pub fn set_write_to_output_string(&mut self, enabled: bool) {
+        ffi::poly_data_writer_set_write_to_output_string(self.ptr.as_mut(), enabled);
+    }
+
+    /// Get the result of the last write when writing to a string
+    #[doc(alias = "GetBinaryOutputString")]
+    pub fn get_output_bytes(&self) -> Vec<u8> {
+        ffi::poly_data_writer_get_output_string(&self.ptr.as_ref())
+    }
+
+    /// Get the result of the last write as text (only meaningful for ASCII output)
+    #[doc(alias = "GetOutputString")]
+    pub fn get_output_string(&self) -> String {
+        String::from_utf8_lossy(&self.get_output_bytes()).into_owned()
+    }
+
+    /// Sets the input from an algorithm output port
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::poly_data_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the poly data to write
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, poly_data: &crate::PolyData) {
+        unsafe {
+            let ptr = poly_data.as_ptr() as *mut ffi::vtkPolyData;
+            ffi::poly_data_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::poly_data_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+}
@@ -0,0 +1,173 @@
+use std::path::Path;
+use std::pin::Pin;
+
+use crate::{ DoubleArray, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_rectilinear_grid.h");
+
+        type vtkRectilinearGrid;
+        type vtkDoubleArray;
+
+        fn vtk_rectilinear_grid_new() -> *mut vtkRectilinearGrid;
+        fn vtk_rectilinear_grid_delete(grid: Pin<&mut vtkRectilinearGrid>);
+
+        fn rectilinear_grid_set_dimensions(
+            grid: Pin<&mut vtkRectilinearGrid>,
+            nx: i32,
+            ny: i32,
+            nz: i32
+        );
+        unsafe fn rectilinear_grid_get_dimensions(
+            grid: &vtkRectilinearGrid,
+            nx: *mut i32,
+            ny: *mut i32,
+            nz: *mut i32
+        );
+
+        unsafe fn rectilinear_grid_set_coordinates(
+            grid: Pin<&mut vtkRectilinearGrid>,
+            axis: i32,
+            coordinates: *mut vtkDoubleArray
+        );
+        fn rectilinear_grid_get_coordinates(grid: &vtkRectilinearGrid, axis: i32) -> Vec<f64>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkRectilinearGrid.html",
+    @name RectilinearGrid, ffi::vtkRectilinearGrid,
+    @new ffi::vtk_rectilinear_grid_new,
+    @delete ffi::vtk_rectilinear_grid_delete
+);
+
+impl RectilinearGrid {
+    /// Create a grid from the point coordinates along each axis
+    ///
+    /// The dimensions are the lengths of the three slices. Use a single coordinate
+    /// (e.g. `&[0.0]`) for an axis the grid does not extend along.
+    pub fn from_coordinates(x: &[f64], y: &[f64], z: &[f64]) -> Self {
+        let mut grid = Self::new();
+        grid.set_dimensions(x.len() as i32, y.len() as i32, z.len() as i32);
+        grid.set_x_coordinates(&DoubleArray::from_vec("X", 1, x.to_vec()));
+        grid.set_y_coordinates(&DoubleArray::from_vec("Y", 1, y.to_vec()));
+        grid.set_z_coordinates(&DoubleArray::from_vec("Z", 1, z.to_vec()));
+        grid
+    }
+
+    /// Set the dimensions (number of points along each axis)
+    ///
+    /// They must match the lengths of the coordinate arrays.
+    #[doc(alias = "SetDimensions")]
+    pub fn set_dimensions(&mut self, nx: i32, ny: i32, nz: i32) {
+        ffi::rectilinear_grid_set_dimensions(self.ptr.as_mut(), nx, ny, nz);
+    }
+
+    /// Get the dimensions
+    #[doc(alias = "GetDimensions")]
+    pub fn get_dimensions(&self) -> (i32, i32, i32) {
+        let mut nx = 0;
+        let mut ny = 0;
+        let mut nz = 0;
+        unsafe {
+            ffi::rectilinear_grid_get_dimensions(
+                self.ptr.as_ref().get_ref(),
+                &mut nx,
+                &mut ny,
+                &mut nz
+            );
+        }
+        (nx, ny, nz)
+    }
+
+    /// Set the point coordinates along x (one value per point, increasing)
+    #[doc(alias = "SetXCoordinates")]
+    pub fn set_x_coordinates(&mut self, coordinates: &DoubleArray) {
+        self.set_coordinates(0, coordinates);
+    }
+
+    #[doc(alias = "SetYCoordinates")]
+    pub fn set_y_coordinates(&mut self, coordinates: &DoubleArray) {
+        self.set_coordinates(1, coordinates);
+    }
+
+    #[doc(alias = "SetZCoordinates")]
+    pub fn set_z_coordinates(&mut self, coordinates: &DoubleArray) {
+        self.set_coordinates(2, coordinates);
+    }
+
+    /// Get a copy of the point coordinates along x
+    #[doc(alias = "GetXCoordinates")]
+    pub fn get_x_coordinates(&self) -> Vec<f64> {
+        ffi::rectilinear_grid_get_coordinates(self.ptr.as_ref().get_ref(), 0)
+    }
+
+    #[doc(alias = "GetYCoordinates")]
+    pub fn get_y_coordinates(&self) -> Vec<f64> {
+        ffi::rectilinear_grid_get_coordinates(self.ptr.as_ref().get_ref(), 1)
+    }
+
+    #[doc(alias = "GetZCoordinates")]
+    pub fn get_z_coordinates(&self) -> Vec<f64> {
+        ffi::rectilinear_grid_get_coordinates(self.ptr.as_ref().get_ref(), 2)
+    }
+
+    fn set_coordinates(&mut self, axis: i32, coordinates: &DoubleArray) {
+        unsafe {
+            let ptr = coordinates.as_ptr() as *mut ffi::vtkDoubleArray;
+            ffi::rectilinear_grid_set_coordinates(self.ptr.as_mut(), axis, ptr);
+        }
+    }
+
+    /// Get raw pointer for VTK pipeline connections
+    pub fn as_raw_ptr(&mut self) -> *mut ffi::vtkRectilinearGrid {
+        unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
+    }
+
+    /// Load an XML `.vtr` file
+    ///
+    /// See [`crate::XMLRectilinearGridReader`] for more options.
+    pub fn read_vtr(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::XMLRectilinearGridReader::read(path)
+    }
+
+    /// Save as an XML `.vtr` file with zlib compressed appended data
+    ///
+    /// See [`crate::XMLRectilinearGridWriter`] for other encodings.
+    pub fn write_vtr(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let mut writer = crate::XMLRectilinearGridWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_data_mode_to_appended();
+        writer.set_compressor_type_to_zlib();
+        writer.set_input_data(self);
+        writer.write()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataSet;
+
+    #[test]
+    fn test_rectilinear_grid_vtr_round_trip() {
+        // Boundary layer mesh, refined towards y = 0
+        let grid =
+            RectilinearGrid::from_coordinates(&[0.0, 1.0, 2.0], &[0.0, 0.1, 0.5, 2.0], &[0.0]);
+        assert_eq!(grid.get_dimensions(), (3, 4, 1));
+        assert_eq!(grid.get_number_of_points(), 12);
+        assert_eq!(grid.get_number_of_cells(), 6);
+        assert_eq!(grid.get_point(4), Some([1.0, 0.1, 0.0]));
+        assert_eq!(grid.get_bounds(), [0.0, 2.0, 0.0, 2.0, 0.0, 0.0]);
+
+        let path = std::env::temp_dir().join(format!("vtk-rs-{}.vtr", std::process::id()));
+        grid.write_vtr(&path).unwrap();
+        let read = RectilinearGrid::read_vtr(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.get_dimensions(), (3, 4, 1));
+        assert_eq!(read.get_y_coordinates(), vec![0.0, 0.1, 0.5, 2.0]);
+    }
+}
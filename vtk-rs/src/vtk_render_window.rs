@@ -1,3 +1,7 @@
+use std::path::Path;
+
+use crate::{ JPEGWriter, PNGWriter, RgbaImage, VtkError, WindowToImageFilter };
+
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
@@ -10,6 +14,7 @@ mod ffi {
         type vtkCommand;
 
         fn render_window_new() -> *mut vtkRenderWindow;
+        fn render_window_new_offscreen() -> *mut vtkRenderWindow;
         fn render_window_delete(window: Pin<&mut vtkRenderWindow>);
         unsafe fn render_window_add_renderer(
             window: Pin<&mut vtkRenderWindow>,
@@ -18,6 +23,8 @@ mod ffi {
         fn render_window_set_size(window: Pin<&mut vtkRenderWindow>, width: i32, height: i32);
         fn render_window_set_window_name(window: Pin<&mut vtkRenderWindow>, name: &str);
         fn render_window_render(window: Pin<&mut vtkRenderWindow>);
+        fn render_window_set_off_screen_rendering(window: Pin<&mut vtkRenderWindow>, on: bool);
+        fn render_window_get_off_screen_rendering(window: &vtkRenderWindow) -> bool;
         fn render_window_get_size(
             window: Pin<&mut vtkRenderWindow>,
             width: &mut i32,
@@ -49,6 +56,18 @@ crate::define_object!(
 );
 
 impl RenderWindow {
+    /// Create a render window that draws into an offscreen buffer
+    ///
+    /// When VTK was built with OSMesa the software renderer is used, so no display
+    /// server is needed. Otherwise the default window type is created with its window
+    /// hidden and offscreen rendering enabled. Use [`RenderWindow::capture_rgba`] or
+    /// [`RenderWindow::write_png`] to retrieve the rendered image.
+    pub fn new_offscreen() -> Self {
+        crate::init_vtk();
+
+        unsafe { Self::from_raw(ffi::render_window_new_offscreen()) }
+    }
+
     pub fn add_renderer(&mut self, renderer: &mut crate::Renderer) {
         unsafe {
             let renderer_ptr = renderer.as_mut_ptr() as *mut ffi::vtkRenderer;
@@ -68,6 +87,17 @@ impl RenderWindow {
         ffi::render_window_render(self.ptr.as_mut());
     }
 
+    /// Render into an offscreen buffer instead of the on-screen window
+    #[doc(alias = "SetOffScreenRendering")]
+    pub fn set_off_screen_rendering(&mut self, on: bool) {
+        ffi::render_window_set_off_screen_rendering(self.ptr.as_mut(), on);
+    }
+
+    #[doc(alias = "GetOffScreenRendering")]
+    pub fn get_off_screen_rendering(&self) -> bool {
+        ffi::render_window_get_off_screen_rendering(&self.ptr.as_ref())
+    }
+
     pub fn get_size(&mut self) -> (i32, i32) {
         let mut width: i32 = 0;
         let mut height: i32 = 0;
@@ -95,6 +125,64 @@ impl RenderWindow {
         }
     }
 
+    /// Render the scene and capture it as an RGBA image
+    ///
+    /// Uses a [`WindowToImageFilter`] reading the back buffer, which works for both
+    /// on-screen and offscreen windows.
+    pub fn capture_rgba(&mut self) -> RgbaImage {
+        let mut filter = WindowToImageFilter::new();
+        filter.set_input(self);
+        filter.set_input_buffer_type_to_rgba();
+        filter.set_read_front_buffer(false);
+        filter.get_rgba_image()
+    }
+
+    /// Render the scene and save it as a PNG file (including the alpha channel)
+    pub fn write_png(&mut self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let file_name = crate::error::path_to_str(path.as_ref())?;
+        let mut filter = WindowToImageFilter::new();
+        filter.set_input(self);
+        filter.set_input_buffer_type_to_rgba();
+        filter.set_read_front_buffer(false);
+        filter.update();
+
+        let mut writer = PNGWriter::new();
+        writer.set_file_name(file_name);
+        writer.set_input_connection(filter.get_output_port());
+        writer.write()
+    }
+
+    /// Render the scene and save it as a JPEG file with the given quality (0-100)
+    pub fn write_jpeg(&mut self, path: impl AsRef<Path>, quality: i32) -> Result<(), VtkError> {
+        let file_name = crate::error::path_to_str(path.as_ref())?;
+        let mut filter = WindowToImageFilter::new();
+        filter.set_input(self);
+        filter.set_input_buffer_type_to_rgb();
+        filter.set_read_front_buffer(false);
+        filter.update();
+
+        let mut writer = JPEGWriter::new();
+        writer.set_file_name(file_name);
+        writer.set_quality(quality);
+        writer.set_input_connection(filter.get_output_port());
+        writer.write()
+    }
+
+    /// Render the scene and export all actors, colors and the camera as a glTF file
+    pub fn export_gltf(&mut self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        crate::GLTFExporter::export(path, self)
+    }
+
+    /// Render the scene and export it as an X3D file
+    pub fn export_x3d(&mut self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        crate::X3DExporter::export(path, self)
+    }
+
+    /// Render the scene and export it as a VRML 2.0 file
+    pub fn export_vrml(&mut self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        crate::VRMLExporter::export(path, self)
+    }
+
     /// Add observer for window events (internal use)
     pub(crate) fn add_observer_raw(
         &mut self,
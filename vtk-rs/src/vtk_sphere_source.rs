@@ -6,6 +6,7 @@ mod ffi {
 
         type vtkSphereSource;
         type vtkAlgorithmOutput;
+        type vtkPolyData;
 
         fn vtk_sphere_source_new() -> *mut vtkSphereSource;
         fn vtk_sphere_source_delete(ptr: Pin<&mut vtkSphereSource>);
@@ -23,6 +24,7 @@ mod ffi {
             resolution: i64
         );
         fn vtk_sphere_source_get_theta_resolution(sphere_source: &vtkSphereSource) -> i64;
+        fn sphere_source_get_output(sphere_source: &vtkSphereSource) -> *mut vtkPolyData;
         unsafe fn sphere_source_get_output_port(
             sphere_source: Pin<&mut vtkSphereSource>
         ) -> *mut vtkAlgorithmOutput;
@@ -78,6 +80,15 @@ impl SphereSource {
         ffi::vtk_sphere_source_set_theta_resolution(self.ptr.as_mut(), theta_resolution)
     }
 
+    /// Generate the sphere and get a copy of its surface
+    ///
+    /// The surface is made of triangles, see [`crate::PolyData::get_polys`].
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&self) -> crate::PolyData {
+        let ptr = ffi::sphere_source_get_output(&self.ptr.as_ref());
+        unsafe { crate::PolyData::from_raw(ptr as *mut _) }
+    }
+
     #[doc(alias = "GetOutputPort")]
     pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
         unsafe {
@@ -121,6 +132,21 @@ mod test {
         assert_eq!(sphere.get_phi_resolution(), 245);
     }
 
+    #[test]
+    fn get_output_triangles() {
+        use crate::{ DataSet, VtkCellType };
+        let mut sphere = SphereSource::new();
+        sphere.set_theta_resolution(8);
+        sphere.set_phi_resolution(8);
+        let surface = sphere.get_output();
+        // Theta rings of phi - 2 points plus the poles
+        assert_eq!(surface.get_number_of_points(), 8 * 6 + 2);
+        assert_eq!(surface.get_number_of_polys(), 2 * 8 * 6);
+        assert_eq!(surface.get_number_of_cells(), surface.get_number_of_polys());
+        assert_eq!(surface.get_cell_type(0), Some(VtkCellType::Triangle));
+        assert_eq!(surface.get_cell_points(0).map(|ids| ids.len()), Some(3));
+    }
+
     #[test]
     fn print_self() {
         use crate::vtk_object_base::*;
@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use crate::{ PolyData, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_stl_reader.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkSTLReader;
+        type vtkPolyData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_stl_reader_new() -> *mut vtkSTLReader;
+        fn vtk_stl_reader_delete(reader: Pin<&mut vtkSTLReader>);
+
+        fn stl_reader_set_file_name(reader: Pin<&mut vtkSTLReader>, file_name: &str);
+        fn stl_reader_get_file_name(reader: &vtkSTLReader) -> String;
+        fn stl_reader_set_merging(reader: Pin<&mut vtkSTLReader>, merging: bool);
+        fn stl_reader_get_merging(reader: &vtkSTLReader) -> bool;
+        fn stl_reader_set_scalar_tags(reader: Pin<&mut vtkSTLReader>, scalar_tags: bool);
+        fn stl_reader_get_scalar_tags(reader: &vtkSTLReader) -> bool;
+        fn stl_reader_update(reader: Pin<&mut vtkSTLReader>) -> Result<()>;
+        fn stl_reader_get_output(reader: Pin<&mut vtkSTLReader>) -> *mut vtkPolyData;
+        unsafe fn stl_reader_get_output_port(
+            reader: Pin<&mut vtkSTLReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkSTLReader.html",
+    @name STLReader, ffi::vtkSTLReader,
+    @new ffi::vtk_stl_reader_new,
+    @delete ffi::vtk_stl_reader_delete
+);
+
+impl STLReader {
+    /// Set the name of the file to read
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::stl_reader_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::stl_reader_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Merge coincident points so that neighbouring triangles share vertices (on by default)
+    #[doc(alias = "SetMerging")]
+    pub fn set_merging(&mut self, merging: bool) {
+        ffi::stl_reader_set_merging(self.ptr.as_mut(), merging);
+    }
+
+    #[doc(alias = "GetMerging")]
+    pub fn get_merging(&self) -> bool {
+        ffi::stl_reader_get_merging(&self.ptr.as_ref())
+    }
+
+    /// Store the index of the `solid` each triangle belongs to as cell scalars (ASCII files only)
+    #[doc(alias = "SetScalarTags")]
+    pub fn set_scalar_tags(&mut self, scalar_tags: bool) {
+        ffi::stl_reader_set_scalar_tags(self.ptr.as_mut(), scalar_tags);
+    }
+
+    #[doc(alias = "GetScalarTags")]
+    pub fn get_scalar_tags(&self) -> bool {
+        ffi::stl_reader_get_scalar_tags(&self.ptr.as_ref())
+    }
+
+    /// Read the file
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        let file_name = self.get_file_name();
+        crate::error::check_readable(Path::new(&file_name))?;
+        ffi::stl_reader_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::invalid_file(&file_name, e.what()))
+    }
+
+    /// Get a copy of the surface that was read
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> PolyData {
+        unsafe { PolyData::from_raw(ffi::stl_reader_get_output(self.ptr.as_mut()) as *mut _) }
+    }
+
+    /// Get the output port for connecting to filters and mappers
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::stl_reader_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// Read a STL file into a [`PolyData`]
+    pub fn read(path: impl AsRef<Path>) -> Result<PolyData, VtkError> {
+        let mut reader = Self::new();
+        reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        reader.update()?;
+        Ok(reader.get_output())
+    }
+}
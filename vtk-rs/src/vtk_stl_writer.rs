@@ -0,0 +1,113 @@
+use crate::{ FileType, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_stl_writer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkSTLWriter;
+        type vtkPolyData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_stl_writer_new() -> *mut vtkSTLWriter;
+        fn vtk_stl_writer_delete(writer: Pin<&mut vtkSTLWriter>);
+
+        fn stl_writer_set_file_name(writer: Pin<&mut vtkSTLWriter>, file_name: &str);
+        fn stl_writer_get_file_name(writer: &vtkSTLWriter) -> String;
+        fn stl_writer_set_header(writer: Pin<&mut vtkSTLWriter>, header: &str);
+        fn stl_writer_get_header(writer: &vtkSTLWriter) -> String;
+        fn stl_writer_set_file_type(writer: Pin<&mut vtkSTLWriter>, file_type: i32);
+        fn stl_writer_get_file_type(writer: &vtkSTLWriter) -> i32;
+        unsafe fn stl_writer_set_input_connection(
+            writer: Pin<&mut vtkSTLWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn stl_writer_set_input_data(
+            writer: Pin<&mut vtkSTLWriter>,
+            poly_data: *mut vtkPolyData
+        );
+        fn stl_writer_write(writer: Pin<&mut vtkSTLWriter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkSTLWriter.html",
+    @name STLWriter, ffi::vtkSTLWriter,
+    @new ffi::vtk_stl_writer_new,
+    @delete ffi::vtk_stl_writer_delete
+);
+
+impl STLWriter {
+    /// Set the name of the file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::stl_writer_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::stl_writer_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the text of the header (`solid` line or the 80 byte binary header)
+    #[doc(alias = "SetHeader")]
+    pub fn set_header(&mut self, header: &str) {
+        ffi::stl_writer_set_header(self.ptr.as_mut(), header);
+    }
+
+    #[doc(alias = "GetHeader")]
+    pub fn get_header(&self) -> String {
+        ffi::stl_writer_get_header(&self.ptr.as_ref())
+    }
+
+    /// Choose between ASCII (default) and binary STL
+    #[doc(alias = "SetFileType")]
+    pub fn set_file_type(&mut self, file_type: FileType) {
+        ffi::stl_writer_set_file_type(self.ptr.as_mut(), file_type as i32);
+    }
+
+    #[doc(alias = "GetFileType")]
+    pub fn get_file_type(&self) -> FileType {
+        match ffi::stl_writer_get_file_type(&self.ptr.as_ref()) {
+            1 => FileType::Ascii,
+            _ => FileType::Binary,
+        }
+    }
+
+    #[doc(alias = "SetFileTypeToASCII")]
+    pub fn set_file_type_to_ascii(&mut self) {
+        self.set_file_type(FileType::Ascii);
+    }
+
+    #[doc(alias = "SetFileTypeToBinary")]
+    pub fn set_file_type_to_binary(&mut self) {
+        self.set_file_type(FileType::Binary);
+    }
+
+    /// Sets the input from an algorithm output port
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = output.into();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::stl_writer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sets the triangulated poly data to write
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, poly_data: &crate::PolyData) {
+        unsafe {
+            let ptr = poly_data.as_ptr() as *mut ffi::vtkPolyData;
+            ffi::stl_writer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Write the file
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::stl_writer_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+}
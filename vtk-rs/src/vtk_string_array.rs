@@ -0,0 +1,176 @@
+use std::ffi::{ CStr, CString };
+
+/// Opaque handle to vtkStringArray from VTK
+#[repr(C)]
+pub struct vtkStringArray {
+    _private: [u8; 0],
+}
+
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn string_array_new() -> *mut vtkStringArray;
+    fn string_array_delete(array: *mut vtkStringArray);
+    fn string_array_set_number_of_values(array: *mut vtkStringArray, num_values: i64);
+    fn string_array_get_number_of_values(array: *mut vtkStringArray) -> i64;
+    fn string_array_set_name(array: *mut vtkStringArray, name: *const i8);
+    fn string_array_get_name(array: *mut vtkStringArray) -> *const i8;
+    fn string_array_insert_next_value(array: *mut vtkStringArray, value: *const i8) -> i64;
+    fn string_array_set_value(array: *mut vtkStringArray, id: i64, value: *const i8);
+    fn string_array_get_value(array: *mut vtkStringArray, id: i64) -> *const i8;
+    fn string_array_initialize(array: *mut vtkStringArray);
+}
+
+/// Safe wrapper for vtkStringArray
+///
+/// StringArray stores text values, one per tuple:
+/// - Labels per node or element (e.g., part or material names)
+/// - Dataset metadata in [`crate::FieldData`] (e.g., load case name, units, solver version)
+pub struct StringArray {
+    ptr: *mut vtkStringArray,
+}
+
+impl StringArray {
+    /// Create a new empty StringArray
+    pub fn new() -> Self {
+        let ptr = unsafe { string_array_new() };
+        assert!(!ptr.is_null(), "Failed to create vtkStringArray");
+        Self { ptr }
+    }
+
+    /// Take ownership of a reference to an existing vtkStringArray (e.g. from field data)
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkStringArray) -> Self {
+        assert!(!ptr.is_null(), "vtkStringArray pointer is null");
+        Self { ptr }
+    }
+
+    /// Create a named array holding a copy of `values`
+    pub fn from_strings<S: AsRef<str>>(name: &str, values: &[S]) -> Self {
+        let mut array = Self::new();
+        array.set_name(name);
+        for value in values {
+            array.insert_next_value(value.as_ref());
+        }
+        array
+    }
+
+    /// Set the number of values (pre-allocate memory, new values are empty)
+    pub fn set_number_of_values(&mut self, num_values: i64) {
+        unsafe {
+            string_array_set_number_of_values(self.ptr, num_values);
+        }
+    }
+
+    /// Get the number of values
+    pub fn get_number_of_values(&self) -> i64 {
+        unsafe { string_array_get_number_of_values(self.ptr) }
+    }
+
+    /// Set the array name (used for identifying data in VTK pipeline)
+    pub fn set_name(&mut self, name: &str) {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            string_array_set_name(self.ptr, c_name.as_ptr());
+        }
+    }
+
+    /// Get the array name
+    pub fn get_name(&self) -> Option<String> {
+        unsafe {
+            let name_ptr = string_array_get_name(self.ptr);
+            if name_ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name_ptr).to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Append a value and return its index
+    ///
+    /// # Panics
+    /// If `value` contains a NUL character.
+    pub fn insert_next_value(&mut self, value: &str) -> i64 {
+        let c_value = CString::new(value).expect("CString conversion failed");
+        unsafe { string_array_insert_next_value(self.ptr, c_value.as_ptr()) }
+    }
+
+    /// Set the value at a specific index
+    ///
+    /// # Panics
+    /// If `value` contains a NUL character.
+    pub fn set_value(&mut self, id: i64, value: &str) {
+        let c_value = CString::new(value).expect("CString conversion failed");
+        unsafe {
+            string_array_set_value(self.ptr, id, c_value.as_ptr());
+        }
+    }
+
+    /// Get the value at a specific index (empty if out of range)
+    pub fn get_value(&self, id: i64) -> String {
+        unsafe {
+            let value_ptr = string_array_get_value(self.ptr, id);
+            if value_ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(value_ptr).to_string_lossy().into_owned()
+            }
+        }
+    }
+
+    /// Get a copy of all values
+    pub fn get_values(&self) -> Vec<String> {
+        (0..self.get_number_of_values()).map(|id| self.get_value(id)).collect()
+    }
+
+    /// Clear all data
+    pub fn initialize(&mut self) {
+        unsafe {
+            string_array_initialize(self.ptr);
+        }
+    }
+
+    /// Get the raw pointer (for internal use with other VTK functions)
+    pub fn as_ptr(&self) -> *mut vtkStringArray {
+        self.ptr
+    }
+}
+
+impl crate::vtk_data_array::private::Sealed for StringArray {}
+impl crate::AbstractArray for StringArray {
+    fn as_abstract_array_ptr(&self) -> *mut std::ffi::c_void {
+        self.ptr as *mut std::ffi::c_void
+    }
+}
+
+impl Default for StringArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for StringArray {
+    fn drop(&mut self) {
+        unsafe {
+            string_array_delete(self.ptr);
+        }
+    }
+}
+
+unsafe impl Send for StringArray {}
+unsafe impl Sync for StringArray {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_array_values() {
+        let mut array = StringArray::from_strings("Part", &["flange", "web"]);
+        assert_eq!(array.get_name().as_deref(), Some("Part"));
+        assert_eq!(array.insert_next_value("stiffener"), 2);
+
+        array.set_value(0, "top flange");
+        assert_eq!(array.get_values(), vec!["top flange", "web", "stiffener"]);
+        assert_eq!(array.get_value(10), "");
+    }
+}
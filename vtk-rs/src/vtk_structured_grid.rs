@@ -0,0 +1,165 @@
+use std::path::Path;
+use std::pin::Pin;
+
+use crate::{ Points, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_structured_grid.h");
+        include!("vtk_points.h");
+
+        type vtkStructuredGrid;
+        type vtkPoints;
+
+        fn vtk_structured_grid_new() -> *mut vtkStructuredGrid;
+        fn vtk_structured_grid_delete(grid: Pin<&mut vtkStructuredGrid>);
+
+        fn structured_grid_set_dimensions(
+            grid: Pin<&mut vtkStructuredGrid>,
+            nx: i32,
+            ny: i32,
+            nz: i32
+        );
+        unsafe fn structured_grid_get_dimensions(
+            grid: &vtkStructuredGrid,
+            nx: *mut i32,
+            ny: *mut i32,
+            nz: *mut i32
+        );
+
+        unsafe fn structured_grid_set_points(
+            grid: Pin<&mut vtkStructuredGrid>,
+            points: *mut vtkPoints
+        );
+        fn structured_grid_get_points(grid: Pin<&mut vtkStructuredGrid>) -> *mut vtkPoints;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkStructuredGrid.html",
+    @name StructuredGrid, ffi::vtkStructuredGrid,
+    @new ffi::vtk_structured_grid_new,
+    @delete ffi::vtk_structured_grid_delete
+);
+
+impl StructuredGrid {
+    /// Create a curvilinear grid of `nx * ny * nz` points
+    ///
+    /// The points are ordered with i (along `nx`) varying fastest, then j, then k.
+    /// Fails if the number of points does not match the dimensions.
+    pub fn from_points(
+        dimensions: (i32, i32, i32),
+        points: &mut Points
+    ) -> Result<Self, VtkError> {
+        let (nx, ny, nz) = dimensions;
+        let expected = nx as i64 * ny as i64 * nz as i64;
+        if points.get_number_of_points() != expected {
+            return Err(VtkError::InvalidArgument(format!(
+                "{} points do not match dimensions {} x {} x {}",
+                points.get_number_of_points(),
+                nx,
+                ny,
+                nz
+            )));
+        }
+        let mut grid = Self::new();
+        grid.set_dimensions(nx, ny, nz);
+        grid.set_points(points);
+        Ok(grid)
+    }
+
+    /// Set the dimensions (number of points along i, j and k)
+    #[doc(alias = "SetDimensions")]
+    pub fn set_dimensions(&mut self, nx: i32, ny: i32, nz: i32) {
+        ffi::structured_grid_set_dimensions(self.ptr.as_mut(), nx, ny, nz);
+    }
+
+    /// Get the dimensions
+    #[doc(alias = "GetDimensions")]
+    pub fn get_dimensions(&self) -> (i32, i32, i32) {
+        let mut nx = 0;
+        let mut ny = 0;
+        let mut nz = 0;
+        unsafe {
+            ffi::structured_grid_get_dimensions(
+                self.ptr.as_ref().get_ref(),
+                &mut nx,
+                &mut ny,
+                &mut nz
+            );
+        }
+        (nx, ny, nz)
+    }
+
+    /// Set the points, ordered with i varying fastest, then j, then k
+    #[doc(alias = "SetPoints")]
+    pub fn set_points(&mut self, points: &mut Points) {
+        let vtk_points_ptr = points.as_mut_ptr() as *mut ffi::vtkPoints;
+        unsafe {
+            ffi::structured_grid_set_points(self.ptr.as_mut(), vtk_points_ptr);
+        }
+    }
+
+    /// Get the points, shared with the grid
+    #[doc(alias = "GetPoints")]
+    pub fn get_points(&mut self) -> Option<Points> {
+        let ptr = ffi::structured_grid_get_points(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { Points::from_raw(ptr as *mut _) })
+    }
+
+    /// Get raw pointer for VTK pipeline connections
+    pub fn as_raw_ptr(&mut self) -> *mut ffi::vtkStructuredGrid {
+        unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
+    }
+
+    /// Load an XML `.vts` file
+    ///
+    /// See [`crate::XMLStructuredGridReader`] for more options.
+    pub fn read_vts(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::XMLStructuredGridReader::read(path)
+    }
+
+    /// Save as an XML `.vts` file with zlib compressed appended data
+    ///
+    /// See [`crate::XMLStructuredGridWriter`] for other encodings.
+    pub fn write_vts(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let mut writer = crate::XMLStructuredGridWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_data_mode_to_appended();
+        writer.set_compressor_type_to_zlib();
+        writer.set_input_data(self);
+        writer.write()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataSet;
+
+    #[test]
+    fn test_structured_grid_vts_round_trip() {
+        // Quarter annulus, 3 points radially by 2 around
+        let mut points = Points::new();
+        for angle in [0.0, std::f64::consts::FRAC_PI_2] {
+            for radius in [1.0, 1.5, 2.0] {
+                points.insert_next_point(radius * f64::cos(angle), radius * f64::sin(angle), 0.0);
+            }
+        }
+        assert!(StructuredGrid::from_points((3, 3, 1), &mut points).is_err());
+
+        let mut grid = StructuredGrid::from_points((3, 2, 1), &mut points).unwrap();
+        assert_eq!(grid.get_number_of_cells(), 2);
+        assert_eq!(grid.get_cell(0).map(|(_, ids)| ids.len()), Some(4));
+        assert_eq!(grid.get_points().unwrap().get_number_of_points(), 6);
+
+        let path = std::env::temp_dir().join(format!("vtk-rs-{}.vts", std::process::id()));
+        grid.write_vts(&path).unwrap();
+        let read = StructuredGrid::read_vts(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.get_dimensions(), (3, 2, 1));
+        assert_eq!(read.get_point(2), Some([2.0, 0.0, 0.0]));
+    }
+}
@@ -0,0 +1,215 @@
+use std::ffi::CString;
+
+use crate::{ DoubleArray, IntArray, PolyData, VtkError };
+
+// Columns are the extern "C" array types, so they are passed outside the cxx bridge
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn table_add_column(table: *mut std::ffi::c_void, array: *mut std::ffi::c_void);
+    fn table_get_double_column(
+        table: *mut std::ffi::c_void,
+        name: *const i8
+    ) -> *mut crate::vtk_double_array::vtkDoubleArray;
+    fn table_get_int_column(
+        table: *mut std::ffi::c_void,
+        name: *const i8
+    ) -> *mut crate::vtk_int_array::vtkIntArray;
+}
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_table.h");
+
+        type vtkTable;
+
+        fn vtk_table_new() -> *mut vtkTable;
+        fn vtk_table_delete(table: Pin<&mut vtkTable>);
+
+        fn table_get_number_of_rows(table: &vtkTable) -> i64;
+        fn table_get_number_of_columns(table: &vtkTable) -> i64;
+        fn table_get_column_name(table: &vtkTable, index: i64) -> String;
+        fn table_get_column_type(table: &vtkTable, name: &str) -> i32;
+        fn table_remove_column(table: Pin<&mut vtkTable>, name: &str);
+
+        fn table_add_string_column(table: Pin<&mut vtkTable>, name: &str, values: Vec<String>);
+        fn table_get_string_column(table: &vtkTable, name: &str) -> Vec<String>;
+        fn table_get_value_as_string(table: &vtkTable, row: i64, column: i64) -> String;
+    }
+}
+
+/// Type of the values stored in a [`Table`] column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum TableColumnType {
+    /// A `vtkDoubleArray`, read with [`Table::get_double_column`]
+    Double = 0,
+    /// A `vtkIntArray`, read with [`Table::get_int_column`]
+    Int = 1,
+    /// A `vtkStringArray`, read with [`Table::get_string_column`]
+    String = 2,
+    /// Any other array type
+    Other = 3,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkTable.html",
+    @name Table, ffi::vtkTable,
+    @new ffi::vtk_table_new,
+    @delete ffi::vtk_table_delete
+);
+
+impl Table {
+    /// Get the number of rows (the number of tuples in the columns)
+    #[doc(alias = "GetNumberOfRows")]
+    pub fn get_number_of_rows(&self) -> i64 {
+        ffi::table_get_number_of_rows(&self.ptr.as_ref())
+    }
+
+    #[doc(alias = "GetNumberOfColumns")]
+    pub fn get_number_of_columns(&self) -> i64 {
+        ffi::table_get_number_of_columns(&self.ptr.as_ref())
+    }
+
+    #[doc(alias = "GetColumnName")]
+    pub fn get_column_name(&self, index: i64) -> String {
+        ffi::table_get_column_name(&self.ptr.as_ref(), index)
+    }
+
+    /// Get the names of all columns, in order
+    pub fn get_column_names(&self) -> Vec<String> {
+        (0..self.get_number_of_columns()).map(|index| self.get_column_name(index)).collect()
+    }
+
+    /// Get the value type of a column, or `None` if there is no column with that name
+    pub fn get_column_type(&self, name: &str) -> Option<TableColumnType> {
+        match ffi::table_get_column_type(&self.ptr.as_ref(), name) {
+            0 => Some(TableColumnType::Double),
+            1 => Some(TableColumnType::Int),
+            2 => Some(TableColumnType::String),
+            3 => Some(TableColumnType::Other),
+            _ => None,
+        }
+    }
+
+    /// Add a floating-point column named after the array
+    ///
+    /// The array should have the same number of tuples as the table has rows.
+    #[doc(alias = "AddColumn")]
+    pub fn add_column(&mut self, array: &DoubleArray) {
+        unsafe {
+            table_add_column(self.as_mut_ptr() as *mut _, array.as_ptr() as *mut _);
+        }
+    }
+
+    /// Add an integer column named after the array
+    #[doc(alias = "AddColumn")]
+    pub fn add_int_column(&mut self, array: &IntArray) {
+        unsafe {
+            table_add_column(self.as_mut_ptr() as *mut _, array.as_ptr() as *mut _);
+        }
+    }
+
+    /// Add a text column with one value per row
+    #[doc(alias = "AddColumn")]
+    pub fn add_string_column<S: AsRef<str>>(&mut self, name: &str, values: &[S]) {
+        let values = values.iter().map(|value| value.as_ref().to_string()).collect();
+        ffi::table_add_string_column(self.ptr.as_mut(), name, values);
+    }
+
+    /// Get a floating-point column
+    ///
+    /// The returned array shares its values with the table. Returns `None` if there is no
+    /// such column or it is not a [`TableColumnType::Double`] column.
+    #[doc(alias = "GetColumnByName")]
+    pub fn get_double_column(&self, name: &str) -> Option<DoubleArray> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let ptr = table_get_double_column(self.as_ptr() as *mut _, c_name.as_ptr());
+            (!ptr.is_null()).then(|| DoubleArray::from_raw(ptr))
+        }
+    }
+
+    /// Get an integer column
+    ///
+    /// The returned array shares its values with the table. Returns `None` if there is no
+    /// such column or it is not a [`TableColumnType::Int`] column.
+    #[doc(alias = "GetColumnByName")]
+    pub fn get_int_column(&self, name: &str) -> Option<IntArray> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let ptr = table_get_int_column(self.as_ptr() as *mut _, c_name.as_ptr());
+            (!ptr.is_null()).then(|| IntArray::from_raw(ptr))
+        }
+    }
+
+    /// Get a copy of a text column
+    ///
+    /// Returns `None` if there is no such column or it is not a
+    /// [`TableColumnType::String`] column.
+    #[doc(alias = "GetColumnByName")]
+    pub fn get_string_column(&self, name: &str) -> Option<Vec<String>> {
+        if self.get_column_type(name) != Some(TableColumnType::String) {
+            return None;
+        }
+        Some(ffi::table_get_string_column(&self.ptr.as_ref(), name))
+    }
+
+    /// Get the value of a cell as text, whatever the column type
+    #[doc(alias = "GetValue")]
+    pub fn get_value_as_string(&self, row: i64, column: i64) -> String {
+        ffi::table_get_value_as_string(&self.ptr.as_ref(), row, column)
+    }
+
+    #[doc(alias = "RemoveColumnByName")]
+    pub fn remove_column(&mut self, name: &str) {
+        ffi::table_remove_column(self.ptr.as_mut(), name);
+    }
+
+    /// Turn the rows into points, using the named columns as coordinates
+    ///
+    /// The remaining columns become point data arrays of the same name.
+    /// See [`crate::TableToPolyData`] for more options.
+    pub fn to_poly_data(&mut self, x: &str, y: &str, z: &str) -> Result<PolyData, VtkError> {
+        let mut filter = crate::TableToPolyData::new();
+        filter.set_input_data(self);
+        filter.set_x_column(x);
+        filter.set_y_column(y);
+        filter.set_z_column(z);
+        filter.update()?;
+        Ok(filter.get_output())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_columns() {
+        let mut strain = DoubleArray::new_scalar("strain");
+        strain.insert_next_value(1.5e-4);
+        strain.insert_next_value(-2.0e-4);
+        let mut channel = IntArray::new_id_array("channel");
+        channel.insert_next_value(7);
+        channel.insert_next_value(8);
+
+        let mut table = Table::new();
+        table.add_column(&strain);
+        table.add_int_column(&channel);
+        table.add_string_column("label", &["base", "top"]);
+
+        assert_eq!(table.get_number_of_rows(), 2);
+        assert_eq!(table.get_column_names(), vec!["strain", "channel", "label"]);
+        assert_eq!(table.get_column_type("channel"), Some(TableColumnType::Int));
+        assert_eq!(table.get_column_type("missing"), None);
+        assert_eq!(table.get_double_column("strain").unwrap().get_value(1), -2.0e-4);
+        assert!(table.get_double_column("channel").is_none());
+        assert_eq!(table.get_int_column("channel").unwrap().get_value(0), 7);
+        assert_eq!(table.get_string_column("label").unwrap(), vec!["base", "top"]);
+        assert_eq!(table.get_value_as_string(1, 2), "top");
+
+        table.remove_column("label");
+        assert_eq!(table.get_number_of_columns(), 2);
+    }
+}
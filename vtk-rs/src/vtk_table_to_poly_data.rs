@@ -0,0 +1,150 @@
+use crate::{ PolyData, Table, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_table_to_poly_data.h");
+        include!("vtk_table.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkTableToPolyData;
+        type vtkTable = crate::vtk_table::ffi::vtkTable;
+        type vtkPolyData;
+        type vtkAlgorithmOutput;
+
+        fn vtk_table_to_poly_data_new() -> *mut vtkTableToPolyData;
+        fn vtk_table_to_poly_data_delete(filter: Pin<&mut vtkTableToPolyData>);
+
+        unsafe fn table_to_poly_data_set_input_connection(
+            filter: Pin<&mut vtkTableToPolyData>,
+            port: *mut vtkAlgorithmOutput
+        );
+        unsafe fn table_to_poly_data_set_input_data(
+            filter: Pin<&mut vtkTableToPolyData>,
+            table: *mut vtkTable
+        );
+
+        fn table_to_poly_data_set_x_column(filter: Pin<&mut vtkTableToPolyData>, name: &str);
+        fn table_to_poly_data_set_y_column(filter: Pin<&mut vtkTableToPolyData>, name: &str);
+        fn table_to_poly_data_set_z_column(filter: Pin<&mut vtkTableToPolyData>, name: &str);
+        fn table_to_poly_data_set_x_column_index(filter: Pin<&mut vtkTableToPolyData>, index: i32);
+        fn table_to_poly_data_set_y_column_index(filter: Pin<&mut vtkTableToPolyData>, index: i32);
+        fn table_to_poly_data_set_z_column_index(filter: Pin<&mut vtkTableToPolyData>, index: i32);
+        fn table_to_poly_data_set_create_2d_points(
+            filter: Pin<&mut vtkTableToPolyData>,
+            enabled: bool
+        );
+        fn table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays(
+            filter: Pin<&mut vtkTableToPolyData>,
+            enabled: bool
+        );
+
+        fn table_to_poly_data_update(filter: Pin<&mut vtkTableToPolyData>) -> Result<()>;
+        fn table_to_poly_data_get_output(
+            filter: Pin<&mut vtkTableToPolyData>
+        ) -> *mut vtkPolyData;
+        unsafe fn table_to_poly_data_get_output_port(
+            filter: Pin<&mut vtkTableToPolyData>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkTableToPolyData.html",
+    @name TableToPolyData, ffi::vtkTableToPolyData,
+    @new ffi::vtk_table_to_poly_data_new,
+    @delete ffi::vtk_table_to_poly_data_delete
+);
+
+impl TableToPolyData {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, port: impl Into<*mut std::ffi::c_void>) {
+        unsafe {
+            let ptr = port.into() as *mut ffi::vtkAlgorithmOutput;
+            ffi::table_to_poly_data_set_input_connection(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, table: &mut Table) {
+        unsafe {
+            ffi::table_to_poly_data_set_input_data(self.ptr.as_mut(), table.as_mut_ptr());
+        }
+    }
+
+    /// Set the column holding the x coordinates
+    #[doc(alias = "SetXColumn")]
+    pub fn set_x_column(&mut self, name: &str) {
+        ffi::table_to_poly_data_set_x_column(self.ptr.as_mut(), name);
+    }
+
+    /// Set the column holding the y coordinates
+    #[doc(alias = "SetYColumn")]
+    pub fn set_y_column(&mut self, name: &str) {
+        ffi::table_to_poly_data_set_y_column(self.ptr.as_mut(), name);
+    }
+
+    /// Set the column holding the z coordinates (not needed with 2D points)
+    #[doc(alias = "SetZColumn")]
+    pub fn set_z_column(&mut self, name: &str) {
+        ffi::table_to_poly_data_set_z_column(self.ptr.as_mut(), name);
+    }
+
+    /// Select the x column by position, e.g. for files without a header line
+    #[doc(alias = "SetXColumnIndex")]
+    pub fn set_x_column_index(&mut self, index: i32) {
+        ffi::table_to_poly_data_set_x_column_index(self.ptr.as_mut(), index);
+    }
+
+    #[doc(alias = "SetYColumnIndex")]
+    pub fn set_y_column_index(&mut self, index: i32) {
+        ffi::table_to_poly_data_set_y_column_index(self.ptr.as_mut(), index);
+    }
+
+    #[doc(alias = "SetZColumnIndex")]
+    pub fn set_z_column_index(&mut self, index: i32) {
+        ffi::table_to_poly_data_set_z_column_index(self.ptr.as_mut(), index);
+    }
+
+    /// Build points from the x and y columns only, with z = 0
+    #[doc(alias = "SetCreate2DPoints")]
+    pub fn set_create_2d_points(&mut self, enabled: bool) {
+        ffi::table_to_poly_data_set_create_2d_points(self.ptr.as_mut(), enabled);
+    }
+
+    /// Keep the coordinate columns as point data arrays as well (default off)
+    #[doc(alias = "SetPreserveCoordinateColumnsAsDataArrays")]
+    pub fn set_preserve_coordinate_columns_as_data_arrays(&mut self, enabled: bool) {
+        ffi::table_to_poly_data_set_preserve_coordinate_columns_as_data_arrays(
+            self.ptr.as_mut(),
+            enabled
+        );
+    }
+
+    /// Run the conversion
+    ///
+    /// Fails if the coordinate columns are missing or not numeric.
+    #[doc(alias = "Update")]
+    pub fn update(&mut self) -> Result<(), VtkError> {
+        ffi::table_to_poly_data_update(self.ptr.as_mut())
+            .map_err(|e| VtkError::InvalidArgument(e.what().to_string()))
+    }
+
+    /// Get a copy of the points, with one vertex cell per row
+    #[doc(alias = "GetOutput")]
+    pub fn get_output(&mut self) -> PolyData {
+        unsafe {
+            let ptr = ffi::table_to_poly_data_get_output(self.ptr.as_mut());
+            PolyData::from_raw(ptr as *mut _)
+        }
+    }
+
+    /// Get the output port for connecting to filters
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+        unsafe {
+            let ptr = ffi::table_to_poly_data_get_output_port(self.ptr.as_mut());
+            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+}
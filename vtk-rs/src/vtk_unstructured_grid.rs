@@ -1,12 +1,7 @@
+use std::path::Path;
 use std::pin::Pin;
 
-// Direct extern "C" for get_point_data to avoid cxx bridge issues with opaque types
-#[link(name = "vtkrs", kind = "static")]
-extern "C" {
-    fn unstructured_grid_get_point_data(
-        grid: *mut std::ffi::c_void
-    ) -> *mut crate::vtk_point_data::vtkPointData;
-}
+use crate::{ CellArray, Points, VtkError };
 
 #[cxx::bridge]
 mod ffi {
@@ -16,6 +11,7 @@ mod ffi {
 
         type vtkUnstructuredGrid;
         type vtkPoints;
+        type vtkCellArray;
 
         fn vtk_unstructured_grid_new() -> *mut vtkUnstructuredGrid;
         fn vtk_unstructured_grid_delete(grid: Pin<&mut vtkUnstructuredGrid>);
@@ -24,9 +20,7 @@ mod ffi {
             grid: Pin<&mut vtkUnstructuredGrid>,
             points: *mut vtkPoints
         );
-        unsafe fn unstructured_grid_get_points(
-            grid: Pin<&mut vtkUnstructuredGrid>
-        ) -> *mut vtkPoints;
+        fn unstructured_grid_get_points(grid: &vtkUnstructuredGrid) -> *mut vtkPoints;
 
         fn unstructured_grid_allocate(grid: Pin<&mut vtkUnstructuredGrid>, num_cells: i32);
 
@@ -36,10 +30,16 @@ mod ffi {
             num_points: i32,
             point_ids: *const i32
         );
+        fn unstructured_grid_insert_next_polyhedron(
+            grid: Pin<&mut vtkUnstructuredGrid>,
+            face_stream: &[i64]
+        ) -> i64;
 
-        fn unstructured_grid_get_number_of_points(grid: &vtkUnstructuredGrid) -> i32;
-        fn unstructured_grid_get_number_of_cells(grid: &vtkUnstructuredGrid) -> i32;
-        unsafe fn unstructured_grid_get_bounds(grid: &vtkUnstructuredGrid, bounds: *mut f64);
+        fn unstructured_grid_get_cells(grid: &vtkUnstructuredGrid) -> *mut vtkCellArray;
+        fn unstructured_grid_get_connectivity(grid: &vtkUnstructuredGrid) -> Vec<i64>;
+        fn unstructured_grid_get_offsets(grid: &vtkUnstructuredGrid) -> Vec<i64>;
+        fn unstructured_grid_get_cell_types(grid: &vtkUnstructuredGrid) -> Vec<u8>;
+        fn unstructured_grid_get_face_stream(grid: &vtkUnstructuredGrid, cell_id: i64) -> Vec<i64>;
     }
 }
 
@@ -56,6 +56,7 @@ pub enum VtkCellType {
     Triangle = 5,
     TriangleStrip = 6,
     Polygon = 7,
+    Pixel = 8,
     Quad = 9,
     Tetra = 10,
     Voxel = 11,
@@ -71,6 +72,52 @@ pub enum VtkCellType {
     QuadraticQuad = 23,
     QuadraticTetra = 24,
     QuadraticHexahedron = 25,
+    QuadraticWedge = 26,
+    QuadraticPyramid = 27,
+    BiquadraticQuad = 28,
+    TriquadraticHexahedron = 29,
+
+    // Polyhedral cells
+    /// Arbitrary polyhedron described by its faces, see
+    /// [`UnstructuredGrid::insert_next_polyhedron`]
+    Polyhedron = 42,
+}
+
+impl VtkCellType {
+    /// Convert a raw VTK cell type, `None` for types without a variant
+    pub(crate) fn from_raw(cell_type: i32) -> Option<Self> {
+        use VtkCellType::*;
+        let cell_type = match cell_type {
+            1 => Vertex,
+            2 => PolyVertex,
+            3 => Line,
+            4 => PolyLine,
+            5 => Triangle,
+            6 => TriangleStrip,
+            7 => Polygon,
+            8 => Pixel,
+            9 => Quad,
+            10 => Tetra,
+            11 => Voxel,
+            12 => Hexahedron,
+            13 => Wedge,
+            14 => Pyramid,
+            15 => PentagonalPrism,
+            16 => HexagonalPrism,
+            21 => QuadraticEdge,
+            22 => QuadraticTriangle,
+            23 => QuadraticQuad,
+            24 => QuadraticTetra,
+            25 => QuadraticHexahedron,
+            26 => QuadraticWedge,
+            27 => QuadraticPyramid,
+            28 => BiquadraticQuad,
+            29 => TriquadraticHexahedron,
+            42 => Polyhedron,
+            _ => return None,
+        };
+        Some(cell_type)
+    }
 }
 
 crate::define_object!(
@@ -90,6 +137,13 @@ impl UnstructuredGrid {
         }
     }
 
+    /// Get the points, shared with the grid, or `None` if none were set
+    #[doc(alias = "GetPoints")]
+    pub fn get_points(&self) -> Option<Points> {
+        let ptr = ffi::unstructured_grid_get_points(&self.ptr.as_ref());
+        (!ptr.is_null()).then(|| unsafe { Points::from_raw(ptr as *mut _) })
+    }
+
     /// Allocate memory for a given number of cells
     #[doc(alias = "Allocate")]
     pub fn allocate(&mut self, num_cells: i32) {
@@ -97,6 +151,8 @@ impl UnstructuredGrid {
     }
 
     /// Insert the next cell into the grid
+    ///
+    /// Use [`insert_next_polyhedron`](Self::insert_next_polyhedron) for polyhedra.
     #[doc(alias = "InsertNextCell")]
     pub fn insert_next_cell(&mut self, cell_type: VtkCellType, point_ids: &[i32]) {
         unsafe {
@@ -109,26 +165,100 @@ impl UnstructuredGrid {
         }
     }
 
-    /// Get the number of points in the grid
-    #[doc(alias = "GetNumberOfPoints")]
-    pub fn get_number_of_points(&self) -> i32 {
-        ffi::unstructured_grid_get_number_of_points(self.ptr.as_ref().get_ref())
+    /// Insert a polyhedron given the point ids of each face and return its cell id
+    ///
+    /// The points of each face are ordered counterclockwise when seen from outside.
+    ///
+    /// # Example
+    /// ```
+    /// # use vtk_rs::{UnstructuredGrid, VtkCellType, DataSet};
+    /// let mut grid = UnstructuredGrid::new();
+    /// // Square pyramid with apex 4
+    /// let pyramid: [&[i32]; 5] = [&[0, 3, 2, 1], &[0, 1, 4], &[1, 2, 4], &[2, 3, 4], &[3, 0, 4]];
+    /// let cell_id = grid.insert_next_polyhedron(&pyramid);
+    /// assert_eq!(grid.get_cell_type(cell_id), Some(VtkCellType::Polyhedron));
+    /// ```
+    #[doc(alias = "InsertNextCell")]
+    pub fn insert_next_polyhedron(&mut self, faces: &[&[i32]]) -> i64 {
+        let mut face_stream = vec![faces.len() as i64];
+        for face in faces {
+            face_stream.push(face.len() as i64);
+            face_stream.extend(face.iter().map(|&id| id as i64));
+        }
+        ffi::unstructured_grid_insert_next_polyhedron(self.ptr.as_mut(), &face_stream)
     }
 
-    /// Get the number of cells in the grid
-    #[doc(alias = "GetNumberOfCells")]
-    pub fn get_number_of_cells(&self) -> i32 {
-        ffi::unstructured_grid_get_number_of_cells(self.ptr.as_ref().get_ref())
+    /// Get the point ids of each face of a polyhedron
+    ///
+    /// Returns `None` if the id is out of range or the cell is not a
+    /// [`VtkCellType::Polyhedron`].
+    #[doc(alias = "GetFaceStream")]
+    pub fn get_faces(&self, cell_id: i64) -> Option<Vec<Vec<i64>>> {
+        let face_stream = ffi::unstructured_grid_get_face_stream(&self.ptr.as_ref(), cell_id);
+        // (number of faces, then the number of points and point ids of each face)
+        let (&num_faces, mut rest) = face_stream.split_first()?;
+        let num_faces = usize::try_from(num_faces).ok()?;
+        // Each face takes at least its point count, so a valid stream can't hold more faces
+        let mut faces = Vec::with_capacity(num_faces.min(rest.len()));
+        for _ in 0..num_faces {
+            let (&num_points, ids) = rest.split_first()?;
+            let (face, remaining) = ids.split_at_checked(usize::try_from(num_points).ok()?)?;
+            faces.push(face.to_vec());
+            rest = remaining;
+        }
+        Some(faces)
     }
 
-    /// Get the bounding box of the grid
-    #[doc(alias = "GetBounds")]
-    pub fn get_bounds(&self) -> [f64; 6] {
-        let mut bounds = [0.0; 6];
-        unsafe {
-            ffi::unstructured_grid_get_bounds(self.ptr.as_ref().get_ref(), bounds.as_mut_ptr());
+    /// Get the cells, shared with the grid, or `None` if no cell was inserted yet
+    ///
+    /// Cells are stored as in [`get_offsets`](Self::get_offsets) and
+    /// [`get_connectivity`](Self::get_connectivity); their types are kept separately.
+    #[doc(alias = "GetCells")]
+    pub fn get_cells(&self) -> Option<CellArray> {
+        let ptr = ffi::unstructured_grid_get_cells(&self.ptr.as_ref());
+        (!ptr.is_null()).then(|| unsafe { CellArray::from_raw(ptr as *mut _) })
+    }
+
+    /// Copy the point ids of all cells, one after the other
+    ///
+    /// Polyhedra list each of their points once; see [`get_faces`](Self::get_faces).
+    #[doc(alias = "GetConnectivityArray")]
+    pub fn get_connectivity(&self) -> Vec<i64> {
+        ffi::unstructured_grid_get_connectivity(&self.ptr.as_ref())
+    }
+
+    /// Copy the offsets of the cells in [`get_connectivity`](Self::get_connectivity)
+    ///
+    /// Cell `i` is made of the ids from `offsets[i]` to `offsets[i + 1]`, so there is one
+    /// more offset than cells.
+    #[doc(alias = "GetOffsetsArray")]
+    pub fn get_offsets(&self) -> Vec<i64> {
+        ffi::unstructured_grid_get_offsets(&self.ptr.as_ref())
+    }
+
+    /// Copy the raw VTK cell type of every cell
+    #[doc(alias = "GetCellTypesArray")]
+    pub fn get_cell_types(&self) -> Vec<u8> {
+        ffi::unstructured_grid_get_cell_types(&self.ptr.as_ref())
+    }
+
+    /// Copy the type and point ids of all cells
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vtk_rs::*;
+    /// let mesh = UnstructuredGrid::read_vtu("mesh.vtu")?;
+    /// let cells = mesh.cells();
+    /// let tets = cells.iter().filter(|&(_, cell_type, _)| cell_type == Some(VtkCellType::Tetra));
+    /// println!("{} tetrahedra", tets.count());
+    /// # Ok::<(), VtkError>(())
+    /// ```
+    pub fn cells(&self) -> Cells {
+        Cells {
+            offsets: self.get_offsets(),
+            connectivity: self.get_connectivity(),
+            cell_types: self.get_cell_types(),
         }
-        bounds
     }
 
     /// Get raw pointer for VTK pipeline connections
@@ -136,12 +266,116 @@ impl UnstructuredGrid {
         unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
     }
 
-    /// Get point data for adding scalar/vector fields
-    #[doc(alias = "GetPointData")]
-    pub fn get_point_data(&mut self) -> crate::PointData {
-        unsafe {
-            let ptr = unstructured_grid_get_point_data(self.as_raw_ptr() as *mut std::ffi::c_void);
-            crate::PointData::from_raw(ptr)
+    /// Load an XML `.vtu` file
+    ///
+    /// See [`crate::XMLUnstructuredGridReader`] for more options.
+    pub fn read_vtu(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::XMLUnstructuredGridReader::read(path)
+    }
+
+    /// Save as an XML `.vtu` file with zlib compressed appended data
+    ///
+    /// See [`crate::XMLUnstructuredGridWriter`] for other encodings.
+    pub fn write_vtu(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        let mut writer = crate::XMLUnstructuredGridWriter::new();
+        writer.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        writer.set_data_mode_to_appended();
+        writer.set_compressor_type_to_zlib();
+        writer.set_input_data(self);
+        writer.write()
+    }
+
+    /// Load a `.vtkhdf` file
+    ///
+    /// Reads the first time step of temporal files; see [`crate::HDFReader`] to select others.
+    pub fn read_vtkhdf(path: impl AsRef<Path>) -> Result<Self, VtkError> {
+        crate::HDFReader::read_unstructured_grid(path)
+    }
+
+    /// Save as a `.vtkhdf` file
+    ///
+    /// See [`crate::HDFWriter`] for compression and time series. Requires VTK 9.4.
+    #[cfg(feature = "v094")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "v094")))]
+    pub fn write_vtkhdf(&self, path: impl AsRef<Path>) -> Result<(), VtkError> {
+        crate::HDFWriter::write_unstructured_grid(path, self)
+    }
+}
+
+/// Copy of the cells of an [`UnstructuredGrid`], see [`UnstructuredGrid::cells`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cells {
+    offsets: Vec<i64>,
+    connectivity: Vec<i64>,
+    cell_types: Vec<u8>,
+}
+
+impl Cells {
+    /// Get the number of cells
+    pub fn len(&self) -> usize {
+        self.cell_types.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cell_types.is_empty()
+    }
+
+    /// Iterate over the id, type and point ids of the cells, in cell id order
+    ///
+    /// The type is `None` for cells whose VTK type has no [`VtkCellType`] variant; the raw
+    /// types are available from [`UnstructuredGrid::get_cell_types`].
+    pub fn iter(&self) -> impl Iterator<Item = (i64, Option<VtkCellType>, &[i64])> + '_ {
+        self.cell_types.iter().zip(self.offsets.windows(2)).enumerate().map(
+            |(cell_id, (&cell_type, range))| {
+                let point_ids = &self.connectivity[range[0] as usize..range[1] as usize];
+                (cell_id as i64, VtkCellType::from_raw(cell_type as i32), point_ids)
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntArray;
+
+    #[test]
+    fn test_unstructured_grid_cells_round_trip() {
+        // Unit cube split into a tetrahedron and a pyramid polyhedron sharing face 1-2-4
+        let mut points = Points::new();
+        for [x, y, z] in [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]] {
+            points.insert_next_point(x, y, z);
         }
+        points.insert_next_point(1.0, 0.0, 1.0);
+
+        let mut grid = UnstructuredGrid::new();
+        grid.set_points(&mut points);
+        grid.allocate(2);
+        grid.insert_next_cell(VtkCellType::Tetra, &[0, 1, 3, 4]);
+        let pyramid: [&[i32]; 4] = [&[1, 3, 2], &[1, 2, 4], &[2, 3, 4], &[3, 1, 4]];
+        assert_eq!(grid.insert_next_polyhedron(&pyramid), 1);
+        grid.get_cell_data().add_array(&IntArray::from_slice("Material", 1, &[7, 8]));
+
+        let path = std::env::temp_dir().join(format!("vtk-rs-cells-{}.vtu", std::process::id()));
+        grid.write_vtu(&path).unwrap();
+        let read = UnstructuredGrid::read_vtu(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.get_points().unwrap().get_number_of_points(), 5);
+        assert_eq!(read.get_cell_types(), &[10, 42]);
+        assert_eq!(read.get_offsets(), &[0, 4, 8]);
+        assert_eq!(read.get_cells().unwrap().get_number_of_cells(), 2);
+        assert_eq!(read.get_cell_data().get_number_of_arrays(), 1);
+
+        let cells = read.cells();
+        assert_eq!(cells.len(), 2);
+        let cells: Vec<_> = cells.iter().collect();
+        assert_eq!(cells[0], (0, Some(VtkCellType::Tetra), &[0, 1, 3, 4][..]));
+        assert_eq!(cells[1].1, Some(VtkCellType::Polyhedron));
+        assert_eq!(cells[1].2.len(), 4);
+
+        assert_eq!(read.get_faces(0), None);
+        assert_eq!(read.get_faces(1).unwrap()[1], vec![1, 2, 4]);
+        assert_eq!(read.get_faces(2), None);
     }
 }
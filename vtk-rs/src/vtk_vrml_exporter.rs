@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_vrml_exporter.h");
+
+        type vtkVRMLExporter;
+        type vtkRenderWindow;
+
+        fn vtk_vrml_exporter_new() -> *mut vtkVRMLExporter;
+        fn vtk_vrml_exporter_delete(exporter: Pin<&mut vtkVRMLExporter>);
+
+        fn vrml_exporter_set_file_name(exporter: Pin<&mut vtkVRMLExporter>, file_name: &str);
+        fn vrml_exporter_get_file_name(exporter: &vtkVRMLExporter) -> String;
+        unsafe fn vrml_exporter_set_render_window(
+            exporter: Pin<&mut vtkVRMLExporter>,
+            window: *mut vtkRenderWindow
+        );
+        fn vrml_exporter_write(exporter: Pin<&mut vtkVRMLExporter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkVRMLExporter.html",
+    @name VRMLExporter, ffi::vtkVRMLExporter,
+    @new ffi::vtk_vrml_exporter_new,
+    @delete ffi::vtk_vrml_exporter_delete
+);
+
+impl VRMLExporter {
+    /// Set the name of the VRML 2.0 (`.wrl`) file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::vrml_exporter_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::vrml_exporter_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the render window whose actors, colors and camera are exported
+    ///
+    /// The window can be an offscreen window.
+    #[doc(alias = "SetRenderWindow")]
+    pub fn set_render_window(&mut self, window: &mut crate::RenderWindow) {
+        unsafe {
+            let window_ptr = window.as_mut_ptr() as *mut ffi::vtkRenderWindow;
+            ffi::vrml_exporter_set_render_window(self.ptr.as_mut(), window_ptr);
+        }
+    }
+
+    /// Write the scene
+    ///
+    /// Actors are written with their colors, and the active camera as the initial viewpoint.
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::vrml_exporter_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+
+    /// Render the window and export its scene to a VRML 2.0 (`.wrl`) file
+    pub fn export(
+        path: impl AsRef<Path>,
+        window: &mut crate::RenderWindow
+    ) -> Result<(), VtkError> {
+        let mut exporter = Self::new();
+        exporter.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        window.render();
+        exporter.set_render_window(window);
+        exporter.write()
+    }
+}
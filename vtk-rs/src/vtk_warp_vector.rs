@@ -48,10 +48,11 @@ impl WarpVector {
         }
     }
 
-    /// Set input data directly
-    pub fn set_input_data(&mut self, dataset: &mut crate::UnstructuredGrid) {
+    /// Set input data directly (any [`crate::DataSet`] with point vectors)
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, dataset: &impl crate::DataSet) {
         unsafe {
-            let ptr = dataset.as_raw_ptr() as *mut ffi::vtkDataSet;
+            let ptr = dataset.as_data_set_ptr() as *mut ffi::vtkDataSet;
             ffi::warp_vector_set_input_data(self.ptr.as_mut(), ptr);
         }
     }
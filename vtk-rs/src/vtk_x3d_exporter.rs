@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use crate::VtkError;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_x3d_exporter.h");
+
+        type vtkX3DExporter;
+        type vtkRenderWindow;
+
+        fn vtk_x3d_exporter_new() -> *mut vtkX3DExporter;
+        fn vtk_x3d_exporter_delete(exporter: Pin<&mut vtkX3DExporter>);
+
+        fn x3d_exporter_set_file_name(exporter: Pin<&mut vtkX3DExporter>, file_name: &str);
+        fn x3d_exporter_get_file_name(exporter: &vtkX3DExporter) -> String;
+        unsafe fn x3d_exporter_set_render_window(
+            exporter: Pin<&mut vtkX3DExporter>,
+            window: *mut vtkRenderWindow
+        );
+        fn x3d_exporter_set_binary(exporter: Pin<&mut vtkX3DExporter>, enabled: bool);
+        fn x3d_exporter_get_binary(exporter: &vtkX3DExporter) -> bool;
+        fn x3d_exporter_set_fastest(exporter: Pin<&mut vtkX3DExporter>, enabled: bool);
+        fn x3d_exporter_get_fastest(exporter: &vtkX3DExporter) -> bool;
+        fn x3d_exporter_write(exporter: Pin<&mut vtkX3DExporter>) -> Result<()>;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkX3DExporter.html",
+    @name X3DExporter, ffi::vtkX3DExporter,
+    @new ffi::vtk_x3d_exporter_new,
+    @delete ffi::vtk_x3d_exporter_delete
+);
+
+impl X3DExporter {
+    /// Set the name of the X3D (`.x3d`) file to write
+    #[doc(alias = "SetFileName")]
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::x3d_exporter_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    #[doc(alias = "GetFileName")]
+    pub fn get_file_name(&self) -> String {
+        ffi::x3d_exporter_get_file_name(&self.ptr.as_ref())
+    }
+
+    /// Set the render window whose actors, colors and camera are exported
+    ///
+    /// The window can be an offscreen window.
+    #[doc(alias = "SetRenderWindow")]
+    pub fn set_render_window(&mut self, window: &mut crate::RenderWindow) {
+        unsafe {
+            let window_ptr = window.as_mut_ptr() as *mut ffi::vtkRenderWindow;
+            ffi::x3d_exporter_set_render_window(self.ptr.as_mut(), window_ptr);
+        }
+    }
+
+    /// Write the binary X3D encoding (`.x3db`) instead of XML
+    #[doc(alias = "SetBinary")]
+    pub fn set_binary(&mut self, enabled: bool) {
+        ffi::x3d_exporter_set_binary(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetBinary")]
+    pub fn get_binary(&self) -> bool {
+        ffi::x3d_exporter_get_binary(&self.ptr.as_ref())
+    }
+
+    /// Favor writing speed over compression of the binary encoding
+    #[doc(alias = "SetFastest")]
+    pub fn set_fastest(&mut self, enabled: bool) {
+        ffi::x3d_exporter_set_fastest(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetFastest")]
+    pub fn get_fastest(&self) -> bool {
+        ffi::x3d_exporter_get_fastest(&self.ptr.as_ref())
+    }
+
+    /// Write the scene
+    ///
+    /// Writes XML by default, or the binary X3D encoding if
+    /// [`set_binary`](Self::set_binary) is enabled.
+    #[doc(alias = "Write")]
+    pub fn write(&mut self) -> Result<(), VtkError> {
+        ffi::x3d_exporter_write(self.ptr.as_mut())
+            .map_err(|e| VtkError::write_failed(self.get_file_name(), e.what()))
+    }
+
+    /// Render the window and export its scene to a X3D (`.x3d`) file
+    pub fn export(
+        path: impl AsRef<Path>,
+        window: &mut crate::RenderWindow
+    ) -> Result<(), VtkError> {
+        let mut exporter = Self::new();
+        exporter.set_file_name(crate::error::path_to_str(path.as_ref())?);
+        window.render();
+        exporter.set_render_window(window);
+        exporter.write()
+    }
+}
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_image_data_reader.h");
+
+        type vtkXMLImageDataReader;
+
+        fn vtk_xml_image_data_reader_new() -> *mut vtkXMLImageDataReader;
+        fn vtk_xml_image_data_reader_delete(reader: Pin<&mut vtkXMLImageDataReader>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLImageDataReader.html",
+    @name XMLImageDataReader, ffi::vtkXMLImageDataReader,
+    @new ffi::vtk_xml_image_data_reader_new,
+    @delete ffi::vtk_xml_image_data_reader_delete
+);
+
+crate::vtk_xml_writer::impl_xml_reader!(
+    XMLImageDataReader, ffi::vtkXMLImageDataReader,
+    @data ImageData, "image data",
+    @extension "vti"
+);
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_image_data_writer.h");
+
+        type vtkXMLImageDataWriter;
+
+        fn vtk_xml_image_data_writer_new() -> *mut vtkXMLImageDataWriter;
+        fn vtk_xml_image_data_writer_delete(writer: Pin<&mut vtkXMLImageDataWriter>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLImageDataWriter.html",
+    @name XMLImageDataWriter, ffi::vtkXMLImageDataWriter,
+    @new ffi::vtk_xml_image_data_writer_new,
+    @delete ffi::vtk_xml_image_data_writer_delete
+);
+
+crate::vtk_xml_writer::impl_xml_writer!(
+    XMLImageDataWriter, ffi::vtkXMLImageDataWriter,
+    @data ImageData, "image data",
+    @extension "vti"
+);
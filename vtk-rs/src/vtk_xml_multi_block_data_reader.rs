@@ -0,0 +1,25 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_multi_block_data_reader.h");
+
+        type vtkXMLMultiBlockDataReader;
+
+        fn vtk_xml_multi_block_data_reader_new() -> *mut vtkXMLMultiBlockDataReader;
+        fn vtk_xml_multi_block_data_reader_delete(reader: Pin<&mut vtkXMLMultiBlockDataReader>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLMultiBlockDataReader.html",
+    @name XMLMultiBlockDataReader, ffi::vtkXMLMultiBlockDataReader,
+    @new ffi::vtk_xml_multi_block_data_reader_new,
+    @delete ffi::vtk_xml_multi_block_data_reader_delete
+);
+
+crate::vtk_xml_writer::impl_xml_reader!(
+    XMLMultiBlockDataReader, ffi::vtkXMLMultiBlockDataReader,
+    @data MultiBlockDataSet, "multiblock dataset",
+    @extension "vtm",
+    @note "The files of the blocks are read as well."
+);
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_multi_block_data_writer.h");
+
+        type vtkXMLMultiBlockDataWriter;
+
+        fn vtk_xml_multi_block_data_writer_new() -> *mut vtkXMLMultiBlockDataWriter;
+        fn vtk_xml_multi_block_data_writer_delete(writer: Pin<&mut vtkXMLMultiBlockDataWriter>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLMultiBlockDataWriter.html",
+    @name XMLMultiBlockDataWriter, ffi::vtkXMLMultiBlockDataWriter,
+    @new ffi::vtk_xml_multi_block_data_writer_new,
+    @delete ffi::vtk_xml_multi_block_data_writer_delete
+);
+
+crate::vtk_xml_writer::impl_xml_writer!(
+    XMLMultiBlockDataWriter, ffi::vtkXMLMultiBlockDataWriter,
+    @composite MultiBlockDataSet, "multiblock dataset",
+    @extension "vtm"
+);
@@ -0,0 +1,28 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_partitioned_data_set_collection_reader.h");
+
+        type vtkXMLPartitionedDataSetCollectionReader;
+
+        fn vtk_xml_partitioned_data_set_collection_reader_new(
+        ) -> *mut vtkXMLPartitionedDataSetCollectionReader;
+        fn vtk_xml_partitioned_data_set_collection_reader_delete(
+            reader: Pin<&mut vtkXMLPartitionedDataSetCollectionReader>
+        );
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLPartitionedDataSetCollectionReader.html",
+    @name XMLPartitionedDataSetCollectionReader, ffi::vtkXMLPartitionedDataSetCollectionReader,
+    @new ffi::vtk_xml_partitioned_data_set_collection_reader_new,
+    @delete ffi::vtk_xml_partitioned_data_set_collection_reader_delete
+);
+
+crate::vtk_xml_writer::impl_xml_reader!(
+    XMLPartitionedDataSetCollectionReader, ffi::vtkXMLPartitionedDataSetCollectionReader,
+    @data PartitionedDataSetCollection, "partitioned dataset collection",
+    @extension "vtpc",
+    @note "The files of the partitions are read as well."
+);
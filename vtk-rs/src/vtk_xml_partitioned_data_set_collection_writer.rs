@@ -0,0 +1,27 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_partitioned_data_set_collection_writer.h");
+
+        type vtkXMLPartitionedDataSetCollectionWriter;
+
+        fn vtk_xml_partitioned_data_set_collection_writer_new(
+        ) -> *mut vtkXMLPartitionedDataSetCollectionWriter;
+        fn vtk_xml_partitioned_data_set_collection_writer_delete(
+            writer: Pin<&mut vtkXMLPartitionedDataSetCollectionWriter>
+        );
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLPartitionedDataSetCollectionWriter.html",
+    @name XMLPartitionedDataSetCollectionWriter, ffi::vtkXMLPartitionedDataSetCollectionWriter,
+    @new ffi::vtk_xml_partitioned_data_set_collection_writer_new,
+    @delete ffi::vtk_xml_partitioned_data_set_collection_writer_delete
+);
+
+crate::vtk_xml_writer::impl_xml_writer!(
+    XMLPartitionedDataSetCollectionWriter, ffi::vtkXMLPartitionedDataSetCollectionWriter,
+    @composite PartitionedDataSetCollection, "partitioned dataset collection",
+    @extension "vtpc"
+);
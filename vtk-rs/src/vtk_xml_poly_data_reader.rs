@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_poly_data_reader.h");
+
+        type vtkXMLPolyDataReader;
+
+        fn vtk_xml_poly_data_reader_new() -> *mut vtkXMLPolyDataReader;
+        fn vtk_xml_poly_data_reader_delete(reader: Pin<&mut vtkXMLPolyDataReader>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLPolyDataReader.html",
+    @name XMLPolyDataReader, ffi::vtkXMLPolyDataReader,
+    @new ffi::vtk_xml_poly_data_reader_new,
+    @delete ffi::vtk_xml_poly_data_reader_delete
+);
+
+crate::vtk_xml_writer::impl_xml_reader!(
+    XMLPolyDataReader, ffi::vtkXMLPolyDataReader,
+    @data PolyData, "poly data",
+    @extension "vtp"
+);
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_poly_data_writer.h");
+
+        type vtkXMLPolyDataWriter;
+
+        fn vtk_xml_poly_data_writer_new() -> *mut vtkXMLPolyDataWriter;
+        fn vtk_xml_poly_data_writer_delete(writer: Pin<&mut vtkXMLPolyDataWriter>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLPolyDataWriter.html",
+    @name XMLPolyDataWriter, ffi::vtkXMLPolyDataWriter,
+    @new ffi::vtk_xml_poly_data_writer_new,
+    @delete ffi::vtk_xml_poly_data_writer_delete
+);
+
+crate::vtk_xml_writer::impl_xml_writer!(
+    XMLPolyDataWriter, ffi::vtkXMLPolyDataWriter,
+    @data PolyData, "poly data",
+    @extension "vtp"
+);
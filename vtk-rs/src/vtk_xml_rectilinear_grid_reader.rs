@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_rectilinear_grid_reader.h");
+
+        type vtkXMLRectilinearGridReader;
+
+        fn vtk_xml_rectilinear_grid_reader_new() -> *mut vtkXMLRectilinearGridReader;
+        fn vtk_xml_rectilinear_grid_reader_delete(reader: Pin<&mut vtkXMLRectilinearGridReader>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLRectilinearGridReader.html",
+    @name XMLRectilinearGridReader, ffi::vtkXMLRectilinearGridReader,
+    @new ffi::vtk_xml_rectilinear_grid_reader_new,
+    @delete ffi::vtk_xml_rectilinear_grid_reader_delete
+);
+
+crate::vtk_xml_writer::impl_xml_reader!(
+    XMLRectilinearGridReader, ffi::vtkXMLRectilinearGridReader,
+    @data RectilinearGrid, "rectilinear grid",
+    @extension "vtr"
+);
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_rectilinear_grid_writer.h");
+
+        type vtkXMLRectilinearGridWriter;
+
+        fn vtk_xml_rectilinear_grid_writer_new() -> *mut vtkXMLRectilinearGridWriter;
+        fn vtk_xml_rectilinear_grid_writer_delete(writer: Pin<&mut vtkXMLRectilinearGridWriter>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLRectilinearGridWriter.html",
+    @name XMLRectilinearGridWriter, ffi::vtkXMLRectilinearGridWriter,
+    @new ffi::vtk_xml_rectilinear_grid_writer_new,
+    @delete ffi::vtk_xml_rectilinear_grid_writer_delete
+);
+
+crate::vtk_xml_writer::impl_xml_writer!(
+    XMLRectilinearGridWriter, ffi::vtkXMLRectilinearGridWriter,
+    @data RectilinearGrid, "rectilinear grid",
+    @extension "vtr"
+);
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_structured_grid_reader.h");
+
+        type vtkXMLStructuredGridReader;
+
+        fn vtk_xml_structured_grid_reader_new() -> *mut vtkXMLStructuredGridReader;
+        fn vtk_xml_structured_grid_reader_delete(reader: Pin<&mut vtkXMLStructuredGridReader>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLStructuredGridReader.html",
+    @name XMLStructuredGridReader, ffi::vtkXMLStructuredGridReader,
+    @new ffi::vtk_xml_structured_grid_reader_new,
+    @delete ffi::vtk_xml_structured_grid_reader_delete
+);
+
+crate::vtk_xml_writer::impl_xml_reader!(
+    XMLStructuredGridReader, ffi::vtkXMLStructuredGridReader,
+    @data StructuredGrid, "structured grid",
+    @extension "vts"
+);
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_structured_grid_writer.h");
+
+        type vtkXMLStructuredGridWriter;
+
+        fn vtk_xml_structured_grid_writer_new() -> *mut vtkXMLStructuredGridWriter;
+        fn vtk_xml_structured_grid_writer_delete(writer: Pin<&mut vtkXMLStructuredGridWriter>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLStructuredGridWriter.html",
+    @name XMLStructuredGridWriter, ffi::vtkXMLStructuredGridWriter,
+    @new ffi::vtk_xml_structured_grid_writer_new,
+    @delete ffi::vtk_xml_structured_grid_writer_delete
+);
+
+crate::vtk_xml_writer::impl_xml_writer!(
+    XMLStructuredGridWriter, ffi::vtkXMLStructuredGridWriter,
+    @data StructuredGrid, "structured grid",
+    @extension "vts"
+);
@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_unstructured_grid_reader.h");
+
+        type vtkXMLUnstructuredGridReader;
+
+        fn vtk_xml_unstructured_grid_reader_new() -> *mut vtkXMLUnstructuredGridReader;
+        fn vtk_xml_unstructured_grid_reader_delete(reader: Pin<&mut vtkXMLUnstructuredGridReader>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLUnstructuredGridReader.html",
+    @name XMLUnstructuredGridReader, ffi::vtkXMLUnstructuredGridReader,
+    @new ffi::vtk_xml_unstructured_grid_reader_new,
+    @delete ffi::vtk_xml_unstructured_grid_reader_delete
+);
+
+crate::vtk_xml_writer::impl_xml_reader!(
+    XMLUnstructuredGridReader, ffi::vtkXMLUnstructuredGridReader,
+    @data UnstructuredGrid, "unstructured grid",
+    @extension "vtu"
+);
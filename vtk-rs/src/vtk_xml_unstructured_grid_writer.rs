@@ -0,0 +1,24 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_unstructured_grid_writer.h");
+
+        type vtkXMLUnstructuredGridWriter;
+
+        fn vtk_xml_unstructured_grid_writer_new() -> *mut vtkXMLUnstructuredGridWriter;
+        fn vtk_xml_unstructured_grid_writer_delete(writer: Pin<&mut vtkXMLUnstructuredGridWriter>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkXMLUnstructuredGridWriter.html",
+    @name XMLUnstructuredGridWriter, ffi::vtkXMLUnstructuredGridWriter,
+    @new ffi::vtk_xml_unstructured_grid_writer_new,
+    @delete ffi::vtk_xml_unstructured_grid_writer_delete
+);
+
+crate::vtk_xml_writer::impl_xml_writer!(
+    XMLUnstructuredGridWriter, ffi::vtkXMLUnstructuredGridWriter,
+    @data UnstructuredGrid, "unstructured grid",
+    @extension "vtu"
+);
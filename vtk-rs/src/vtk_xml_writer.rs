@@ -0,0 +1,459 @@
+//! Options and bindings shared by the XML readers and writers (`.vtp`, `.vtu`, ...)
+//!
+//! See [`vtkXMLWriter`](https://vtk.org/doc/nightly/html/classvtkXMLWriter.html) and
+//! [`vtkXMLReader`](https://vtk.org/doc/nightly/html/classvtkXMLReader.html).
+
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_xml_writer.h");
+
+        pub type vtkXMLWriter;
+        pub type vtkXMLReader;
+        pub type vtkDataObject;
+        pub type vtkAlgorithmOutput;
+
+        // Writers
+        fn xml_writer_set_file_name(writer: Pin<&mut vtkXMLWriter>, file_name: &str);
+        fn xml_writer_get_file_name(writer: &vtkXMLWriter) -> String;
+        fn xml_writer_get_default_file_extension(writer: Pin<&mut vtkXMLWriter>) -> String;
+        fn xml_writer_set_data_mode(writer: Pin<&mut vtkXMLWriter>, mode: i32);
+        fn xml_writer_get_data_mode(writer: &vtkXMLWriter) -> i32;
+        fn xml_writer_set_compressor_type(writer: Pin<&mut vtkXMLWriter>, compressor: i32);
+        fn xml_writer_get_compressor_type(writer: &vtkXMLWriter) -> i32;
+        fn xml_writer_set_compression_level(writer: Pin<&mut vtkXMLWriter>, level: i32);
+        fn xml_writer_get_compression_level(writer: &vtkXMLWriter) -> i32;
+        fn xml_writer_set_encode_appended_data(writer: Pin<&mut vtkXMLWriter>, encode: bool);
+        fn xml_writer_get_encode_appended_data(writer: &vtkXMLWriter) -> bool;
+        fn xml_writer_set_write_to_output_string(writer: Pin<&mut vtkXMLWriter>, enabled: bool);
+        fn xml_writer_get_output_string(writer: &vtkXMLWriter) -> Vec<u8>;
+        unsafe fn xml_writer_set_input_connection(
+            writer: Pin<&mut vtkXMLWriter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn xml_writer_set_input_data(
+            writer: Pin<&mut vtkXMLWriter>,
+            data: *mut vtkDataObject
+        );
+        fn xml_writer_write(writer: Pin<&mut vtkXMLWriter>) -> Result<()>;
+
+        // Readers
+        fn xml_reader_set_file_name(reader: Pin<&mut vtkXMLReader>, file_name: &str);
+        fn xml_reader_get_file_name(reader: &vtkXMLReader) -> String;
+        fn xml_reader_can_read_file(reader: Pin<&mut vtkXMLReader>, file_name: &str) -> bool;
+        fn xml_reader_update(reader: Pin<&mut vtkXMLReader>) -> Result<()>;
+        fn xml_reader_get_number_of_point_arrays(reader: Pin<&mut vtkXMLReader>) -> i32;
+        fn xml_reader_get_point_array_name(reader: Pin<&mut vtkXMLReader>, index: i32) -> String;
+        fn xml_reader_get_number_of_cell_arrays(reader: Pin<&mut vtkXMLReader>) -> i32;
+        fn xml_reader_get_cell_array_name(reader: Pin<&mut vtkXMLReader>, index: i32) -> String;
+        fn xml_reader_get_output(reader: Pin<&mut vtkXMLReader>) -> *mut vtkDataObject;
+        unsafe fn xml_reader_get_output_port(
+            reader: Pin<&mut vtkXMLReader>
+        ) -> *mut vtkAlgorithmOutput;
+    }
+}
+
+/// How the data arrays are stored inside an XML file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XMLDataMode {
+    /// Human readable text inside each `<DataArray>` element
+    Ascii = 0,
+    /// Base64 encoded binary inside each `<DataArray>` element
+    Binary = 1,
+    /// All arrays in a single `<AppendedData>` section at the end of the file (VTK default)
+    Appended = 2,
+}
+
+impl XMLDataMode {
+    pub(crate) fn from_raw(mode: i32) -> Self {
+        match mode {
+            0 => XMLDataMode::Ascii,
+            1 => XMLDataMode::Binary,
+            _ => XMLDataMode::Appended,
+        }
+    }
+}
+
+/// Compression applied to binary and appended data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XMLCompressorType {
+    None = 0,
+    ZLib = 1,
+    LZ4 = 2,
+    LZMA = 3,
+}
+
+impl XMLCompressorType {
+    pub(crate) fn from_raw(compressor: i32) -> Self {
+        match compressor {
+            1 => XMLCompressorType::ZLib,
+            2 => XMLCompressorType::LZ4,
+            3 => XMLCompressorType::LZMA,
+            _ => XMLCompressorType::None,
+        }
+    }
+}
+
+/// Implement the [`vtkXMLWriter`](https://vtk.org/doc/nightly/html/classvtkXMLWriter.html)
+/// methods for a writer of `crate::$data`, whose files end in `.$extension`
+///
+/// `@data` is for datasets and `@composite` for composite datasets, whose writers also
+/// write one file per block and so can't write to a string.
+macro_rules! impl_xml_writer {
+    (
+        $name:ident, $ptr_type:ty,
+        @data $data:ident, $description:literal,
+        @extension $extension:literal
+    ) => {
+        crate::vtk_xml_writer::impl_xml_writer!(@common $name, $ptr_type, $extension);
+
+        impl $name {
+            /// Write into a string instead of a file (see
+            /// [`get_output_string`](Self::get_output_string))
+            #[doc(alias = "SetWriteToOutputString")]
+            pub fn set_write_to_output_string(&mut self, enabled: bool) {
+                crate::vtk_xml_writer::ffi::xml_writer_set_write_to_output_string(
+                    self.as_xml_writer_mut(),
+                    enabled
+                );
+            }
+
+            /// Get the result of the last write when writing to a string
+            #[doc(alias = "GetBinaryOutputString")]
+            pub fn get_output_bytes(&self) -> Vec<u8> {
+                crate::vtk_xml_writer::ffi::xml_writer_get_output_string(self.as_xml_writer())
+            }
+
+            /// Get the result of the last write as text (only meaningful for ASCII output)
+            #[doc(alias = "GetOutputString")]
+            pub fn get_output_string(&self) -> String {
+                String::from_utf8_lossy(&self.get_output_bytes()).into_owned()
+            }
+
+            #[doc = concat!("Sets the ", $description, " to write")]
+            #[doc(alias = "SetInputData")]
+            pub fn set_input_data(&mut self, data: &crate::$data) {
+                unsafe {
+                    let ptr = crate::DataSet::as_data_set_ptr(data) as *mut _;
+                    crate::vtk_xml_writer::ffi::xml_writer_set_input_data(
+                        self.as_xml_writer_mut(),
+                        ptr
+                    );
+                }
+            }
+        }
+    };
+    (
+        $name:ident, $ptr_type:ty,
+        @composite $data:ident, $description:literal,
+        @extension $extension:literal
+    ) => {
+        crate::vtk_xml_writer::impl_xml_writer!(@common $name, $ptr_type, $extension);
+
+        impl $name {
+            #[doc = concat!("Sets the ", $description, " to write")]
+            #[doc(alias = "SetInputData")]
+            pub fn set_input_data(&mut self, data: &crate::$data) {
+                unsafe {
+                    let ptr = crate::CompositeDataSet::as_composite_data_set_ptr(data) as *mut _;
+                    crate::vtk_xml_writer::ffi::xml_writer_set_input_data(
+                        self.as_xml_writer_mut(),
+                        ptr
+                    );
+                }
+            }
+        }
+    };
+    (@common $name:ident, $ptr_type:ty, $extension:literal) => {
+        crate::impl_as_ref_mut!(crate::vtk_xml_writer::ffi::vtkXMLWriter, $ptr_type);
+
+        impl $name {
+            fn as_xml_writer(&self) -> &crate::vtk_xml_writer::ffi::vtkXMLWriter {
+                self.ptr.as_ref().get_ref().as_ref()
+            }
+
+            fn as_xml_writer_mut(
+                &mut self
+            ) -> core::pin::Pin<&mut crate::vtk_xml_writer::ffi::vtkXMLWriter> {
+                unsafe { self.ptr.as_mut().map_unchecked_mut(|x| x.as_mut()) }
+            }
+
+            #[doc = concat!(
+                "Set the name of the file to write (usually ending in `.", $extension, "`)"
+            )]
+            #[doc(alias = "SetFileName")]
+            pub fn set_file_name(&mut self, file_name: &str) {
+                crate::vtk_xml_writer::ffi::xml_writer_set_file_name(
+                    self.as_xml_writer_mut(),
+                    file_name
+                );
+            }
+
+            #[doc(alias = "GetFileName")]
+            pub fn get_file_name(&self) -> String {
+                crate::vtk_xml_writer::ffi::xml_writer_get_file_name(self.as_xml_writer())
+            }
+
+            #[doc = concat!(
+                "Get the file extension ParaView expects for this writer (`", $extension, "`)"
+            )]
+            #[doc(alias = "GetDefaultFileExtension")]
+            pub fn get_default_file_extension(&mut self) -> String {
+                crate::vtk_xml_writer::ffi::xml_writer_get_default_file_extension(
+                    self.as_xml_writer_mut()
+                )
+            }
+
+            /// Choose how the arrays are stored in the file
+            #[doc(alias = "SetDataMode")]
+            pub fn set_data_mode(&mut self, mode: crate::XMLDataMode) {
+                crate::vtk_xml_writer::ffi::xml_writer_set_data_mode(
+                    self.as_xml_writer_mut(),
+                    mode as i32
+                );
+            }
+
+            #[doc(alias = "GetDataMode")]
+            pub fn get_data_mode(&self) -> crate::XMLDataMode {
+                crate::XMLDataMode::from_raw(
+                    crate::vtk_xml_writer::ffi::xml_writer_get_data_mode(self.as_xml_writer())
+                )
+            }
+
+            #[doc(alias = "SetDataModeToAscii")]
+            pub fn set_data_mode_to_ascii(&mut self) {
+                self.set_data_mode(crate::XMLDataMode::Ascii);
+            }
+
+            #[doc(alias = "SetDataModeToBinary")]
+            pub fn set_data_mode_to_binary(&mut self) {
+                self.set_data_mode(crate::XMLDataMode::Binary);
+            }
+
+            #[doc(alias = "SetDataModeToAppended")]
+            pub fn set_data_mode_to_appended(&mut self) {
+                self.set_data_mode(crate::XMLDataMode::Appended);
+            }
+
+            /// Choose the compression of binary and appended data (ignored in ASCII mode)
+            #[doc(alias = "SetCompressorType")]
+            pub fn set_compressor_type(&mut self, compressor: crate::XMLCompressorType) {
+                crate::vtk_xml_writer::ffi::xml_writer_set_compressor_type(
+                    self.as_xml_writer_mut(),
+                    compressor as i32
+                );
+            }
+
+            #[doc(alias = "GetCompressorType")]
+            pub fn get_compressor_type(&self) -> crate::XMLCompressorType {
+                crate::XMLCompressorType::from_raw(
+                    crate::vtk_xml_writer::ffi::xml_writer_get_compressor_type(
+                        self.as_xml_writer()
+                    )
+                )
+            }
+
+            #[doc(alias = "SetCompressorTypeToNone")]
+            pub fn set_compressor_type_to_none(&mut self) {
+                self.set_compressor_type(crate::XMLCompressorType::None);
+            }
+
+            #[doc(alias = "SetCompressorTypeToZLib")]
+            pub fn set_compressor_type_to_zlib(&mut self) {
+                self.set_compressor_type(crate::XMLCompressorType::ZLib);
+            }
+
+            /// Set the compression level (1 = fastest, 9 = smallest)
+            #[doc(alias = "SetCompressionLevel")]
+            pub fn set_compression_level(&mut self, level: i32) {
+                crate::vtk_xml_writer::ffi::xml_writer_set_compression_level(
+                    self.as_xml_writer_mut(),
+                    level
+                );
+            }
+
+            #[doc(alias = "GetCompressionLevel")]
+            pub fn get_compression_level(&self) -> i32 {
+                crate::vtk_xml_writer::ffi::xml_writer_get_compression_level(self.as_xml_writer())
+            }
+
+            /// Base64 encode the appended data section (disable for raw, smaller files)
+            #[doc(alias = "SetEncodeAppendedData")]
+            pub fn set_encode_appended_data(&mut self, encode: bool) {
+                crate::vtk_xml_writer::ffi::xml_writer_set_encode_appended_data(
+                    self.as_xml_writer_mut(),
+                    encode
+                );
+            }
+
+            #[doc(alias = "GetEncodeAppendedData")]
+            pub fn get_encode_appended_data(&self) -> bool {
+                crate::vtk_xml_writer::ffi::xml_writer_get_encode_appended_data(
+                    self.as_xml_writer()
+                )
+            }
+
+            /// Sets the input from an algorithm output port
+            #[doc(alias = "SetInputConnection")]
+            pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+                unsafe {
+                    let algo_output = output.into() as *mut _;
+                    crate::vtk_xml_writer::ffi::xml_writer_set_input_connection(
+                        self.as_xml_writer_mut(),
+                        algo_output
+                    );
+                }
+            }
+
+            /// Write the file
+            #[doc(alias = "Write")]
+            pub fn write(&mut self) -> Result<(), crate::VtkError> {
+                crate::vtk_xml_writer::ffi::xml_writer_write(self.as_xml_writer_mut())
+                    .map_err(|e| crate::VtkError::write_failed(self.get_file_name(), e.what()))
+            }
+        }
+    };
+}
+
+/// Implement the [`vtkXMLReader`](https://vtk.org/doc/nightly/html/classvtkXMLReader.html)
+/// methods for a reader of `crate::$data`, whose files end in `.$extension`
+///
+/// The optional `@note` is appended to the documentation of `update`.
+macro_rules! impl_xml_reader {
+    (
+        $name:ident, $ptr_type:ty,
+        @data $data:ident, $description:literal,
+        @extension $extension:literal
+        $(, @note $note:literal)?
+    ) => {
+        crate::impl_as_ref_mut!(crate::vtk_xml_writer::ffi::vtkXMLReader, $ptr_type);
+
+        impl $name {
+            fn as_xml_reader(&self) -> &crate::vtk_xml_writer::ffi::vtkXMLReader {
+                self.ptr.as_ref().get_ref().as_ref()
+            }
+
+            fn as_xml_reader_mut(
+                &mut self
+            ) -> core::pin::Pin<&mut crate::vtk_xml_writer::ffi::vtkXMLReader> {
+                unsafe { self.ptr.as_mut().map_unchecked_mut(|x| x.as_mut()) }
+            }
+
+            #[doc = concat!("Set the name of the `.", $extension, "` file to read")]
+            #[doc(alias = "SetFileName")]
+            pub fn set_file_name(&mut self, file_name: &str) {
+                crate::vtk_xml_writer::ffi::xml_reader_set_file_name(
+                    self.as_xml_reader_mut(),
+                    file_name
+                );
+            }
+
+            #[doc(alias = "GetFileName")]
+            pub fn get_file_name(&self) -> String {
+                crate::vtk_xml_writer::ffi::xml_reader_get_file_name(self.as_xml_reader())
+            }
+
+            #[doc = concat!("Check whether the given file is a VTK XML ", $description, " file")]
+            #[doc(alias = "CanReadFile")]
+            pub fn can_read_file(&mut self, file_name: &str) -> bool {
+                crate::vtk_xml_writer::ffi::xml_reader_can_read_file(
+                    self.as_xml_reader_mut(),
+                    file_name
+                )
+            }
+
+            /// Read the file
+            ///
+            #[doc = concat!(
+                "Fails if the file does not exist, is not a VTK XML ", $description,
+                " file or its content is corrupt."
+            )]
+            $(#[doc = $note])?
+            #[doc(alias = "Update")]
+            pub fn update(&mut self) -> Result<(), crate::VtkError> {
+                let file_name = self.get_file_name();
+                crate::error::check_readable(std::path::Path::new(&file_name))?;
+                if !self.can_read_file(&file_name) {
+                    return Err(crate::VtkError::invalid_file(
+                        &file_name,
+                        concat!("not a VTK XML ", $description, " file")
+                    ));
+                }
+                crate::vtk_xml_writer::ffi::xml_reader_update(self.as_xml_reader_mut())
+                    .map_err(|e| crate::VtkError::invalid_file(&file_name, e.what()))
+            }
+
+            /// Get the number of point arrays in the file (available after
+            /// [`update`](Self::update))
+            #[doc(alias = "GetNumberOfPointArrays")]
+            pub fn get_number_of_point_arrays(&mut self) -> i32 {
+                crate::vtk_xml_writer::ffi::xml_reader_get_number_of_point_arrays(
+                    self.as_xml_reader_mut()
+                )
+            }
+
+            #[doc(alias = "GetPointArrayName")]
+            pub fn get_point_array_name(&mut self, index: i32) -> String {
+                crate::vtk_xml_writer::ffi::xml_reader_get_point_array_name(
+                    self.as_xml_reader_mut(),
+                    index
+                )
+            }
+
+            /// Get the number of cell arrays in the file (available after
+            /// [`update`](Self::update))
+            #[doc(alias = "GetNumberOfCellArrays")]
+            pub fn get_number_of_cell_arrays(&mut self) -> i32 {
+                crate::vtk_xml_writer::ffi::xml_reader_get_number_of_cell_arrays(
+                    self.as_xml_reader_mut()
+                )
+            }
+
+            #[doc(alias = "GetCellArrayName")]
+            pub fn get_cell_array_name(&mut self, index: i32) -> String {
+                crate::vtk_xml_writer::ffi::xml_reader_get_cell_array_name(
+                    self.as_xml_reader_mut(),
+                    index
+                )
+            }
+
+            #[doc = concat!("Get a copy of the ", $description, " that was read")]
+            #[doc(alias = "GetOutput")]
+            pub fn get_output(&mut self) -> crate::$data {
+                unsafe {
+                    let ptr = crate::vtk_xml_writer::ffi::xml_reader_get_output(
+                        self.as_xml_reader_mut()
+                    );
+                    crate::$data::from_raw(ptr as *mut _)
+                }
+            }
+
+            /// Get the output port for connecting to filters
+            #[doc(alias = "GetOutputPort")]
+            pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+                unsafe {
+                    let ptr = crate::vtk_xml_writer::ffi::xml_reader_get_output_port(
+                        self.as_xml_reader_mut()
+                    );
+                    crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+                }
+            }
+
+            #[doc = concat!(
+                "Read a `.", $extension, "` file into a [`", stringify!($data), "`](crate::",
+                stringify!($data), ")"
+            )]
+            pub fn read(
+                path: impl AsRef<std::path::Path>
+            ) -> Result<crate::$data, crate::VtkError> {
+                let mut reader = Self::new();
+                reader.set_file_name(crate::error::path_to_str(path.as_ref())?);
+                reader.update()?;
+                Ok(reader.get_output())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_xml_reader;
+pub(crate) use impl_xml_writer;
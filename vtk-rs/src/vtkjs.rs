@@ -0,0 +1,212 @@
+//! vtk.js scene archives (`.vtkjs`).
+//!
+//! A `.vtkjs` file is a zip archive of the directory written by
+//! [`crate::JSONSceneExporter`]: an `index.json` describing the scene (actors,
+//! coloring, lookup tables and camera) and a `data/` directory with the arrays.
+//! vtk.js loads it with `vtkHttpSceneLoader` or the standalone scene viewer, so the
+//! archive can be served from any static file server.
+//!
+//! The archive is written uncompressed, as the arrays are already compressed by the
+//! exporter.
+
+use std::io::Write;
+use std::path::{ Path, PathBuf };
+
+use crate::VtkError;
+
+/// Pack the scene directory written by [`crate::JSONSceneExporter`] into a `.vtkjs` archive
+///
+/// All files below `directory` are stored with paths relative to it, so
+/// `index.json` is at the root of the archive.
+pub fn write_vtkjs_archive(
+    directory: impl AsRef<Path>,
+    path: impl AsRef<Path>
+) -> Result<(), VtkError> {
+    let directory = directory.as_ref();
+    let path = path.as_ref();
+    if !directory.join("index.json").is_file() {
+        return Err(VtkError::invalid_file(directory, "directory has no index.json"));
+    }
+
+    let mut files = Vec::new();
+    collect_files(directory, &mut files)
+        .map_err(|e| VtkError::invalid_file(directory, e.to_string()))?;
+    files.sort();
+
+    let mut entries = Vec::with_capacity(files.len());
+    for file in &files {
+        let name = file
+            .strip_prefix(directory)
+            .unwrap_or(file)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let data = std::fs::read(file).map_err(|e| VtkError::invalid_file(file, e.to_string()))?;
+        entries.push((name, data));
+    }
+
+    let archive = zip_stored(&entries).map_err(|message| VtkError::write_failed(path, message))?;
+    let mut file = std::fs::File::create(path)
+        .map_err(|e| VtkError::write_failed(path, e.to_string()))?;
+    file.write_all(&archive).map_err(|e| VtkError::write_failed(path, e.to_string()))
+}
+
+fn collect_files(directory: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Build a zip archive storing the entries without compression
+fn zip_stored(entries: &[(String, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    // Without the zip64 extension sizes, offsets and counts are limited
+    let limit = |value: usize, what: &str| -> Result<u32, String> {
+        u32::try_from(value).map_err(|_| format!("{} exceeds the 4 GiB zip limit", what))
+    };
+    if entries.len() > u16::MAX as usize {
+        return Err(format!("too many files for a zip archive ({})", entries.len()));
+    }
+
+    // 1980-01-01 00:00, the earliest DOS date, keeps archives reproducible
+    const DOS_TIME: u16 = 0;
+    const DOS_DATE: u16 = (1 << 5) | 1;
+    // Names are UTF-8
+    const FLAGS: u16 = 1 << 11;
+
+    let mut archive = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in entries {
+        let offset = limit(archive.len(), "archive")?;
+        let size = limit(data.len(), name)?;
+        let crc = crc32(data);
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&FLAGS.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes()); // stored
+        header.extend_from_slice(&DOS_TIME.to_le_bytes());
+        header.extend_from_slice(&DOS_DATE.to_le_bytes());
+        header.extend_from_slice(&crc.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+
+        archive.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        archive.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        archive.extend_from_slice(&header);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(data);
+
+        central.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        central.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        central.extend_from_slice(&header);
+        central.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        central.extend_from_slice(&0u16.to_le_bytes()); // disk number
+        central.extend_from_slice(&0u16.to_le_bytes()); // internal attributes
+        central.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+
+    let central_offset = limit(archive.len(), "archive")?;
+    let central_size = limit(central.len(), "central directory")?;
+    archive.extend_from_slice(&central);
+    archive.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes()); // this disk
+    archive.extend_from_slice(&0u16.to_le_bytes()); // disk with the central directory
+    archive.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&central_size.to_le_bytes());
+    archive.extend_from_slice(&central_offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes()); // comment length
+    Ok(archive)
+}
+
+/// CRC-32 (IEEE) as used by zip
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn u16_at(data: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    }
+
+    fn u32_at(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn archive_lists_all_entries() {
+        let entries = vec![
+            ("index.json".to_string(), b"{}".to_vec()),
+            ("data/abc".to_string(), vec![1, 2, 3, 4, 5]),
+        ];
+        let archive = zip_stored(&entries).unwrap();
+
+        // End of central directory record
+        let end = archive.len() - 22;
+        assert_eq!(u32_at(&archive, end), 0x06054b50);
+        assert_eq!(u16_at(&archive, end + 10), 2);
+        let central_offset = u32_at(&archive, end + 16) as usize;
+
+        // Walk the central directory and check each local entry
+        let mut offset = central_offset;
+        for (name, data) in &entries {
+            assert_eq!(u32_at(&archive, offset), 0x02014b50);
+            assert_eq!(u32_at(&archive, offset + 16), crc32(data));
+            let name_length = u16_at(&archive, offset + 28) as usize;
+            assert_eq!(&archive[offset + 46..offset + 46 + name_length], name.as_bytes());
+
+            let local = u32_at(&archive, offset + 42) as usize;
+            assert_eq!(u32_at(&archive, local), 0x04034b50);
+            let start = local + 30 + name_length;
+            assert_eq!(&archive[start..start + data.len()], &data[..]);
+            offset += 46 + name_length;
+        }
+        assert_eq!(offset, end);
+    }
+
+    #[test]
+    fn writes_directory_with_relative_names() {
+        let directory = std::env::temp_dir().join(format!("vtkjs_test_{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("data")).unwrap();
+        std::fs::write(directory.join("index.json"), b"{\"version\": 1}").unwrap();
+        std::fs::write(directory.join("data").join("0123"), b"points").unwrap();
+        let path = directory.with_extension("vtkjs");
+
+        write_vtkjs_archive(&directory, &path).unwrap();
+        let archive = std::fs::read(&path).unwrap();
+        let contains = |name: &[u8]| archive.windows(name.len()).any(|window| window == name);
+        assert!(contains(b"index.json"));
+        assert!(contains(b"data/0123"));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(write_vtkjs_archive(&directory, &path).is_err());
+    }
+}